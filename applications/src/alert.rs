@@ -0,0 +1,16 @@
+mod alert_application_error;
+mod alert_event_data;
+mod alert_rule_data;
+mod alert_rule_update_command;
+mod alert_service;
+mod alert_service_impl;
+mod inmemory_alert_repository_impl;
+
+pub use alert_application_error::AlertApplicationError;
+pub use alert_application_error::AlertApplicationResult;
+pub use alert_event_data::AlertEventData;
+pub use alert_rule_data::AlertRuleData;
+pub use alert_rule_update_command::AlertRuleUpdateCommand;
+pub use alert_service::AlertService;
+pub use alert_service_impl::AlertServiceImpl;
+pub use inmemory_alert_repository_impl::InmemoryAlertRepositoryImpl;
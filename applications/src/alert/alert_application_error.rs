@@ -0,0 +1,108 @@
+use thiserror::Error;
+
+use crate::stock::StockQueryError;
+use domain::{
+    alert::AlertDomainError, money::MoneyDomainError, stock::StockDomainError,
+    user::UserDomainError,
+};
+
+#[derive(Error, Debug)]
+pub enum AlertApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("user not found: id={0:?}")]
+    UserNotFound(String),
+    #[error("user is already exsist: id={0:?}")]
+    UserAlreadyExist(String),
+    #[error("alert rule not found: id={0}")]
+    AlertRuleNotFound(i32),
+    #[error("alert event not found: id={0}")]
+    AlertEventNotFound(i32),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+}
+
+pub type AlertApplicationResult<T> = Result<T, AlertApplicationError>;
+
+impl From<AlertDomainError> for AlertApplicationError {
+    fn from(value: AlertDomainError) -> Self {
+        match value {
+            AlertDomainError::Disconnect(e) => Self::Disconnect(e),
+            AlertDomainError::InvalidCondition(value) => Self::InvalidParameter {
+                name: "condition",
+                value,
+            },
+            AlertDomainError::InvalidThreshold(value) => Self::InvalidParameter {
+                name: "threshold",
+                value: value.map(|v| v.to_string()).unwrap_or_default(),
+            },
+        }
+    }
+}
+
+impl From<MoneyDomainError> for AlertApplicationError {
+    fn from(value: MoneyDomainError) -> Self {
+        match value {
+            MoneyDomainError::InvalidPrice(value) => Self::InvalidParameter {
+                name: "price",
+                value: value.to_string(),
+            },
+            MoneyDomainError::InvalidQuantity(value) => Self::InvalidParameter {
+                name: "quantity",
+                value: value.to_string(),
+            },
+            MoneyDomainError::Overflow(value) => Self::InvalidParameter {
+                name: "amount",
+                value,
+            },
+        }
+    }
+}
+
+impl From<StockDomainError> for AlertApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
+
+impl From<UserDomainError> for AlertApplicationError {
+    fn from(value: UserDomainError) -> Self {
+        match value {
+            UserDomainError::Disconnect(e) => Self::Disconnect(e),
+            UserDomainError::UserAlreadyExist(user_id) => Self::UserAlreadyExist(user_id.into()),
+            UserDomainError::UserNotFound(user_id) => Self::UserNotFound(user_id.into()),
+            UserDomainError::InvalidUserEmail(value) => Self::InvalidParameter {
+                name: "email",
+                value,
+            },
+            UserDomainError::InvalidUserName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
+        }
+    }
+}
+
+impl From<StockQueryError> for AlertApplicationError {
+    fn from(value: StockQueryError) -> Self {
+        match value {
+            StockQueryError::Disconnect(e) => Self::Disconnect(e),
+            StockQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            StockQueryError::InvalidRangeOfDate { name, value } => Self::InvalidParameter {
+                name,
+                value: value.to_string(),
+            },
+            StockQueryError::StockDataNotFound(stock_id) => Self::InvalidParameter {
+                name: "stock_id",
+                value: stock_id,
+            },
+        }
+    }
+}
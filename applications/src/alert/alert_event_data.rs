@@ -0,0 +1,39 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use rust_decimal::Decimal;
+
+use domain::alert::AlertEvent;
+
+/// アラートの通知
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AlertEventData {
+    pub id: i32,
+    pub rule_id: i32,
+    pub user_id: String,
+    pub stock_id: String,
+    pub condition: String,
+    pub threshold: Option<Decimal>,
+    /// 条件を満たした株価の日付
+    pub date: NaiveDate,
+    /// 終値
+    pub price: Decimal,
+    pub read: bool,
+    /// 通知日時(UTC)
+    pub triggered_at: NaiveDateTime,
+}
+
+impl From<AlertEvent> for AlertEventData {
+    fn from(value: AlertEvent) -> Self {
+        Self {
+            id: value.id.map(i32::from).unwrap_or_default(),
+            rule_id: *value.rule_id,
+            user_id: value.user_id.to_string(),
+            stock_id: value.stock_id.to_string(),
+            condition: value.condition.as_str().to_string(),
+            threshold: value.condition.threshold(),
+            date: value.date,
+            price: value.price.into(),
+            read: value.read,
+            triggered_at: value.triggered_at,
+        }
+    }
+}
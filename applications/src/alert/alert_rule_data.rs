@@ -0,0 +1,70 @@
+use rust_decimal::Decimal;
+
+use crate::alert::AlertApplicationError;
+use domain::{
+    alert::{AlertCondition, AlertId, AlertRule},
+    stock::StockId,
+    user::UserId,
+};
+
+/// アラートのルール
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AlertRuleData {
+    /// 未登録の場合はNone
+    pub id: Option<i32>,
+    pub user_id: String,
+    pub stock_id: String,
+    /// price_above / price_below / change_rate / year_high
+    pub condition: String,
+    /// 価格(円)または前日比(%)、year_highの場合はNone
+    pub threshold: Option<Decimal>,
+    pub enabled: bool,
+}
+
+impl AlertRuleData {
+    /// コンストラクタ
+    pub fn new(
+        user_id: String,
+        stock_id: String,
+        condition: String,
+        threshold: Option<Decimal>,
+    ) -> Self {
+        Self {
+            id: None,
+            user_id,
+            stock_id,
+            condition,
+            threshold,
+            enabled: true,
+        }
+    }
+}
+
+impl From<AlertRule> for AlertRuleData {
+    fn from(value: AlertRule) -> Self {
+        Self {
+            id: value.id.map(i32::from),
+            user_id: value.user_id.to_string(),
+            stock_id: value.stock_id.to_string(),
+            condition: value.condition.as_str().to_string(),
+            threshold: value.condition.threshold(),
+            enabled: value.enabled,
+        }
+    }
+}
+
+impl TryFrom<AlertRuleData> for AlertRule {
+    type Error = AlertApplicationError;
+
+    fn try_from(value: AlertRuleData) -> Result<Self, Self::Error> {
+        let condition = AlertCondition::new(&value.condition, value.threshold)?;
+
+        Ok(AlertRule {
+            id: value.id.map(AlertId::new),
+            user_id: UserId::new(value.user_id),
+            stock_id: StockId::new(value.stock_id)?,
+            condition,
+            enabled: value.enabled,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use rust_decimal::Decimal;
+
+/// アラートのルールの更新内容(Noneの項目は変更しない)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AlertRuleUpdateCommand {
+    pub user_id: String,
+    pub rule_id: i32,
+    pub condition: Option<String>,
+    pub threshold: Option<Decimal>,
+    pub enabled: Option<bool>,
+}
+
+impl AlertRuleUpdateCommand {
+    /// コンストラクタ
+    pub fn new(user_id: String, rule_id: i32) -> Self {
+        Self {
+            user_id,
+            rule_id,
+            ..Default::default()
+        }
+    }
+}
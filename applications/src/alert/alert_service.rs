@@ -0,0 +1,31 @@
+use crate::alert::{AlertApplicationResult, AlertEventData, AlertRuleData, AlertRuleUpdateCommand};
+
+#[async_trait::async_trait]
+pub trait AlertService {
+    /// 登録順に取得する
+    async fn get_rules(&self, user_id: &str) -> AlertApplicationResult<Vec<AlertRuleData>>;
+    async fn add_rule(&self, rule: AlertRuleData) -> AlertApplicationResult<AlertRuleData>;
+    async fn update_rule(
+        &self,
+        update_command: AlertRuleUpdateCommand,
+    ) -> AlertApplicationResult<AlertRuleData>;
+    /// 通知もあわせて削除する
+    async fn remove_rule(&self, user_id: &str, rule_id: i32) -> AlertApplicationResult<()>;
+    /// 新しい順に取得する(unread_onlyがtrueの場合は未読のみ)
+    async fn get_events(
+        &self,
+        user_id: &str,
+        unread_only: bool,
+    ) -> AlertApplicationResult<Vec<AlertEventData>>;
+    async fn mark_read(
+        &self,
+        user_id: &str,
+        event_id: i32,
+        read: bool,
+    ) -> AlertApplicationResult<AlertEventData>;
+    /// 指定した銘柄の最新の株価で有効なルールを判定し、新たに登録した通知を返す
+    ///
+    /// 同じルールの通知は同じ日付の株価に対して1回のみ登録する
+    async fn evaluate(&self, stock_ids: Vec<String>)
+        -> AlertApplicationResult<Vec<AlertEventData>>;
+}
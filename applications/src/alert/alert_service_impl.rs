@@ -0,0 +1,451 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{Duration, Utc};
+
+use crate::{
+    alert::{
+        AlertApplicationError, AlertApplicationResult, AlertEventData, AlertRuleData,
+        AlertRuleUpdateCommand, AlertService,
+    },
+    stock::{QuoteData, StockQueryCommand, StockQueryService},
+};
+use domain::{
+    alert::{AlertCondition, AlertEvent, AlertId, AlertPrice, AlertRepository, AlertRule},
+    money::Price,
+    stock::StockId,
+    user::{UserDomainService, UserId, UserRepository},
+};
+
+/// 52週高値の判定期間(日)
+const YEAR_HIGH_DAYS: i64 = 364;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AlertServiceImpl<T, U, V>
+where
+    T: AlertRepository,
+    U: StockQueryService,
+    V: UserRepository,
+{
+    alert_repository: Arc<T>,
+    stock_query_service: U,
+    user_domain_service: UserDomainService<V>,
+}
+
+impl<T, U, V> AlertServiceImpl<T, U, V>
+where
+    T: AlertRepository,
+    U: StockQueryService,
+    V: UserRepository,
+{
+    /// コンストラクタ
+    pub fn new(
+        alert_repository: &Arc<T>,
+        stock_query_service: U,
+        user_domain_service: UserDomainService<V>,
+    ) -> Self {
+        Self {
+            alert_repository: Arc::clone(alert_repository),
+            stock_query_service,
+            user_domain_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U, V> AlertService for AlertServiceImpl<T, U, V>
+where
+    T: AlertRepository + std::fmt::Debug + Send + Sync,
+    U: StockQueryService + std::fmt::Debug + Send + Sync,
+    V: UserRepository + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn get_rules(&self, user_id: &str) -> AlertApplicationResult<Vec<AlertRuleData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let result = self
+            .alert_repository
+            .find_rules(&user_id)
+            .await?
+            .into_iter()
+            .map(AlertRuleData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_rule(&self, rule: AlertRuleData) -> AlertApplicationResult<AlertRuleData> {
+        let user_id = UserId::new(rule.user_id.clone());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let rule = AlertRule::try_from(AlertRuleData { id: None, ..rule })?;
+        let id = self.alert_repository.save_rule(rule.clone()).await?;
+
+        Ok(AlertRule {
+            id: Some(id),
+            ..rule
+        }
+        .into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn update_rule(
+        &self,
+        update_command: AlertRuleUpdateCommand,
+    ) -> AlertApplicationResult<AlertRuleData> {
+        let user_id = UserId::new(update_command.user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let mut rule = self.find_rule(&user_id, update_command.rule_id).await?;
+        if update_command.condition.is_some() || update_command.threshold.is_some() {
+            let kind = update_command
+                .condition
+                .unwrap_or_else(|| rule.condition.as_str().to_string());
+            let threshold = update_command.threshold.or(rule.condition.threshold());
+            rule.condition = AlertCondition::new(&kind, threshold)?;
+        }
+        if let Some(enabled) = update_command.enabled {
+            rule.enabled = enabled;
+        }
+        self.alert_repository.save_rule(rule.clone()).await?;
+
+        Ok(rule.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_rule(&self, user_id: &str, rule_id: i32) -> AlertApplicationResult<()> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        self.alert_repository
+            .delete_rule(&user_id, &AlertId::new(rule_id))
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_events(
+        &self,
+        user_id: &str,
+        unread_only: bool,
+    ) -> AlertApplicationResult<Vec<AlertEventData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let result = self
+            .alert_repository
+            .find_events(&user_id)
+            .await?
+            .into_iter()
+            .filter(|e| !(unread_only && e.read))
+            .map(AlertEventData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn mark_read(
+        &self,
+        user_id: &str,
+        event_id: i32,
+        read: bool,
+    ) -> AlertApplicationResult<AlertEventData> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let mut event = self
+            .alert_repository
+            .find_events(&user_id)
+            .await?
+            .into_iter()
+            .find(|e| e.id.map(i32::from) == Some(event_id))
+            .ok_or(AlertApplicationError::AlertEventNotFound(event_id))?;
+        event.read = read;
+        self.alert_repository.update_event(event.clone()).await?;
+
+        Ok(event.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn evaluate(
+        &self,
+        stock_ids: Vec<String>,
+    ) -> AlertApplicationResult<Vec<AlertEventData>> {
+        // 証券コードとして不正な銘柄はアラートのルールに登録できないため除く
+        let stock_ids: Vec<StockId> = stock_ids
+            .into_iter()
+            .filter_map(|stock_id| StockId::new(stock_id).ok())
+            .collect();
+        let rules = self.alert_repository.find_enabled_rules(&stock_ids).await?;
+        if rules.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // ルールが登録されている銘柄の最新の株価
+        let mut target_ids: Vec<String> = rules.iter().map(|r| r.stock_id.to_string()).collect();
+        target_ids.sort();
+        target_ids.dedup();
+        let quotes: HashMap<String, QuoteData> = self
+            .stock_query_service
+            .find_latest_many(target_ids)
+            .await?
+            .into_iter()
+            .map(|q| (q.stock_id.clone(), q))
+            .collect();
+
+        let mut prices: HashMap<String, AlertPrice> = HashMap::new();
+        let triggered_at = Utc::now().naive_utc();
+        let mut result = Vec::new();
+        for rule in rules {
+            let Some(quote) = quotes.get(rule.stock_id.as_str()) else {
+                continue;
+            };
+            let Some(rule_id) = rule.id else { continue };
+            let price = match prices.get(quote.stock_id.as_str()) {
+                Some(price) => *price,
+                None => {
+                    let price = self.find_alert_price(quote).await?;
+                    prices.insert(quote.stock_id.clone(), price);
+                    price
+                }
+            };
+            if !rule.condition.is_triggered(&price) {
+                continue;
+            }
+
+            let event = AlertEvent {
+                id: None,
+                rule_id,
+                user_id: rule.user_id,
+                stock_id: rule.stock_id,
+                condition: rule.condition,
+                date: quote.date,
+                price: Price::new(quote.end_price)?,
+                read: false,
+                triggered_at,
+            };
+            if let Some(id) = self.alert_repository.add_event(event.clone()).await? {
+                result.push(AlertEvent {
+                    id: Some(id),
+                    ..event
+                });
+            }
+        }
+
+        Ok(result.into_iter().map(AlertEventData::from).collect())
+    }
+}
+
+impl<T, U, V> AlertServiceImpl<T, U, V>
+where
+    T: AlertRepository + Send + Sync,
+    U: StockQueryService + Send + Sync,
+    V: UserRepository + Send + Sync,
+{
+    async fn find_rule(&self, user_id: &UserId, rule_id: i32) -> AlertApplicationResult<AlertRule> {
+        self.alert_repository
+            .find_rules(user_id)
+            .await?
+            .into_iter()
+            .find(|r| r.id.map(i32::from) == Some(rule_id))
+            .ok_or(AlertApplicationError::AlertRuleNotFound(rule_id))
+    }
+
+    /// 判定に使用する株価(52週高値は修正株価から算出する)
+    async fn find_alert_price(&self, quote: &QuoteData) -> AlertApplicationResult<AlertPrice> {
+        let mut command = StockQueryCommand::new();
+        command.stock_id = Some(quote.stock_id.clone());
+        command.start = quote
+            .date
+            .checked_sub_signed(Duration::days(YEAR_HIGH_DAYS));
+        command.end = Some(quote.date);
+        command.adjusted = true;
+        let mut stocks = self.stock_query_service.find(command).await?;
+
+        let latest = stocks.pop().filter(|s| s.date == quote.date);
+        let year_high = stocks.iter().map(|s| s.high_price).max();
+
+        Ok(AlertPrice {
+            end_price: Price::new(quote.end_price)?,
+            previous_close: quote.previous_close.map(Price::new).transpose()?,
+            high_price: Price::new(latest.map(|s| s.high_price).unwrap_or(quote.end_price))?,
+            year_high: year_high.map(Price::new).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        alert::{
+            AlertApplicationError, AlertRuleData, AlertRuleUpdateCommand, AlertService,
+            AlertServiceImpl, InmemoryAlertRepositoryImpl,
+        },
+        stock::{InmemoryStockQueryServiceImpl, StockData},
+        user::InmemoryUserRepositoryImpl,
+    };
+    use domain::user::{User, UserDomainService, UserEmail, UserId, UserName, UserRepository};
+
+    const USER_ID: &str = "sample user";
+    const STOCK_ID: &str = "1234";
+
+    /// (日, 終値, 高値)
+    async fn setup(prices: &[(u32, i32, i32)]) -> impl AlertService {
+        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
+        for (day, end_price, high_price) in prices {
+            let mut stock = StockData::new();
+            stock.stock_id = STOCK_ID.to_string();
+            stock.date = NaiveDate::from_ymd_opt(2023, 3, *day).unwrap();
+            stock.start_price = Decimal::from(*end_price);
+            stock.end_price = Decimal::from(*end_price);
+            stock.high_price = Decimal::from(*high_price);
+            stock.low_price = Decimal::from(*end_price);
+            stock_query_service.stocks.push(stock);
+        }
+
+        let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
+        let sample_user = User::new(
+            UserId::new(USER_ID.to_string()),
+            UserName::default(),
+            UserEmail::default(),
+        );
+        user_repository.save(sample_user).await.unwrap();
+
+        AlertServiceImpl::new(
+            &Arc::new(InmemoryAlertRepositoryImpl::new()),
+            stock_query_service,
+            UserDomainService::new(&user_repository),
+        )
+    }
+
+    fn rule(condition: &str, threshold: Option<i64>) -> AlertRuleData {
+        AlertRuleData::new(
+            USER_ID.to_string(),
+            STOCK_ID.to_string(),
+            condition.to_string(),
+            threshold.map(Decimal::from),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_and_update_rule() -> anyhow::Result<()> {
+        let service = setup(&[]).await;
+
+        let added = service.add_rule(rule("price_above", Some(1000))).await?;
+        let Some(rule_id) = added.id else {
+            return Err(anyhow!("rule id not assigned"));
+        };
+
+        let mut command = AlertRuleUpdateCommand::new(USER_ID.to_string(), rule_id);
+        command.threshold = Some(Decimal::from(1200));
+        command.enabled = Some(false);
+        let updated = service.update_rule(command).await?;
+
+        assert!(updated.condition == "price_above");
+        assert!(updated.threshold == Some(Decimal::from(1200)));
+        assert!(!updated.enabled);
+        assert!(service.get_rules(USER_ID).await? == vec![updated]);
+
+        service.remove_rule(USER_ID, rule_id).await?;
+        assert!(service.get_rules(USER_ID).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_invalid_rule_return_err() -> anyhow::Result<()> {
+        let service = setup(&[]).await;
+
+        let Err(AlertApplicationError::InvalidParameter {
+            name: "condition", ..
+        }) = service.add_rule(rule("unknown", Some(1))).await
+        else {
+            return Err(anyhow!("unexpected add result"));
+        };
+        let Err(AlertApplicationError::InvalidParameter {
+            name: "threshold", ..
+        }) = service.add_rule(rule("change_rate", None)).await
+        else {
+            return Err(anyhow!("unexpected add result"));
+        };
+        let Err(AlertApplicationError::AlertRuleNotFound(_)) = service
+            .update_rule(AlertRuleUpdateCommand::new(USER_ID.to_string(), 1))
+            .await
+        else {
+            return Err(anyhow!("unexpected update result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn evaluate_price_and_change_rate() -> anyhow::Result<()> {
+        let service = setup(&[(1, 1000, 1000), (2, 1100, 1100)]).await;
+        service.add_rule(rule("price_above", Some(1050))).await?;
+        service.add_rule(rule("price_below", Some(900))).await?;
+        service.add_rule(rule("change_rate", Some(5))).await?;
+        let mut disabled = rule("price_above", Some(1000));
+        disabled.enabled = false;
+        service.add_rule(disabled).await?;
+
+        let triggered = service.evaluate(vec![STOCK_ID.to_string()]).await?;
+
+        assert!(triggered.len() == 2);
+        assert!(triggered[0].condition == "price_above");
+        assert!(triggered[1].condition == "change_rate");
+        assert!(triggered[0].date == NaiveDate::from_ymd_opt(2023, 3, 2).unwrap());
+        assert!(triggered[0].price == Decimal::from(1100));
+
+        // 同じ日付の株価では再通知しない
+        let triggered = service.evaluate(vec![STOCK_ID.to_string()]).await?;
+        assert!(triggered.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn evaluate_year_high() -> anyhow::Result<()> {
+        let service = setup(&[(1, 1000, 1200), (2, 1100, 1150), (3, 1150, 1250)]).await;
+        service.add_rule(rule("year_high", None)).await?;
+
+        let triggered = service.evaluate(vec![STOCK_ID.to_string()]).await?;
+
+        assert!(triggered.len() == 1);
+        assert!(triggered[0].condition == "year_high");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mark_event_as_read() -> anyhow::Result<()> {
+        let service = setup(&[(1, 1000, 1000), (2, 1100, 1100)]).await;
+        service.add_rule(rule("price_above", Some(1050))).await?;
+        service.add_rule(rule("change_rate", Some(5))).await?;
+        let triggered = service.evaluate(vec![STOCK_ID.to_string()]).await?;
+
+        let read = service.mark_read(USER_ID, triggered[0].id, true).await?;
+
+        assert!(read.read);
+        let unread = service.get_events(USER_ID, true).await?;
+        assert!(unread.len() == 1);
+        assert!(unread[0].id == triggered[1].id);
+        assert!(service.get_events(USER_ID, false).await?.len() == 2);
+
+        let Err(AlertApplicationError::AlertEventNotFound(_)) =
+            service.mark_read(USER_ID, 999, true).await
+        else {
+            return Err(anyhow!("unexpected mark result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::{Arc, Mutex};
+
+use domain::{
+    alert::{AlertDomainResult, AlertEvent, AlertEventId, AlertId, AlertRepository, AlertRule},
+    stock::StockId,
+    user::UserId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct InmemoryAlertRepositoryImpl {
+    pub rules: Arc<Mutex<Vec<AlertRule>>>,
+    pub events: Arc<Mutex<Vec<AlertEvent>>>,
+}
+
+impl InmemoryAlertRepositoryImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl AlertRepository for InmemoryAlertRepositoryImpl {
+    async fn save_rule(&self, rule: AlertRule) -> AlertDomainResult<AlertId> {
+        let mut rules = self.rules.lock().unwrap();
+        let id = if let Some(id) = rule.id {
+            rules.retain(|target| target.id != Some(id));
+            id
+        } else {
+            // 採番
+            let max_id = rules.iter().filter_map(|r| r.id).map(|id| *id).max();
+            AlertId::new(max_id.unwrap_or(0) + 1)
+        };
+        rules.push(AlertRule {
+            id: Some(id),
+            ..rule
+        });
+
+        Ok(id)
+    }
+
+    async fn delete_rule(&self, user_id: &UserId, rule_id: &AlertId) -> AlertDomainResult<()> {
+        let mut rules = self.rules.lock().unwrap();
+        let Some(index) = rules
+            .iter()
+            .position(|target| target.id == Some(*rule_id) && &target.user_id == user_id)
+        else {
+            return Ok(());
+        };
+        rules.remove(index);
+        self.events
+            .lock()
+            .unwrap()
+            .retain(|target| target.rule_id != *rule_id);
+
+        Ok(())
+    }
+
+    async fn find_rules(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertRule>> {
+        let mut result = self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| &target.user_id == user_id)
+            .cloned()
+            .collect::<Vec<AlertRule>>();
+        result.sort_by_key(|r| r.id.map(|id| *id));
+
+        Ok(result)
+    }
+
+    async fn find_enabled_rules(&self, stock_ids: &[StockId]) -> AlertDomainResult<Vec<AlertRule>> {
+        let mut result = self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| target.enabled && stock_ids.contains(&target.stock_id))
+            .cloned()
+            .collect::<Vec<AlertRule>>();
+        result.sort_by_key(|r| r.id.map(|id| *id));
+
+        Ok(result)
+    }
+
+    async fn add_event(&self, event: AlertEvent) -> AlertDomainResult<Option<AlertEventId>> {
+        let mut events = self.events.lock().unwrap();
+        if events
+            .iter()
+            .any(|target| target.rule_id == event.rule_id && target.date == event.date)
+        {
+            return Ok(None);
+        }
+        // 採番
+        let max_id = events.iter().filter_map(|e| e.id).map(|id| *id).max();
+        let id = AlertEventId::new(max_id.unwrap_or(0) + 1);
+        events.push(AlertEvent {
+            id: Some(id),
+            ..event
+        });
+
+        Ok(Some(id))
+    }
+
+    async fn update_event(&self, event: AlertEvent) -> AlertDomainResult<()> {
+        let mut events = self.events.lock().unwrap();
+        if let Some(target) = events
+            .iter_mut()
+            .find(|target| target.id.is_some() && target.id == event.id)
+        {
+            target.read = event.read;
+        }
+
+        Ok(())
+    }
+
+    async fn find_events(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertEvent>> {
+        let mut result = self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| &target.user_id == user_id)
+            .cloned()
+            .collect::<Vec<AlertEvent>>();
+        result.sort_by_key(|e| std::cmp::Reverse(e.id.map(|id| *id)));
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,13 @@
+mod benchmark_application_error;
+mod benchmark_data;
+mod benchmark_query_command;
+mod benchmark_service;
+mod benchmark_service_impl;
+
+pub use benchmark_application_error::BenchmarkApplicationError;
+pub use benchmark_application_error::BenchmarkApplicationResult;
+pub use benchmark_data::BenchmarkData;
+pub use benchmark_data::BenchmarkValueData;
+pub use benchmark_query_command::BenchmarkQueryCommand;
+pub use benchmark_service::BenchmarkService;
+pub use benchmark_service_impl::BenchmarkServiceImpl;
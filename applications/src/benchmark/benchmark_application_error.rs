@@ -0,0 +1,91 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{
+    market_index::MarketIndexQueryError, portfolio::PortfolioApplicationError,
+    stock::StockQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum BenchmarkApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("invalid date parameter: {name}={value}")]
+    InvalidRangeOfDate {
+        name: &'static str,
+        value: NaiveDate,
+    },
+    #[error("market index not found: id={0}")]
+    MarketIndexNotFound(String),
+    #[error("portfolio account not found: id={0}")]
+    PortfolioAccountNotFound(i32),
+    #[error("stock data not found: id={0}")]
+    StockDataNotFound(String),
+    #[error("user not found: id={0}")]
+    UserNotFound(String),
+}
+
+pub type BenchmarkApplicationResult<T> = Result<T, BenchmarkApplicationError>;
+
+impl From<MarketIndexQueryError> for BenchmarkApplicationError {
+    fn from(value: MarketIndexQueryError) -> Self {
+        match value {
+            MarketIndexQueryError::Disconnect(e) => Self::Disconnect(e),
+            MarketIndexQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            MarketIndexQueryError::InvalidRangeOfDate { name, value } => {
+                Self::InvalidRangeOfDate { name, value }
+            }
+            MarketIndexQueryError::MarketIndexNotFound(index_id) => {
+                Self::MarketIndexNotFound(index_id)
+            }
+        }
+    }
+}
+
+impl From<StockQueryError> for BenchmarkApplicationError {
+    fn from(value: StockQueryError) -> Self {
+        match value {
+            StockQueryError::Disconnect(e) => Self::Disconnect(e),
+            StockQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            StockQueryError::InvalidRangeOfDate { name, value } => {
+                Self::InvalidRangeOfDate { name, value }
+            }
+            StockQueryError::StockDataNotFound(stock_id) => Self::StockDataNotFound(stock_id),
+        }
+    }
+}
+
+impl From<PortfolioApplicationError> for BenchmarkApplicationError {
+    fn from(value: PortfolioApplicationError) -> Self {
+        match value {
+            PortfolioApplicationError::Disconnect(e) => Self::Disconnect(e),
+            PortfolioApplicationError::UserNotFound(user_id) => Self::UserNotFound(user_id),
+            PortfolioApplicationError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            PortfolioApplicationError::InvalidRangeOfDate { name, value } => {
+                Self::InvalidRangeOfDate { name, value }
+            }
+            PortfolioApplicationError::StockDataNotFound(stock_id) => {
+                Self::StockDataNotFound(stock_id)
+            }
+            // 評価額の推移の取得では発生しない
+            PortfolioApplicationError::PortfolioAccountNotFound(portfolio_id) => {
+                Self::PortfolioAccountNotFound(portfolio_id)
+            }
+            e @ (PortfolioApplicationError::PortfolioNotFound(_)
+            | PortfolioApplicationError::UserAlreadyExist(_)
+            | PortfolioApplicationError::TransactionNotFound(_)
+            | PortfolioApplicationError::InsufficientStock { .. }
+            | PortfolioApplicationError::TransactionsExist(_)) => {
+                Self::Disconnect(anyhow::anyhow!(e))
+            }
+        }
+    }
+}
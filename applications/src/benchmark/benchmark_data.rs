@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+/// 年率換算に用いる年間の営業日数
+const TRADING_DAYS_PER_YEAR: f64 = 245.0;
+
+/// 比較対象と指数の日次の累積収益率
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BenchmarkValueData {
+    pub date: NaiveDate,
+    /// 比較対象の期間初日からの収益率(%)
+    pub target_return: f64,
+    /// 指数の期間初日からの収益率(%)
+    pub benchmark_return: f64,
+}
+
+/// 指数との比較結果
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BenchmarkData {
+    pub index_id: String,
+    pub history: Vec<BenchmarkValueData>,
+    /// 期間全体の比較対象の収益率(%)
+    pub target_return: Option<f64>,
+    /// 期間全体の指数の収益率(%)
+    pub benchmark_return: Option<f64>,
+    /// 指数に対する超過収益率(%ポイント)
+    pub relative_return: Option<f64>,
+    /// 日次収益率のベータ
+    pub beta: Option<f64>,
+    /// 日次収益率のアルファ(年率換算, %)
+    pub alpha: Option<f64>,
+    /// 日次収益率の相関係数
+    pub correlation: Option<f64>,
+}
+
+impl BenchmarkData {
+    /// 両方に値がある日付のみを用いて比較する
+    ///
+    /// 収益率は共通する最初の日付を基準とし、アルファは無リスク金利を0として計算する
+    pub fn calculate(
+        index_id: String,
+        target: Vec<(NaiveDate, f64)>,
+        benchmark: Vec<(NaiveDate, f64)>,
+    ) -> Self {
+        let benchmark = benchmark.into_iter().collect::<BTreeMap<NaiveDate, f64>>();
+        let values = target
+            .into_iter()
+            .collect::<BTreeMap<NaiveDate, f64>>()
+            .into_iter()
+            .filter_map(|(date, value)| Some((date, value, *benchmark.get(&date)?)))
+            .filter(|(_, value, benchmark)| *value > 0.0 && *benchmark > 0.0)
+            .collect::<Vec<(NaiveDate, f64, f64)>>();
+
+        let Some((_, target_base, benchmark_base)) = values.first().cloned() else {
+            return Self {
+                index_id,
+                ..Default::default()
+            };
+        };
+        let history = values
+            .iter()
+            .map(|(date, value, benchmark)| BenchmarkValueData {
+                date: *date,
+                target_return: (value / target_base - 1.0) * 100.0,
+                benchmark_return: (benchmark / benchmark_base - 1.0) * 100.0,
+            })
+            .collect::<Vec<BenchmarkValueData>>();
+
+        // 日次収益率
+        let (target_returns, benchmark_returns): (Vec<f64>, Vec<f64>) = values
+            .windows(2)
+            .map(|w| (w[1].1 / w[0].1 - 1.0, w[1].2 / w[0].2 - 1.0))
+            .unzip();
+        let (beta, alpha, correlation) = regression(&target_returns, &benchmark_returns);
+
+        let last = history.last().cloned().unwrap_or_default();
+        Self {
+            index_id,
+            history,
+            target_return: Some(last.target_return),
+            benchmark_return: Some(last.benchmark_return),
+            relative_return: Some(last.target_return - last.benchmark_return),
+            beta,
+            alpha,
+            correlation,
+        }
+    }
+}
+
+/// (ベータ, 年率換算アルファ, 相関係数)を返す
+fn regression(target: &[f64], benchmark: &[f64]) -> (Option<f64>, Option<f64>, Option<f64>) {
+    if target.len() < 2 {
+        return (None, None, None);
+    }
+    let n = target.len() as f64;
+    let target_mean = target.iter().sum::<f64>() / n;
+    let benchmark_mean = benchmark.iter().sum::<f64>() / n;
+    let covariance = target
+        .iter()
+        .zip(benchmark)
+        .map(|(t, b)| (t - target_mean) * (b - benchmark_mean))
+        .sum::<f64>()
+        / n;
+    let target_variance = target
+        .iter()
+        .map(|t| (t - target_mean).powi(2))
+        .sum::<f64>()
+        / n;
+    let benchmark_variance = benchmark
+        .iter()
+        .map(|b| (b - benchmark_mean).powi(2))
+        .sum::<f64>()
+        / n;
+
+    if benchmark_variance == 0.0 {
+        return (None, None, None);
+    }
+    let beta = covariance / benchmark_variance;
+    let alpha = (target_mean - beta * benchmark_mean) * TRADING_DAYS_PER_YEAR * 100.0;
+    let correlation = if target_variance == 0.0 {
+        None
+    } else {
+        Some(covariance / (target_variance.sqrt() * benchmark_variance.sqrt()))
+    };
+
+    (Some(beta), Some(alpha), correlation)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::benchmark::BenchmarkData;
+
+    fn series(values: &[f64]) -> Vec<(NaiveDate, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (NaiveDate::from_ymd_opt(2023, 1, i as u32 + 1).unwrap(), *v))
+            .collect()
+    }
+
+    #[test]
+    fn compare_with_leveraged_series() {
+        let benchmark = series(&[100.0, 101.0, 99.0, 102.0, 103.0]);
+        // 指数の日次収益率のちょうど2倍で推移する系列
+        let mut target = vec![1000.0];
+        for w in benchmark.windows(2) {
+            let last = *target.last().unwrap();
+            target.push(last * (1.0 + 2.0 * (w[1].1 / w[0].1 - 1.0)));
+        }
+
+        let result = BenchmarkData::calculate("topix".to_string(), series(&target), benchmark);
+
+        assert!(result.history.len() == 5);
+        assert!(result.history[0].target_return == 0.0);
+        assert!((result.benchmark_return.unwrap() - 3.0).abs() < 1e-9);
+        assert!((result.beta.unwrap() - 2.0).abs() < 1e-9);
+        assert!(result.alpha.unwrap().abs() < 1e-9);
+        assert!((result.correlation.unwrap() - 1.0).abs() < 1e-9);
+        assert!(result.relative_return.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn compare_only_common_dates() {
+        let benchmark = series(&[100.0, 110.0, 121.0]);
+        let target = vec![benchmark[0], benchmark[2]];
+        let target = target.into_iter().map(|(d, v)| (d, v * 2.0)).collect();
+
+        let result = BenchmarkData::calculate("topix".to_string(), target, benchmark);
+
+        assert!(result.history.len() == 2);
+        assert!((result.target_return.unwrap() - 21.0).abs() < 1e-9);
+        assert!((result.benchmark_return.unwrap() - 21.0).abs() < 1e-9);
+        // 日次収益率が1件では回帰できない
+        assert!(result.beta.is_none());
+    }
+
+    #[test]
+    fn compare_without_common_dates() {
+        let result =
+            BenchmarkData::calculate("topix".to_string(), Vec::new(), series(&[100.0, 101.0]));
+
+        assert!(result.history.is_empty());
+        assert!(result.target_return.is_none());
+    }
+}
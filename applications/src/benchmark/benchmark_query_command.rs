@@ -0,0 +1,15 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BenchmarkQueryCommand {
+    /// 比較する指数のID
+    pub index_id: String,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+}
+
+impl BenchmarkQueryCommand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
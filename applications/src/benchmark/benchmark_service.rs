@@ -0,0 +1,18 @@
+use crate::benchmark::{BenchmarkApplicationResult, BenchmarkData, BenchmarkQueryCommand};
+
+#[async_trait::async_trait]
+pub trait BenchmarkService {
+    /// 株価(修正株価)と指数の比較
+    async fn compare_stock(
+        &self,
+        stock_id: &str,
+        command: BenchmarkQueryCommand,
+    ) -> BenchmarkApplicationResult<BenchmarkData>;
+    /// ポートフォリオの時間加重収益率と指数の比較(portfolio_idがNoneの場合は既定のポートフォリオ)
+    async fn compare_portfolio(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        command: BenchmarkQueryCommand,
+    ) -> BenchmarkApplicationResult<BenchmarkData>;
+}
@@ -0,0 +1,261 @@
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{
+    benchmark::{
+        BenchmarkApplicationResult, BenchmarkData, BenchmarkQueryCommand, BenchmarkService,
+    },
+    market_index::{MarketIndexQueryCommand, MarketIndexQueryService},
+    portfolio::PortfolioService,
+    stock::{StockQueryCommand, StockQueryService},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BenchmarkServiceImpl<T, U, V>
+where
+    T: MarketIndexQueryService,
+    U: StockQueryService,
+    V: PortfolioService,
+{
+    market_index_query_service: T,
+    stock_query_service: U,
+    portfolio_service: V,
+}
+
+impl<T, U, V> BenchmarkServiceImpl<T, U, V>
+where
+    T: MarketIndexQueryService,
+    U: StockQueryService,
+    V: PortfolioService,
+{
+    /// コンストラクタ
+    pub fn new(
+        market_index_query_service: T,
+        stock_query_service: U,
+        portfolio_service: V,
+    ) -> Self {
+        Self {
+            market_index_query_service,
+            stock_query_service,
+            portfolio_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U, V> BenchmarkService for BenchmarkServiceImpl<T, U, V>
+where
+    T: MarketIndexQueryService + std::fmt::Debug + Send + Sync,
+    U: StockQueryService + std::fmt::Debug + Send + Sync,
+    V: PortfolioService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn compare_stock(
+        &self,
+        stock_id: &str,
+        command: BenchmarkQueryCommand,
+    ) -> BenchmarkApplicationResult<BenchmarkData> {
+        let benchmark = self.find_benchmark(&command).await?;
+
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some(stock_id.to_string());
+        param.start = command.start;
+        param.end = command.end;
+        // 株式分割・併合による株価の変動を除く
+        param.adjusted = true;
+        let target = self
+            .stock_query_service
+            .find(param)
+            .await?
+            .into_iter()
+            .filter_map(|s| Some((s.date, s.end_price.to_f64()?)))
+            .collect();
+
+        Ok(BenchmarkData::calculate(
+            command.index_id,
+            target,
+            benchmark,
+        ))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn compare_portfolio(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        command: BenchmarkQueryCommand,
+    ) -> BenchmarkApplicationResult<BenchmarkData> {
+        let benchmark = self.find_benchmark(&command).await?;
+
+        // 売買による評価額の増減を除くため時間加重収益率を指数化して比較する
+        let target = self
+            .portfolio_service
+            .get_history(user_id, portfolio_id, command.start, command.end)
+            .await?
+            .history
+            .into_iter()
+            .map(|v| (v.date, 1.0 + v.cumulative_return / 100.0))
+            .collect();
+
+        Ok(BenchmarkData::calculate(
+            command.index_id,
+            target,
+            benchmark,
+        ))
+    }
+}
+
+impl<T, U, V> BenchmarkServiceImpl<T, U, V>
+where
+    T: MarketIndexQueryService + Send + Sync,
+    U: StockQueryService + Send + Sync,
+    V: PortfolioService + Send + Sync,
+{
+    async fn find_benchmark(
+        &self,
+        command: &BenchmarkQueryCommand,
+    ) -> BenchmarkApplicationResult<Vec<(chrono::NaiveDate, f64)>> {
+        let mut param = MarketIndexQueryCommand::new();
+        param.index_id = command.index_id.clone();
+        param.start = command.start;
+        param.end = command.end;
+
+        let result = self
+            .market_index_query_service
+            .find_prices(param)
+            .await?
+            .into_iter()
+            .filter_map(|p| Some((p.date, p.end_price.to_f64()?)))
+            .collect();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        benchmark::{
+            BenchmarkApplicationError, BenchmarkQueryCommand, BenchmarkService,
+            BenchmarkServiceImpl,
+        },
+        corporate_action::InmemoryCorporateActionRepositoryImpl,
+        dividend::InmemoryDividendRepositoryImpl,
+        market_index::{IndexPriceData, InmemoryMarketIndexQueryServiceImpl, MarketIndexData},
+        portfolio::{InmemoryPortfolioRepositoryImpl, PortfolioServiceImpl},
+        stock::{InmemoryStockQueryServiceImpl, StockData},
+        user::InmemoryUserRepositoryImpl,
+    };
+    use domain::user::{User, UserDomainService, UserEmail, UserId, UserName, UserRepository};
+
+    const USER_ID: &str = "sample user";
+    const STOCK_ID: &str = "1301";
+    const INDEX_ID: &str = "topix";
+
+    async fn setup() -> impl BenchmarkService {
+        let mut market_index_query_service = InmemoryMarketIndexQueryServiceImpl::new();
+        market_index_query_service.indices.push(MarketIndexData {
+            index_id: INDEX_ID.to_string(),
+            name: "TOPIX".to_string(),
+        });
+        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
+        for (day, index_price, stock_price) in
+            [(4, 2000, 1000), (5, 2100, 1100), (6, 1995, 990)]
+        {
+            let date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+            let mut price = IndexPriceData::new();
+            price.index_id = INDEX_ID.to_string();
+            price.date = date;
+            price.end_price = Decimal::from(index_price);
+            market_index_query_service.prices.push(price);
+
+            let mut stock = StockData::new();
+            stock.stock_id = STOCK_ID.to_string();
+            stock.date = date;
+            stock.end_price = Decimal::from(stock_price);
+            stock_query_service.stocks.push(stock);
+        }
+
+        let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
+        let sample_user = User::new(
+            UserId::new(USER_ID.to_string()),
+            UserName::default(),
+            UserEmail::default(),
+        );
+        user_repository.save(sample_user).await.unwrap();
+        let portfolio_repository = Arc::new(InmemoryPortfolioRepositoryImpl::new());
+        let portfolio_service = PortfolioServiceImpl::new(
+            &portfolio_repository,
+            &Arc::new(InmemoryDividendRepositoryImpl::new()),
+            &Arc::new(InmemoryCorporateActionRepositoryImpl::new()),
+            stock_query_service.clone(),
+            UserDomainService::new(&user_repository),
+        );
+
+        BenchmarkServiceImpl::new(
+            market_index_query_service,
+            stock_query_service,
+            portfolio_service,
+        )
+    }
+
+    fn command(index_id: &str) -> BenchmarkQueryCommand {
+        BenchmarkQueryCommand {
+            index_id: index_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn compare_stock_with_index() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let result = service.compare_stock(STOCK_ID, command(INDEX_ID)).await?;
+
+        // 株価は指数の日次収益率の2倍で推移
+        assert!(result.history.len() == 3);
+        let (Some(beta), Some(correlation)) = (result.beta, result.correlation) else {
+            return Err(anyhow!("regression not calculated"));
+        };
+        assert!((beta - 2.0).abs() < 1e-9);
+        assert!((correlation - 1.0).abs() < 1e-9);
+        let Some(relative_return) = result.relative_return else {
+            return Err(anyhow!("relative return not calculated"));
+        };
+        assert!((relative_return - (-1.0 + 0.25)).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn compare_with_unknown_index_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let Err(BenchmarkApplicationError::MarketIndexNotFound(_)) =
+            service.compare_stock(STOCK_ID, command("unknown")).await
+        else {
+            return Err(anyhow!("unexpected compare result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn compare_notexist_user_portfolio_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let Err(BenchmarkApplicationError::UserNotFound(_)) = service
+            .compare_portfolio("not registed user", None, command(INDEX_ID))
+            .await
+        else {
+            return Err(anyhow!("unexpected compare result"));
+        };
+
+        Ok(())
+    }
+}
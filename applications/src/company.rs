@@ -1,12 +1,24 @@
 mod company_data;
+mod company_import_error;
+mod company_import_service;
 mod company_query_command;
 mod company_query_error;
 mod company_query_service;
+mod company_search;
+mod company_sort_key;
+mod company_suggest_command;
 mod inmemory_company_query_service_impl;
 
 pub use company_data::CompanyData;
+pub use company_import_error::CompanyImportError;
+pub use company_import_error::CompanyImportResult;
+pub use company_import_service::CompanyImportService;
 pub use company_query_command::CompanyQueryCommand;
 pub use company_query_error::CompanyQueryError;
 pub use company_query_error::CompanyQueryResult;
 pub use company_query_service::CompanyQueryService;
+pub use company_search::normalize_search_text;
+pub use company_search::search_rank;
+pub use company_sort_key::CompanySortKey;
+pub use company_suggest_command::CompanySuggestCommand;
 pub use inmemory_company_query_service_impl::InmemoryCompanyQueryServiceImpl;
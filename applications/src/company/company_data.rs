@@ -1,3 +1,5 @@
+use crate::company::{CompanyImportError, CompanyImportResult};
+
 /// 企業情報
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompanyData {
@@ -10,3 +12,75 @@ pub struct CompanyData {
     // 産業
     pub industry: String,
 }
+
+impl CompanyData {
+    /// 登録データとしての妥当性チェック
+    pub fn validate(&self) -> CompanyImportResult<()> {
+        // 各項目の最大文字数はテーブル定義に合わせる
+        for (name, value, max_length) in [
+            ("stock_id", &self.stock_id, 10),
+            ("name", &self.name, 100),
+            ("sector", &self.sector, 50),
+            ("industry", &self.industry, 50),
+        ] {
+            if value.is_empty() || value.chars().count() > max_length {
+                return Err(CompanyImportError::InvalidData {
+                    name,
+                    value: value.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::company::{CompanyData, CompanyImportError};
+
+    fn sample() -> CompanyData {
+        CompanyData {
+            name: "トヨタ自動車".to_string(),
+            stock_id: "7203".to_string(),
+            sector: "輸送用機器".to_string(),
+            industry: "製造業".to_string(),
+        }
+    }
+
+    #[test]
+    fn validate_success() -> anyhow::Result<()> {
+        sample().validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_empty_name_return_err() -> anyhow::Result<()> {
+        let mut company = sample();
+        company.name = "".to_string();
+
+        let Err(CompanyImportError::InvalidData { name: "name", .. }) = company.validate() else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_too_long_stock_id_return_err() -> anyhow::Result<()> {
+        let mut company = sample();
+        company.stock_id = "12345678901".to_string();
+
+        let Err(CompanyImportError::InvalidData {
+            name: "stock_id", ..
+        }) = company.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+}
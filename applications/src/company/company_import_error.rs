@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CompanyImportError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid company data: {name}={value}")]
+    InvalidData { name: &'static str, value: String },
+}
+
+pub type CompanyImportResult<T> = Result<T, CompanyImportError>;
@@ -0,0 +1,11 @@
+use crate::company::{CompanyData, CompanyImportResult};
+
+#[async_trait::async_trait]
+pub trait CompanyImportService {
+    /// 企業情報の登録(同一の証券コードのデータは上書き)
+    async fn upsert(&self, companies: Vec<CompanyData>) -> CompanyImportResult<u64>;
+    /// 登録済みの企業名から検索用に正規化した値を作り直し、変更した件数を返す
+    ///
+    /// 正規化を取り込み時に行うようになる前に登録した企業の移行に使う
+    async fn refresh_search_names(&self) -> CompanyImportResult<u64>;
+}
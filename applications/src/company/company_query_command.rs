@@ -1,3 +1,5 @@
+use crate::company::{CompanyQueryError, CompanyQueryResult, CompanySortKey};
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CompanyQueryCommand {
     pub name: Option<String>,
@@ -6,6 +8,10 @@ pub struct CompanyQueryCommand {
     pub industry: Option<String>,
     pub page: Option<i32>,
     pub size: Option<i32>,
+    /// 並び替えの項目(同じ値の場合は証券コード順)
+    ///
+    /// Noneの場合、企業名を指定した時は一致度の高い順、それ以外は証券コード順
+    pub sort: Option<CompanySortKey>,
 }
 
 impl CompanyQueryCommand {
@@ -13,4 +19,18 @@ impl CompanyQueryCommand {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// パラメータチェック
+    pub fn validate(&self) -> CompanyQueryResult<()> {
+        for (name, value) in [("page", self.page), ("size", self.size)] {
+            if let Some(value) = value.filter(|value| *value < 1) {
+                return Err(CompanyQueryError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
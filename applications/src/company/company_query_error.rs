@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use domain::stock::StockDomainError;
+
 #[derive(Error, Debug)]
 pub enum CompanyQueryError {
     #[error(transparent)]
@@ -11,3 +13,14 @@ pub enum CompanyQueryError {
 }
 
 pub type CompanyQueryResult<T> = Result<T, CompanyQueryError>;
+
+impl From<StockDomainError> for CompanyQueryError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use crate::company::{CompanyData, CompanyQueryCommand, CompanyQueryResult};
+use crate::company::{CompanyData, CompanyQueryCommand, CompanyQueryResult, CompanySuggestCommand};
 
 #[async_trait::async_trait]
 pub trait CompanyQueryService {
+    /// 条件に一致する企業を取得(企業名は部分一致、一致度の高い順)
     async fn find(&self, param: CompanyQueryCommand) -> CompanyQueryResult<Vec<CompanyData>>;
+    /// 条件に一致する企業の件数(ページ番号・ページサイズは無視する)
+    async fn count(&self, param: CompanyQueryCommand) -> CompanyQueryResult<i64>;
     async fn find_by_id(&self, stock_id: &str) -> CompanyQueryResult<CompanyData>;
     async fn find_list(
         &self,
         stock_id_list: Vec<String>,
     ) -> CompanyQueryResult<Vec<CompanyData>>;
+    /// 企業名の入力補完(一致度の高い順)
+    async fn suggest(
+        &self,
+        command: CompanySuggestCommand,
+    ) -> CompanyQueryResult<Vec<CompanyData>>;
 }
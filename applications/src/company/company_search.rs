@@ -0,0 +1,56 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// 企業名を検索用に正規化する
+///
+/// * 全角英数字・記号は半角に、半角カタカナは全角にする(NFKC)
+/// * ひらがなはカタカナにする
+/// * 英字は小文字にする
+/// * 空白は取り除く
+pub fn normalize_search_text(text: &str) -> String {
+    text.nfkc()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            // ぁ(U+3041)〜ゖ(U+3096)はァ(U+30A1)〜ヶ(U+30F6)に対応する
+            'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            c => c,
+        })
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// 正規化した企業名と検索語の一致度(小さいほど上位、一致しない場合はNone)
+///
+/// 0: 完全一致, 1: 前方一致, 2: 部分一致
+pub fn search_rank(search_name: &str, keyword: &str) -> Option<u8> {
+    if search_name == keyword {
+        Some(0)
+    } else if search_name.starts_with(keyword) {
+        Some(1)
+    } else if search_name.contains(keyword) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::company::{normalize_search_text, search_rank};
+
+    #[test]
+    fn normalize_width_and_kana() {
+        assert!(normalize_search_text("ﾄﾖﾀ自動車") == "トヨタ自動車");
+        assert!(normalize_search_text("とよた") == "トヨタ");
+        assert!(normalize_search_text("ｶﾞｽ") == "ガス");
+        assert!(normalize_search_text("ＫＤＤＩ") == "kddi");
+        assert!(normalize_search_text("T&D　ホールディングス") == "t&dホールディングス");
+    }
+
+    #[test]
+    fn rank_by_match_quality() {
+        assert!(search_rank("トヨタ自動車", "トヨタ自動車") == Some(0));
+        assert!(search_rank("トヨタ自動車", "トヨタ") == Some(1));
+        assert!(search_rank("トヨタ自動車", "自動車") == Some(2));
+        assert!(search_rank("トヨタ自動車", "日産").is_none());
+    }
+}
@@ -0,0 +1,29 @@
+/// 企業情報の並び替えの項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompanySortKey {
+    #[default]
+    StockId,
+    Name,
+    Sector,
+}
+
+impl CompanySortKey {
+    /// 項目名から作成する
+    pub fn new(name: &str) -> Option<Self> {
+        match name {
+            "stock_id" => Some(CompanySortKey::StockId),
+            "name" => Some(CompanySortKey::Name),
+            "sector" => Some(CompanySortKey::Sector),
+            _ => None,
+        }
+    }
+
+    /// 項目名(Postgresの列名と同じ)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompanySortKey::StockId => "stock_id",
+            CompanySortKey::Name => "name",
+            CompanySortKey::Sector => "sector",
+        }
+    }
+}
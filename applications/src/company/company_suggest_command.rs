@@ -0,0 +1,47 @@
+use crate::company::{normalize_search_text, CompanyQueryError, CompanyQueryResult};
+
+/// 候補数の既定値
+const DEFAULT_SUGGEST_LIMIT: i32 = 10;
+/// 候補数の上限
+const MAX_SUGGEST_LIMIT: i32 = 50;
+
+/// 企業名の入力補完の条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanySuggestCommand {
+    /// 企業名の一部または証券コードの先頭
+    pub keyword: String,
+    pub limit: i32,
+}
+
+impl CompanySuggestCommand {
+    /// コンストラクタ
+    pub fn new(keyword: String) -> Self {
+        Self {
+            keyword,
+            limit: DEFAULT_SUGGEST_LIMIT,
+        }
+    }
+
+    /// 証券コードの前方一致に使う値(前後の空白を除いて大文字にする)
+    pub fn stock_id_prefix(&self) -> String {
+        self.keyword.trim().to_uppercase()
+    }
+
+    /// パラメータチェック
+    pub fn validate(&self) -> CompanyQueryResult<()> {
+        if normalize_search_text(&self.keyword).is_empty() {
+            return Err(CompanyQueryError::InvalidParameter {
+                name: "q",
+                value: self.keyword.clone(),
+            });
+        }
+        if !(1..=MAX_SUGGEST_LIMIT).contains(&self.limit) {
+            return Err(CompanyQueryError::InvalidParameter {
+                name: "limit",
+                value: self.limit.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use std::ops::Deref;
 
 use crate::company::{
-    CompanyData, CompanyQueryCommand, CompanyQueryError, CompanyQueryResult, CompanyQueryService,
+    normalize_search_text, search_rank, CompanyData, CompanyQueryCommand, CompanyQueryError,
+    CompanyQueryResult, CompanyQueryService, CompanySortKey, CompanySuggestCommand,
 };
+use domain::stock::StockId;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct InmemoryCompanyQueryServiceImpl {
@@ -20,17 +22,10 @@ impl InmemoryCompanyQueryServiceImpl {
 impl CompanyQueryService for InmemoryCompanyQueryServiceImpl {
     #[tracing::instrument(skip(self), err)]
     async fn find(&self, param: CompanyQueryCommand) -> CompanyQueryResult<Vec<CompanyData>> {
-        // 企業名検索
-        if let Some(name) = param.name {
-            if let Some(company) = self.companies.iter().find(|c| c.name == name) {
-                return Ok(vec![company.clone()]);
-            } else {
-                return Ok(vec![]);
-            };
-        }
+        param.validate()?;
         // ID検索
         if let Some(id) = param.stock_id {
-            if let Some(company) = self.companies.iter().find(|c| c.stock_id.deref() == &id) {
+            if let Some(company) = self.companies.iter().find(|c| c.stock_id.deref() == id) {
                 return Ok(vec![company.clone()]);
             } else {
                 return Ok(vec![]);
@@ -44,6 +39,15 @@ impl CompanyQueryService for InmemoryCompanyQueryServiceImpl {
                 true
             }
         };
+        // 企業名検索(部分一致、指定しない場合は全て同じ一致度)
+        let keyword = param.name.as_deref().map(normalize_search_text);
+        let rank_by_name = |c: &CompanyData| {
+            if let Some(keyword) = &keyword {
+                search_rank(&normalize_search_text(&c.name), keyword)
+            } else {
+                Some(0)
+            }
+        };
         // 産業種別指定
         let find_by_industry = |c: &CompanyData| {
             if let Some(industry) = &param.industry {
@@ -65,22 +69,54 @@ impl CompanyQueryService for InmemoryCompanyQueryServiceImpl {
             self.companies.len()
         };
 
-        let iter = self
+        let mut found: Vec<(u8, &CompanyData)> = self
             .companies
-            .to_vec()
+            .iter()
+            .filter(|c| find_by_sector(c))
+            .filter(|c| find_by_industry(c))
+            .filter_map(|c| rank_by_name(c).map(|rank| (rank, c)))
+            .collect();
+        match param.sort {
+            // 指定した項目順(同じ場合は証券コード順)
+            Some(CompanySortKey::Name) => {
+                found.sort_by(|(_, a), (_, b)| (&a.name, &a.stock_id).cmp(&(&b.name, &b.stock_id)))
+            }
+            Some(CompanySortKey::Sector) => found.sort_by(|(_, a), (_, b)| {
+                (&a.sector, &a.stock_id).cmp(&(&b.sector, &b.stock_id))
+            }),
+            // 一致度の高い順(同じ場合は企業名の短い順)
+            None if keyword.is_some() => {
+                found.sort_by_key(|(rank, c)| (*rank, c.name.chars().count(), c.stock_id.clone()))
+            }
+            _ => found.sort_by(|(_, a), (_, b)| a.stock_id.cmp(&b.stock_id)),
+        }
+
+        let iter = found
             .into_iter()
-            .filter(find_by_sector)
-            .filter(find_by_industry)
+            .map(|(_, c)| c)
             .skip(page_index as usize * page_size)
-            .take(page_size);
+            .take(page_size)
+            .cloned();
 
         let result: Vec<CompanyData> = iter.collect();
         tracing::info!("company data count = {}", result.len());
         Ok(result)
     }
 
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn count(&self, mut param: CompanyQueryCommand) -> CompanyQueryResult<i64> {
+        param.validate()?;
+        param.page = None;
+        param.size = None;
+
+        let result = self.find(param).await?;
+
+        Ok(result.len() as i64)
+    }
+
     #[tracing::instrument(skip(self), err, ret)]
     async fn find_by_id(&self, stock_id: &str) -> CompanyQueryResult<CompanyData> {
+        StockId::new(stock_id.to_string())?;
         let result = self
             .companies
             .iter()
@@ -101,6 +137,39 @@ impl CompanyQueryService for InmemoryCompanyQueryServiceImpl {
 
         Ok(result)
     }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn suggest(
+        &self,
+        command: CompanySuggestCommand,
+    ) -> CompanyQueryResult<Vec<CompanyData>> {
+        command.validate()?;
+
+        let keyword = normalize_search_text(&command.keyword);
+        let stock_id_prefix = command.stock_id_prefix();
+        let mut found: Vec<(u8, &CompanyData)> = self
+            .companies
+            .iter()
+            .filter_map(|c| {
+                // 企業名の一致度と証券コードの前方一致の高い方
+                let rank_by_id = search_rank(&c.stock_id, &stock_id_prefix).filter(|rank| *rank <= 1);
+                let rank_by_name = search_rank(&normalize_search_text(&c.name), &keyword);
+                match (rank_by_id, rank_by_name) {
+                    (Some(a), Some(b)) => Some((a.min(b), c)),
+                    (rank, None) | (None, rank) => rank.map(|rank| (rank, c)),
+                }
+            })
+            .collect();
+        found.sort_by_key(|(rank, c)| (*rank, c.name.chars().count(), c.stock_id.clone()));
+
+        let result = found
+            .into_iter()
+            .take(command.limit as usize)
+            .map(|(_, c)| c.clone())
+            .collect();
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -108,8 +177,8 @@ mod tests {
     use anyhow::anyhow;
 
     use crate::company::{
-        CompanyData, CompanyQueryCommand, CompanyQueryError, CompanyQueryService,
-        InmemoryCompanyQueryServiceImpl,
+        CompanyData, CompanyQueryCommand, CompanyQueryError, CompanyQueryService, CompanySortKey,
+        CompanySuggestCommand, InmemoryCompanyQueryServiceImpl,
     };
 
     fn setup() -> impl CompanyQueryService {
@@ -163,6 +232,27 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn find_by_partial_name_with_kana_and_width() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = CompanyQueryCommand::new();
+        command.name = Some("ﾎｰﾙﾃﾞｨﾝｸﾞｽ".to_string());
+
+        let result: Vec<String> = service
+            .find(command)
+            .await?
+            .into_iter()
+            .map(|c| c.stock_id)
+            .collect();
+        assert!(result == vec!["3333", "5555"]);
+
+        let mut command = CompanyQueryCommand::new();
+        command.name = Some("イスゞ".to_string());
+        assert!(service.find(command).await?.pop().unwrap().stock_id == "4444");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn find_by_id_command() -> anyhow::Result<()> {
         let service = setup();
@@ -185,7 +275,17 @@ mod tests {
     #[tokio::test]
     async fn find_notexist_id_return_err() -> anyhow::Result<()> {
         let service = setup();
-        let Err(CompanyQueryError::CompanyNotFound(_)) = service.find_by_id("not exits id").await else {
+        let Err(CompanyQueryError::CompanyNotFound(_)) = service.find_by_id("9999").await else {
+            return Err(anyhow!("unexpected stock query result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_invalid_id_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let Err(CompanyQueryError::InvalidParameter { name: "stock_id", .. }) = service.find_by_id("not exits id").await else {
             return Err(anyhow!("unexpected stock query result"));
         };
 
@@ -217,6 +317,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
     async fn pagenation() -> anyhow::Result<()> {
         let service = setup();
         let mut param = CompanyQueryCommand::new();
@@ -232,6 +333,123 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn suggest_by_match_quality() -> anyhow::Result<()> {
+        let mut service = InmemoryCompanyQueryServiceImpl::new();
+        for (stock_id, name) in [
+            ("7201", "日産自動車"),
+            ("7203", "トヨタ自動車"),
+            ("7211", "三菱自動車工業"),
+            ("9999", "自動車"),
+        ] {
+            service.companies.push(CompanyData {
+                name: name.to_string(),
+                stock_id: stock_id.to_string(),
+                sector: "輸送用機器".to_string(),
+                industry: "製造業".to_string(),
+            });
+        }
+
+        let mut command = CompanySuggestCommand::new("自動車".to_string());
+        command.limit = 3;
+        let result: Vec<String> = service
+            .suggest(command)
+            .await?
+            .into_iter()
+            .map(|c| c.stock_id)
+            .collect();
+        assert!(result == vec!["9999", "7201", "7203"]);
+
+        let command = CompanySuggestCommand::new("720".to_string());
+        let result: Vec<String> = service
+            .suggest(command)
+            .await?
+            .into_iter()
+            .map(|c| c.stock_id)
+            .collect();
+        assert!(result == vec!["7201", "7203"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn suggest_by_alphanumeric_stock_id() -> anyhow::Result<()> {
+        let mut service = InmemoryCompanyQueryServiceImpl::new();
+        for (stock_id, name) in [("130A", "Veritas In Silico"), ("1301", "極洋")] {
+            service.companies.push(CompanyData {
+                name: name.to_string(),
+                stock_id: stock_id.to_string(),
+                sector: "".to_string(),
+                industry: "".to_string(),
+            });
+        }
+
+        // 証券コードは企業名の正規化(小文字化)をせず大文字で比較する
+        for keyword in ["130A", " 130a"] {
+            let command = CompanySuggestCommand::new(keyword.to_string());
+            let result: Vec<String> = service
+                .suggest(command)
+                .await?
+                .into_iter()
+                .map(|c| c.stock_id)
+                .collect();
+            assert!(result == vec!["130A"]);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn suggest_empty_keyword_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let command = CompanySuggestCommand::new(" 　".to_string());
+
+        let Err(CompanyQueryError::InvalidParameter { name: "q", .. }) =
+            service.suggest(command).await
+        else {
+            return Err(anyhow!("unexpected suggest result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pagenation_with_sort_and_count() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = CompanyQueryCommand::new();
+        param.sector = Some("化学繊維製造業".to_string());
+        param.sort = Some(CompanySortKey::Name);
+        param.page = Some(1);
+        param.size = Some(1);
+
+        let found = service.find(param.clone()).await?;
+        assert!(found.len() == 1);
+        assert!(&found[0].stock_id == "2222");
+        assert!(service.count(param.clone()).await? == 2);
+
+        param.sort = Some(CompanySortKey::Sector);
+        param.sector = None;
+        param.page = Some(2);
+        // 人材派遣業, 化学繊維製造業(2222, 5555), 旅館, 製氷業の順
+        let found = service.find(param).await?;
+        assert!(&found[0].stock_id == "2222");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_page_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = CompanyQueryCommand::new();
+        param.page = Some(0);
+
+        let Err(CompanyQueryError::InvalidParameter { name: "page", .. }) = service.find(param).await else {
+            return Err(anyhow!("unexpected company query result"));
+        };
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn find_list() -> anyhow::Result<()> {
         let service = setup();
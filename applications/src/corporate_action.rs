@@ -0,0 +1,12 @@
+mod corporate_action_application_error;
+mod corporate_action_data;
+mod corporate_action_service;
+mod corporate_action_service_impl;
+mod inmemory_corporate_action_repository_impl;
+
+pub use corporate_action_application_error::CorporateActionApplicationError;
+pub use corporate_action_application_error::CorporateActionApplicationResult;
+pub use corporate_action_data::CorporateActionData;
+pub use corporate_action_service::CorporateActionService;
+pub use corporate_action_service_impl::CorporateActionServiceImpl;
+pub use inmemory_corporate_action_repository_impl::InmemoryCorporateActionRepositoryImpl;
@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+use crate::company::CompanyQueryError;
+use domain::{corporate_action::CorporateActionDomainError, stock::StockDomainError};
+
+#[derive(Error, Debug)]
+pub enum CorporateActionApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("company data not found: id={0}")]
+    CompanyNotFound(String),
+}
+
+pub type CorporateActionApplicationResult<T> = Result<T, CorporateActionApplicationError>;
+
+impl From<CorporateActionDomainError> for CorporateActionApplicationError {
+    fn from(value: CorporateActionDomainError) -> Self {
+        match value {
+            CorporateActionDomainError::Disconnect(e) => Self::Disconnect(e),
+            CorporateActionDomainError::InvalidRatio { from, to } => Self::InvalidParameter {
+                name: "ratio",
+                value: format!("{from}:{to}"),
+            },
+        }
+    }
+}
+
+impl From<StockDomainError> for CorporateActionApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
+
+impl From<CompanyQueryError> for CorporateActionApplicationError {
+    fn from(value: CompanyQueryError) -> Self {
+        match value {
+            CompanyQueryError::Disconnect(e) => Self::Disconnect(e),
+            CompanyQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            CompanyQueryError::CompanyNotFound(stock_id) => Self::CompanyNotFound(stock_id),
+        }
+    }
+}
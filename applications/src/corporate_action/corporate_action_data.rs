@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+
+use crate::corporate_action::CorporateActionApplicationError;
+use domain::{corporate_action::CorporateAction, stock::StockId};
+
+/// 株式分割・併合
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CorporateActionData {
+    pub stock_id: String,
+    /// 権利落ち日
+    pub ex_date: NaiveDate,
+    /// 分割・併合前の株数
+    pub ratio_from: i32,
+    /// 分割・併合後の株数
+    pub ratio_to: i32,
+}
+
+impl CorporateActionData {
+    /// コンストラクタ
+    pub fn new(stock_id: String, ex_date: NaiveDate, ratio_from: i32, ratio_to: i32) -> Self {
+        Self {
+            stock_id,
+            ex_date,
+            ratio_from,
+            ratio_to,
+        }
+    }
+}
+
+impl From<CorporateAction> for CorporateActionData {
+    fn from(value: CorporateAction) -> Self {
+        Self {
+            stock_id: value.stock_id().to_string(),
+            ex_date: *value.ex_date(),
+            ratio_from: value.ratio_from(),
+            ratio_to: value.ratio_to(),
+        }
+    }
+}
+
+impl TryFrom<CorporateActionData> for CorporateAction {
+    type Error = CorporateActionApplicationError;
+
+    fn try_from(value: CorporateActionData) -> Result<Self, Self::Error> {
+        Ok(CorporateAction::new(
+            StockId::new(value.stock_id)?,
+            value.ex_date,
+            value.ratio_from,
+            value.ratio_to,
+        )?)
+    }
+}
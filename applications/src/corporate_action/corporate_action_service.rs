@@ -0,0 +1,13 @@
+use crate::corporate_action::{CorporateActionApplicationResult, CorporateActionData};
+
+#[async_trait::async_trait]
+pub trait CorporateActionService {
+    async fn get_all(
+        &self,
+        stock_id: &str,
+    ) -> CorporateActionApplicationResult<Vec<CorporateActionData>>;
+    async fn save(
+        &self,
+        corporate_action: CorporateActionData,
+    ) -> CorporateActionApplicationResult<()>;
+}
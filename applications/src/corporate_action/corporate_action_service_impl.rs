@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use crate::{
+    company::CompanyQueryService,
+    corporate_action::{
+        CorporateActionApplicationResult, CorporateActionData, CorporateActionService,
+    },
+};
+use domain::{
+    corporate_action::{CorporateAction, CorporateActionRepository},
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CorporateActionServiceImpl<T, U>
+where
+    T: CorporateActionRepository,
+    U: CompanyQueryService,
+{
+    corporate_action_repository: Arc<T>,
+    company_query_service: U,
+}
+
+impl<T, U> CorporateActionServiceImpl<T, U>
+where
+    T: CorporateActionRepository,
+    U: CompanyQueryService,
+{
+    /// コンストラクタ
+    pub fn new(corporate_action_repository: &Arc<T>, company_query_service: U) -> Self {
+        Self {
+            corporate_action_repository: Arc::clone(corporate_action_repository),
+            company_query_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U> CorporateActionService for CorporateActionServiceImpl<T, U>
+where
+    T: CorporateActionRepository + std::fmt::Debug + Send + Sync,
+    U: CompanyQueryService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn get_all(
+        &self,
+        stock_id: &str,
+    ) -> CorporateActionApplicationResult<Vec<CorporateActionData>> {
+        let stock_id = StockId::new(stock_id.to_string())?;
+        let result = self
+            .corporate_action_repository
+            .find_all(&stock_id)
+            .await?
+            .into_iter()
+            .map(CorporateActionData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn save(
+        &self,
+        corporate_action: CorporateActionData,
+    ) -> CorporateActionApplicationResult<()> {
+        let corporate_action = CorporateAction::try_from(corporate_action)?;
+        self.company_query_service
+            .find_by_id(corporate_action.stock_id())
+            .await?;
+
+        self.corporate_action_repository
+            .save(corporate_action)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+
+    use crate::{
+        company::{CompanyData, InmemoryCompanyQueryServiceImpl},
+        corporate_action::{
+            CorporateActionApplicationError, CorporateActionData, CorporateActionService,
+            CorporateActionServiceImpl, InmemoryCorporateActionRepositoryImpl,
+        },
+    };
+
+    const STOCK_ID: &str = "7203";
+
+    fn setup() -> impl CorporateActionService {
+        let mut company_query_service = InmemoryCompanyQueryServiceImpl::new();
+        company_query_service.companies.push(CompanyData {
+            name: "トヨタ自動車".to_string(),
+            stock_id: STOCK_ID.to_string(),
+            sector: "輸送用機器".to_string(),
+            industry: "製造業".to_string(),
+        });
+
+        let corporate_action_repository = Arc::new(InmemoryCorporateActionRepositoryImpl::new());
+        CorporateActionServiceImpl::new(&corporate_action_repository, company_query_service)
+    }
+
+    #[tokio::test]
+    async fn save_corporate_action_success() -> anyhow::Result<()> {
+        let service = setup();
+        let split = CorporateActionData::new(
+            STOCK_ID.to_string(),
+            NaiveDate::from_ymd_opt(2021, 9, 29).unwrap(),
+            1,
+            5,
+        );
+        let reverse_split = CorporateActionData::new(
+            STOCK_ID.to_string(),
+            NaiveDate::from_ymd_opt(2004, 1, 1).unwrap(),
+            10,
+            1,
+        );
+        service.save(split.clone()).await?;
+        service.save(reverse_split.clone()).await?;
+
+        let result = service.get_all(STOCK_ID).await?;
+        // 権利落ち日の昇順
+        assert!(result == vec![reverse_split, split]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_invalid_ratio_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let ex_date = NaiveDate::from_ymd_opt(2021, 9, 29).unwrap();
+
+        for (from, to) in [(1, 1), (0, 2), (2, -1)] {
+            let corporate_action =
+                CorporateActionData::new(STOCK_ID.to_string(), ex_date, from, to);
+            let Err(CorporateActionApplicationError::InvalidParameter { name: "ratio", .. }) =
+                service.save(corporate_action).await
+            else {
+                return Err(anyhow!("unexpected save corporate action result"));
+            };
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_notexist_company_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let corporate_action = CorporateActionData::new(
+            "9999".to_string(),
+            NaiveDate::from_ymd_opt(2021, 9, 29).unwrap(),
+            1,
+            2,
+        );
+
+        let Err(CorporateActionApplicationError::CompanyNotFound(_)) =
+            service.save(corporate_action).await
+        else {
+            return Err(anyhow!("unexpected save corporate action result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use domain::{
+    corporate_action::{CorporateAction, CorporateActionDomainResult, CorporateActionRepository},
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct InmemoryCorporateActionRepositoryImpl {
+    pub store: Arc<Mutex<Vec<CorporateAction>>>,
+}
+
+impl InmemoryCorporateActionRepositoryImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl CorporateActionRepository for InmemoryCorporateActionRepositoryImpl {
+    async fn save(&self, corporate_action: CorporateAction) -> CorporateActionDomainResult<()> {
+        let mut store = self.store.lock().unwrap();
+        store.retain(|target| {
+            !(target.stock_id() == corporate_action.stock_id()
+                && target.ex_date() == corporate_action.ex_date())
+        });
+        store.push(corporate_action);
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+    ) -> CorporateActionDomainResult<Vec<CorporateAction>> {
+        let mut result: Vec<CorporateAction> = self
+            .store
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|corporate_action| corporate_action.stock_id() == stock_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|corporate_action| *corporate_action.ex_date());
+
+        Ok(result)
+    }
+}
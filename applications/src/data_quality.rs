@@ -0,0 +1,15 @@
+mod data_quality_application_error;
+mod data_quality_check_command;
+mod data_quality_data;
+mod data_quality_issue_kind;
+mod data_quality_service;
+mod data_quality_service_impl;
+
+pub use data_quality_application_error::DataQualityApplicationError;
+pub use data_quality_application_error::DataQualityApplicationResult;
+pub use data_quality_check_command::DataQualityCheckCommand;
+pub use data_quality_data::DataQualityIssueData;
+pub use data_quality_data::DataQualityReportData;
+pub use data_quality_issue_kind::DataQualityIssueKind;
+pub use data_quality_service::DataQualityService;
+pub use data_quality_service_impl::DataQualityServiceImpl;
@@ -0,0 +1,48 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{company::CompanyQueryError, stock::StockQueryError};
+
+#[derive(Error, Debug)]
+pub enum DataQualityApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("invalid date parameter: {name}={value}")]
+    InvalidRangeOfDate {
+        name: &'static str,
+        value: NaiveDate,
+    },
+    #[error("stock data not found: id={0}")]
+    StockDataNotFound(String),
+}
+
+pub type DataQualityApplicationResult<T> = Result<T, DataQualityApplicationError>;
+
+impl From<StockQueryError> for DataQualityApplicationError {
+    fn from(value: StockQueryError) -> Self {
+        match value {
+            StockQueryError::Disconnect(e) => Self::Disconnect(e),
+            StockQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            StockQueryError::InvalidRangeOfDate { name, value } => {
+                Self::InvalidRangeOfDate { name, value }
+            }
+            StockQueryError::StockDataNotFound(stock_id) => Self::StockDataNotFound(stock_id),
+        }
+    }
+}
+
+impl From<CompanyQueryError> for DataQualityApplicationError {
+    fn from(value: CompanyQueryError) -> Self {
+        match value {
+            CompanyQueryError::Disconnect(e) => Self::Disconnect(e),
+            CompanyQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            CompanyQueryError::CompanyNotFound(stock_id) => Self::StockDataNotFound(stock_id),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+use crate::data_quality::{DataQualityApplicationError, DataQualityApplicationResult};
+
+/// 前営業日からの終値の変動率の上限の既定値(30%)
+const DEFAULT_MAX_CHANGE_RATE: f64 = 0.3;
+
+/// 株価データの検査条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataQualityCheckCommand {
+    /// 検査する銘柄(Noneの場合は全銘柄)
+    pub stock_id: Option<String>,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+    /// 前営業日からの終値の変動率(修正株価)の上限、超えた場合は異常値とする
+    pub max_change_rate: f64,
+}
+
+impl DataQualityCheckCommand {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// パラメータチェック
+    pub fn validate(&self) -> DataQualityApplicationResult<()> {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start > end {
+                return Err(DataQualityApplicationError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        }
+        if !self.max_change_rate.is_finite() || self.max_change_rate <= 0.0 {
+            return Err(DataQualityApplicationError::InvalidParameter {
+                name: "max_change_rate",
+                value: self.max_change_rate.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for DataQualityCheckCommand {
+    fn default() -> Self {
+        Self {
+            stock_id: None,
+            start: None,
+            end: None,
+            max_change_rate: DEFAULT_MAX_CHANGE_RATE,
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{data_quality::DataQualityIssueKind, stock::StockData};
+use domain::trading_calendar::TradingCalendar;
+
+/// 株価データの問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataQualityIssueData {
+    pub stock_id: String,
+    pub date: NaiveDate,
+    pub kind: DataQualityIssueKind,
+    /// 問題の詳細
+    pub message: String,
+}
+
+/// 株価データの検査結果
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataQualityReportData {
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+    /// 前営業日からの終値の変動率の上限
+    pub max_change_rate: f64,
+    /// 検査した銘柄数
+    pub checked_stocks: usize,
+    /// 検査した株価の件数
+    pub checked_rows: usize,
+    /// 検出した問題(証券コード・日付の順)
+    pub issues: Vec<DataQualityIssueData>,
+}
+
+impl DataQualityIssueData {
+    /// 1銘柄の日足の株価を検査する
+    ///
+    /// 欠損は最初と最後の株価の間の営業日を対象とし、変動率は株式分割・併合の影響を除いた修正株価で判定する
+    pub fn inspect(
+        stocks: &[StockData],
+        adjusted: &[StockData],
+        calendar: &TradingCalendar,
+        max_change_rate: f64,
+    ) -> Vec<Self> {
+        let mut issues = Vec::new();
+
+        // 営業日の欠損
+        let dates: BTreeSet<NaiveDate> = stocks.iter().map(|s| s.date).collect();
+        if let (Some(first), Some(last)) = (dates.first(), dates.last()) {
+            issues.extend(
+                calendar
+                    .trading_days(*first, *last)
+                    .into_iter()
+                    .filter(|date| !dates.contains(date))
+                    .map(|date| Self {
+                        stock_id: stocks[0].stock_id.clone(),
+                        date,
+                        kind: DataQualityIssueKind::MissingTradingDay,
+                        message: "no stock data on trading day".to_string(),
+                    }),
+            );
+        }
+
+        // 四本値の整合性
+        for stock in stocks {
+            let issue = |kind: DataQualityIssueKind, message: String| Self {
+                stock_id: stock.stock_id.clone(),
+                date: stock.date,
+                kind,
+                message,
+            };
+
+            let non_positive: Vec<String> = [
+                ("start_price", stock.start_price),
+                ("end_price", stock.end_price),
+                ("high_price", stock.high_price),
+                ("low_price", stock.low_price),
+            ]
+            .into_iter()
+            .filter(|(_, price)| *price <= Decimal::ZERO)
+            .map(|(name, price)| format!("{name}={price}"))
+            .collect();
+            if !non_positive.is_empty() {
+                issues.push(issue(
+                    DataQualityIssueKind::NonPositivePrice,
+                    non_positive.join(", "),
+                ));
+                continue;
+            }
+            if stock.high_price < stock.low_price {
+                issues.push(issue(
+                    DataQualityIssueKind::HighBelowLow,
+                    format!(
+                        "high_price={} < low_price={}",
+                        stock.high_price, stock.low_price
+                    ),
+                ));
+                continue;
+            }
+            if !(stock.low_price..=stock.high_price).contains(&stock.start_price) {
+                issues.push(issue(
+                    DataQualityIssueKind::OpenOutsideRange,
+                    format!(
+                        "start_price={} is outside {}..={}",
+                        stock.start_price, stock.low_price, stock.high_price
+                    ),
+                ));
+            }
+            if !(stock.low_price..=stock.high_price).contains(&stock.end_price) {
+                issues.push(issue(
+                    DataQualityIssueKind::CloseOutsideRange,
+                    format!(
+                        "end_price={} is outside {}..={}",
+                        stock.end_price, stock.low_price, stock.high_price
+                    ),
+                ));
+            }
+        }
+
+        // 前営業日からの急激な変動
+        for w in adjusted.windows(2) {
+            let (previous, current) = (&w[0], &w[1]);
+            if previous.end_price <= Decimal::ZERO || current.end_price <= Decimal::ZERO {
+                continue;
+            }
+            let Some(change_rate) = (current.end_price / previous.end_price - Decimal::ONE).to_f64()
+            else {
+                continue;
+            };
+            if change_rate.abs() > max_change_rate {
+                issues.push(Self {
+                    stock_id: current.stock_id.clone(),
+                    date: current.date,
+                    kind: DataQualityIssueKind::ExtremeMove,
+                    message: format!(
+                        "end_price changed {:+.1}% from {} ({} -> {})",
+                        change_rate * 100.0,
+                        previous.date,
+                        previous.end_price,
+                        current.end_price
+                    ),
+                });
+            }
+        }
+
+        issues.sort_by_key(|issue| (issue.date, issue.kind));
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate};
+    use rust_decimal::Decimal;
+
+    use crate::{
+        data_quality::{DataQualityIssueData, DataQualityIssueKind},
+        stock::StockData,
+    };
+    use domain::trading_calendar::TradingCalendar;
+
+    fn stock(day: u32, start: i32, end: i32, high: i32, low: i32) -> StockData {
+        StockData {
+            stock_id: "7203".to_string(),
+            date: NaiveDate::from_ymd_opt(2023, 4, day).unwrap(),
+            volume: 100,
+            start_price: Decimal::from(start),
+            end_price: Decimal::from(end),
+            high_price: Decimal::from(high),
+            low_price: Decimal::from(low),
+        }
+    }
+
+    fn kinds(issues: &[DataQualityIssueData]) -> Vec<(u32, DataQualityIssueKind)> {
+        issues.iter().map(|i| (i.date.day(), i.kind)).collect()
+    }
+
+    #[test]
+    fn inspect_valid_stocks() {
+        // 2023/4/8, 4/9は土日
+        let stocks = vec![
+            stock(6, 100, 110, 120, 90),
+            stock(7, 110, 105, 115, 100),
+            stock(10, 105, 108, 110, 104),
+        ];
+
+        let issues = DataQualityIssueData::inspect(&stocks, &stocks, &TradingCalendar::new(), 0.3);
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn inspect_missing_trading_days() {
+        let stocks = vec![stock(6, 100, 110, 120, 90), stock(12, 110, 105, 115, 100)];
+
+        let issues = DataQualityIssueData::inspect(&stocks, &stocks, &TradingCalendar::new(), 0.3);
+
+        assert!(
+            kinds(&issues)
+                == vec![
+                    (7, DataQualityIssueKind::MissingTradingDay),
+                    (10, DataQualityIssueKind::MissingTradingDay),
+                    (11, DataQualityIssueKind::MissingTradingDay),
+                ]
+        );
+    }
+
+    #[test]
+    fn inspect_missing_trading_days_with_calendar_override() {
+        let stocks = vec![stock(6, 100, 110, 120, 90), stock(10, 110, 105, 115, 100)];
+        let mut calendar = TradingCalendar::new();
+        calendar.set_trading_day(NaiveDate::from_ymd_opt(2023, 4, 7).unwrap(), false);
+
+        let issues = DataQualityIssueData::inspect(&stocks, &stocks, &calendar, 0.3);
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn inspect_invalid_prices() {
+        let stocks = vec![
+            stock(3, 0, 110, 120, 90),
+            stock(4, 100, 100, 90, 110),
+            stock(5, 130, 80, 120, 90),
+            stock(6, 100, 100, 120, 90),
+        ];
+
+        let issues = DataQualityIssueData::inspect(&stocks, &stocks, &TradingCalendar::new(), 0.3);
+
+        assert!(
+            kinds(&issues)
+                == vec![
+                    (3, DataQualityIssueKind::NonPositivePrice),
+                    (4, DataQualityIssueKind::HighBelowLow),
+                    (5, DataQualityIssueKind::OpenOutsideRange),
+                    (5, DataQualityIssueKind::CloseOutsideRange),
+                ]
+        );
+        assert!(issues[0].message == "start_price=0");
+    }
+
+    #[test]
+    fn inspect_extreme_moves_with_adjusted_prices() {
+        // 4/5に1株を2株に分割
+        let stocks = vec![
+            stock(3, 200, 200, 200, 200),
+            stock(4, 200, 200, 200, 200),
+            stock(5, 100, 100, 100, 100),
+            stock(6, 100, 140, 140, 100),
+        ];
+        let adjusted = vec![
+            stock(3, 100, 100, 100, 100),
+            stock(4, 100, 100, 100, 100),
+            stock(5, 100, 100, 100, 100),
+            stock(6, 100, 140, 140, 100),
+        ];
+
+        let issues =
+            DataQualityIssueData::inspect(&stocks, &adjusted, &TradingCalendar::new(), 0.3);
+
+        assert!(kinds(&issues) == vec![(6, DataQualityIssueKind::ExtremeMove)]);
+        assert!(issues[0].message == "end_price changed +40.0% from 2023-04-05 (100 -> 140)");
+    }
+}
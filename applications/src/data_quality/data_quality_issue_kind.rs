@@ -0,0 +1,29 @@
+/// 株価データの問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DataQualityIssueKind {
+    /// 営業日の株価が無い
+    MissingTradingDay,
+    /// 0以下の価格
+    NonPositivePrice,
+    /// 高値が安値を下回っている
+    HighBelowLow,
+    /// 始値が高値・安値の範囲外
+    OpenOutsideRange,
+    /// 終値が高値・安値の範囲外
+    CloseOutsideRange,
+    /// 前営業日からの終値の変動率が大きすぎる
+    ExtremeMove,
+}
+
+impl DataQualityIssueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataQualityIssueKind::MissingTradingDay => "missing_trading_day",
+            DataQualityIssueKind::NonPositivePrice => "non_positive_price",
+            DataQualityIssueKind::HighBelowLow => "high_below_low",
+            DataQualityIssueKind::OpenOutsideRange => "open_outside_range",
+            DataQualityIssueKind::CloseOutsideRange => "close_outside_range",
+            DataQualityIssueKind::ExtremeMove => "extreme_move",
+        }
+    }
+}
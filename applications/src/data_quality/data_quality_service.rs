@@ -0,0 +1,12 @@
+use crate::data_quality::{
+    DataQualityApplicationResult, DataQualityCheckCommand, DataQualityReportData,
+};
+
+#[async_trait::async_trait]
+pub trait DataQualityService {
+    /// 銘柄ごとに株価の欠損・異常値を検出する
+    async fn check(
+        &self,
+        command: DataQualityCheckCommand,
+    ) -> DataQualityApplicationResult<DataQualityReportData>;
+}
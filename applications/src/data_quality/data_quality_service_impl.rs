@@ -0,0 +1,241 @@
+use crate::{
+    company::{CompanyQueryCommand, CompanyQueryService},
+    data_quality::{
+        DataQualityApplicationError, DataQualityApplicationResult, DataQualityCheckCommand,
+        DataQualityIssueData, DataQualityReportData, DataQualityService,
+    },
+    stock::{StockQueryCommand, StockQueryService},
+};
+use domain::trading_calendar::TradingCalendar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DataQualityServiceImpl<T, U>
+where
+    T: CompanyQueryService,
+    U: StockQueryService,
+{
+    company_query_service: T,
+    stock_query_service: U,
+    trading_calendar: TradingCalendar,
+}
+
+impl<T, U> DataQualityServiceImpl<T, U>
+where
+    T: CompanyQueryService,
+    U: StockQueryService,
+{
+    /// コンストラクタ
+    pub fn new(
+        company_query_service: T,
+        stock_query_service: U,
+        trading_calendar: TradingCalendar,
+    ) -> Self {
+        Self {
+            company_query_service,
+            stock_query_service,
+            trading_calendar,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U> DataQualityService for DataQualityServiceImpl<T, U>
+where
+    T: CompanyQueryService + std::fmt::Debug + Send + Sync,
+    U: StockQueryService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn check(
+        &self,
+        command: DataQualityCheckCommand,
+    ) -> DataQualityApplicationResult<DataQualityReportData> {
+        command.validate()?;
+
+        let stock_ids = if let Some(stock_id) = &command.stock_id {
+            vec![stock_id.clone()]
+        } else {
+            self.company_query_service
+                .find(CompanyQueryCommand::new())
+                .await?
+                .into_iter()
+                .map(|company| company.stock_id)
+                .collect()
+        };
+
+        let mut report = DataQualityReportData {
+            start: command.start,
+            end: command.end,
+            max_change_rate: command.max_change_rate,
+            ..Default::default()
+        };
+        for stock_id in stock_ids {
+            let mut param = StockQueryCommand::new();
+            param.stock_id = Some(stock_id);
+            param.start = command.start;
+            param.end = command.end;
+            let stocks = self.stock_query_service.find(param.clone()).await?;
+            if stocks.is_empty() {
+                continue;
+            }
+            // 株式分割・併合による株価の変動を除く
+            param.adjusted = true;
+            let adjusted = self.stock_query_service.find(param).await?;
+
+            report.checked_stocks += 1;
+            report.checked_rows += stocks.len();
+            report.issues.extend(DataQualityIssueData::inspect(
+                &stocks,
+                &adjusted,
+                &self.trading_calendar,
+                command.max_change_rate,
+            ));
+        }
+
+        if let (Some(stock_id), 0) = (command.stock_id, report.checked_stocks) {
+            return Err(DataQualityApplicationError::StockDataNotFound(stock_id));
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        company::{CompanyData, InmemoryCompanyQueryServiceImpl},
+        corporate_action::CorporateActionData,
+        data_quality::{
+            DataQualityApplicationError, DataQualityCheckCommand, DataQualityIssueKind,
+            DataQualityService, DataQualityServiceImpl,
+        },
+        stock::{InmemoryStockQueryServiceImpl, StockData},
+    };
+    use domain::trading_calendar::TradingCalendar;
+
+    fn setup() -> impl DataQualityService {
+        let mut company_query_service = InmemoryCompanyQueryServiceImpl::new();
+        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
+        for stock_id in ["1111", "2222", "3333"] {
+            company_query_service.companies.push(CompanyData {
+                stock_id: stock_id.to_string(),
+                name: format!("company {stock_id}"),
+                sector: "sector".to_string(),
+                industry: "industry".to_string(),
+            });
+        }
+        // 1111: 4/5に1株を2株に分割, 4/7の株価が欠損
+        // 2222: 4/6の高値が安値を下回っている
+        // 3333: 株価なし
+        for (stock_id, day, price, high) in [
+            ("1111", 3, 200, 200),
+            ("1111", 4, 200, 200),
+            ("1111", 5, 100, 100),
+            ("1111", 6, 100, 100),
+            ("1111", 10, 100, 100),
+            ("2222", 5, 100, 100),
+            ("2222", 6, 100, 90),
+            ("2222", 7, 100, 100),
+        ] {
+            stock_query_service.stocks.push(StockData {
+                stock_id: stock_id.to_string(),
+                date: NaiveDate::from_ymd_opt(2023, 4, day).unwrap(),
+                volume: 100,
+                start_price: Decimal::from(price),
+                end_price: Decimal::from(price),
+                high_price: Decimal::from(high),
+                low_price: Decimal::from(price),
+            });
+        }
+        stock_query_service
+            .corporate_actions
+            .push(CorporateActionData {
+                stock_id: "1111".to_string(),
+                ex_date: NaiveDate::from_ymd_opt(2023, 4, 5).unwrap(),
+                ratio_from: 1,
+                ratio_to: 2,
+            });
+
+        DataQualityServiceImpl::new(
+            company_query_service,
+            stock_query_service,
+            TradingCalendar::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn check_all_stocks() -> anyhow::Result<()> {
+        let service = setup();
+
+        let report = service.check(DataQualityCheckCommand::new()).await?;
+
+        assert!(report.checked_stocks == 2);
+        assert!(report.checked_rows == 8);
+        let issues: Vec<(&str, DataQualityIssueKind)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.stock_id.as_str(), issue.kind))
+            .collect();
+        // 分割による株価の変動は異常値としない
+        assert!(
+            issues
+                == vec![
+                    ("1111", DataQualityIssueKind::MissingTradingDay),
+                    ("2222", DataQualityIssueKind::HighBelowLow),
+                ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stock_with_range() -> anyhow::Result<()> {
+        let service = setup();
+
+        let mut command = DataQualityCheckCommand::new();
+        command.stock_id = Some("1111".to_string());
+        command.end = NaiveDate::from_ymd_opt(2023, 4, 6);
+        let report = service.check(command).await?;
+
+        assert!(report.checked_stocks == 1);
+        assert!(report.checked_rows == 4);
+        assert!(report.issues.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stock_without_data() -> anyhow::Result<()> {
+        let service = setup();
+
+        let mut command = DataQualityCheckCommand::new();
+        command.stock_id = Some("3333".to_string());
+        let result = service.check(command).await;
+
+        let Err(DataQualityApplicationError::StockDataNotFound(stock_id)) = result else {
+            return Err(anyhow!("unexpected result: {:?}", result));
+        };
+        assert!(stock_id == "3333");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_with_invalid_max_change_rate() -> anyhow::Result<()> {
+        let service = setup();
+
+        let mut command = DataQualityCheckCommand::new();
+        command.max_change_rate = 0.0;
+        let result = service.check(command).await;
+
+        let Err(DataQualityApplicationError::InvalidParameter { name, .. }) = result else {
+            return Err(anyhow!("unexpected result: {:?}", result));
+        };
+        assert!(name == "max_change_rate");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+mod dividend_application_error;
+mod dividend_data;
+mod dividend_service;
+mod dividend_service_impl;
+mod inmemory_dividend_repository_impl;
+
+pub use dividend_application_error::DividendApplicationError;
+pub use dividend_application_error::DividendApplicationResult;
+pub use dividend_data::DividendData;
+pub use dividend_service::DividendService;
+pub use dividend_service_impl::DividendServiceImpl;
+pub use inmemory_dividend_repository_impl::InmemoryDividendRepositoryImpl;
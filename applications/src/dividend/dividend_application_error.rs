@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+use crate::company::CompanyQueryError;
+use domain::{dividend::DividendDomainError, stock::StockDomainError};
+
+#[derive(Error, Debug)]
+pub enum DividendApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("company data not found: id={0}")]
+    CompanyNotFound(String),
+}
+
+pub type DividendApplicationResult<T> = Result<T, DividendApplicationError>;
+
+impl From<DividendDomainError> for DividendApplicationError {
+    fn from(value: DividendDomainError) -> Self {
+        match value {
+            DividendDomainError::Disconnect(e) => Self::Disconnect(e),
+            DividendDomainError::InvalidAmount(amount) => Self::InvalidParameter {
+                name: "amount_per_share",
+                value: amount.to_string(),
+            },
+            DividendDomainError::InvalidDate { name, value } => Self::InvalidParameter {
+                name,
+                value: value.to_string(),
+            },
+        }
+    }
+}
+
+impl From<StockDomainError> for DividendApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
+
+impl From<CompanyQueryError> for DividendApplicationError {
+    fn from(value: CompanyQueryError) -> Self {
+        match value {
+            CompanyQueryError::Disconnect(e) => Self::Disconnect(e),
+            CompanyQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            CompanyQueryError::CompanyNotFound(stock_id) => Self::CompanyNotFound(stock_id),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::dividend::DividendApplicationError;
+use domain::{
+    dividend::{Dividend, DividendDomainError},
+    money::Price,
+    stock::StockId,
+};
+
+/// 1株当たり配当金
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DividendData {
+    pub stock_id: String,
+    /// 権利落ち日
+    pub ex_date: NaiveDate,
+    /// 権利確定日
+    pub record_date: NaiveDate,
+    /// 支払開始日
+    pub payment_date: NaiveDate,
+    /// 1株当たり配当金(円)
+    pub amount_per_share: Decimal,
+}
+
+impl DividendData {
+    /// コンストラクタ
+    pub fn new(
+        stock_id: String,
+        ex_date: NaiveDate,
+        record_date: NaiveDate,
+        payment_date: NaiveDate,
+        amount_per_share: Decimal,
+    ) -> Self {
+        Self {
+            stock_id,
+            ex_date,
+            record_date,
+            payment_date,
+            amount_per_share,
+        }
+    }
+}
+
+impl From<Dividend> for DividendData {
+    fn from(value: Dividend) -> Self {
+        Self {
+            stock_id: value.stock_id().to_string(),
+            ex_date: *value.ex_date(),
+            record_date: *value.record_date(),
+            payment_date: *value.payment_date(),
+            amount_per_share: value.amount_per_share().into(),
+        }
+    }
+}
+
+impl TryFrom<DividendData> for Dividend {
+    type Error = DividendApplicationError;
+
+    fn try_from(value: DividendData) -> Result<Self, Self::Error> {
+        let amount_per_share = Price::new(value.amount_per_share)
+            .map_err(|_| DividendDomainError::InvalidAmount(value.amount_per_share))?;
+
+        Ok(Dividend::new(
+            StockId::new(value.stock_id)?,
+            value.ex_date,
+            value.record_date,
+            value.payment_date,
+            amount_per_share,
+        )?)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::dividend::{DividendApplicationResult, DividendData};
+
+#[async_trait::async_trait]
+pub trait DividendService {
+    async fn get_all(&self, stock_id: &str) -> DividendApplicationResult<Vec<DividendData>>;
+    async fn save(&self, dividend: DividendData) -> DividendApplicationResult<()>;
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use crate::{
+    company::CompanyQueryService,
+    dividend::{DividendApplicationResult, DividendData, DividendService},
+};
+use domain::{
+    dividend::{Dividend, DividendRepository},
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DividendServiceImpl<T, U>
+where
+    T: DividendRepository,
+    U: CompanyQueryService,
+{
+    dividend_repository: Arc<T>,
+    company_query_service: U,
+}
+
+impl<T, U> DividendServiceImpl<T, U>
+where
+    T: DividendRepository,
+    U: CompanyQueryService,
+{
+    /// コンストラクタ
+    pub fn new(dividend_repository: &Arc<T>, company_query_service: U) -> Self {
+        Self {
+            dividend_repository: Arc::clone(dividend_repository),
+            company_query_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U> DividendService for DividendServiceImpl<T, U>
+where
+    T: DividendRepository + std::fmt::Debug + Send + Sync,
+    U: CompanyQueryService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn get_all(&self, stock_id: &str) -> DividendApplicationResult<Vec<DividendData>> {
+        let stock_id = StockId::new(stock_id.to_string())?;
+        let result = self
+            .dividend_repository
+            .find_all(&stock_id)
+            .await?
+            .into_iter()
+            .map(DividendData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn save(&self, dividend: DividendData) -> DividendApplicationResult<()> {
+        let dividend = Dividend::try_from(dividend)?;
+        self.company_query_service
+            .find_by_id(dividend.stock_id())
+            .await?;
+
+        self.dividend_repository.save(dividend).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        company::{CompanyData, InmemoryCompanyQueryServiceImpl},
+        dividend::{
+            DividendApplicationError, DividendData, DividendService, DividendServiceImpl,
+            InmemoryDividendRepositoryImpl,
+        },
+    };
+
+    const STOCK_ID: &str = "8058";
+
+    fn setup() -> impl DividendService {
+        let mut company_query_service = InmemoryCompanyQueryServiceImpl::new();
+        company_query_service.companies.push(CompanyData {
+            name: "三菱商事".to_string(),
+            stock_id: STOCK_ID.to_string(),
+            sector: "卸売業".to_string(),
+            industry: "商業".to_string(),
+        });
+
+        let dividend_repository = Arc::new(InmemoryDividendRepositoryImpl::new());
+        DividendServiceImpl::new(&dividend_repository, company_query_service)
+    }
+
+    fn dividend(ex_date: (i32, u32, u32), amount_per_share: Decimal) -> DividendData {
+        let ex_date = NaiveDate::from_ymd_opt(ex_date.0, ex_date.1, ex_date.2).unwrap();
+        DividendData::new(
+            STOCK_ID.to_string(),
+            ex_date,
+            ex_date.succ_opt().unwrap(),
+            ex_date + chrono::Duration::days(90),
+            amount_per_share,
+        )
+    }
+
+    #[tokio::test]
+    async fn save_dividend_success() -> anyhow::Result<()> {
+        let service = setup();
+        let year_end = dividend((2023, 3, 30), Decimal::from(85));
+        let interim = dividend((2022, 9, 29), Decimal::from(70));
+        service.save(year_end.clone()).await?;
+        service.save(interim.clone()).await?;
+        // 同じ権利落ち日は上書き
+        let revised = dividend((2023, 3, 30), Decimal::new(905, 1));
+        service.save(revised.clone()).await?;
+
+        let result = service.get_all(STOCK_ID).await?;
+        // 権利落ち日の昇順
+        assert!(result == vec![interim, revised]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_invalid_dividend_return_err() -> anyhow::Result<()> {
+        let service = setup();
+
+        let mut invalid_amount = dividend((2023, 3, 30), Decimal::ZERO);
+        let Err(DividendApplicationError::InvalidParameter {
+            name: "amount_per_share",
+            ..
+        }) = service.save(invalid_amount.clone()).await
+        else {
+            return Err(anyhow!("unexpected save dividend result"));
+        };
+        invalid_amount.amount_per_share = Decimal::from(-85);
+        let Err(DividendApplicationError::InvalidParameter {
+            name: "amount_per_share",
+            ..
+        }) = service.save(invalid_amount.clone()).await
+        else {
+            return Err(anyhow!("unexpected save dividend result"));
+        };
+
+        invalid_amount.amount_per_share = Decimal::from(85);
+        invalid_amount.payment_date = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+        let Err(DividendApplicationError::InvalidParameter {
+            name: "payment_date",
+            ..
+        }) = service.save(invalid_amount).await
+        else {
+            return Err(anyhow!("unexpected save dividend result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_notexist_company_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut dividend = dividend((2023, 3, 30), Decimal::from(85));
+        dividend.stock_id = "9999".to_string();
+
+        let Err(DividendApplicationError::CompanyNotFound(_)) = service.save(dividend).await else {
+            return Err(anyhow!("unexpected save dividend result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::{Arc, Mutex};
+
+use domain::{
+    dividend::{Dividend, DividendDomainResult, DividendRepository},
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct InmemoryDividendRepositoryImpl {
+    pub store: Arc<Mutex<Vec<Dividend>>>,
+}
+
+impl InmemoryDividendRepositoryImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl DividendRepository for InmemoryDividendRepositoryImpl {
+    async fn save(&self, dividend: Dividend) -> DividendDomainResult<()> {
+        let mut store = self.store.lock().unwrap();
+        store.retain(|target| {
+            !(target.stock_id() == dividend.stock_id() && target.ex_date() == dividend.ex_date())
+        });
+        store.push(dividend);
+
+        Ok(())
+    }
+
+    async fn find_all(&self, stock_id: &StockId) -> DividendDomainResult<Vec<Dividend>> {
+        let mut result: Vec<Dividend> = self
+            .store
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|dividend| dividend.stock_id() == stock_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|dividend| *dividend.ex_date());
+
+        Ok(result)
+    }
+}
@@ -2,11 +2,15 @@ mod favorite_application_error;
 mod favorite_data;
 mod favorite_service;
 mod favorite_service_impl;
+mod favorite_update_command;
 mod inmemory_favorite_repository_impl;
+mod watchlist_data;
 
 pub use favorite_application_error::FavoriteApplicationError;
 pub use favorite_application_error::FavoriteApplicationResult;
 pub use favorite_data::FavoriteData;
 pub use favorite_service::FavoriteService;
 pub use favorite_service_impl::FavoriteServiceImpl;
+pub use favorite_update_command::FavoriteUpdateCommand;
 pub use inmemory_favorite_repository_impl::InmemoryFavoriteRepositoryImpl;
+pub use watchlist_data::WatchlistData;
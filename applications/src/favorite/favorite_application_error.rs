@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use domain::{favorite::FavoriteDomainError, user::UserDomainError};
+use domain::{favorite::FavoriteDomainError, stock::StockDomainError, user::UserDomainError};
 
 #[derive(Error, Debug)]
 pub enum FavoriteApplicationError {
@@ -10,6 +10,12 @@ pub enum FavoriteApplicationError {
     UserNotFound(String),
     #[error("user is already exsist: id={0:?}")]
     UserAlreadyExist(String),
+    #[error("watchlist not found: id={0}")]
+    WatchlistNotFound(i32),
+    #[error("favorite not found: id={0}")]
+    FavoriteNotFound(String),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
 }
 
 pub type FavoriteApplicationResult<T> = Result<T, FavoriteApplicationError>;
@@ -18,6 +24,29 @@ impl From<FavoriteDomainError> for FavoriteApplicationError {
     fn from(value: FavoriteDomainError) -> Self {
         match value {
             FavoriteDomainError::Disconnect(e) => Self::Disconnect(e),
+            FavoriteDomainError::InvalidWatchlistName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
+            FavoriteDomainError::InvalidNote(value) => Self::InvalidParameter {
+                name: "note",
+                value,
+            },
+            FavoriteDomainError::InvalidTag(value) => Self::InvalidParameter {
+                name: "tags",
+                value,
+            },
+        }
+    }
+}
+
+impl From<StockDomainError> for FavoriteApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
         }
     }
 }
@@ -28,6 +57,14 @@ impl From<UserDomainError> for FavoriteApplicationError {
             UserDomainError::Disconnect(e) => Self::Disconnect(e),
             UserDomainError::UserAlreadyExist(user_id) => Self::UserAlreadyExist(user_id.into()),
             UserDomainError::UserNotFound(user_id) => Self::UserNotFound(user_id.into()),
+            UserDomainError::InvalidUserEmail(value) => Self::InvalidParameter {
+                name: "email",
+                value,
+            },
+            UserDomainError::InvalidUserName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
         }
     }
 }
@@ -1,32 +1,42 @@
-use domain::{favorite::Favorite, stock::StockId, user::UserId};
+use chrono::NaiveDateTime;
+
+use domain::favorite::Favorite;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct FavoriteData {
+    /// ウォッチリストのID(Noneの場合は既定のウォッチリスト)
+    pub watchlist_id: Option<i32>,
     pub user_id: String,
     pub stock_id: String,
+    /// ウォッチリスト内の表示順(1始まり)
+    pub sort_order: i32,
+    pub note: String,
+    pub tags: Vec<String>,
+    /// 登録日時(UTC)
+    pub added_at: NaiveDateTime,
 }
 
 impl FavoriteData {
     /// コンストラクタ
     pub fn new(user_id: String, stock_id: String) -> Self {
-        Self { user_id, stock_id }
+        Self {
+            user_id,
+            stock_id,
+            ..Default::default()
+        }
     }
 }
 
 impl From<Favorite> for FavoriteData {
     fn from(favorite: Favorite) -> Self {
         Self {
+            watchlist_id: Some(*favorite.watchlist_id),
             user_id: favorite.user_id.to_string(),
             stock_id: favorite.stock_id.to_string(),
-        }
-    }
-}
-
-impl Into<Favorite> for FavoriteData {
-    fn into(self) -> Favorite {
-        Favorite {
-            user_id: UserId::new(self.user_id),
-            stock_id: StockId::new(self.stock_id),
+            sort_order: favorite.sort_order,
+            note: favorite.note,
+            tags: favorite.tags,
+            added_at: favorite.added_at,
         }
     }
 }
@@ -1,8 +1,39 @@
-use crate::favorite::{FavoriteApplicationResult, FavoriteData};
+use crate::favorite::{
+    FavoriteApplicationResult, FavoriteData, FavoriteUpdateCommand, WatchlistData,
+};
 
+/// watchlist_idがNoneの場合は既定のウォッチリスト(最初に作成したウォッチリスト)を対象とする
 #[async_trait::async_trait]
 pub trait FavoriteService {
-    async fn add(&self, favorite: FavoriteData) -> FavoriteApplicationResult<()>;
+    /// 登録順に取得する
+    async fn get_watchlists(&self, user_id: &str) -> FavoriteApplicationResult<Vec<WatchlistData>>;
+    async fn add_watchlist(
+        &self,
+        watchlist: WatchlistData,
+    ) -> FavoriteApplicationResult<WatchlistData>;
+    async fn rename_watchlist(
+        &self,
+        user_id: &str,
+        watchlist_id: i32,
+        name: String,
+    ) -> FavoriteApplicationResult<WatchlistData>;
+    /// 登録されている銘柄もあわせて削除する
+    async fn remove_watchlist(
+        &self,
+        user_id: &str,
+        watchlist_id: i32,
+    ) -> FavoriteApplicationResult<()>;
+    /// 表示順に取得する
+    async fn get_all(
+        &self,
+        user_id: &str,
+        watchlist_id: Option<i32>,
+    ) -> FavoriteApplicationResult<Vec<FavoriteData>>;
+    /// ウォッチリストの末尾に追加する(登録済みの場合は登録情報を変更しない)
+    async fn add(&self, favorite: FavoriteData) -> FavoriteApplicationResult<FavoriteData>;
+    async fn update(
+        &self,
+        update_command: FavoriteUpdateCommand,
+    ) -> FavoriteApplicationResult<FavoriteData>;
     async fn remove(&self, favorite: FavoriteData) -> FavoriteApplicationResult<()>;
-    async fn get_all(&self, user_id: &str) -> FavoriteApplicationResult<Vec<FavoriteData>>;
 }
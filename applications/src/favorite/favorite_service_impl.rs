@@ -1,11 +1,20 @@
 use std::sync::Arc;
 
-use crate::favorite::{FavoriteApplicationResult, FavoriteData, FavoriteService};
+use chrono::Utc;
+
+use crate::favorite::{
+    FavoriteApplicationError, FavoriteApplicationResult, FavoriteData, FavoriteService,
+    FavoriteUpdateCommand, WatchlistData,
+};
 use domain::{
-    favorite::FavoriteRepository,
+    favorite::{Favorite, FavoriteRepository, Watchlist, WatchlistId},
+    stock::StockId,
     user::{UserDomainService, UserId, UserRepository},
 };
 
+/// 既定のウォッチリストが未作成の場合に作成するウォッチリスト名
+const DEFAULT_WATCHLIST_NAME: &str = "default";
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct FavoriteServiceImpl<T, U>
 where
@@ -37,45 +46,245 @@ where
     U: UserRepository + Send + Sync,
 {
     #[tracing::instrument(skip(self), err, ret)]
-    async fn get_all(&self, user_id: &str) -> FavoriteApplicationResult<Vec<FavoriteData>> {
+    async fn get_watchlists(&self, user_id: &str) -> FavoriteApplicationResult<Vec<WatchlistData>> {
         let user_id = UserId::new(user_id.into());
         self.user_domain_service.exists(&user_id).await?;
 
         let result = self
             .favorite_repository
-            .find_all(&user_id)
-            .await
-            .map(|favorites| {
-                favorites
-                    .into_iter()
-                    .map(|f| FavoriteData::from(f))
-                    .collect()
-            })?;
+            .find_watchlists(&user_id)
+            .await?
+            .into_iter()
+            .map(WatchlistData::from)
+            .collect();
 
         Ok(result)
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn add(&self, favorite: FavoriteData) -> FavoriteApplicationResult<()> {
-        let user_id = UserId::new(favorite.user_id.clone());
+    async fn add_watchlist(
+        &self,
+        watchlist: WatchlistData,
+    ) -> FavoriteApplicationResult<WatchlistData> {
+        let user_id = UserId::new(watchlist.user_id.clone());
         self.user_domain_service.exists(&user_id).await?;
 
-        self.favorite_repository.save(favorite.into()).await?;
+        // 新規登録のため指定されたIDは無視する
+        let watchlist = Watchlist::try_from(WatchlistData {
+            id: None,
+            ..watchlist
+        })?;
+        let id = self
+            .favorite_repository
+            .save_watchlist(watchlist.clone())
+            .await?;
+
+        Ok(watchlist.with_id(id).into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn rename_watchlist(
+        &self,
+        user_id: &str,
+        watchlist_id: i32,
+        name: String,
+    ) -> FavoriteApplicationResult<WatchlistData> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let watchlist_id = WatchlistId::new(watchlist_id);
+        let mut watchlist = self
+            .favorite_repository
+            .find_watchlists(&user_id)
+            .await?
+            .into_iter()
+            .find(|w| w.id() == Some(watchlist_id))
+            .ok_or(FavoriteApplicationError::WatchlistNotFound(*watchlist_id))?;
+
+        watchlist.rename(name)?;
+        self.favorite_repository
+            .save_watchlist(watchlist.clone())
+            .await?;
+
+        Ok(watchlist.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_watchlist(
+        &self,
+        user_id: &str,
+        watchlist_id: i32,
+    ) -> FavoriteApplicationResult<()> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let Some(watchlist_id) = self.find_watchlist_id(&user_id, Some(watchlist_id)).await? else {
+            return Ok(());
+        };
+        self.favorite_repository
+            .delete_watchlist(&user_id, &watchlist_id)
+            .await?;
 
         Ok(())
     }
 
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn get_all(
+        &self,
+        user_id: &str,
+        watchlist_id: Option<i32>,
+    ) -> FavoriteApplicationResult<Vec<FavoriteData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let Some(watchlist_id) = self.find_watchlist_id(&user_id, watchlist_id).await? else {
+            return Ok(vec![]);
+        };
+        let result = self
+            .favorite_repository
+            .find_all(&watchlist_id)
+            .await?
+            .into_iter()
+            .map(FavoriteData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add(&self, favorite: FavoriteData) -> FavoriteApplicationResult<FavoriteData> {
+        let user_id = UserId::new(favorite.user_id.clone());
+        self.user_domain_service.exists(&user_id).await?;
+        let stock_id = StockId::new(favorite.stock_id)?;
+
+        let watchlist_id = self
+            .get_or_create_watchlist_id(&user_id, favorite.watchlist_id)
+            .await?;
+        let favorites = self.favorite_repository.find_all(&watchlist_id).await?;
+        if let Some(registered) = favorites.iter().find(|f| f.stock_id == stock_id) {
+            return Ok(registered.clone().into());
+        }
+
+        let mut new_favorite =
+            Favorite::new(watchlist_id, user_id, stock_id, Utc::now().naive_utc());
+        new_favorite.sort_order = favorites.iter().map(|f| f.sort_order).max().unwrap_or(0) + 1;
+        new_favorite.update_note(favorite.note)?;
+        new_favorite.update_tags(favorite.tags)?;
+        self.favorite_repository.save(new_favorite.clone()).await?;
+
+        Ok(new_favorite.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn update(
+        &self,
+        update_command: FavoriteUpdateCommand,
+    ) -> FavoriteApplicationResult<FavoriteData> {
+        let user_id = UserId::new(update_command.user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let stock_id = StockId::new(update_command.stock_id)?;
+        let mut favorites = match self
+            .find_watchlist_id(&user_id, update_command.watchlist_id)
+            .await?
+        {
+            Some(watchlist_id) => self.favorite_repository.find_all(&watchlist_id).await?,
+            None => vec![],
+        };
+        let Some(index) = favorites.iter().position(|f| f.stock_id == stock_id) else {
+            return Err(FavoriteApplicationError::FavoriteNotFound(
+                stock_id.to_string(),
+            ));
+        };
+
+        let mut target = favorites.remove(index);
+        if let Some(note) = update_command.note {
+            target.update_note(note)?;
+        }
+        if let Some(tags) = update_command.tags {
+            target.update_tags(tags)?;
+        }
+        let Some(sort_order) = update_command.sort_order else {
+            self.favorite_repository.save(target.clone()).await?;
+            return Ok(target.into());
+        };
+
+        // 指定された位置に移動し、表示順を振り直す
+        let index = (sort_order.clamp(1, favorites.len() as i32 + 1) - 1) as usize;
+        favorites.insert(index, target);
+        for (i, favorite) in favorites.iter_mut().enumerate() {
+            let sort_order = i as i32 + 1;
+            if favorite.sort_order != sort_order || i == index {
+                favorite.sort_order = sort_order;
+                self.favorite_repository.save(favorite.clone()).await?;
+            }
+        }
+
+        Ok(favorites.swap_remove(index).into())
+    }
+
     #[tracing::instrument(skip(self), err)]
     async fn remove(&self, favorite: FavoriteData) -> FavoriteApplicationResult<()> {
         let user_id = UserId::new(favorite.user_id.clone());
         self.user_domain_service.exists(&user_id).await?;
 
-        self.favorite_repository.delete(favorite.into()).await?;
+        let Some(watchlist_id) = self
+            .find_watchlist_id(&user_id, favorite.watchlist_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        self.favorite_repository
+            .delete(&watchlist_id, &StockId::new(favorite.stock_id)?)
+            .await?;
 
         Ok(())
     }
 }
 
+impl<T, U> FavoriteServiceImpl<T, U>
+where
+    T: FavoriteRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+{
+    /// 指定されたウォッチリストのIDを確認する
+    ///
+    /// 未指定の場合は既定のウォッチリストを対象とし、まだ作成されていなければNoneを返す
+    async fn find_watchlist_id(
+        &self,
+        user_id: &UserId,
+        watchlist_id: Option<i32>,
+    ) -> FavoriteApplicationResult<Option<WatchlistId>> {
+        let watchlists = self.favorite_repository.find_watchlists(user_id).await?;
+        let Some(watchlist_id) = watchlist_id else {
+            return Ok(watchlists.first().and_then(|w| w.id()));
+        };
+
+        let watchlist_id = WatchlistId::new(watchlist_id);
+        if !watchlists.iter().any(|w| w.id() == Some(watchlist_id)) {
+            return Err(FavoriteApplicationError::WatchlistNotFound(*watchlist_id));
+        }
+
+        Ok(Some(watchlist_id))
+    }
+
+    /// 既定のウォッチリストが未作成の場合は作成してIDを返す
+    async fn get_or_create_watchlist_id(
+        &self,
+        user_id: &UserId,
+        watchlist_id: Option<i32>,
+    ) -> FavoriteApplicationResult<WatchlistId> {
+        if let Some(watchlist_id) = self.find_watchlist_id(user_id, watchlist_id).await? {
+            return Ok(watchlist_id);
+        }
+
+        let watchlist = Watchlist::new(None, user_id.clone(), DEFAULT_WATCHLIST_NAME.to_string())?;
+        let watchlist_id = self.favorite_repository.save_watchlist(watchlist).await?;
+
+        Ok(watchlist_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc};
@@ -83,15 +292,19 @@ mod tests {
     use anyhow::anyhow;
 
     use crate::{
-        favorite::{FavoriteService, FavoriteServiceImpl, InmemoryFavoriteRepositoryImpl, FavoriteData, FavoriteApplicationError},
+        favorite::{
+            FavoriteApplicationError, FavoriteData, FavoriteService, FavoriteServiceImpl,
+            FavoriteUpdateCommand, InmemoryFavoriteRepositoryImpl, WatchlistData,
+        },
         user::InmemoryUserRepositoryImpl,
     };
     use domain::{user::{UserDomainService, User, UserId, UserEmail, UserName, UserRepository}};
 
     const USER_ID: &str = "sample user";
 
+    #[allow(clippy::let_and_return)]
     async fn setup() -> impl FavoriteService {
-        let sample_user = User::new(UserId::new(USER_ID.to_string()), UserName::new("".to_string()), UserEmail::new("".to_string()));
+        let sample_user = User::new(UserId::new(USER_ID.to_string()), UserName::default(), UserEmail::default());
         let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
         user_repository.save(sample_user).await.unwrap();
         let favorite_repository = Arc::new(InmemoryFavoriteRepositoryImpl::new());
@@ -105,20 +318,33 @@ mod tests {
     #[tokio::test]
     async fn add_favorite_success() -> anyhow::Result<()> {
         let service = setup().await;
-        let favorite = FavoriteData::new(USER_ID.to_string(), "sample".to_string());
+        let favorite = FavoriteData::new(USER_ID.to_string(), "7203".to_string());
         service.add(favorite).await?;
         
-        let result = service.get_all(USER_ID).await?;
+        let result = service.get_all(USER_ID, None).await?;
         assert!(result[0].user_id == USER_ID);
         assert!(result.len() == 1);
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn add_invalid_stock_id_favorite_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        let favorite = FavoriteData::new(USER_ID.to_string(), "72O3".to_string());
+
+        let Err(FavoriteApplicationError::InvalidParameter { name: "stock_id", .. }) = service.add(favorite).await else {
+            return Err(anyhow!("unexpected add favorite result"));
+        };
+        assert!(service.get_all(USER_ID, None).await?.is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn add_notexist_user_favorite_return_err() -> anyhow::Result<()> {
         let service = setup().await;
-        let favorite = FavoriteData::new("not registed user".to_string(), "sample".to_string());
+        let favorite = FavoriteData::new("not registed user".to_string(), "7203".to_string());
         
         let Err(FavoriteApplicationError::UserNotFound(_)) = service.add(favorite.clone()).await else {
             return Err(anyhow!("unexpected add favorite result"));
@@ -130,9 +356,9 @@ mod tests {
     #[tokio::test]
     async fn get_all_notexist_user_favorite_return_err() -> anyhow::Result<()> {
         let service = setup().await;
-        let favorite = FavoriteData::new("not registed user".to_string(), "sample".to_string());
+        let favorite = FavoriteData::new("not registed user".to_string(), "7203".to_string());
         
-        let Err(FavoriteApplicationError::UserNotFound(_)) = service.get_all(&favorite.user_id).await else {
+        let Err(FavoriteApplicationError::UserNotFound(_)) = service.get_all(&favorite.user_id, None).await else {
             return Err(anyhow!("unexpected add favorite result"));
         };        
 
@@ -140,16 +366,17 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::len_zero)]
     async fn remove_favorite_success() -> anyhow::Result<()> {
         let service = setup().await;
-        let favorite = FavoriteData::new(USER_ID.to_string(), "sample".to_string());
+        let favorite = FavoriteData::new(USER_ID.to_string(), "7203".to_string());
         service.add(favorite.clone()).await?;
         
-        let result = service.get_all(USER_ID).await?;
+        let result = service.get_all(USER_ID, None).await?;
         assert!(result.len() == 1);
 
         service.remove(favorite).await?;
-        let result = service.get_all(USER_ID).await?;
+        let result = service.get_all(USER_ID, None).await?;
         assert!(result.len() == 0);
 
         Ok(())
@@ -158,7 +385,7 @@ mod tests {
     #[tokio::test]
     async fn remove_notexist_user_favorite_return_err() -> anyhow::Result<()> {
         let service = setup().await;
-        let favorite = FavoriteData::new("not registed user".to_string(), "sample".to_string());
+        let favorite = FavoriteData::new("not registed user".to_string(), "7203".to_string());
         
         let Err(FavoriteApplicationError::UserNotFound(_)) = service.remove(favorite).await else {
             return Err(anyhow!("unexpected add favorite result"));
@@ -166,4 +393,128 @@ mod tests {
 
         Ok(())
     }
+
+    async fn add_favorites(
+        service: &impl FavoriteService,
+        stock_ids: &[&str],
+    ) -> anyhow::Result<()> {
+        for stock_id in stock_ids {
+            service
+                .add(FavoriteData::new(USER_ID.to_string(), stock_id.to_string()))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn stock_ids(favorites: &[FavoriteData]) -> Vec<&str> {
+        favorites.iter().map(|f| f.stock_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn add_favorite_append_to_default_watchlist() -> anyhow::Result<()> {
+        let service = setup().await;
+        add_favorites(&service, &["7203", "6758", "9984"]).await?;
+        // 登録済みの銘柄は変更しない
+        let registered = service
+            .add(FavoriteData::new(USER_ID.to_string(), "7203".to_string()))
+            .await?;
+        assert!(registered.sort_order == 1);
+
+        let watchlists = service.get_watchlists(USER_ID).await?;
+        assert!(watchlists.len() == 1);
+        assert!(watchlists[0].name == "default");
+
+        let result = service.get_all(USER_ID, None).await?;
+        assert!(stock_ids(&result) == vec!["7203", "6758", "9984"]);
+        assert!(result.iter().map(|f| f.sort_order).collect::<Vec<_>>() == vec![1, 2, 3]);
+        assert!(result.iter().all(|f| f.watchlist_id == watchlists[0].id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_favorite_order_note_and_tags() -> anyhow::Result<()> {
+        let service = setup().await;
+        add_favorites(&service, &["7203", "6758", "9984", "8306"]).await?;
+
+        let mut command = FavoriteUpdateCommand::new(USER_ID.to_string(), "9984".to_string());
+        command.sort_order = Some(1);
+        command.note = Some(" 決算待ち ".to_string());
+        command.tags = Some(
+            ["通信", " ", "通信", "高配当"]
+                .iter()
+                .map(|tag| tag.to_string())
+                .collect(),
+        );
+        let updated = service.update(command).await?;
+        assert!(updated.sort_order == 1);
+        assert!(updated.note == "決算待ち");
+        assert!(updated.tags == vec!["通信".to_string(), "高配当".to_string()]);
+
+        let result = service.get_all(USER_ID, None).await?;
+        assert!(stock_ids(&result) == vec!["9984", "7203", "6758", "8306"]);
+
+        // 範囲外の表示順は末尾に移動する
+        let mut command = FavoriteUpdateCommand::new(USER_ID.to_string(), "7203".to_string());
+        command.sort_order = Some(100);
+        service.update(command).await?;
+        let result = service.get_all(USER_ID, None).await?;
+        assert!(stock_ids(&result) == vec!["9984", "6758", "8306", "7203"]);
+        assert!(result.iter().map(|f| f.sort_order).collect::<Vec<_>>() == vec![1, 2, 3, 4]);
+
+        let command = FavoriteUpdateCommand::new(USER_ID.to_string(), "9999".to_string());
+        let Err(FavoriteApplicationError::FavoriteNotFound(_)) = service.update(command).await else {
+            return Err(anyhow!("unexpected update favorite result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_invalid_favorite_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        let mut favorite = FavoriteData::new(USER_ID.to_string(), "7203".to_string());
+        favorite.tags = vec!["a".repeat(21)];
+
+        let Err(FavoriteApplicationError::InvalidParameter { name: "tags", .. }) = service.add(favorite).await else {
+            return Err(anyhow!("unexpected add favorite result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn favorites_are_separated_by_watchlist() -> anyhow::Result<()> {
+        let service = setup().await;
+        add_favorites(&service, &["7203"]).await?;
+        let watchlist = service
+            .add_watchlist(WatchlistData::new(USER_ID.to_string(), "半導体".to_string()))
+            .await?;
+        let Some(watchlist_id) = watchlist.id else {
+            return Err(anyhow!("watchlist id not assigned"));
+        };
+        let mut favorite = FavoriteData::new(USER_ID.to_string(), "8035".to_string());
+        favorite.watchlist_id = Some(watchlist_id);
+        service.add(favorite).await?;
+
+        assert!(stock_ids(&service.get_all(USER_ID, None).await?) == vec!["7203"]);
+        assert!(stock_ids(&service.get_all(USER_ID, Some(watchlist_id)).await?) == vec!["8035"]);
+
+        let renamed = service
+            .rename_watchlist(USER_ID, watchlist_id, "半導体関連".to_string())
+            .await?;
+        assert!(renamed.name == "半導体関連");
+        let Err(FavoriteApplicationError::InvalidParameter { name: "name", .. }) = service.rename_watchlist(USER_ID, watchlist_id, " ".to_string()).await else {
+            return Err(anyhow!("unexpected rename watchlist result"));
+        };
+
+        service.remove_watchlist(USER_ID, watchlist_id).await?;
+        assert!(service.get_watchlists(USER_ID).await?.len() == 1);
+        let Err(FavoriteApplicationError::WatchlistNotFound(_)) = service.get_all(USER_ID, Some(watchlist_id)).await else {
+            return Err(anyhow!("unexpected get favorites result"));
+        };
+
+        Ok(())
+    }
 }
@@ -0,0 +1,23 @@
+/// お気に入り登録情報の更新内容(Noneの項目は変更しない)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FavoriteUpdateCommand {
+    pub user_id: String,
+    /// ウォッチリストのID(Noneの場合は既定のウォッチリスト)
+    pub watchlist_id: Option<i32>,
+    pub stock_id: String,
+    /// 移動先の表示順(1始まり, 範囲外の場合は先頭または末尾)
+    pub sort_order: Option<i32>,
+    pub note: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+impl FavoriteUpdateCommand {
+    /// コンストラクタ
+    pub fn new(user_id: String, stock_id: String) -> Self {
+        Self {
+            user_id,
+            stock_id,
+            ..Default::default()
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use std::sync::{Arc, Mutex};
 
 use domain::{
-    favorite::{Favorite, FavoriteRepository, FavoriteDomainResult},
+    favorite::{Favorite, FavoriteDomainResult, FavoriteRepository, Watchlist, WatchlistId},
+    stock::StockId,
     user::UserId,
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct InmemoryFavoriteRepositoryImpl {
+    pub watchlists: Arc<Mutex<Vec<Watchlist>>>,
     pub store: Arc<Mutex<Vec<Favorite>>>,
 }
 
@@ -19,29 +21,101 @@ impl InmemoryFavoriteRepositoryImpl {
 
 #[async_trait::async_trait]
 impl FavoriteRepository for InmemoryFavoriteRepositoryImpl {
-    async fn save(&self, favorite: Favorite) -> FavoriteDomainResult<()> {
-        self.store.lock().unwrap().push(favorite);
+    async fn save_watchlist(&self, watchlist: Watchlist) -> FavoriteDomainResult<WatchlistId> {
+        let mut watchlists = self.watchlists.lock().unwrap();
+        let id = if let Some(id) = watchlist.id() {
+            watchlists.retain(|target| target.id() != Some(id));
+            id
+        } else {
+            // 採番
+            let max_id = watchlists.iter().filter_map(|w| w.id()).map(|id| *id).max();
+            WatchlistId::new(max_id.unwrap_or(0) + 1)
+        };
+        watchlists.push(watchlist.with_id(id));
+
+        Ok(id)
+    }
+
+    async fn delete_watchlist(
+        &self,
+        user_id: &UserId,
+        watchlist_id: &WatchlistId,
+    ) -> FavoriteDomainResult<()> {
+        let mut watchlists = self.watchlists.lock().unwrap();
+        let Some(index) = watchlists
+            .iter()
+            .position(|target| target.id() == Some(*watchlist_id) && target.user_id() == user_id)
+        else {
+            return Ok(());
+        };
+        watchlists.remove(index);
+        self.store
+            .lock()
+            .unwrap()
+            .retain(|target| target.watchlist_id != *watchlist_id);
 
         Ok(())
     }
 
-    async fn delete(&self, favorite: Favorite) -> FavoriteDomainResult<()> {
+    async fn find_watchlists(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Watchlist>> {
+        let mut result = self
+            .watchlists
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| target.user_id() == user_id)
+            .cloned()
+            .collect::<Vec<Watchlist>>();
+        result.sort_by_key(|w| w.id().map(|id| *id));
+
+        Ok(result)
+    }
+
+    async fn save(&self, favorite: Favorite) -> FavoriteDomainResult<()> {
         let mut store = self.store.lock().unwrap();
-        let Some(index) = store.iter().position(|target| target == &favorite) else {return Ok(())};
-        store.remove(index);
+        store.retain(|target| {
+            !(target.watchlist_id == favorite.watchlist_id && target.stock_id == favorite.stock_id)
+        });
+        store.push(favorite);
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        watchlist_id: &WatchlistId,
+        stock_id: &StockId,
+    ) -> FavoriteDomainResult<()> {
+        self.store.lock().unwrap().retain(|target| {
+            !(target.watchlist_id == *watchlist_id && target.stock_id == *stock_id)
+        });
 
         Ok(())
     }
 
-    async fn find_all(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Favorite>> {
+    async fn find_all(&self, watchlist_id: &WatchlistId) -> FavoriteDomainResult<Vec<Favorite>> {
+        let mut result = self
+            .store
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|favorite| favorite.watchlist_id == *watchlist_id)
+            .cloned()
+            .collect::<Vec<Favorite>>();
+        result.sort_by_key(|favorite| (favorite.sort_order, favorite.added_at));
+
+        Ok(result)
+    }
+
+    async fn find_by_stocks(&self, stock_ids: &[StockId]) -> FavoriteDomainResult<Vec<Favorite>> {
         let result = self
             .store
             .lock()
             .unwrap()
-            .to_vec()
-            .into_iter()
-            .filter(|favorite| favorite.user_id == *user_id);
-        let result = result.collect::<Vec<Favorite>>();
+            .iter()
+            .filter(|favorite| stock_ids.contains(&favorite.stock_id))
+            .cloned()
+            .collect();
 
         Ok(result)
     }
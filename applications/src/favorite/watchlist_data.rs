@@ -0,0 +1,46 @@
+use domain::{
+    favorite::{FavoriteDomainError, Watchlist, WatchlistId},
+    user::UserId,
+};
+
+/// ウォッチリスト
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WatchlistData {
+    /// 未登録の場合はNone
+    pub id: Option<i32>,
+    pub user_id: String,
+    pub name: String,
+}
+
+impl WatchlistData {
+    /// コンストラクタ
+    pub fn new(user_id: String, name: String) -> Self {
+        Self {
+            user_id,
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Watchlist> for WatchlistData {
+    fn from(value: Watchlist) -> Self {
+        Self {
+            id: value.id().map(i32::from),
+            user_id: value.user_id().to_string(),
+            name: value.name().to_string(),
+        }
+    }
+}
+
+impl TryFrom<WatchlistData> for Watchlist {
+    type Error = FavoriteDomainError;
+
+    fn try_from(value: WatchlistData) -> Result<Self, Self::Error> {
+        Watchlist::new(
+            value.id.map(WatchlistId::new),
+            UserId::new(value.user_id),
+            value.name,
+        )
+    }
+}
@@ -0,0 +1,12 @@
+mod financial_statement_application_error;
+mod financial_statement_data;
+mod financial_statement_service;
+mod financial_statement_service_impl;
+mod inmemory_financial_statement_repository_impl;
+
+pub use financial_statement_application_error::FinancialStatementApplicationError;
+pub use financial_statement_application_error::FinancialStatementApplicationResult;
+pub use financial_statement_data::FinancialStatementData;
+pub use financial_statement_service::FinancialStatementService;
+pub use financial_statement_service_impl::FinancialStatementServiceImpl;
+pub use inmemory_financial_statement_repository_impl::InmemoryFinancialStatementRepositoryImpl;
@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+use crate::company::CompanyQueryError;
+use domain::{financial_statement::FinancialStatementDomainError, stock::StockDomainError};
+
+#[derive(Error, Debug)]
+pub enum FinancialStatementApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("company data not found: id={0}")]
+    CompanyNotFound(String),
+}
+
+pub type FinancialStatementApplicationResult<T> = Result<T, FinancialStatementApplicationError>;
+
+impl From<FinancialStatementDomainError> for FinancialStatementApplicationError {
+    fn from(value: FinancialStatementDomainError) -> Self {
+        match value {
+            FinancialStatementDomainError::Disconnect(e) => Self::Disconnect(e),
+            FinancialStatementDomainError::InvalidPeriodType(period) => Self::InvalidParameter {
+                name: "period",
+                value: period,
+            },
+        }
+    }
+}
+
+impl From<StockDomainError> for FinancialStatementApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
+
+impl From<CompanyQueryError> for FinancialStatementApplicationError {
+    fn from(value: CompanyQueryError) -> Self {
+        match value {
+            CompanyQueryError::Disconnect(e) => Self::Disconnect(e),
+            CompanyQueryError::InvalidParameter { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            CompanyQueryError::CompanyNotFound(stock_id) => Self::CompanyNotFound(stock_id),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+
+use crate::financial_statement::FinancialStatementApplicationError;
+use domain::{financial_statement::FinancialStatement, stock::StockId};
+
+/// 財務諸表
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FinancialStatementData {
+    pub stock_id: String,
+    /// 決算期間の種別(annual/quarterly)
+    pub period_type: String,
+    /// 期首日
+    pub period_start: NaiveDate,
+    /// 期末日
+    pub period_end: NaiveDate,
+    /// 売上高
+    pub revenue: Option<i64>,
+    /// 営業利益
+    pub operating_income: Option<i64>,
+    /// 当期純利益
+    pub net_income: Option<i64>,
+    /// 総資産
+    pub total_assets: Option<i64>,
+    /// 純資産
+    pub equity: Option<i64>,
+    /// 1株当たり当期純利益
+    pub eps: Option<f64>,
+}
+
+impl FinancialStatementData {
+    /// コンストラクタ
+    pub fn new(stock_id: String, period_type: String) -> Self {
+        Self {
+            stock_id,
+            period_type,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<FinancialStatement> for FinancialStatementData {
+    fn from(value: FinancialStatement) -> Self {
+        Self {
+            stock_id: value.stock_id.to_string(),
+            period_type: value.period_type.as_str().to_string(),
+            period_start: value.period_start,
+            period_end: value.period_end,
+            revenue: value.revenue,
+            operating_income: value.operating_income,
+            net_income: value.net_income,
+            total_assets: value.total_assets,
+            equity: value.equity,
+            eps: value.eps,
+        }
+    }
+}
+
+impl TryFrom<FinancialStatementData> for FinancialStatement {
+    type Error = FinancialStatementApplicationError;
+
+    fn try_from(value: FinancialStatementData) -> Result<Self, Self::Error> {
+        Ok(FinancialStatement {
+            stock_id: StockId::new(value.stock_id)?,
+            period_type: value.period_type.parse()?,
+            period_start: value.period_start,
+            period_end: value.period_end,
+            revenue: value.revenue,
+            operating_income: value.operating_income,
+            net_income: value.net_income,
+            total_assets: value.total_assets,
+            equity: value.equity,
+            eps: value.eps,
+        })
+    }
+}
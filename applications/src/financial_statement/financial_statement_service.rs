@@ -0,0 +1,14 @@
+use crate::financial_statement::{FinancialStatementApplicationResult, FinancialStatementData};
+
+#[async_trait::async_trait]
+pub trait FinancialStatementService {
+    async fn get_all(
+        &self,
+        stock_id: &str,
+        period_type: &str,
+    ) -> FinancialStatementApplicationResult<Vec<FinancialStatementData>>;
+    async fn save(
+        &self,
+        statement: FinancialStatementData,
+    ) -> FinancialStatementApplicationResult<()>;
+}
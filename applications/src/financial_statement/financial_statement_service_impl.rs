@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use crate::{
+    company::CompanyQueryService,
+    financial_statement::{
+        FinancialStatementApplicationError, FinancialStatementApplicationResult,
+        FinancialStatementData, FinancialStatementService,
+    },
+};
+use domain::{
+    financial_statement::{FinancialStatement, FinancialStatementRepository, PeriodType},
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FinancialStatementServiceImpl<T, U>
+where
+    T: FinancialStatementRepository,
+    U: CompanyQueryService,
+{
+    financial_statement_repository: Arc<T>,
+    company_query_service: U,
+}
+
+impl<T, U> FinancialStatementServiceImpl<T, U>
+where
+    T: FinancialStatementRepository,
+    U: CompanyQueryService,
+{
+    /// コンストラクタ
+    pub fn new(financial_statement_repository: &Arc<T>, company_query_service: U) -> Self {
+        Self {
+            financial_statement_repository: Arc::clone(financial_statement_repository),
+            company_query_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U> FinancialStatementService for FinancialStatementServiceImpl<T, U>
+where
+    T: FinancialStatementRepository + std::fmt::Debug + Send + Sync,
+    U: CompanyQueryService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn get_all(
+        &self,
+        stock_id: &str,
+        period_type: &str,
+    ) -> FinancialStatementApplicationResult<Vec<FinancialStatementData>> {
+        let period_type: PeriodType = period_type.parse()?;
+        let stock_id = StockId::new(stock_id.to_string())?;
+        self.company_query_service.find_by_id(&stock_id).await?;
+
+        let result = self
+            .financial_statement_repository
+            .find_all(&stock_id, period_type)
+            .await?
+            .into_iter()
+            .map(FinancialStatementData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn save(
+        &self,
+        statement: FinancialStatementData,
+    ) -> FinancialStatementApplicationResult<()> {
+        // パラメータチェック
+        if statement.period_start > statement.period_end {
+            return Err(FinancialStatementApplicationError::InvalidParameter {
+                name: "period_start",
+                value: statement.period_start.to_string(),
+            });
+        }
+        self.company_query_service
+            .find_by_id(&statement.stock_id)
+            .await?;
+
+        let statement = FinancialStatement::try_from(statement)?;
+        self.financial_statement_repository.save(statement).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+
+    use crate::{
+        company::{CompanyData, InmemoryCompanyQueryServiceImpl},
+        financial_statement::{
+            FinancialStatementApplicationError, FinancialStatementData, FinancialStatementService,
+            FinancialStatementServiceImpl, InmemoryFinancialStatementRepositoryImpl,
+        },
+    };
+
+    const STOCK_ID: &str = "7203";
+
+    fn setup() -> impl FinancialStatementService {
+        let mut company_query_service = InmemoryCompanyQueryServiceImpl::new();
+        company_query_service.companies.push(CompanyData {
+            name: "トヨタ自動車".to_string(),
+            stock_id: STOCK_ID.to_string(),
+            sector: "輸送用機器".to_string(),
+            industry: "製造業".to_string(),
+        });
+
+        let financial_statement_repository =
+            Arc::new(InmemoryFinancialStatementRepositoryImpl::new());
+        FinancialStatementServiceImpl::new(&financial_statement_repository, company_query_service)
+    }
+
+    fn statement(
+        period_type: &str,
+        start: (i32, u32, u32),
+        end: (i32, u32, u32),
+    ) -> FinancialStatementData {
+        let mut statement =
+            FinancialStatementData::new(STOCK_ID.to_string(), period_type.to_string());
+        statement.period_start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        statement.period_end = NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap();
+        statement
+    }
+
+    #[tokio::test]
+    async fn get_all_filter_by_period_type() -> anyhow::Result<()> {
+        let service = setup();
+        service
+            .save(statement("annual", (2022, 4, 1), (2023, 3, 31)))
+            .await?;
+        service
+            .save(statement("annual", (2021, 4, 1), (2022, 3, 31)))
+            .await?;
+        service
+            .save(statement("quarterly", (2022, 4, 1), (2022, 6, 30)))
+            .await?;
+
+        let result = service.get_all(STOCK_ID, "annual").await?;
+        assert!(result.len() == 2);
+        // 期末日の昇順
+        assert!(result[0].period_end < result[1].period_end);
+
+        let result = service.get_all(STOCK_ID, "quarterly").await?;
+        assert!(result.len() == 1);
+        assert!(result[0].period_type == "quarterly");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_same_period_overwrite() -> anyhow::Result<()> {
+        let service = setup();
+        let mut first = statement("annual", (2022, 4, 1), (2023, 3, 31));
+        first.revenue = Some(100);
+        let mut second = first.clone();
+        second.revenue = Some(200);
+
+        service.save(first).await?;
+        service.save(second.clone()).await?;
+
+        let result = service.get_all(STOCK_ID, "annual").await?;
+        assert!(result == vec![second]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_all_invalid_period_return_err() -> anyhow::Result<()> {
+        let service = setup();
+
+        let Err(FinancialStatementApplicationError::InvalidParameter { name: "period", .. }) =
+            service.get_all(STOCK_ID, "monthly").await
+        else {
+            return Err(anyhow!("unexpected get financial statements result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_notexist_company_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut statement = statement("annual", (2022, 4, 1), (2023, 3, 31));
+        statement.stock_id = "9999".to_string();
+
+        let Err(FinancialStatementApplicationError::CompanyNotFound(_)) =
+            service.save(statement).await
+        else {
+            return Err(anyhow!("unexpected save financial statement result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_reversed_period_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let statement = statement("annual", (2023, 3, 31), (2022, 4, 1));
+
+        let Err(FinancialStatementApplicationError::InvalidParameter {
+            name: "period_start",
+            ..
+        }) = service.save(statement).await
+        else {
+            return Err(anyhow!("unexpected save financial statement result"));
+        };
+
+        Ok(())
+    }
+}
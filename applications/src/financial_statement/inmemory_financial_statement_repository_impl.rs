@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex};
+
+use domain::{
+    financial_statement::{
+        FinancialStatement, FinancialStatementDomainResult, FinancialStatementRepository,
+        PeriodType,
+    },
+    stock::StockId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct InmemoryFinancialStatementRepositoryImpl {
+    pub store: Arc<Mutex<Vec<FinancialStatement>>>,
+}
+
+impl InmemoryFinancialStatementRepositoryImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl FinancialStatementRepository for InmemoryFinancialStatementRepositoryImpl {
+    async fn save(&self, statement: FinancialStatement) -> FinancialStatementDomainResult<()> {
+        let mut store = self.store.lock().unwrap();
+        store.retain(|target| {
+            !(target.stock_id == statement.stock_id
+                && target.period_type == statement.period_type
+                && target.period_end == statement.period_end)
+        });
+        store.push(statement);
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+        period_type: PeriodType,
+    ) -> FinancialStatementDomainResult<Vec<FinancialStatement>> {
+        let mut result: Vec<FinancialStatement> = self
+            .store
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|statement| {
+                statement.stock_id == *stock_id && statement.period_type == period_type
+            })
+            .cloned()
+            .collect();
+        result.sort_by_key(|statement| statement.period_end);
+
+        Ok(result)
+    }
+}
@@ -1,5 +1,14 @@
+pub mod alert;
+pub mod benchmark;
 pub mod company;
+pub mod corporate_action;
+pub mod data_quality;
+pub mod dividend;
 pub mod favorite;
+pub mod financial_statement;
+pub mod market_index;
 pub mod portfolio;
+pub mod screener;
 pub mod stock;
 pub mod user;
+pub mod webhook;
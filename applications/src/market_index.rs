@@ -0,0 +1,19 @@
+mod index_price_data;
+mod inmemory_market_index_query_service_impl;
+mod market_index_data;
+mod market_index_import_error;
+mod market_index_import_service;
+mod market_index_query_command;
+mod market_index_query_error;
+mod market_index_query_service;
+
+pub use index_price_data::IndexPriceData;
+pub use inmemory_market_index_query_service_impl::InmemoryMarketIndexQueryServiceImpl;
+pub use market_index_data::MarketIndexData;
+pub use market_index_import_error::MarketIndexImportError;
+pub use market_index_import_error::MarketIndexImportResult;
+pub use market_index_import_service::MarketIndexImportService;
+pub use market_index_query_command::MarketIndexQueryCommand;
+pub use market_index_query_error::MarketIndexQueryError;
+pub use market_index_query_error::MarketIndexQueryResult;
+pub use market_index_query_service::MarketIndexQueryService;
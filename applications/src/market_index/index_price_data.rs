@@ -0,0 +1,127 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::market_index::{MarketIndexImportError, MarketIndexImportResult};
+
+/// 株価指数の日次の値
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IndexPriceData {
+    pub index_id: String,
+    /// 日付
+    pub date: NaiveDate,
+    /// 始値
+    pub start_price: Decimal,
+    /// 終値
+    pub end_price: Decimal,
+    /// 高値
+    pub high_price: Decimal,
+    /// 安値
+    pub low_price: Decimal,
+}
+
+impl IndexPriceData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登録データとしての妥当性チェック
+    pub fn validate(&self) -> MarketIndexImportResult<()> {
+        if self.index_id.is_empty() || self.index_id.chars().count() > 20 {
+            return Err(MarketIndexImportError::InvalidData {
+                name: "index_id",
+                value: self.index_id.clone(),
+            });
+        }
+        // 値は全て正の値
+        for (name, price) in [
+            ("start_price", self.start_price),
+            ("end_price", self.end_price),
+            ("high_price", self.high_price),
+            ("low_price", self.low_price),
+        ] {
+            if price <= Decimal::ZERO {
+                return Err(MarketIndexImportError::InvalidData {
+                    name,
+                    value: price.to_string(),
+                });
+            }
+        }
+        // 高値と安値の範囲に始値と終値が含まれること
+        if self.high_price < self.low_price {
+            return Err(MarketIndexImportError::InvalidData {
+                name: "high_price",
+                value: self.high_price.to_string(),
+            });
+        }
+        for (name, price) in [
+            ("start_price", self.start_price),
+            ("end_price", self.end_price),
+        ] {
+            if price < self.low_price || price > self.high_price {
+                return Err(MarketIndexImportError::InvalidData {
+                    name,
+                    value: price.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use rust_decimal::Decimal;
+
+    use crate::market_index::{IndexPriceData, MarketIndexImportError};
+
+    fn sample() -> IndexPriceData {
+        IndexPriceData {
+            index_id: "topix".to_string(),
+            start_price: Decimal::new(195025, 2),
+            end_price: Decimal::new(19625, 1),
+            high_price: Decimal::from(1970),
+            low_price: Decimal::new(194875, 2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate_success() -> anyhow::Result<()> {
+        sample().validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_non_positive_price_return_err() -> anyhow::Result<()> {
+        let mut price = sample();
+        price.end_price = Decimal::ZERO;
+
+        let Err(MarketIndexImportError::InvalidData {
+            name: "end_price", ..
+        }) = price.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_start_price_out_of_range_return_err() -> anyhow::Result<()> {
+        let mut price = sample();
+        price.start_price = Decimal::from(1900);
+
+        let Err(MarketIndexImportError::InvalidData {
+            name: "start_price",
+            ..
+        }) = price.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::market_index::{
+    IndexPriceData, MarketIndexData, MarketIndexQueryCommand, MarketIndexQueryError,
+    MarketIndexQueryResult, MarketIndexQueryService,
+};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InmemoryMarketIndexQueryServiceImpl {
+    pub indices: Vec<MarketIndexData>,
+    pub prices: Vec<IndexPriceData>,
+}
+
+impl InmemoryMarketIndexQueryServiceImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl MarketIndexQueryService for InmemoryMarketIndexQueryServiceImpl {
+    #[tracing::instrument(skip(self), err)]
+    async fn find_all(&self) -> MarketIndexQueryResult<Vec<MarketIndexData>> {
+        Ok(self.indices.clone())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn find_prices(
+        &self,
+        param: MarketIndexQueryCommand,
+    ) -> MarketIndexQueryResult<Vec<IndexPriceData>> {
+        // パラメータチェック
+        if let (Some(start), Some(end)) = (param.start, param.end) {
+            if start > end {
+                return Err(MarketIndexQueryError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        };
+        if !self.indices.iter().any(|i| i.index_id == param.index_id) {
+            return Err(MarketIndexQueryError::MarketIndexNotFound(param.index_id));
+        }
+
+        let mut result = self
+            .prices
+            .iter()
+            .filter(|p| p.index_id == param.index_id)
+            .filter(|p| param.start.is_none_or(|start| p.date >= start))
+            .filter(|p| param.end.is_none_or(|end| p.date <= end))
+            .cloned()
+            .collect::<Vec<IndexPriceData>>();
+        result.sort_by_key(|p| p.date);
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+
+    use crate::market_index::{
+        IndexPriceData, InmemoryMarketIndexQueryServiceImpl, MarketIndexData,
+        MarketIndexQueryCommand, MarketIndexQueryError, MarketIndexQueryService,
+    };
+
+    const INDEX_ID: &str = "topix";
+
+    fn setup() -> InmemoryMarketIndexQueryServiceImpl {
+        let mut service = InmemoryMarketIndexQueryServiceImpl::new();
+        service.indices.push(MarketIndexData {
+            index_id: INDEX_ID.to_string(),
+            name: "TOPIX".to_string(),
+        });
+        for day in [6, 4, 5] {
+            let mut price = IndexPriceData::new();
+            price.index_id = INDEX_ID.to_string();
+            price.date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+            service.prices.push(price);
+        }
+
+        service
+    }
+
+    #[tokio::test]
+    async fn find_prices_by_date() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = MarketIndexQueryCommand::new();
+        param.index_id = INDEX_ID.to_string();
+        param.start = NaiveDate::from_ymd_opt(2023, 1, 5);
+
+        let found = service.find_prices(param).await?;
+
+        assert!(found.len() == 2);
+        assert!(found[0].date == NaiveDate::from_ymd_opt(2023, 1, 5).unwrap());
+        assert!(found[1].date == NaiveDate::from_ymd_opt(2023, 1, 6).unwrap());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_prices_of_unknown_index_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = MarketIndexQueryCommand::new();
+        param.index_id = "unknown".to_string();
+
+        let Err(MarketIndexQueryError::MarketIndexNotFound(_)) = service.find_prices(param).await
+        else {
+            return Err(anyhow!("unexpected find prices result"));
+        };
+
+        Ok(())
+    }
+}
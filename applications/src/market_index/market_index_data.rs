@@ -0,0 +1,29 @@
+use crate::market_index::{MarketIndexImportError, MarketIndexImportResult};
+
+/// 株価指数(TOPIX, 日経平均株価など)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MarketIndexData {
+    /// ID(例: topix, nikkei225)
+    pub index_id: String,
+    /// 指数名
+    pub name: String,
+}
+
+impl MarketIndexData {
+    /// 登録データとしての妥当性チェック
+    pub fn validate(&self) -> MarketIndexImportResult<()> {
+        // 各項目の最大文字数はテーブル定義に合わせる
+        for (name, value, max_length) in
+            [("index_id", &self.index_id, 20), ("name", &self.name, 100)]
+        {
+            if value.is_empty() || value.chars().count() > max_length {
+                return Err(MarketIndexImportError::InvalidData {
+                    name,
+                    value: value.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MarketIndexImportError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid index data: {name}={value}")]
+    InvalidData { name: &'static str, value: String },
+}
+
+pub type MarketIndexImportResult<T> = Result<T, MarketIndexImportError>;
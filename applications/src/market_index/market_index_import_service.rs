@@ -0,0 +1,9 @@
+use crate::market_index::{IndexPriceData, MarketIndexData, MarketIndexImportResult};
+
+#[async_trait::async_trait]
+pub trait MarketIndexImportService {
+    /// 株価指数の登録(同一のIDのデータは上書き)
+    async fn upsert_indices(&self, indices: Vec<MarketIndexData>) -> MarketIndexImportResult<u64>;
+    /// 株価指数の値の登録(同一のID・日付のデータは上書き)
+    async fn upsert_prices(&self, prices: Vec<IndexPriceData>) -> MarketIndexImportResult<u64>;
+}
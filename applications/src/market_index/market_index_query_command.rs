@@ -0,0 +1,14 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MarketIndexQueryCommand {
+    pub index_id: String,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+}
+
+impl MarketIndexQueryCommand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
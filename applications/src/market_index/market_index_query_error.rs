@@ -0,0 +1,19 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MarketIndexQueryError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+    #[error("invalid date parameter: {name}={value}")]
+    InvalidRangeOfDate {
+        name: &'static str,
+        value: NaiveDate,
+    },
+    #[error("market index not found: id={0}")]
+    MarketIndexNotFound(String),
+}
+
+pub type MarketIndexQueryResult<T> = Result<T, MarketIndexQueryError>;
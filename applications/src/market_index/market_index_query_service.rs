@@ -0,0 +1,13 @@
+use crate::market_index::{
+    IndexPriceData, MarketIndexData, MarketIndexQueryCommand, MarketIndexQueryResult,
+};
+
+#[async_trait::async_trait]
+pub trait MarketIndexQueryService {
+    async fn find_all(&self) -> MarketIndexQueryResult<Vec<MarketIndexData>>;
+    /// 日付順に取得する(未登録の指数の場合はエラー)
+    async fn find_prices(
+        &self,
+        param: MarketIndexQueryCommand,
+    ) -> MarketIndexQueryResult<Vec<IndexPriceData>>;
+}
@@ -1,14 +1,31 @@
+mod dividend_calendar_data;
+mod dividend_income_data;
 mod inmemory_portfolio_repository_impl;
+mod portfolio_account_data;
+mod portfolio_account_update_command;
 mod portfolio_application_error;
 mod portfolio_data;
+mod portfolio_history_data;
 mod portfolio_service;
 mod portfolio_service_impl;
+mod portfolio_summary_data;
 mod portfolio_update_command;
+mod transaction_data;
+mod transaction_update_command;
 
+pub use dividend_calendar_data::DividendCalendarData;
+pub use dividend_income_data::DividendIncomeData;
 pub use inmemory_portfolio_repository_impl::InmemoryPortfolioRepositoryImpl;
+pub use portfolio_account_data::PortfolioAccountData;
+pub use portfolio_account_update_command::PortfolioAccountUpdateCommand;
 pub use portfolio_application_error::PortfoliApplicationResult;
 pub use portfolio_application_error::PortfolioApplicationError;
 pub use portfolio_data::PortfolioData;
+pub use portfolio_history_data::PortfolioHistoryData;
+pub use portfolio_history_data::PortfolioValueData;
 pub use portfolio_service::PortfolioService;
 pub use portfolio_service_impl::PortfolioServiceImpl;
+pub use portfolio_summary_data::PortfolioSummaryData;
 pub use portfolio_update_command::PortfolioUpdateCommand;
+pub use transaction_data::TransactionData;
+pub use transaction_update_command::TransactionUpdateCommand;
@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// 保有銘柄の配当予定・実績
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DividendCalendarData {
+    pub stock_id: String,
+    /// 権利落ち日
+    pub ex_date: NaiveDate,
+    /// 権利確定日
+    pub record_date: NaiveDate,
+    /// 支払開始日
+    pub payment_date: NaiveDate,
+    /// 1株当たり配当金(円)
+    pub amount_per_share: Decimal,
+    /// 権利落ち日の前日時点の保有株数
+    pub stock_count: i64,
+}
+
+impl DividendCalendarData {
+    /// 配当金額(円)
+    pub fn amount(&self) -> Decimal {
+        self.amount_per_share * Decimal::from(self.stock_count)
+    }
+}
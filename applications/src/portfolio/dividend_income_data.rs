@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+
+use crate::portfolio::DividendCalendarData;
+
+/// 保有銘柄の配当金
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DividendIncomeData {
+    /// 直近1年間(最新の権利落ち日から遡って1年)の1株当たり配当金合計
+    pub annual_dividend_per_share: Decimal,
+    /// 支払年ごとの受取済み配当金(円)
+    pub received: BTreeMap<i32, Decimal>,
+}
+
+impl DividendIncomeData {
+    /// 配当予定・実績から集計する
+    ///
+    /// 支払開始日がtoday以前の配当を受取済みとする
+    pub fn calculate(dividends: &[DividendCalendarData], today: NaiveDate) -> Self {
+        let mut income = Self::default();
+        if let Some(latest) = dividends.iter().map(|d| d.ex_date).max() {
+            let from = latest - Months::new(12);
+            income.annual_dividend_per_share = dividends
+                .iter()
+                .filter(|d| d.ex_date > from)
+                .map(|d| d.amount_per_share)
+                .sum();
+        }
+        for dividend in dividends
+            .iter()
+            .filter(|d| d.payment_date <= today && d.stock_count > 0)
+        {
+            *income
+                .received
+                .entry(dividend.payment_date.year())
+                .or_default() += dividend.amount();
+        }
+
+        income
+    }
+
+    /// 受取済み配当金合計(円)
+    pub fn received_total(&self) -> Decimal {
+        self.received.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::portfolio::{DividendCalendarData, DividendIncomeData};
+
+    fn dividend(
+        ex_date: NaiveDate,
+        amount_per_share: Decimal,
+        stock_count: i64,
+    ) -> DividendCalendarData {
+        DividendCalendarData {
+            ex_date,
+            record_date: ex_date.succ_opt().unwrap(),
+            payment_date: ex_date + chrono::Duration::days(90),
+            amount_per_share,
+            stock_count,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn calculate_dividend_income() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dividends = vec![
+            dividend(date(2021, 9, 29), Decimal::from(30), 0),
+            dividend(date(2022, 3, 30), Decimal::from(35), 100),
+            dividend(date(2022, 9, 29), Decimal::from(40), 100),
+            dividend(date(2023, 3, 30), Decimal::new(455, 1), 200),
+        ];
+
+        let income = DividendIncomeData::calculate(&dividends, date(2023, 6, 30));
+
+        // 2022/3/30より後の権利落ち日の配当
+        assert!(income.annual_dividend_per_share == Decimal::new(855, 1));
+        // 2023/3/30権利落ちの配当は支払開始日(6/28)以降のため受取済み
+        assert!(income.received.get(&2022) == Some(&Decimal::from(7500)));
+        assert!(income.received.get(&2023) == Some(&Decimal::from(9100)));
+        assert!(income.received_total() == Decimal::from(16600));
+
+        let income = DividendIncomeData::calculate(&dividends, date(2023, 6, 27));
+        assert!(!income.received.contains_key(&2023));
+    }
+
+    #[test]
+    fn calculate_without_dividends() {
+        let income = DividendIncomeData::calculate(&[], NaiveDate::default());
+
+        assert!(income.annual_dividend_per_share.is_zero());
+        assert!(income.received.is_empty());
+    }
+}
@@ -1,14 +1,19 @@
 use std::sync::{Arc, Mutex};
 
 use domain::{
-    portfolio::{Portfolio, PortfolioDomainResult, PortfolioReposotory},
+    portfolio::{
+        Portfolio, PortfolioAccount, PortfolioDomainResult, PortfolioId, PortfolioReposotory,
+        Transaction, TransactionId,
+    },
     stock::StockId,
     user::UserId,
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct InmemoryPortfolioRepositoryImpl {
+    pub accounts: Arc<Mutex<Vec<PortfolioAccount>>>,
     pub store: Arc<Mutex<Vec<Portfolio>>>,
+    pub transactions: Arc<Mutex<Vec<Transaction>>>,
 }
 
 impl InmemoryPortfolioRepositoryImpl {
@@ -20,28 +25,101 @@ impl InmemoryPortfolioRepositoryImpl {
 
 #[async_trait::async_trait]
 impl PortfolioReposotory for InmemoryPortfolioRepositoryImpl {
+    async fn save_account(&self, account: PortfolioAccount) -> PortfolioDomainResult<PortfolioId> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let id = if let Some(id) = account.id() {
+            accounts.retain(|target| target.id() != Some(id));
+            id
+        } else {
+            // 採番
+            let max_id = accounts.iter().filter_map(|a| a.id()).map(|id| *id).max();
+            PortfolioId::new(max_id.unwrap_or(0) + 1)
+        };
+        accounts.push(account.with_id(id));
+
+        Ok(id)
+    }
+
+    async fn delete_account(
+        &self,
+        user_id: &UserId,
+        portfolio_id: &PortfolioId,
+    ) -> PortfolioDomainResult<()> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let Some(index) = accounts
+            .iter()
+            .position(|target| target.id() == Some(*portfolio_id) && target.user_id() == user_id)
+        else {
+            return Ok(());
+        };
+        accounts.remove(index);
+        self.store
+            .lock()
+            .unwrap()
+            .retain(|target| target.portfolio_id != *portfolio_id);
+        self.transactions
+            .lock()
+            .unwrap()
+            .retain(|target| target.portfolio_id() != *portfolio_id);
+
+        Ok(())
+    }
+
+    async fn find_accounts(
+        &self,
+        user_id: &UserId,
+    ) -> PortfolioDomainResult<Vec<PortfolioAccount>> {
+        let mut result = self
+            .accounts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| target.user_id() == user_id)
+            .cloned()
+            .collect::<Vec<PortfolioAccount>>();
+        result.sort_by_key(|a| a.id().map(|id| *id));
+
+        Ok(result)
+    }
+
     async fn save(&self, portfolio: Portfolio) -> PortfolioDomainResult<()> {
-        self.store.lock().unwrap().push(portfolio);
+        let mut store = self.store.lock().unwrap();
+        store.retain(|target| {
+            !(target.stock_id == portfolio.stock_id
+                && target.portfolio_id == portfolio.portfolio_id)
+        });
+        store.push(portfolio);
 
         Ok(())
     }
 
-    async fn delete(&self, user_id: &UserId, stock_id: &StockId) -> PortfolioDomainResult<()> {
+    async fn delete(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<()> {
         let mut store = self.store.lock().unwrap();
-        let Some(index) = store.iter().position(|target| target.stock_id == *stock_id && target.user_id == *user_id) else {return Ok(())};
+        let Some(index) = store.iter().position(|target| {
+            target.stock_id == *stock_id && target.portfolio_id == *portfolio_id
+        }) else {
+            return Ok(());
+        };
         store.remove(index);
+        self.transactions.lock().unwrap().retain(|target| {
+            !(target.stock_id() == stock_id && target.portfolio_id() == *portfolio_id)
+        });
 
         Ok(())
     }
 
-    async fn find_all(&self, user_id: &UserId) -> PortfolioDomainResult<Vec<Portfolio>> {
+    async fn find_all(&self, portfolio_id: &PortfolioId) -> PortfolioDomainResult<Vec<Portfolio>> {
         let result = self
             .store
             .lock()
             .unwrap()
             .to_vec()
             .into_iter()
-            .filter(|favorite| favorite.user_id == *user_id);
+            .filter(|favorite| favorite.portfolio_id == *portfolio_id);
         let result = result.collect::<Vec<Portfolio>>();
 
         Ok(result)
@@ -49,7 +127,7 @@ impl PortfolioReposotory for InmemoryPortfolioRepositoryImpl {
 
     async fn find(
         &self,
-        user_id: &UserId,
+        portfolio_id: &PortfolioId,
         stock_id: &StockId,
     ) -> PortfolioDomainResult<Option<Portfolio>> {
         let result = self
@@ -57,8 +135,70 @@ impl PortfolioReposotory for InmemoryPortfolioRepositoryImpl {
             .lock()
             .unwrap()
             .iter()
-            .find(|favorite| favorite.user_id == *user_id && favorite.stock_id == *stock_id)
-            .map(|found| found.clone());
+            .find(|favorite| {
+                favorite.portfolio_id == *portfolio_id && favorite.stock_id == *stock_id
+            })
+            .cloned();
+
+        Ok(result)
+    }
+
+    async fn save_transactions(
+        &self,
+        portfolio: Portfolio,
+        transactions: Vec<Transaction>,
+    ) -> PortfolioDomainResult<Vec<TransactionId>> {
+        let mut ids = Vec::new();
+        {
+            let mut store = self.transactions.lock().unwrap();
+            for transaction in transactions {
+                let id = if let Some(id) = transaction.id() {
+                    store.retain(|target| target.id() != Some(id));
+                    id
+                } else {
+                    // 採番
+                    let max_id = store.iter().filter_map(|t| t.id()).map(|id| *id).max();
+                    TransactionId::new(max_id.unwrap_or(0) + 1)
+                };
+                store.push(transaction.with_id(id));
+                ids.push(id);
+            }
+        }
+        self.save(portfolio).await?;
+
+        Ok(ids)
+    }
+
+    async fn delete_transaction(
+        &self,
+        portfolio: Portfolio,
+        transaction_id: &TransactionId,
+    ) -> PortfolioDomainResult<()> {
+        self.transactions.lock().unwrap().retain(|target| {
+            !(target.id() == Some(*transaction_id)
+                && target.portfolio_id() == portfolio.portfolio_id)
+        });
+        self.save(portfolio).await?;
+
+        Ok(())
+    }
+
+    async fn find_transactions(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<Vec<Transaction>> {
+        let mut result = self
+            .transactions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| {
+                target.portfolio_id() == *portfolio_id && target.stock_id() == stock_id
+            })
+            .cloned()
+            .collect::<Vec<Transaction>>();
+        result.sort_by_key(|t| (*t.date(), t.id().map(|id| *id)));
 
         Ok(result)
     }
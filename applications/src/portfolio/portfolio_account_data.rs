@@ -0,0 +1,51 @@
+use domain::{
+    portfolio::{PortfolioAccount, PortfolioDomainError, PortfolioId},
+    user::UserId,
+};
+
+/// ポートフォリオ(口座)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PortfolioAccountData {
+    /// 未登録の場合はNone
+    pub id: Option<i32>,
+    pub user_id: String,
+    pub name: String,
+    /// nisa(NISA口座) / tokutei(特定口座) / general(一般口座)
+    pub account_type: String,
+}
+
+impl PortfolioAccountData {
+    /// コンストラクタ
+    pub fn new(user_id: String, name: String) -> Self {
+        Self {
+            user_id,
+            name,
+            account_type: "general".to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PortfolioAccount> for PortfolioAccountData {
+    fn from(value: PortfolioAccount) -> Self {
+        Self {
+            id: value.id().map(i32::from),
+            user_id: value.user_id().to_string(),
+            name: value.name().to_string(),
+            account_type: value.account_type().as_str().to_string(),
+        }
+    }
+}
+
+impl TryFrom<PortfolioAccountData> for PortfolioAccount {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: PortfolioAccountData) -> Result<Self, Self::Error> {
+        PortfolioAccount::new(
+            value.id.map(PortfolioId::new),
+            UserId::new(value.user_id),
+            value.name,
+            value.account_type.parse()?,
+        )
+    }
+}
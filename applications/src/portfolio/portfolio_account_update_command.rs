@@ -0,0 +1,19 @@
+/// ポートフォリオ(口座)の更新内容(Noneの項目は変更しない)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PortfolioAccountUpdateCommand {
+    pub user_id: String,
+    pub portfolio_id: i32,
+    pub name: Option<String>,
+    pub account_type: Option<String>,
+}
+
+impl PortfolioAccountUpdateCommand {
+    /// コンストラクタ
+    pub fn new(user_id: String, portfolio_id: i32) -> Self {
+        Self {
+            user_id,
+            portfolio_id,
+            ..Default::default()
+        }
+    }
+}
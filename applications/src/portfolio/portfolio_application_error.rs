@@ -2,7 +2,11 @@ use chrono::NaiveDate;
 use thiserror::Error;
 
 use crate::stock::StockQueryError;
-use domain::{portfolio::PortfolioDomainError, user::UserDomainError};
+use domain::{
+    corporate_action::CorporateActionDomainError, dividend::DividendDomainError,
+    money::MoneyDomainError, portfolio::PortfolioDomainError, stock::StockDomainError,
+    user::UserDomainError,
+};
 
 #[derive(Error, Debug)]
 pub enum PortfolioApplicationError {
@@ -10,6 +14,8 @@ pub enum PortfolioApplicationError {
     Disconnect(#[from] anyhow::Error),
     #[error("portfolio not found: id={0}")]
     PortfolioNotFound(String),
+    #[error("portfolio account not found: id={0}")]
+    PortfolioAccountNotFound(i32),
     #[error("user is already exsist: id={0}")]
     UserAlreadyExist(String),
     #[error("user not found: id={0}")]
@@ -23,6 +29,12 @@ pub enum PortfolioApplicationError {
     },
     #[error("stock data not found: id={0}")]
     StockDataNotFound(String),
+    #[error("transaction not found: id={0}")]
+    TransactionNotFound(i32),
+    #[error("sell quantity exceeds holdings: id={stock_id}, date={date}")]
+    InsufficientStock { stock_id: String, date: NaiveDate },
+    #[error("position is calculated from transactions: id={0}")]
+    TransactionsExist(String),
 }
 
 pub type PortfoliApplicationResult<T> = Result<T, PortfolioApplicationError>;
@@ -31,6 +43,52 @@ impl From<PortfolioDomainError> for PortfolioApplicationError {
     fn from(value: PortfolioDomainError) -> Self {
         match value {
             PortfolioDomainError::Disconnect(e) => Self::Disconnect(e),
+            PortfolioDomainError::InvalidPortfolio { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            PortfolioDomainError::InvalidTransaction { name, value } => {
+                Self::InvalidParameter { name, value }
+            }
+            PortfolioDomainError::InvalidTransactionType(value) => Self::InvalidParameter {
+                name: "transaction_type",
+                value,
+            },
+            PortfolioDomainError::InvalidCostBasisMethod(value) => Self::InvalidParameter {
+                name: "cost_basis_method",
+                value,
+            },
+            PortfolioDomainError::InvalidAccountType(value) => Self::InvalidParameter {
+                name: "account_type",
+                value,
+            },
+            PortfolioDomainError::InvalidPortfolioName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
+            PortfolioDomainError::InsufficientStock { stock_id, date } => {
+                Self::InsufficientStock { stock_id, date }
+            }
+            PortfolioDomainError::AmountOverflow(value) => Self::InvalidParameter {
+                name: "amount",
+                value,
+            },
+        }
+    }
+}
+
+impl From<MoneyDomainError> for PortfolioApplicationError {
+    fn from(value: MoneyDomainError) -> Self {
+        PortfolioDomainError::from(value).into()
+    }
+}
+
+impl From<StockDomainError> for PortfolioApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
         }
     }
 }
@@ -41,6 +99,42 @@ impl From<UserDomainError> for PortfolioApplicationError {
             UserDomainError::Disconnect(e) => Self::Disconnect(e),
             UserDomainError::UserAlreadyExist(user_id) => Self::UserAlreadyExist(user_id.into()),
             UserDomainError::UserNotFound(user_id) => Self::UserNotFound(user_id.into()),
+            UserDomainError::InvalidUserEmail(value) => Self::InvalidParameter {
+                name: "email",
+                value,
+            },
+            UserDomainError::InvalidUserName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
+        }
+    }
+}
+
+impl From<DividendDomainError> for PortfolioApplicationError {
+    fn from(value: DividendDomainError) -> Self {
+        match value {
+            DividendDomainError::Disconnect(e) => Self::Disconnect(e),
+            DividendDomainError::InvalidAmount(amount) => Self::InvalidParameter {
+                name: "amount_per_share",
+                value: amount.to_string(),
+            },
+            DividendDomainError::InvalidDate { name, value } => Self::InvalidParameter {
+                name,
+                value: value.to_string(),
+            },
+        }
+    }
+}
+
+impl From<CorporateActionDomainError> for PortfolioApplicationError {
+    fn from(value: CorporateActionDomainError) -> Self {
+        match value {
+            CorporateActionDomainError::Disconnect(e) => Self::Disconnect(e),
+            CorporateActionDomainError::InvalidRatio { from, to } => Self::InvalidParameter {
+                name: "ratio",
+                value: format!("{from}:{to}"),
+            },
         }
     }
 }
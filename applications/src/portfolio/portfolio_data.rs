@@ -1,18 +1,38 @@
 use chrono::NaiveDate;
-use domain::{portfolio::Portfolio, stock::StockId, user::UserId};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+use crate::portfolio::DividendIncomeData;
+use domain::{
+    money::{Price, Quantity},
+    portfolio::{CostBasisMethod, Portfolio, PortfolioDomainError, PortfolioId},
+    stock::{StockDomainError, StockId},
+    user::UserId,
+};
+
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct PortfolioData {
+    /// ポートフォリオ(口座)のID(Noneの場合は既定のポートフォリオ)
+    pub portfolio_id: Option<i32>,
     pub user_id: String,
     pub stock_id: String,
     /// 購入株数
-    pub stock_count: i32,
+    pub stock_count: i64,
     /// 購入価格
-    pub purchase: i32,
+    pub purchase: Decimal,
+    /// 取得価額(手数料込み, 円)
+    pub acquisition_cost: Decimal,
+    /// 取得単価の計算方法
+    pub cost_basis_method: CostBasisMethod,
     /// 時価
-    pub market_price: i32,
+    pub market_price: Decimal,
     /// 時価更新日
     pub latest_date: NaiveDate,
+    /// 時価更新日の前営業日の終値
+    pub previous_price: Option<Decimal>,
+    /// 実現損益(売買履歴がある場合のみ)
+    pub realized_gain: Decimal,
+    /// 配当金
+    pub dividend: DividendIncomeData,
 }
 
 impl PortfolioData {
@@ -24,15 +44,89 @@ impl PortfolioData {
             ..Default::default()
         }
     }
+
+    /// 評価額
+    pub fn market_value(&self) -> Decimal {
+        self.market_price * Decimal::from(self.stock_count)
+    }
+
+    /// 取得価額
+    pub fn cost_basis(&self) -> Decimal {
+        self.acquisition_cost
+    }
+
+    /// 評価損益
+    pub fn unrealized_gain(&self) -> Decimal {
+        self.market_value() - self.cost_basis()
+    }
+
+    /// 評価損益率(%)
+    pub fn unrealized_gain_rate(&self) -> Option<f64> {
+        rate(self.unrealized_gain(), self.cost_basis())
+    }
+
+    /// 前日比
+    pub fn day_change(&self) -> Option<Decimal> {
+        let previous_price = self.previous_price?;
+        Some((self.market_price - previous_price) * Decimal::from(self.stock_count))
+    }
+
+    /// 前日比(%)
+    pub fn day_change_rate(&self) -> Option<f64> {
+        let previous_price = self.previous_price?;
+        rate(self.market_price - previous_price, previous_price)
+    }
+
+    /// 予想年間配当金(直近1年間の1株当たり配当金 × 保有株数, 円)
+    pub fn expected_annual_dividend(&self) -> Decimal {
+        self.dividend.annual_dividend_per_share * Decimal::from(self.stock_count)
+    }
+
+    /// 取得価額に対する予想年間配当金の利回り(%)
+    pub fn yield_on_cost(&self) -> Option<f64> {
+        rate(self.expected_annual_dividend(), self.cost_basis())
+    }
 }
 
-impl Into<Portfolio> for PortfolioData {
-    fn into(self) -> Portfolio {
-        Portfolio {
-            user_id: UserId::new(self.user_id),
-            stock_id: StockId::new(self.stock_id),
-            stock_count: self.stock_count,
-            purchase: self.purchase,
-        }
+/// 百分率(分母が0の場合はNone)
+pub(super) fn rate(numerator: Decimal, denominator: Decimal) -> Option<f64> {
+    if denominator.is_zero() {
+        return None;
+    }
+    (numerator / denominator * Decimal::ONE_HUNDRED).to_f64()
+}
+
+impl TryFrom<PortfolioData> for Portfolio {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: PortfolioData) -> Result<Self, Self::Error> {
+        let stock_count = Quantity::new(value.stock_count).map_err(|_| {
+            PortfolioDomainError::InvalidPortfolio {
+                name: "stock_count",
+                value: value.stock_count.to_string(),
+            }
+        })?;
+        let purchase = Price::new(value.purchase).map_err(|_| {
+            PortfolioDomainError::InvalidPortfolio {
+                name: "purchase",
+                value: value.purchase.to_string(),
+            }
+        })?;
+        let stock_id = StockId::new(value.stock_id).map_err(
+            |StockDomainError::InvalidStockId(value)| PortfolioDomainError::InvalidPortfolio {
+                name: "stock_id",
+                value,
+            },
+        )?;
+
+        Ok(Portfolio {
+            // 未指定の場合はサービスで既定のポートフォリオを設定する
+            portfolio_id: PortfolioId::new(value.portfolio_id.unwrap_or_default()),
+            user_id: UserId::new(value.user_id),
+            stock_id,
+            stock_count,
+            purchase,
+            cost_basis_method: value.cost_basis_method,
+        })
     }
 }
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// ポートフォリオの日次評価額
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PortfolioValueData {
+    pub date: NaiveDate,
+    /// 評価額合計
+    pub market_value: Decimal,
+    /// 取得価額合計
+    pub cost_basis: Decimal,
+    /// 期間初日からの時間加重収益率(%)
+    pub cumulative_return: f64,
+}
+
+/// 収益率計算に用いる日次の評価額と売買金額
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct DailyValue {
+    pub date: NaiveDate,
+    pub market_value: Decimal,
+    pub cost_basis: Decimal,
+    /// 前の日付より後、当日までに約定した買付金額(手数料込み)
+    pub inflow: Decimal,
+    /// 前の日付より後、当日までに約定した売却金額(手数料控除後)
+    pub outflow: Decimal,
+}
+
+/// ポートフォリオの評価額推移
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PortfolioHistoryData {
+    pub history: Vec<PortfolioValueData>,
+    /// 期間全体の時間加重収益率(%)
+    pub time_weighted_return: Option<f64>,
+    /// 期間中の最大下落率(%)
+    pub max_drawdown: Option<f64>,
+}
+
+impl PortfolioHistoryData {
+    /// 日次の評価額と売買金額から時間加重収益率と最大下落率を算出する
+    ///
+    /// 買付は当日の始め、売却は当日の終わりに行われたものとして日次収益率を計算する
+    pub(super) fn calculate(values: Vec<DailyValue>) -> Self {
+        let mut history = Vec::new();
+        // 期間初日を1とした資産指数
+        let mut wealth = 1.0;
+        let mut peak = 1.0;
+        let mut max_drawdown: f64 = 0.0;
+        let mut previous_value: Option<Decimal> = None;
+        for value in values {
+            if let Some(previous_value) = previous_value {
+                let denominator = previous_value + value.inflow;
+                if denominator > Decimal::ZERO {
+                    wealth *= ((value.market_value + value.outflow) / denominator)
+                        .to_f64()
+                        .unwrap_or(1.0);
+                }
+            }
+            peak = f64::max(peak, wealth);
+            max_drawdown = max_drawdown.max((peak - wealth) / peak * 100.0);
+            previous_value = Some(value.market_value);
+
+            history.push(PortfolioValueData {
+                date: value.date,
+                market_value: value.market_value,
+                cost_basis: value.cost_basis,
+                cumulative_return: (wealth - 1.0) * 100.0,
+            });
+        }
+
+        let (time_weighted_return, max_drawdown) = if let Some(last) = history.last() {
+            (Some(last.cumulative_return), Some(max_drawdown))
+        } else {
+            (None, None)
+        };
+        Self {
+            history,
+            time_weighted_return,
+            max_drawdown,
+        }
+    }
+}
@@ -1,10 +1,83 @@
-use crate::portfolio::{PortfoliApplicationResult, PortfolioData, PortfolioUpdateCommand};
+use chrono::NaiveDate;
 
+use crate::portfolio::{
+    DividendCalendarData, PortfoliApplicationResult, PortfolioAccountData,
+    PortfolioAccountUpdateCommand, PortfolioData, PortfolioHistoryData, PortfolioUpdateCommand,
+    TransactionData, TransactionUpdateCommand,
+};
+
+/// portfolio_idがNoneの場合は既定のポートフォリオ(最初に登録したポートフォリオ)を対象とする
 #[async_trait::async_trait]
 pub trait PortfolioService {
-    async fn get_all(&self, user_id: &str) -> PortfoliApplicationResult<Vec<PortfolioData>>;
-    async fn remove(&self, user_id: &str, stock_id: &str) -> PortfoliApplicationResult<()>;
+    /// 登録順に取得する
+    async fn get_accounts(
+        &self,
+        user_id: &str,
+    ) -> PortfoliApplicationResult<Vec<PortfolioAccountData>>;
+    async fn add_account(
+        &self,
+        account: PortfolioAccountData,
+    ) -> PortfoliApplicationResult<PortfolioAccountData>;
+    async fn update_account(
+        &self,
+        update_command: PortfolioAccountUpdateCommand,
+    ) -> PortfoliApplicationResult<PortfolioAccountData>;
+    /// 保有銘柄と売買履歴もあわせて削除する
+    async fn remove_account(
+        &self,
+        user_id: &str,
+        portfolio_id: i32,
+    ) -> PortfoliApplicationResult<()>;
+    async fn get_all(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+    ) -> PortfoliApplicationResult<Vec<PortfolioData>>;
+    /// 保有銘柄全体の日次評価額の推移
+    async fn get_history(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PortfoliApplicationResult<PortfolioHistoryData>;
+    /// 保有銘柄の配当予定・実績(支払開始日の昇順)
+    async fn get_dividend_calendar(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PortfoliApplicationResult<Vec<DividendCalendarData>>;
+    async fn remove(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+    ) -> PortfoliApplicationResult<()>;
     async fn update(&self, update_command: PortfolioUpdateCommand)
         -> PortfoliApplicationResult<()>;
     async fn add(&self, portfolio: PortfolioData) -> PortfoliApplicationResult<()>;
+    async fn get_transactions(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+    ) -> PortfoliApplicationResult<Vec<TransactionData>>;
+    /// 売買履歴を登録し、保有株数と取得単価を再計算する
+    async fn add_transaction(
+        &self,
+        transaction: TransactionData,
+    ) -> PortfoliApplicationResult<TransactionData>;
+    async fn update_transaction(
+        &self,
+        update_command: TransactionUpdateCommand,
+    ) -> PortfoliApplicationResult<TransactionData>;
+    async fn remove_transaction(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+        transaction_id: i32,
+    ) -> PortfoliApplicationResult<()>;
 }
@@ -1,85 +1,405 @@
 use std::sync::Arc;
 
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::{Local, NaiveDate};
 use futures::future::join_all;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::{
     portfolio::{
-        PortfoliApplicationResult, PortfolioApplicationError, PortfolioData, PortfolioService,
-        PortfolioUpdateCommand,
+        portfolio_history_data::DailyValue, DividendCalendarData, DividendIncomeData,
+        PortfoliApplicationResult, PortfolioAccountData, PortfolioAccountUpdateCommand, PortfolioApplicationError, PortfolioData,
+        PortfolioHistoryData, PortfolioService, PortfolioUpdateCommand, TransactionData,
+        TransactionUpdateCommand,
     },
-    stock::StockQueryService,
+    stock::{QuoteData, StockQueryCommand, StockQueryService},
 };
 use domain::{
-    portfolio::{Portfolio, PortfolioReposotory},
+    corporate_action::{CorporateAction, CorporateActionRepository},
+    dividend::DividendRepository,
+    money::{Money, MoneyDomainError, Price, Quantity},
+    portfolio::{
+        AccountType, CostBasisMethod, Portfolio, PortfolioAccount, PortfolioId,
+        PortfolioReposotory, Position, Transaction, TransactionId, TransactionType,
+    },
     stock::StockId,
     user::{UserDomainService, UserId, UserRepository},
 };
 
+/// 既定のポートフォリオが未作成の場合に作成するポートフォリオ名
+const DEFAULT_PORTFOLIO_NAME: &str = "default";
+/// 資産推移の期間初日の時価に使う終値を遡って取得する営業日数
+const HISTORY_LOOKBACK_TRADING_DAYS: i64 = 10;
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
-pub struct PortfolioServiceImpl<T, U, V>
+pub struct PortfolioServiceImpl<T, U, V, W, X>
 where
     T: PortfolioReposotory,
     U: StockQueryService,
     V: UserRepository,
+    W: DividendRepository,
+    X: CorporateActionRepository,
 {
     portfolio_repository: Arc<T>,
+    dividend_repository: Arc<W>,
+    corporate_action_repository: Arc<X>,
     stock_query_service: U,
     user_domain_service: UserDomainService<V>,
 }
 
-impl<T, U, V> PortfolioServiceImpl<T, U, V>
+impl<T, U, V, W, X> PortfolioServiceImpl<T, U, V, W, X>
 where
     T: PortfolioReposotory,
     U: StockQueryService,
     V: UserRepository,
+    W: DividendRepository,
+    X: CorporateActionRepository,
 {
     /// コンストラクタ
     pub fn new(
         portfolio_repository: &Arc<T>,
+        dividend_repository: &Arc<W>,
+        corporate_action_repository: &Arc<X>,
         stock_query_service: U,
         user_domain_service: UserDomainService<V>,
     ) -> Self {
         Self {
             portfolio_repository: Arc::clone(portfolio_repository),
+            dividend_repository: Arc::clone(dividend_repository),
+            corporate_action_repository: Arc::clone(corporate_action_repository),
             stock_query_service,
             user_domain_service,
         }
     }
 }
 #[async_trait::async_trait]
-impl<T, U, V> PortfolioService for PortfolioServiceImpl<T, U, V>
+impl<T, U, V, W, X> PortfolioService for PortfolioServiceImpl<T, U, V, W, X>
 where
     T: PortfolioReposotory + std::fmt::Debug + Send + Sync,
     U: StockQueryService + std::fmt::Debug + Send + Sync,
     V: UserRepository + std::fmt::Debug + Send + Sync,
+    W: DividendRepository + std::fmt::Debug + Send + Sync,
+    X: CorporateActionRepository + std::fmt::Debug + Send + Sync,
 {
     #[tracing::instrument(skip(self), err, ret)]
-    async fn get_all(&self, user_id: &str) -> PortfoliApplicationResult<Vec<PortfolioData>> {
+    async fn get_accounts(
+        &self,
+        user_id: &str,
+    ) -> PortfoliApplicationResult<Vec<PortfolioAccountData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let result = self
+            .portfolio_repository
+            .find_accounts(&user_id)
+            .await?
+            .into_iter()
+            .map(PortfolioAccountData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_account(
+        &self,
+        account: PortfolioAccountData,
+    ) -> PortfoliApplicationResult<PortfolioAccountData> {
+        let user_id = UserId::new(account.user_id.clone());
+        self.user_domain_service.exists(&user_id).await?;
+
+        // 新規登録のため指定されたIDは無視する
+        let account = PortfolioAccount::try_from(PortfolioAccountData {
+            id: None,
+            ..account
+        })?;
+        let id = self
+            .portfolio_repository
+            .save_account(account.clone())
+            .await?;
+
+        Ok(account.with_id(id).into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn update_account(
+        &self,
+        update_command: PortfolioAccountUpdateCommand,
+    ) -> PortfoliApplicationResult<PortfolioAccountData> {
+        let user_id = UserId::new(update_command.user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let portfolio_id = PortfolioId::new(update_command.portfolio_id);
+        let mut account = self
+            .portfolio_repository
+            .find_accounts(&user_id)
+            .await?
+            .into_iter()
+            .find(|a| a.id() == Some(portfolio_id))
+            .ok_or(PortfolioApplicationError::PortfolioAccountNotFound(
+                *portfolio_id,
+            ))?;
+
+        if let Some(name) = update_command.name {
+            account.rename(name)?;
+        }
+        if let Some(account_type) = update_command.account_type {
+            let account_type: AccountType = account_type.parse()?;
+            account.update_account_type(account_type);
+        }
+        self.portfolio_repository
+            .save_account(account.clone())
+            .await?;
+
+        Ok(account.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_account(
+        &self,
+        user_id: &str,
+        portfolio_id: i32,
+    ) -> PortfoliApplicationResult<()> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let Some(portfolio_id) = self.find_portfolio_id(&user_id, Some(portfolio_id)).await? else {
+            return Ok(());
+        };
+        self.portfolio_repository
+            .delete_account(&user_id, &portfolio_id)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn get_all(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+    ) -> PortfoliApplicationResult<Vec<PortfolioData>> {
         let user_id = UserId::new(user_id.into());
         self.user_domain_service.exists(&user_id).await?;
 
-        let all_portfolio = self.portfolio_repository.find_all(&user_id).await?;
+        let Some(portfolio_id) = self.find_portfolio_id(&user_id, portfolio_id).await? else {
+            return Ok(vec![]);
+        };
+        let all_portfolio = self.portfolio_repository.find_all(&portfolio_id).await?;
+        // 保有銘柄の最新の株価はまとめて取得する
+        let stock_ids = all_portfolio.iter().map(|p| p.stock_id.to_string()).collect();
+        let mut quotes: HashMap<String, QuoteData> = self
+            .stock_query_service
+            .find_latest_many(stock_ids)
+            .await?
+            .into_iter()
+            .map(|q| (q.stock_id.clone(), q))
+            .collect();
 
         // ポートフォリオを外部向けデータに変換
-        let result = join_all(
-            all_portfolio
-                .into_iter()
-                .map(|p| self.into_portfolio_data(p)),
-        )
+        let result = join_all(all_portfolio.into_iter().map(|p| {
+            let quote = quotes.remove(p.stock_id.as_str());
+            self.into_portfolio_data(p, quote)
+        }))
         .await;
 
         result.into_iter().collect()
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn remove(&self, user_id: &str, stock_id: &str) -> PortfoliApplicationResult<()> {
+    async fn get_history(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PortfoliApplicationResult<PortfolioHistoryData> {
+        // パラメータチェック
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(PortfolioApplicationError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        };
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let all_portfolio = match self.find_portfolio_id(&user_id, portfolio_id).await? {
+            Some(portfolio_id) => self.portfolio_repository.find_all(&portfolio_id).await?,
+            None => vec![],
+        };
+        let mut holdings = Vec::new();
+        let mut dates = BTreeSet::new();
+        for portfolio in all_portfolio {
+            let transactions = self
+                .portfolio_repository
+                .find_transactions(&portfolio.portfolio_id, &portfolio.stock_id)
+                .await?;
+            // 株価は修正前の値を使い、株式分割・併合は日付ごとに株数へ反映する
+            let corporate_actions = self
+                .corporate_action_repository
+                .find_all(&portfolio.stock_id)
+                .await?;
+            // 期間初日の時価に前営業日以前の終値を使えるよう、売買が無い日を考慮して数営業日前から取得する
+            let mut command = StockQueryCommand::new();
+            command.stock_id = Some(portfolio.stock_id.to_string());
+            command.start = start.map(|start| {
+                self.stock_query_service
+                    .trading_calendar()
+                    .add_trading_days(start, -HISTORY_LOOKBACK_TRADING_DAYS)
+            });
+            command.end = end;
+            let prices = self
+                .stock_query_service
+                .find(command)
+                .await?
+                .into_iter()
+                .map(|s| (s.date, s.end_price))
+                .collect::<BTreeMap<NaiveDate, Decimal>>();
+
+            dates.extend(
+                prices
+                    .keys()
+                    .filter(|date| start.is_none_or(|start| **date >= start)),
+            );
+            holdings.push((portfolio, transactions, corporate_actions, prices));
+        }
+
+        let today = Local::now().date_naive();
+        let mut values = Vec::new();
+        let mut previous_date = None;
+        for date in dates {
+            let mut value = DailyValue {
+                date,
+                ..Default::default()
+            };
+            for (portfolio, transactions, corporate_actions, prices) in holdings.iter() {
+                // 株価が付くまでは評価額・取得価額に含めない
+                let Some((_, price)) = prices.range(..=date).next_back() else {
+                    continue;
+                };
+                // 売買履歴がない銘柄は期間中一定の株数(現在の株数)を保有していたものとする
+                let (stock_count, cost_basis) = if transactions.is_empty() {
+                    let (ratio_from, ratio_to) = split_ratio(corporate_actions, date, today);
+                    (
+                        Decimal::from(*portfolio.stock_count) * ratio_from / ratio_to,
+                        portfolio.purchase.checked_mul(portfolio.stock_count)?,
+                    )
+                } else {
+                    let executed = transactions
+                        .iter()
+                        .filter(|t| *t.date() <= date)
+                        .cloned()
+                        .collect::<Vec<Transaction>>();
+                    let corporate_actions = corporate_actions
+                        .iter()
+                        .filter(|action| *action.ex_date() <= date)
+                        .cloned()
+                        .collect::<Vec<CorporateAction>>();
+                    let position = Position::calculate(
+                        &executed,
+                        &corporate_actions,
+                        portfolio.cost_basis_method,
+                    )?;
+                    (
+                        Decimal::from(*position.stock_count),
+                        position.acquisition_cost,
+                    )
+                };
+                let market_value = price.checked_mul(stock_count).ok_or_else(|| {
+                    MoneyDomainError::Overflow(format!("{price} * {stock_count}"))
+                })?;
+
+                value.market_value += market_value;
+                value.cost_basis += *cost_basis;
+                // 前の日付に株価が無かった銘柄は当日の評価額で組み入れたものとする
+                if previous_date.is_some_and(|previous| prices.range(..=previous).next().is_none())
+                {
+                    value.inflow += market_value;
+                    continue;
+                }
+                // 前の日付から当日までの売買
+                for t in transactions.iter().filter(|t| {
+                    *t.date() <= date && previous_date.is_some_and(|previous| *t.date() > previous)
+                }) {
+                    let amount = t.price().checked_mul(t.quantity())?;
+                    match t.transaction_type() {
+                        TransactionType::Buy => value.inflow += *amount.checked_add(t.fee())?,
+                        TransactionType::Sell => value.outflow += *amount.checked_sub(t.fee())?,
+                    }
+                }
+            }
+            values.push(value);
+            previous_date = Some(date);
+        }
+
+        Ok(PortfolioHistoryData::calculate(values))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_dividend_calendar(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PortfoliApplicationResult<Vec<DividendCalendarData>> {
+        // パラメータチェック
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(PortfolioApplicationError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        };
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let all_portfolio = match self.find_portfolio_id(&user_id, portfolio_id).await? {
+            Some(portfolio_id) => self.portfolio_repository.find_all(&portfolio_id).await?,
+            None => vec![],
+        };
+        let mut result = Vec::new();
+        for portfolio in all_portfolio {
+            let transactions = self
+                .portfolio_repository
+                .find_transactions(&portfolio.portfolio_id, &portfolio.stock_id)
+                .await?;
+            let dividends = self.find_dividends(&portfolio, &transactions).await?;
+            result.extend(dividends.into_iter().filter(|d| {
+                d.stock_count > 0
+                    && start.is_none_or(|start| d.payment_date >= start)
+                    && end.is_none_or(|end| d.payment_date <= end)
+            }));
+        }
+        result.sort_by(|a, b| {
+            (a.payment_date, &a.stock_id, a.ex_date).cmp(&(b.payment_date, &b.stock_id, b.ex_date))
+        });
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+    ) -> PortfoliApplicationResult<()> {
         let user_id = UserId::new(user_id.into());
         self.user_domain_service.exists(&user_id).await?;
 
-        let stock_id = StockId::new(stock_id.to_string());
+        let Some(portfolio_id) = self.find_portfolio_id(&user_id, portfolio_id).await? else {
+            return Ok(());
+        };
+        let stock_id = StockId::new(stock_id.to_string())?;
 
         self.portfolio_repository
-            .delete(&user_id, &stock_id)
+            .delete(&portfolio_id, &stock_id)
             .await?;
         Ok(())
     }
@@ -89,27 +409,58 @@ where
         &self,
         update_command: PortfolioUpdateCommand,
     ) -> PortfoliApplicationResult<()> {
-        let user_id = UserId::new(update_command.user_id.into());
+        let user_id = UserId::new(update_command.user_id);
         self.user_domain_service.exists(&user_id).await?;
 
-        let stock_id = StockId::new(update_command.stock_id.into());
-
+        let stock_id = StockId::new(update_command.stock_id)?;
         let mut portfolio = self
+            .find_portfolio(&user_id, update_command.portfolio_id, &stock_id)
+            .await?;
+
+        if update_command.purchase.is_none()
+            && update_command.stock_count.is_none()
+            && update_command.cost_basis_method.is_none()
+        {
+            return Ok(());
+        }
+
+        let transactions = self
             .portfolio_repository
-            .find(&user_id, &stock_id)
-            .await?
-            .ok_or(PortfolioApplicationError::PortfolioNotFound(
+            .find_transactions(&portfolio.portfolio_id, &stock_id)
+            .await?;
+        // 売買履歴がある場合は保有株数と取得単価を直接変更できない
+        if !transactions.is_empty()
+            && (update_command.purchase.is_some() || update_command.stock_count.is_some())
+        {
+            return Err(PortfolioApplicationError::TransactionsExist(
                 stock_id.into(),
-            ))?;
+            ));
+        }
 
         if let Some(purchase) = update_command.purchase {
+            let Ok(purchase) = Price::new(purchase) else {
+                return Err(PortfolioApplicationError::InvalidParameter {
+                    name: "purchase",
+                    value: purchase.to_string(),
+                });
+            };
             portfolio.update_purchase(purchase);
         }
         if let Some(stock_count) = update_command.stock_count {
+            let Ok(stock_count) = Quantity::new(stock_count) else {
+                return Err(PortfolioApplicationError::InvalidParameter {
+                    name: "stock_count",
+                    value: stock_count.to_string(),
+                });
+            };
             portfolio.update_stock_count(stock_count);
         }
-        if update_command.purchase.is_none() && update_command.stock_count.is_none() {
-            return Ok(());
+        if let Some(cost_basis_method) = update_command.cost_basis_method {
+            let cost_basis_method: CostBasisMethod = cost_basis_method.parse()?;
+            portfolio.update_cost_basis_method(cost_basis_method);
+            if !transactions.is_empty() {
+                portfolio = self.calculate_position(portfolio, &transactions).await?;
+            }
         }
 
         self.portfolio_repository.save(portfolio).await?;
@@ -118,87 +469,528 @@ where
 
     #[tracing::instrument(skip(self), err)]
     async fn add(&self, portfolio: PortfolioData) -> PortfoliApplicationResult<()> {
-        let user_id = UserId::new(portfolio.clone().user_id.into());
+        let user_id = UserId::new(portfolio.clone().user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let portfolio_id = self
+            .get_or_create_portfolio_id(&user_id, portfolio.portfolio_id)
+            .await?;
+        let portfolio = Portfolio {
+            portfolio_id,
+            ..portfolio.try_into()?
+        };
+
+        self.portfolio_repository.save(portfolio).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_transactions(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+    ) -> PortfoliApplicationResult<Vec<TransactionData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let Some(portfolio_id) = self.find_portfolio_id(&user_id, portfolio_id).await? else {
+            return Ok(vec![]);
+        };
+        let stock_id = StockId::new(stock_id.to_string())?;
+        let result = self
+            .portfolio_repository
+            .find_transactions(&portfolio_id, &stock_id)
+            .await?
+            .into_iter()
+            .map(TransactionData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_transaction(
+        &self,
+        transaction: TransactionData,
+    ) -> PortfoliApplicationResult<TransactionData> {
+        let user_id = UserId::new(transaction.user_id.clone());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let portfolio_id = self
+            .get_or_create_portfolio_id(&user_id, transaction.portfolio_id)
+            .await?;
+        // 新規登録のため指定されたIDは無視する
+        let transaction = Transaction::try_from(TransactionData {
+            id: None,
+            portfolio_id: Some(*portfolio_id),
+            ..transaction
+        })?;
+        let stock_id = transaction.stock_id().clone();
+
+        // 未登録の銘柄はポートフォリオに追加する
+        let portfolio = self
+            .portfolio_repository
+            .find(&portfolio_id, &stock_id)
+            .await?
+            .unwrap_or_else(|| {
+                Portfolio::new(
+                    portfolio_id,
+                    user_id.clone(),
+                    stock_id.clone(),
+                    Quantity::default(),
+                    Price::default(),
+                )
+            });
+        let mut transactions = self
+            .portfolio_repository
+            .find_transactions(&portfolio_id, &stock_id)
+            .await?;
+        // 売買履歴のない手入力の保有株は、同じ約定日の買付として先に登録して引き継ぐ
+        let mut saving = Vec::new();
+        if transactions.is_empty() && *portfolio.stock_count > 0 {
+            // 手入力の株数・単価は現在の値のため、約定日より後の株式分割・併合の前の値に戻す
+            let corporate_actions = self.corporate_action_repository.find_all(&stock_id).await?;
+            let today = Local::now().date_naive();
+            let stock_count = unsplit_stock_count(
+                portfolio.stock_count,
+                &corporate_actions,
+                *transaction.date(),
+                today,
+            )?;
+            let (ratio_from, ratio_to) =
+                split_ratio(&corporate_actions, *transaction.date(), today);
+            let purchase = *portfolio.purchase * ratio_to / ratio_from;
+            saving.push(Transaction::new(
+                None,
+                portfolio_id,
+                user_id,
+                stock_id,
+                TransactionType::Buy,
+                *transaction.date(),
+                stock_count,
+                Price::new(purchase)?.round(),
+                Money::default(),
+            )?);
+        }
+        saving.push(transaction.clone());
+        transactions.extend(saving.iter().cloned());
+
+        let portfolio = self.calculate_position(portfolio, &transactions).await?;
+        let ids = self
+            .portfolio_repository
+            .save_transactions(portfolio, saving)
+            .await?;
+        let Some(id) = ids.last() else {
+            return Err(anyhow::anyhow!("transaction id not assigned").into());
+        };
+
+        Ok(transaction.with_id(*id).into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn update_transaction(
+        &self,
+        update_command: TransactionUpdateCommand,
+    ) -> PortfoliApplicationResult<TransactionData> {
+        let user_id = UserId::new(update_command.user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let stock_id = StockId::new(update_command.stock_id)?;
+        let portfolio = self
+            .find_portfolio(&user_id, update_command.portfolio_id, &stock_id)
+            .await?;
+        let mut transactions = self
+            .portfolio_repository
+            .find_transactions(&portfolio.portfolio_id, &stock_id)
+            .await?;
+        let transaction_id = TransactionId::new(update_command.transaction_id);
+        let Some(target) = transactions.iter_mut().find(|t| t.id() == Some(transaction_id)) else {
+            return Err(PortfolioApplicationError::TransactionNotFound(*transaction_id));
+        };
+
+        let current = TransactionData::from(target.clone());
+        *target = Transaction::try_from(TransactionData {
+            transaction_type: update_command
+                .transaction_type
+                .unwrap_or(current.transaction_type),
+            date: update_command.date.unwrap_or(current.date),
+            quantity: update_command.quantity.unwrap_or(current.quantity),
+            price: update_command.price.unwrap_or(current.price),
+            fee: update_command.fee.unwrap_or(current.fee),
+            ..current
+        })?;
+        let transaction = target.clone();
+
+        let portfolio = self.calculate_position(portfolio, &transactions).await?;
+        self.portfolio_repository
+            .save_transactions(portfolio, vec![transaction.clone()])
+            .await?;
+
+        Ok(transaction.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_transaction(
+        &self,
+        user_id: &str,
+        portfolio_id: Option<i32>,
+        stock_id: &str,
+        transaction_id: i32,
+    ) -> PortfoliApplicationResult<()> {
+        let user_id = UserId::new(user_id.into());
         self.user_domain_service.exists(&user_id).await?;
 
-        self.portfolio_repository.save(portfolio.into()).await?;
+        let stock_id = StockId::new(stock_id.to_string())?;
+        let portfolio = self
+            .find_portfolio(&user_id, portfolio_id, &stock_id)
+            .await?;
+        let mut transactions = self
+            .portfolio_repository
+            .find_transactions(&portfolio.portfolio_id, &stock_id)
+            .await?;
+        let transaction_id = TransactionId::new(transaction_id);
+        let Some(index) = transactions.iter().position(|t| t.id() == Some(transaction_id)) else {
+            return Err(PortfolioApplicationError::TransactionNotFound(*transaction_id));
+        };
+        transactions.remove(index);
+
+        let portfolio = self.calculate_position(portfolio, &transactions).await?;
+        self.portfolio_repository
+            .delete_transaction(portfolio, &transaction_id)
+            .await?;
+
         Ok(())
     }
 }
 
-impl<T, U, V> PortfolioServiceImpl<T, U, V>
+impl<T, U, V, W, X> PortfolioServiceImpl<T, U, V, W, X>
 where
     T: PortfolioReposotory + Send + Sync,
     U: StockQueryService + Send + Sync,
     V: UserRepository + Send + Sync,
+    W: DividendRepository + Send + Sync,
+    X: CorporateActionRepository + Send + Sync,
 {
+    /// 指定されたポートフォリオ(口座)のIDを確認する
+    ///
+    /// 未指定の場合は既定のポートフォリオを対象とし、まだ作成されていなければNoneを返す
+    async fn find_portfolio_id(
+        &self,
+        user_id: &UserId,
+        portfolio_id: Option<i32>,
+    ) -> PortfoliApplicationResult<Option<PortfolioId>> {
+        let accounts = self.portfolio_repository.find_accounts(user_id).await?;
+        let Some(portfolio_id) = portfolio_id else {
+            return Ok(accounts.first().and_then(|a| a.id()));
+        };
+
+        let portfolio_id = PortfolioId::new(portfolio_id);
+        if !accounts.iter().any(|a| a.id() == Some(portfolio_id)) {
+            return Err(PortfolioApplicationError::PortfolioAccountNotFound(
+                *portfolio_id,
+            ));
+        }
+
+        Ok(Some(portfolio_id))
+    }
+
+    /// 既定のポートフォリオが未作成の場合は作成してIDを返す
+    async fn get_or_create_portfolio_id(
+        &self,
+        user_id: &UserId,
+        portfolio_id: Option<i32>,
+    ) -> PortfoliApplicationResult<PortfolioId> {
+        if let Some(portfolio_id) = self.find_portfolio_id(user_id, portfolio_id).await? {
+            return Ok(portfolio_id);
+        }
+
+        let account = PortfolioAccount::new(
+            None,
+            user_id.clone(),
+            DEFAULT_PORTFOLIO_NAME.to_string(),
+            AccountType::default(),
+        )?;
+        let portfolio_id = self.portfolio_repository.save_account(account).await?;
+
+        Ok(portfolio_id)
+    }
+
+    /// 登録済みの保有銘柄を取得する
+    async fn find_portfolio(
+        &self,
+        user_id: &UserId,
+        portfolio_id: Option<i32>,
+        stock_id: &StockId,
+    ) -> PortfoliApplicationResult<Portfolio> {
+        let portfolio = match self.find_portfolio_id(user_id, portfolio_id).await? {
+            Some(portfolio_id) => {
+                self.portfolio_repository
+                    .find(&portfolio_id, stock_id)
+                    .await?
+            }
+            None => None,
+        };
+
+        portfolio.ok_or(PortfolioApplicationError::PortfolioNotFound(
+            stock_id.to_string(),
+        ))
+    }
+
+    /// 変更後の売買履歴と本日までの株式分割・併合から保有状況を再計算する
+    ///
+    /// 保有株数を超える売却が含まれる場合はエラーを返す
+    async fn calculate_position(
+        &self,
+        mut portfolio: Portfolio,
+        transactions: &[Transaction],
+    ) -> PortfoliApplicationResult<Portfolio> {
+        let position = self.find_position(&portfolio, transactions).await?;
+        portfolio.apply_position(&position);
+
+        Ok(portfolio)
+    }
+
+    /// 売買履歴と本日までの株式分割・併合から現在の保有状況を求める
+    async fn find_position(
+        &self,
+        portfolio: &Portfolio,
+        transactions: &[Transaction],
+    ) -> PortfoliApplicationResult<Position> {
+        let today = Local::now().date_naive();
+        let corporate_actions = self
+            .corporate_action_repository
+            .find_all(&portfolio.stock_id)
+            .await?
+            .into_iter()
+            .filter(|action| *action.ex_date() <= today)
+            .collect::<Vec<CorporateAction>>();
+
+        Ok(Position::calculate(
+            transactions,
+            &corporate_actions,
+            portfolio.cost_basis_method,
+        )?)
+    }
+
+    /// 保有銘柄の配当ごとに権利落ち日の前日時点の保有株数を求める
+    ///
+    /// 保有株数には前日までに権利落ちした株式分割・併合を反映する。
+    /// 売買履歴がない銘柄は現在の保有株数を、その後の株式分割・併合の前の株数に戻して保有し続けていたものとする
+    async fn find_dividends(
+        &self,
+        portfolio: &Portfolio,
+        transactions: &[Transaction],
+    ) -> PortfoliApplicationResult<Vec<DividendCalendarData>> {
+        let dividends = self
+            .dividend_repository
+            .find_all(&portfolio.stock_id)
+            .await?;
+        let corporate_actions = self
+            .corporate_action_repository
+            .find_all(&portfolio.stock_id)
+            .await?;
+
+        let today = Local::now().date_naive();
+
+        let mut result = Vec::new();
+        for dividend in dividends {
+            let stock_count = if transactions.is_empty() {
+                let start = dividend.ex_date().pred_opt().unwrap_or(NaiveDate::MIN);
+                unsplit_stock_count(portfolio.stock_count, &corporate_actions, start, today)?
+            } else {
+                let executed = transactions
+                    .iter()
+                    .filter(|t| t.date() < dividend.ex_date())
+                    .cloned()
+                    .collect::<Vec<Transaction>>();
+                let corporate_actions = corporate_actions
+                    .iter()
+                    .filter(|action| action.ex_date() < dividend.ex_date())
+                    .cloned()
+                    .collect::<Vec<CorporateAction>>();
+                Position::calculate(&executed, &corporate_actions, portfolio.cost_basis_method)?
+                    .stock_count
+            }
+            .into();
+            result.push(DividendCalendarData {
+                stock_id: dividend.stock_id().to_string(),
+                ex_date: *dividend.ex_date(),
+                record_date: *dividend.record_date(),
+                payment_date: *dividend.payment_date(),
+                amount_per_share: dividend.amount_per_share().into(),
+                stock_count,
+            });
+        }
+
+        Ok(result)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
     async fn into_portfolio_data(
         &self,
         portfolio: Portfolio,
+        quote: Option<QuoteData>,
     ) -> PortfoliApplicationResult<PortfolioData> {
-        let latest = self
-            .stock_query_service
-            .find_latest(&portfolio.stock_id)
+        let Some(quote) = quote else {
+            return Err(PortfolioApplicationError::StockDataNotFound(
+                portfolio.stock_id.to_string(),
+            ));
+        };
+        // 売買履歴がある場合は株式分割・併合を反映した保有状況を求める
+        let transactions = self
+            .portfolio_repository
+            .find_transactions(&portfolio.portfolio_id, &portfolio.stock_id)
             .await?;
+        let (position, purchase) = if transactions.is_empty() {
+            let position = Position {
+                stock_count: portfolio.stock_count,
+                acquisition_cost: portfolio.purchase.checked_mul(portfolio.stock_count)?,
+                ..Default::default()
+            };
+            (position, portfolio.purchase)
+        } else {
+            let position = self.find_position(&portfolio, &transactions).await?;
+            let purchase = position.average_cost();
+            (position, purchase)
+        };
+        // 配当金
+        let dividends = self.find_dividends(&portfolio, &transactions).await?;
+        let dividend = DividendIncomeData::calculate(&dividends, Local::now().date_naive());
 
         let portfolio_data = PortfolioData {
+            portfolio_id: Some(*portfolio.portfolio_id),
             stock_id: portfolio.stock_id.to_string(),
             user_id: portfolio.user_id.to_string(),
-            stock_count: portfolio.stock_count,
-            purchase: portfolio.purchase,
-            market_price: latest.end_price,
-            latest_date: latest.date,
+            stock_count: position.stock_count.into(),
+            purchase: purchase.into(),
+            acquisition_cost: position.acquisition_cost.into(),
+            cost_basis_method: portfolio.cost_basis_method,
+            market_price: quote.end_price,
+            latest_date: quote.date,
+            previous_price: quote.previous_close,
+            realized_gain: position.realized_gain.into(),
+            dividend,
         };
 
         Ok(portfolio_data)
     }
 }
 
+/// startより後、end以前に権利落ちした株式分割・併合の比率(分割・併合前の株数, 分割・併合後の株数)
+fn split_ratio(
+    corporate_actions: &[CorporateAction],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> (Decimal, Decimal) {
+    corporate_actions
+        .iter()
+        .filter(|action| start < *action.ex_date() && *action.ex_date() <= end)
+        .fold((Decimal::ONE, Decimal::ONE), |(from, to), action| {
+            (
+                from * Decimal::from(action.ratio_from()),
+                to * Decimal::from(action.ratio_to()),
+            )
+        })
+}
+
+/// 現在の株数をstartより後、end以前の株式分割・併合の前の株数に戻す(端数切り捨て)
+fn unsplit_stock_count(
+    stock_count: Quantity,
+    corporate_actions: &[CorporateAction],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> PortfoliApplicationResult<Quantity> {
+    let (ratio_from, ratio_to) = split_ratio(corporate_actions, start, end);
+    let stock_count = (Decimal::from(*stock_count) * ratio_from / ratio_to)
+        .floor()
+        .to_i64()
+        .unwrap_or_default();
+
+    Ok(Quantity::new(stock_count)?)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
 
     use crate::{
+        corporate_action::InmemoryCorporateActionRepositoryImpl,
+        dividend::InmemoryDividendRepositoryImpl,
         portfolio::{
-            InmemoryPortfolioRepositoryImpl, PortfolioApplicationError, PortfolioData,
-            PortfolioService, PortfolioServiceImpl, PortfolioUpdateCommand,
+            InmemoryPortfolioRepositoryImpl, PortfolioAccountData, PortfolioAccountUpdateCommand,
+            PortfolioApplicationError, PortfolioData, PortfolioService, PortfolioServiceImpl,
+            PortfolioUpdateCommand, TransactionData, TransactionUpdateCommand,
         },
         stock::{InmemoryStockQueryServiceImpl, StockData},
         user::InmemoryUserRepositoryImpl,
     };
     use domain::{
+        corporate_action::CorporateAction,
+        dividend::Dividend,
+        money::Price,
+        stock::StockId,
         user::{User, UserDomainService, UserEmail, UserId, UserName, UserRepository},
     };
 
     const USER_ID: &str = "sample user";
-    const STOCK_ID: &str = "sample stock";
+    const STOCK_ID: &str = "1301";
 
     async fn setup() -> impl PortfolioService {
-        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
         let mut sample_stock = StockData::new();
         sample_stock.stock_id = STOCK_ID.to_string();
-        stock_query_service.stocks.push(sample_stock);
+        setup_with_stocks(vec![sample_stock]).await
+    }
+
+    async fn setup_with_stocks(stocks: Vec<StockData>) -> impl PortfolioService {
+        setup_with_dividends(stocks, vec![]).await
+    }
+
+    async fn setup_with_dividends(
+        stocks: Vec<StockData>,
+        dividends: Vec<Dividend>,
+    ) -> impl PortfolioService {
+        setup_with_corporate_actions(stocks, dividends, vec![]).await
+    }
+
+    async fn setup_with_corporate_actions(
+        stocks: Vec<StockData>,
+        dividends: Vec<Dividend>,
+        corporate_actions: Vec<CorporateAction>,
+    ) -> impl PortfolioService {
+        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
+        stock_query_service.stocks = stocks;
 
         let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
         let sample_user = User::new(
             UserId::new(USER_ID.to_string()),
-            UserName::new("".to_string()),
-            UserEmail::new("".to_string()),
+            UserName::default(),
+            UserEmail::default(),
         );
         user_repository.save(sample_user).await.unwrap();
 
         let user_domain_service = UserDomainService::new(&user_repository);
 
         let portfolio_repository = Arc::new(InmemoryPortfolioRepositoryImpl::new());
-        let portfolio_service = PortfolioServiceImpl::new(
+        let dividend_repository = Arc::new(InmemoryDividendRepositoryImpl::new());
+        dividend_repository.store.lock().unwrap().extend(dividends);
+        let corporate_action_repository = Arc::new(InmemoryCorporateActionRepositoryImpl::new());
+        corporate_action_repository
+            .store
+            .lock()
+            .unwrap()
+            .extend(corporate_actions);
+        PortfolioServiceImpl::new(
             &portfolio_repository,
+            &dividend_repository,
+            &corporate_action_repository,
             stock_query_service.clone(),
             user_domain_service,
-        );
-
-        portfolio_service
+        )
     }
 
     #[tokio::test]
@@ -207,7 +999,7 @@ mod tests {
         let portfolio = PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string());
         service.add(portfolio).await?;
 
-        let result = service.get_all(USER_ID).await?;
+        let result = service.get_all(USER_ID, None).await?;
         assert!(result[0].user_id == USER_ID);
         assert!(result.len() == 1);
 
@@ -231,7 +1023,7 @@ mod tests {
         let service = setup().await;
         let portfolio = PortfolioData::new("not registed user".to_string(), STOCK_ID.to_string());
 
-        let Err(PortfolioApplicationError::UserNotFound(_)) = service.get_all(&portfolio.user_id).await else {
+        let Err(PortfolioApplicationError::UserNotFound(_)) = service.get_all(&portfolio.user_id, None).await else {
             return Err(anyhow!("unexpected add favorite result"));
         };
 
@@ -244,15 +1036,15 @@ mod tests {
         let portfolio = PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string());
         service.add(portfolio.clone()).await?;
 
-        let result = service.get_all(USER_ID).await?;
+        let result = service.get_all(USER_ID, None).await?;
         assert!(result[0].user_id == USER_ID);
         assert!(result.len() == 1);
 
         service
-            .remove(&portfolio.user_id, &portfolio.stock_id)
+            .remove(&portfolio.user_id, None, &portfolio.stock_id)
             .await?;
-        let result = service.get_all(USER_ID).await?;
-        assert!(result.len() == 0);
+        let result = service.get_all(USER_ID, None).await?;
+        assert!(result.is_empty());
 
         Ok(())
     }
@@ -262,7 +1054,7 @@ mod tests {
         let service = setup().await;
         let portfolio = PortfolioData::new("not registed user".to_string(), STOCK_ID.to_string());
 
-        let Err(PortfolioApplicationError::UserNotFound(_)) = service.remove(&portfolio.user_id, &portfolio.stock_id).await else {
+        let Err(PortfolioApplicationError::UserNotFound(_)) = service.remove(&portfolio.user_id, None, &portfolio.stock_id).await else {
             return Err(anyhow!("unexpected add favorite result"));
         };
 
@@ -272,7 +1064,7 @@ mod tests {
     #[tokio::test]
     async fn update_portfolio_success() -> anyhow::Result<()> {
         let service = setup().await;
-        let purchase = 12;
+        let purchase = Decimal::new(1255, 2);
         let stock_count = 444;
         let portfolio = PortfolioData {
             user_id: USER_ID.to_string(),
@@ -289,7 +1081,7 @@ mod tests {
         service.add(portfolio.clone()).await?;
         service.update(command).await?;
         let result = service
-            .get_all(&portfolio.user_id)
+            .get_all(&portfolio.user_id, None)
             .await?
             .pop()
             .ok_or(anyhow!("portfolio not found"))?;
@@ -300,6 +1092,25 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn update_portfolio_with_negative_purchase_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        let portfolio = PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string());
+        let command = PortfolioUpdateCommand::new(
+            USER_ID.to_string(),
+            STOCK_ID.to_string(),
+            Some(Decimal::from(-1)),
+            None,
+        );
+
+        service.add(portfolio).await?;
+        let Err(PortfolioApplicationError::InvalidParameter { name: "purchase", .. }) = service.update(command).await else {
+            return Err(anyhow!("unexpected update portfolio result"));
+        };
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn update_notexist_user_portfolio_return_err() -> anyhow::Result<()> {
         let service = setup().await;
@@ -324,7 +1135,7 @@ mod tests {
             user_id: USER_ID.to_string(),
             stock_id: STOCK_ID.to_string(),
             stock_count: 43,
-            purchase: 98,
+            purchase: Decimal::from(98),
             ..Default::default()
         };
         let command =
@@ -333,12 +1144,770 @@ mod tests {
         service.add(portfolio.clone()).await?;
         service.update(command).await?;
         let result = service
-            .get_all(&portfolio.user_id)
+            .get_all(&portfolio.user_id, None)
             .await?
             .pop()
             .ok_or(anyhow!("portfolio not found"))?;
+        let accounts = service.get_accounts(USER_ID).await?;
+
+        assert!(
+            result
+                == PortfolioData {
+                    portfolio_id: accounts[0].id,
+                    acquisition_cost: Decimal::from(4214),
+                    ..portfolio
+                }
+        );
+
+        Ok(())
+    }
+
+    fn transaction(transaction_type: &str, day: u32, quantity: i64, price: i64) -> TransactionData {
+        TransactionData {
+            transaction_type: transaction_type.to_string(),
+            date: NaiveDate::from_ymd_opt(2023, 1, day).unwrap(),
+            quantity,
+            price: Decimal::from(price),
+            fee: Decimal::ZERO,
+            ..TransactionData::new(USER_ID.to_string(), STOCK_ID.to_string())
+        }
+    }
+
+    async fn get_portfolio(service: &impl PortfolioService) -> anyhow::Result<PortfolioData> {
+        service
+            .get_all(USER_ID, None)
+            .await?
+            .pop()
+            .ok_or(anyhow!("portfolio not found"))
+    }
+
+    #[tokio::test]
+    async fn add_transactions_with_moving_average() -> anyhow::Result<()> {
+        let service = setup().await;
+        let mut buy = transaction("buy", 4, 100, 1000);
+        buy.fee = Decimal::from(500);
+        service.add_transaction(buy).await?;
+        service
+            .add_transaction(transaction("buy", 5, 100, 1200))
+            .await?;
+
+        // (100 * 1000 + 500 + 100 * 1200) / 200
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 200);
+        assert!(result.purchase == Decimal::new(11025, 1));
 
-        assert!(result == portfolio);
+        service
+            .add_transaction(transaction("sell", 6, 150, 1300))
+            .await?;
+
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 50);
+        assert!(result.purchase == Decimal::new(11025, 1));
+        assert!(service.get_transactions(USER_ID, None, STOCK_ID).await?.len() == 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_transactions_with_fifo() -> anyhow::Result<()> {
+        let service = setup().await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        service
+            .add_transaction(transaction("buy", 5, 100, 1200))
+            .await?;
+        let mut command =
+            PortfolioUpdateCommand::new(USER_ID.to_string(), STOCK_ID.to_string(), None, None);
+        command.cost_basis_method = Some("fifo".to_string());
+        service.update(command).await?;
+
+        service
+            .add_transaction(transaction("sell", 6, 150, 1300))
+            .await?;
+
+        // 先に買付けた100株と後の50株を売却
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 50);
+        assert!(result.purchase == Decimal::from(1200));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_exceeding_holdings_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        service
+            .add_transaction(transaction("buy", 5, 100, 1000))
+            .await?;
+
+        // 買付より前の約定日の売却
+        let Err(PortfolioApplicationError::InsufficientStock { .. }) = service.add_transaction(transaction("sell", 4, 100, 1000)).await else {
+            return Err(anyhow!("unexpected add transaction result"));
+        };
+        assert!(service.get_transactions(USER_ID, None, STOCK_ID).await?.len() == 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn overflowing_holdings_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        service
+            .add_transaction(transaction("buy", 5, i64::MAX, 1))
+            .await?;
+
+        // 保有株数の合計がi64の上限を超える買付
+        let Err(PortfolioApplicationError::InvalidParameter { name: "amount", .. }) = service.add_transaction(transaction("buy", 6, 1, 1)).await else {
+            return Err(anyhow!("unexpected add transaction result"));
+        };
+        assert!(service.get_transactions(USER_ID, None, STOCK_ID).await?.len() == 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_transaction_to_manual_position_keep_holdings() -> anyhow::Result<()> {
+        let service = setup().await;
+        let portfolio = PortfolioData {
+            user_id: USER_ID.to_string(),
+            stock_id: STOCK_ID.to_string(),
+            stock_count: 100,
+            purchase: Decimal::from(1000),
+            ..Default::default()
+        };
+        service.add(portfolio).await?;
+
+        let sell = service
+            .add_transaction(transaction("sell", 5, 40, 1300))
+            .await?;
+
+        // 手入力の保有株を買付として引き継いで売却する
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 60);
+        assert!(result.purchase == Decimal::from(1000));
+        assert!(result.realized_gain == Decimal::from(12000));
+        let transactions = service.get_transactions(USER_ID, None, STOCK_ID).await?;
+        assert!(transactions.len() == 2);
+        assert!(transactions[0].transaction_type == "buy");
+        assert!(transactions[0].date == sell.date);
+        assert!(transactions[0].quantity == 100);
+        assert!(transactions[0].price == Decimal::from(1000));
+        assert!(transactions[1] == sell);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_invalid_transaction_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let Err(PortfolioApplicationError::InvalidParameter { name: "quantity", .. }) = service.add_transaction(transaction("buy", 4, 0, 1000)).await else {
+            return Err(anyhow!("unexpected add transaction result"));
+        };
+        let Err(PortfolioApplicationError::InvalidParameter { name: "transaction_type", .. }) = service.add_transaction(transaction("hold", 4, 100, 1000)).await else {
+            return Err(anyhow!("unexpected add transaction result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_and_remove_transaction_recalculate_position() -> anyhow::Result<()> {
+        let service = setup().await;
+        let first = service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        let second = service
+            .add_transaction(transaction("buy", 5, 100, 1200))
+            .await?;
+        let Some(first_id) = first.id else {
+            return Err(anyhow!("transaction id not assigned"));
+        };
+        let Some(second_id) = second.id else {
+            return Err(anyhow!("transaction id not assigned"));
+        };
+
+        let mut command =
+            TransactionUpdateCommand::new(USER_ID.to_string(), STOCK_ID.to_string(), first_id);
+        command.price = Some(Decimal::from(1400));
+        let updated = service.update_transaction(command).await?;
+        assert!(updated.quantity == 100);
+        assert!(updated.price == Decimal::from(1400));
+
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 200);
+        assert!(result.purchase == Decimal::from(1300));
+
+        service
+            .remove_transaction(USER_ID, None, STOCK_ID, second_id)
+            .await?;
+
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 100);
+        assert!(result.purchase == Decimal::from(1400));
+
+        let Err(PortfolioApplicationError::TransactionNotFound(_)) = service.remove_transaction(USER_ID, None, STOCK_ID, second_id).await else {
+            return Err(anyhow!("unexpected remove transaction result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_all_with_profit_and_loss() -> anyhow::Result<()> {
+        let mut stocks = Vec::new();
+        // 2023/1/9は祝日のため前営業日は1/6
+        for (day, end_price) in [(5, 900), (6, 1000), (10, 1300)] {
+            let mut stock = StockData::new();
+            stock.stock_id = STOCK_ID.to_string();
+            stock.date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+            stock.end_price = Decimal::from(end_price);
+            stocks.push(stock);
+        }
+        let service = setup_with_stocks(stocks).await;
+        service
+            .add_transaction(transaction("buy", 4, 200, 1000))
+            .await?;
+        service
+            .add_transaction(transaction("sell", 6, 100, 1100))
+            .await?;
+
+        let result = get_portfolio(&service).await?;
+        assert!(result.market_price == Decimal::from(1300));
+        assert!(result.previous_price == Some(Decimal::from(1000)));
+        assert!(result.realized_gain == Decimal::from(10000));
+        assert!(result.market_value() == Decimal::from(130000));
+        assert!(result.cost_basis() == Decimal::from(100000));
+        assert!(result.unrealized_gain() == Decimal::from(30000));
+        assert!(result.unrealized_gain_rate() == Some(30.0));
+        assert!(result.day_change() == Some(Decimal::from(30000)));
+        assert!(result.day_change_rate() == Some(30.0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_history_with_transactions() -> anyhow::Result<()> {
+        let mut stocks = Vec::new();
+        for (day, end_price) in [(4, 1000), (5, 1100), (6, 990), (10, 1200)] {
+            let mut stock = StockData::new();
+            stock.stock_id = STOCK_ID.to_string();
+            stock.date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+            stock.end_price = Decimal::from(end_price);
+            stocks.push(stock);
+        }
+        let service = setup_with_stocks(stocks).await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        service
+            .add_transaction(transaction("buy", 6, 100, 1100))
+            .await?;
+
+        let result = service.get_history(USER_ID, None, None, None).await?;
+
+        let market_values = result
+            .history
+            .iter()
+            .map(|v| v.market_value)
+            .collect::<Vec<Decimal>>();
+        assert!(market_values == [100000, 110000, 198000, 240000].map(Decimal::from));
+        assert!(result.history[2].cost_basis == Decimal::from(210000));
+        // 1/6の買付は評価額の変動に含めない
+        let returns = [0.0, 10.0, -1.0, 20.0];
+        for (value, expected) in result.history.iter().zip(returns) {
+            assert!((value.cumulative_return - expected).abs() < 1e-9);
+        }
+        let Some(time_weighted_return) = result.time_weighted_return else {
+            return Err(anyhow!("time weighted return not calculated"));
+        };
+        assert!((time_weighted_return - 20.0).abs() < 1e-9);
+        let Some(max_drawdown) = result.max_drawdown else {
+            return Err(anyhow!("max drawdown not calculated"));
+        };
+        assert!((max_drawdown - 10.0).abs() < 1e-9);
+
+        // 期間初日を基準とする
+        let result = service
+            .get_history(
+                USER_ID,
+                None,
+                NaiveDate::from_ymd_opt(2023, 1, 5),
+                NaiveDate::from_ymd_opt(2023, 1, 6),
+            )
+            .await?;
+        assert!(result.history.len() == 2);
+        assert!(result.history[0].cumulative_return == 0.0);
+        let Some(time_weighted_return) = result.time_weighted_return else {
+            return Err(anyhow!("time weighted return not calculated"));
+        };
+        assert!((time_weighted_return + 10.0).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    fn stock(stock_id: &str, day: u32, end_price: i64) -> StockData {
+        let mut stock = StockData::new();
+        stock.stock_id = stock_id.to_string();
+        stock.date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+        stock.end_price = Decimal::from(end_price);
+        stock
+    }
+
+    #[tokio::test]
+    async fn get_history_skip_stock_without_price() -> anyhow::Result<()> {
+        let other_stock_id = "1332";
+        let service = setup_with_stocks(vec![
+            stock(STOCK_ID, 4, 1000),
+            stock(STOCK_ID, 5, 1000),
+            stock(STOCK_ID, 6, 1100),
+            stock(other_stock_id, 5, 500),
+            stock(other_stock_id, 6, 500),
+        ])
+        .await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        let mut other = transaction("buy", 4, 100, 500);
+        other.stock_id = other_stock_id.to_string();
+        service.add_transaction(other).await?;
+
+        let result = service.get_history(USER_ID, None, None, None).await?;
+
+        let market_values = result
+            .history
+            .iter()
+            .map(|v| v.market_value)
+            .collect::<Vec<Decimal>>();
+        assert!(market_values == [100000, 150000, 160000].map(Decimal::from));
+        assert!(result.history[0].cost_basis == Decimal::from(100000));
+        // 株価が付いた日の評価額で組み入れるため収益率は変動しない
+        let returns = [0.0, 0.0, 100.0 / 15.0];
+        for (value, expected) in result.history.iter().zip(returns) {
+            assert!((value.cumulative_return - expected).abs() < 1e-9);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_history_use_close_before_year_end_holidays() -> anyhow::Result<()> {
+        let other_stock_id = "1332";
+        let mut year_end = stock(other_stock_id, 1, 500);
+        year_end.date = NaiveDate::from_ymd_opt(2022, 12, 20).unwrap();
+        let service = setup_with_stocks(vec![
+            stock(STOCK_ID, 4, 1000),
+            stock(STOCK_ID, 5, 1000),
+            year_end,
+            stock(other_stock_id, 5, 500),
+        ])
+        .await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        let mut other = transaction("buy", 4, 100, 500);
+        other.stock_id = other_stock_id.to_string();
+        service.add_transaction(other).await?;
+
+        // 年末年始の休場を挟んでも期間初日の時価に10営業日前までの終値を使う
+        let result = service
+            .get_history(
+                USER_ID,
+                None,
+                NaiveDate::from_ymd_opt(2023, 1, 4),
+                NaiveDate::from_ymd_opt(2023, 1, 5),
+            )
+            .await?;
+
+        let market_values = result
+            .history
+            .iter()
+            .map(|v| v.market_value)
+            .collect::<Vec<Decimal>>();
+        assert!(market_values == [150000, 150000].map(Decimal::from));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_history_adjust_stock_count_for_split() -> anyhow::Result<()> {
+        let stocks = vec![
+            stock(STOCK_ID, 4, 1000),
+            stock(STOCK_ID, 5, 500),
+            stock(STOCK_ID, 6, 550),
+        ];
+        // 1/5を権利落ち日とする1:2の株式分割
+        let stock_id = StockId::new(STOCK_ID.to_string())?;
+        let split = || {
+            CorporateAction::new(
+                stock_id.clone(),
+                NaiveDate::from_ymd_opt(2023, 1, 5).unwrap(),
+                1,
+                2,
+            )
+        };
+        let service =
+            setup_with_corporate_actions(stocks.clone(), vec![], vec![split()?]).await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        service
+            .add_transaction(transaction("sell", 6, 100, 550))
+            .await?;
+
+        let result = service.get_history(USER_ID, None, None, None).await?;
+
+        let market_values = result
+            .history
+            .iter()
+            .map(|v| v.market_value)
+            .collect::<Vec<Decimal>>();
+        assert!(market_values == [100000, 100000, 55000].map(Decimal::from));
+        assert!(result.history[1].cost_basis == Decimal::from(100000));
+        assert!(result.history[2].cost_basis == Decimal::from(50000));
+        let returns = [0.0, 0.0, 10.0];
+        for (value, expected) in result.history.iter().zip(returns) {
+            assert!((value.cumulative_return - expected).abs() < 1e-9);
+        }
+
+        // 売買履歴がない場合は現在の株数を分割前の株数に戻す
+        let service = setup_with_corporate_actions(stocks, vec![], vec![split()?]).await;
+        service
+            .add(PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string()))
+            .await?;
+        service
+            .update(PortfolioUpdateCommand::new(
+                USER_ID.to_string(),
+                STOCK_ID.to_string(),
+                Some(Decimal::from(500)),
+                Some(200),
+            ))
+            .await?;
+
+        let result = service.get_history(USER_ID, None, None, None).await?;
+
+        let market_values = result
+            .history
+            .iter()
+            .map(|v| v.market_value)
+            .collect::<Vec<Decimal>>();
+        assert!(market_values == [100000, 100000, 110000].map(Decimal::from));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_after_split_success() -> anyhow::Result<()> {
+        let stocks = vec![stock(STOCK_ID, 4, 1000), stock(STOCK_ID, 6, 550)];
+        // 1/5を権利落ち日とする1:2の株式分割
+        let split = CorporateAction::new(
+            StockId::new(STOCK_ID.to_string())?,
+            NaiveDate::from_ymd_opt(2023, 1, 5).unwrap(),
+            1,
+            2,
+        )?;
+        let service = setup_with_corporate_actions(stocks, vec![], vec![split]).await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+
+        // 分割後の200株のうち150株を売却
+        service
+            .add_transaction(transaction("sell", 6, 150, 550))
+            .await?;
+
+        let result = get_portfolio(&service).await?;
+        assert!(result.stock_count == 50);
+        assert!(result.purchase == Decimal::from(500));
+        assert!(result.realized_gain == Decimal::from(7500));
+
+        // 分割後の保有株数を超える売却
+        let Err(PortfolioApplicationError::InsufficientStock { .. }) = service.add_transaction(transaction("sell", 6, 51, 550)).await else {
+            return Err(anyhow!("unexpected add transaction result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_all_after_split_use_acquisition_cost() -> anyhow::Result<()> {
+        let stocks = vec![stock(STOCK_ID, 4, 1000), stock(STOCK_ID, 6, 550)];
+        // 1/5を権利落ち日とする1:2の株式分割
+        let split = CorporateAction::new(
+            StockId::new(STOCK_ID.to_string())?,
+            NaiveDate::from_ymd_opt(2023, 1, 5).unwrap(),
+            1,
+            2,
+        )?;
+        let service = setup_with_corporate_actions(stocks, vec![], vec![split]).await;
+        service
+            .add_transaction(TransactionData {
+                fee: Decimal::from(99),
+                ..transaction("buy", 4, 100, 1000)
+            })
+            .await?;
+
+        let result = get_portfolio(&service).await?;
+
+        assert!(result.stock_count == 200);
+        assert!(result.purchase == Decimal::new(50050, 2));
+        assert!(result.market_value() == Decimal::from(110000));
+        // 取得単価の端数ではなく手数料込みの取得価額から評価損益を求める
+        assert!(result.cost_basis() == Decimal::from(100099));
+        assert!(result.unrealized_gain() == Decimal::from(9901));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_history_with_invalid_range_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let Err(PortfolioApplicationError::InvalidRangeOfDate { .. }) = service.get_history(USER_ID, None, NaiveDate::from_ymd_opt(2023, 1, 6), NaiveDate::from_ymd_opt(2023, 1, 5)).await else {
+            return Err(anyhow!("unexpected get history result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_portfolio_with_transactions_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+        let command = PortfolioUpdateCommand::new(
+            USER_ID.to_string(),
+            STOCK_ID.to_string(),
+            Some(Decimal::ONE),
+            None,
+        );
+
+        let Err(PortfolioApplicationError::TransactionsExist(_)) = service.update(command).await else {
+            return Err(anyhow!("unexpected update portfolio result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_portfolio_create_default_account() -> anyhow::Result<()> {
+        let service = setup().await;
+        assert!(service.get_all(USER_ID, None).await?.is_empty());
+        assert!(service.get_accounts(USER_ID).await?.is_empty());
+
+        let portfolio = PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string());
+        service.add(portfolio).await?;
+
+        let accounts = service.get_accounts(USER_ID).await?;
+        assert!(accounts.len() == 1);
+        assert!(accounts[0].name == "default");
+        assert!(accounts[0].account_type == "general");
+        let result = service.get_all(USER_ID, accounts[0].id).await?;
+        assert!(result.len() == 1);
+        assert!(result[0].portfolio_id == accounts[0].id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn positions_are_separated_by_account() -> anyhow::Result<()> {
+        let service = setup().await;
+        let default = PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string());
+        service.add(default).await?;
+        let mut account = PortfolioAccountData::new(USER_ID.to_string(), "NISA".to_string());
+        account.account_type = "nisa".to_string();
+        let nisa = service.add_account(account).await?;
+
+        let mut buy = transaction("buy", 4, 100, 1000);
+        buy.portfolio_id = nisa.id;
+        service.add_transaction(buy).await?;
+
+        let result = service.get_all(USER_ID, nisa.id).await?;
+        assert!(result.len() == 1);
+        assert!(result[0].stock_count == 100);
+        // 既定のポートフォリオには反映されない
+        let result = service.get_all(USER_ID, None).await?;
+        assert!(result.len() == 1);
+        assert!(result[0].stock_count == 0);
+        assert!(service.get_transactions(USER_ID, None, STOCK_ID).await?.is_empty());
+        assert!(service.get_transactions(USER_ID, nisa.id, STOCK_ID).await?.len() == 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_all_notexist_account_return_err() -> anyhow::Result<()> {
+        let service = setup().await;
+
+        let Err(PortfolioApplicationError::PortfolioAccountNotFound(_)) = service.get_all(USER_ID, Some(1)).await else {
+            return Err(anyhow!("unexpected get all result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_and_remove_account() -> anyhow::Result<()> {
+        let service = setup().await;
+        let account = service
+            .add_account(PortfolioAccountData::new(
+                USER_ID.to_string(),
+                "特定口座".to_string(),
+            ))
+            .await?;
+        let Some(portfolio_id) = account.id else {
+            return Err(anyhow!("portfolio id not assigned"));
+        };
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+
+        let mut command = PortfolioAccountUpdateCommand::new(USER_ID.to_string(), portfolio_id);
+        command.account_type = Some("tokutei".to_string());
+        let result = service.update_account(command).await?;
+        assert!(result.name == "特定口座");
+        assert!(result.account_type == "tokutei");
+
+        let mut command = PortfolioAccountUpdateCommand::new(USER_ID.to_string(), portfolio_id);
+        command.account_type = Some("ideco".to_string());
+        let Err(PortfolioApplicationError::InvalidParameter { name: "account_type", .. }) = service.update_account(command).await else {
+            return Err(anyhow!("unexpected update account result"));
+        };
+
+        service.remove_account(USER_ID, portfolio_id).await?;
+        assert!(service.get_accounts(USER_ID).await?.is_empty());
+        assert!(service.get_all(USER_ID, None).await?.is_empty());
+
+        Ok(())
+    }
+
+    fn dividend(
+        ex_date: NaiveDate,
+        payment_date: NaiveDate,
+        amount_per_share: i64,
+    ) -> anyhow::Result<Dividend> {
+        let dividend = Dividend::new(
+            StockId::new(STOCK_ID.to_string())?,
+            ex_date,
+            ex_date.succ_opt().unwrap(),
+            payment_date,
+            Price::new(Decimal::from(amount_per_share))?,
+        )?;
+
+        Ok(dividend)
+    }
+
+    async fn setup_dividend_service() -> anyhow::Result<impl PortfolioService> {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut stock = StockData::new();
+        stock.stock_id = STOCK_ID.to_string();
+        let dividends = vec![
+            // 買付前の配当
+            dividend(date(2022, 3, 30), date(2022, 6, 28), 35)?,
+            dividend(date(2023, 1, 5), date(2023, 3, 1), 25)?,
+            dividend(date(2023, 1, 10), date(2023, 3, 20), 30)?,
+        ];
+        let service = setup_with_dividends(vec![stock], dividends).await;
+        service
+            .add_transaction(transaction("buy", 4, 200, 1000))
+            .await?;
+        service
+            .add_transaction(transaction("sell", 6, 100, 1100))
+            .await?;
+
+        Ok(service)
+    }
+
+    #[tokio::test]
+    async fn get_all_with_dividend() -> anyhow::Result<()> {
+        let service = setup_dividend_service().await?;
+
+        let result = get_portfolio(&service).await?;
+        // 最新の権利落ち日(2023/1/10)から1年以内の1株当たり配当金
+        assert!(result.dividend.annual_dividend_per_share == Decimal::from(90));
+        assert!(result.expected_annual_dividend() == Decimal::from(9000));
+        assert!(result.yield_on_cost() == Some(9.0));
+        // 200株 × 25円 + 100株 × 30円
+        assert!(result.dividend.received.get(&2023) == Some(&Decimal::from(8000)));
+        assert!(!result.dividend.received.contains_key(&2022));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_dividend_calendar_success() -> anyhow::Result<()> {
+        let service = setup_dividend_service().await?;
+
+        let result = service
+            .get_dividend_calendar(USER_ID, None, None, None)
+            .await?;
+        assert!(result.len() == 2);
+        assert!(result[0].stock_count == 200);
+        assert!(result[0].amount() == Decimal::from(5000));
+        assert!(result[1].stock_count == 100);
+        assert!(result[1].amount() == Decimal::from(3000));
+
+        let start = NaiveDate::from_ymd_opt(2023, 3, 10);
+        let result = service
+            .get_dividend_calendar(USER_ID, None, start, None)
+            .await?;
+        assert!(result.len() == 1);
+        assert!(result[0].payment_date == NaiveDate::from_ymd_opt(2023, 3, 20).unwrap());
+
+        let end = NaiveDate::from_ymd_opt(2023, 3, 1);
+        let Err(PortfolioApplicationError::InvalidRangeOfDate { .. }) = service.get_dividend_calendar(USER_ID, None, start, end).await else {
+            return Err(anyhow!("unexpected get dividend calendar result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_dividend_calendar_after_split() -> anyhow::Result<()> {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut stock = StockData::new();
+        stock.stock_id = STOCK_ID.to_string();
+        let dividends = || -> anyhow::Result<Vec<Dividend>> {
+            Ok(vec![
+                dividend(date(2023, 1, 5), date(2023, 3, 1), 50)?,
+                dividend(date(2023, 1, 10), date(2023, 3, 20), 30)?,
+            ])
+        };
+        // 2つの配当の間の1/8を権利落ち日とする1:2の株式分割
+        let stock_id = StockId::new(STOCK_ID.to_string())?;
+        let split = || CorporateAction::new(stock_id.clone(), date(2023, 1, 8), 1, 2);
+        let service =
+            setup_with_corporate_actions(vec![stock.clone()], dividends()?, vec![split()?]).await;
+        service
+            .add_transaction(transaction("buy", 4, 100, 1000))
+            .await?;
+
+        let result = service
+            .get_dividend_calendar(USER_ID, None, None, None)
+            .await?;
+        assert!(result.len() == 2);
+        assert!(result[0].stock_count == 100);
+        assert!(result[0].amount() == Decimal::from(5000));
+        assert!(result[1].stock_count == 200);
+        assert!(result[1].amount() == Decimal::from(6000));
+
+        // 売買履歴がない場合は現在の株数を分割前の株数に戻す
+        let service = setup_with_corporate_actions(vec![stock], dividends()?, vec![split()?]).await;
+        service
+            .add(PortfolioData {
+                stock_count: 200,
+                purchase: Decimal::from(500),
+                ..PortfolioData::new(USER_ID.to_string(), STOCK_ID.to_string())
+            })
+            .await?;
+
+        let result = service
+            .get_dividend_calendar(USER_ID, None, None, None)
+            .await?;
+        assert!(result[0].stock_count == 100);
+        assert!(result[1].stock_count == 200);
 
         Ok(())
     }
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
+use crate::portfolio::{portfolio_data::rate, PortfolioData};
+
+/// ポートフォリオ全体の集計
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PortfolioSummaryData {
+    pub portfolio: Vec<PortfolioData>,
+    /// 評価額合計
+    pub market_value: Decimal,
+    /// 取得価額合計
+    pub cost_basis: Decimal,
+    /// 評価損益合計
+    pub unrealized_gain: Decimal,
+    /// 実現損益合計
+    pub realized_gain: Decimal,
+    /// 前日比合計(前日終値がない銘柄は含まない)
+    pub day_change: Decimal,
+    /// 予想年間配当金合計(円)
+    pub expected_annual_dividend: Decimal,
+    /// 支払年ごとの受取済み配当金合計(円)
+    pub received_dividend: BTreeMap<i32, Decimal>,
+}
+
+impl PortfolioSummaryData {
+    /// コンストラクタ
+    pub fn new(portfolio: Vec<PortfolioData>) -> Self {
+        let mut summary = Self::default();
+        for p in portfolio.iter() {
+            summary.market_value += p.market_value();
+            summary.cost_basis += p.cost_basis();
+            summary.unrealized_gain += p.unrealized_gain();
+            summary.realized_gain += p.realized_gain;
+            summary.day_change += p.day_change().unwrap_or_default();
+            summary.expected_annual_dividend += p.expected_annual_dividend();
+            for (year, amount) in p.dividend.received.iter() {
+                *summary.received_dividend.entry(*year).or_default() += amount;
+            }
+        }
+        summary.portfolio = portfolio;
+
+        summary
+    }
+
+    /// 評価損益率(%)
+    pub fn unrealized_gain_rate(&self) -> Option<f64> {
+        rate(self.unrealized_gain, self.cost_basis)
+    }
+
+    /// 前日比(%)
+    pub fn day_change_rate(&self) -> Option<f64> {
+        rate(self.day_change, self.market_value - self.day_change)
+    }
+
+    /// 取得価額合計に対する予想年間配当金の利回り(%)
+    pub fn yield_on_cost(&self) -> Option<f64> {
+        rate(self.expected_annual_dividend, self.cost_basis)
+    }
+
+    /// 評価額合計に占める割合(%)
+    pub fn weight(&self, portfolio: &PortfolioData) -> Option<f64> {
+        rate(portfolio.market_value(), self.market_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::portfolio::{PortfolioData, PortfolioSummaryData};
+
+    fn portfolio(stock_count: i64, purchase: i64, market_price: i64) -> PortfolioData {
+        PortfolioData {
+            stock_count,
+            purchase: Decimal::from(purchase),
+            acquisition_cost: Decimal::from(purchase * stock_count),
+            market_price: Decimal::from(market_price),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn summarize_portfolio() {
+        let mut first = portfolio(100, 1000, 1200);
+        first.previous_price = Some(Decimal::from(1100));
+        first.realized_gain = Decimal::from(5000);
+        let second = portfolio(200, 500, 400);
+
+        let summary = PortfolioSummaryData::new(vec![first.clone(), second.clone()]);
+
+        assert!(summary.market_value == Decimal::from(200000));
+        assert!(summary.cost_basis == Decimal::from(200000));
+        assert!(summary.unrealized_gain.is_zero());
+        assert!(summary.realized_gain == Decimal::from(5000));
+        assert!(summary.day_change == Decimal::from(10000));
+        assert!(summary.unrealized_gain_rate() == Some(0.0));
+        assert!(summary.weight(&first) == Some(60.0));
+        assert!(summary.weight(&second) == Some(40.0));
+        assert!(first.unrealized_gain_rate() == Some(20.0));
+        assert!(second.unrealized_gain_rate() == Some(-20.0));
+        assert!(second.day_change().is_none());
+    }
+
+    #[test]
+    fn summarize_dividend() {
+        let mut first = portfolio(100, 1000, 1200);
+        first.dividend.annual_dividend_per_share = Decimal::from(50);
+        first.dividend.received.insert(2022, Decimal::from(4000));
+        first.dividend.received.insert(2023, Decimal::from(2500));
+        let mut second = portfolio(200, 500, 400);
+        second.dividend.annual_dividend_per_share = Decimal::new(125, 1);
+        second.dividend.received.insert(2023, Decimal::from(1250));
+
+        let summary = PortfolioSummaryData::new(vec![first.clone(), second.clone()]);
+
+        assert!(first.expected_annual_dividend() == Decimal::from(5000));
+        assert!(first.yield_on_cost() == Some(5.0));
+        assert!(second.yield_on_cost() == Some(2.5));
+        assert!(summary.expected_annual_dividend == Decimal::from(7500));
+        assert!(summary.yield_on_cost() == Some(3.75));
+        assert!(summary.received_dividend.get(&2022) == Some(&Decimal::from(4000)));
+        assert!(summary.received_dividend.get(&2023) == Some(&Decimal::from(3750)));
+    }
+
+    #[test]
+    fn summarize_fractional_prices() {
+        // 取得単価・時価に1円未満の端数がある
+        let mut first = portfolio(300, 0, 0);
+        first.purchase = Decimal::new(100033, 2);
+        first.acquisition_cost = Decimal::new(300099, 0);
+        first.market_price = Decimal::new(10505, 1);
+        let summary = PortfolioSummaryData::new(vec![first]);
+
+        assert!(summary.market_value == Decimal::new(315150, 0));
+        assert!(summary.cost_basis == Decimal::new(300099, 0));
+        assert!(summary.unrealized_gain == Decimal::new(15051, 0));
+    }
+
+    #[test]
+    fn summarize_empty_portfolio() {
+        let summary = PortfolioSummaryData::new(Vec::new());
+
+        assert!(summary.market_value.is_zero());
+        assert!(summary.unrealized_gain_rate().is_none());
+        assert!(summary.day_change_rate().is_none());
+        assert!(summary.yield_on_cost().is_none());
+    }
+}
@@ -1,9 +1,15 @@
+use rust_decimal::Decimal;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct PortfolioUpdateCommand {
+    /// Noneの場合は既定のポートフォリオ
+    pub portfolio_id: Option<i32>,
     pub user_id: String,
     pub stock_id: String,
-    pub purchase: Option<i32>,
-    pub stock_count: Option<i32>,
+    pub purchase: Option<Decimal>,
+    pub stock_count: Option<i64>,
+    /// moving_average(移動平均法) / fifo(先入先出法)
+    pub cost_basis_method: Option<String>,
 }
 
 impl PortfolioUpdateCommand {
@@ -11,14 +17,16 @@ impl PortfolioUpdateCommand {
     pub fn new(
         user_id: String,
         stock_id: String,
-        purchase: Option<i32>,
-        stock_count: Option<i32>,
+        purchase: Option<Decimal>,
+        stock_count: Option<i64>,
     ) -> Self {
         Self {
+            portfolio_id: None,
             user_id,
             stock_id,
             purchase,
             stock_count,
+            cost_basis_method: None,
         }
     }
 }
@@ -0,0 +1,94 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use domain::{
+    money::{Money, Price, Quantity},
+    portfolio::{PortfolioDomainError, PortfolioId, Transaction, TransactionId},
+    stock::{StockDomainError, StockId},
+    user::UserId,
+};
+
+/// 売買履歴
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TransactionData {
+    /// 未登録の場合はNone
+    pub id: Option<i32>,
+    /// ポートフォリオ(口座)のID(Noneの場合は既定のポートフォリオ)
+    pub portfolio_id: Option<i32>,
+    pub user_id: String,
+    pub stock_id: String,
+    /// buy(買付) / sell(売却)
+    pub transaction_type: String,
+    /// 約定日
+    pub date: NaiveDate,
+    /// 株数
+    pub quantity: i64,
+    /// 1株当たり約定価格
+    pub price: Decimal,
+    /// 手数料
+    pub fee: Decimal,
+}
+
+impl TransactionData {
+    /// コンストラクタ
+    pub fn new(user_id: String, stock_id: String) -> Self {
+        Self {
+            user_id,
+            stock_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Transaction> for TransactionData {
+    fn from(value: Transaction) -> Self {
+        Self {
+            id: value.id().map(i32::from),
+            portfolio_id: Some(value.portfolio_id().into()),
+            user_id: value.user_id().to_string(),
+            stock_id: value.stock_id().to_string(),
+            transaction_type: value.transaction_type().as_str().to_string(),
+            date: *value.date(),
+            quantity: value.quantity().into(),
+            price: value.price().into(),
+            fee: value.fee().into(),
+        }
+    }
+}
+
+impl TryFrom<TransactionData> for Transaction {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: TransactionData) -> Result<Self, Self::Error> {
+        let quantity = Quantity::new(value.quantity).map_err(|_| {
+            PortfolioDomainError::InvalidTransaction {
+                name: "quantity",
+                value: value.quantity.to_string(),
+            }
+        })?;
+        let price =
+            Price::new(value.price).map_err(|_| PortfolioDomainError::InvalidTransaction {
+                name: "price",
+                value: value.price.to_string(),
+            })?;
+        let stock_id = StockId::new(value.stock_id).map_err(
+            |StockDomainError::InvalidStockId(value)| PortfolioDomainError::InvalidTransaction {
+                name: "stock_id",
+                value,
+            },
+        )?;
+
+        Transaction::new(
+            value.id.map(TransactionId::new),
+            // 未指定の場合はサービスで既定のポートフォリオを設定する
+            PortfolioId::new(value.portfolio_id.unwrap_or_default()),
+            UserId::new(value.user_id),
+            stock_id,
+            value.transaction_type.parse()?,
+            value.date,
+            quantity,
+            price,
+            Money::new(value.fee),
+        )
+    }
+}
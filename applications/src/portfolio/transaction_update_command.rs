@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// 売買履歴の更新内容(Noneの項目は変更しない)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TransactionUpdateCommand {
+    /// Noneの場合は既定のポートフォリオ
+    pub portfolio_id: Option<i32>,
+    pub user_id: String,
+    pub stock_id: String,
+    pub transaction_id: i32,
+    pub transaction_type: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub quantity: Option<i64>,
+    pub price: Option<Decimal>,
+    pub fee: Option<Decimal>,
+}
+
+impl TransactionUpdateCommand {
+    /// コンストラクタ
+    pub fn new(user_id: String, stock_id: String, transaction_id: i32) -> Self {
+        Self {
+            user_id,
+            stock_id,
+            transaction_id,
+            ..Default::default()
+        }
+    }
+}
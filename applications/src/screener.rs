@@ -0,0 +1,24 @@
+mod comparison_operator;
+mod inmemory_screener_query_service_impl;
+mod screener_condition;
+mod screener_data;
+mod screener_field;
+mod screener_filter;
+mod screener_page_data;
+mod screener_query_command;
+mod screener_query_error;
+mod screener_query_service;
+
+pub use comparison_operator::ComparisonOperator;
+pub use inmemory_screener_query_service_impl::InmemoryScreenerQueryServiceImpl;
+pub use screener_condition::ScreenerCondition;
+pub use screener_condition::ScreenerValue;
+pub use screener_data::ScreenerData;
+pub use screener_data::YEAR_HIGH_DAYS;
+pub use screener_field::ScreenerField;
+pub use screener_filter::ScreenerFilter;
+pub use screener_page_data::ScreenerPageData;
+pub use screener_query_command::ScreenerQueryCommand;
+pub use screener_query_error::ScreenerQueryError;
+pub use screener_query_error::ScreenerQueryResult;
+pub use screener_query_service::ScreenerQueryService;
@@ -0,0 +1,60 @@
+/// スクリーニング条件の比較演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    /// =
+    Eq,
+    /// !=
+    Ne,
+    /// >
+    Gt,
+    /// >=
+    Ge,
+    /// <
+    Lt,
+    /// <=
+    Le,
+    /// ~ (文字列を含む)
+    Contains,
+}
+
+impl ComparisonOperator {
+    /// 演算子の記号から作成する
+    pub fn new(symbol: &str) -> Option<Self> {
+        match symbol {
+            "=" => Some(ComparisonOperator::Eq),
+            "!=" => Some(ComparisonOperator::Ne),
+            ">" => Some(ComparisonOperator::Gt),
+            ">=" => Some(ComparisonOperator::Ge),
+            "<" => Some(ComparisonOperator::Lt),
+            "<=" => Some(ComparisonOperator::Le),
+            "~" => Some(ComparisonOperator::Contains),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Eq => "=",
+            ComparisonOperator::Ne => "!=",
+            ComparisonOperator::Gt => ">",
+            ComparisonOperator::Ge => ">=",
+            ComparisonOperator::Lt => "<",
+            ComparisonOperator::Le => "<=",
+            ComparisonOperator::Contains => "~",
+        }
+    }
+
+    /// 比較結果が演算子の条件を満たすか判定する(Containsは対象外)
+    pub fn is_satisfied(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering;
+        match self {
+            ComparisonOperator::Eq => ordering == Ordering::Equal,
+            ComparisonOperator::Ne => ordering != Ordering::Equal,
+            ComparisonOperator::Gt => ordering == Ordering::Greater,
+            ComparisonOperator::Ge => ordering != Ordering::Less,
+            ComparisonOperator::Lt => ordering == Ordering::Less,
+            ComparisonOperator::Le => ordering != Ordering::Greater,
+            ComparisonOperator::Contains => false,
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    company::CompanyData,
+    corporate_action::CorporateActionData,
+    screener::{
+        ScreenerData, ScreenerField, ScreenerPageData, ScreenerQueryCommand, ScreenerQueryResult,
+        ScreenerQueryService,
+    },
+    stock::StockData,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InmemoryScreenerQueryServiceImpl {
+    pub companies: Vec<CompanyData>,
+    pub stocks: Vec<StockData>,
+    pub corporate_actions: Vec<CorporateActionData>,
+}
+
+impl InmemoryScreenerQueryServiceImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl ScreenerQueryService for InmemoryScreenerQueryServiceImpl {
+    #[tracing::instrument(skip(self), err)]
+    async fn screen(&self, command: ScreenerQueryCommand) -> ScreenerQueryResult<ScreenerPageData> {
+        command.validate()?;
+
+        // 株価・出来高は株式分割・併合を反映した現在の株数基準で比較する
+        let mut stocks: HashMap<&str, Vec<StockData>> = HashMap::new();
+        for stock in self.stocks.iter() {
+            let mut adjusted = stock.clone();
+            adjusted.adjust(&self.corporate_actions);
+            stocks
+                .entry(stock.stock_id.as_str())
+                .or_default()
+                .push(adjusted);
+        }
+
+        let mut screened: Vec<ScreenerData> = self
+            .companies
+            .iter()
+            .filter_map(|company| {
+                let mut stocks = stocks.get(company.stock_id.as_str())?.clone();
+                stocks.sort_by_key(|s| s.date);
+                ScreenerData::calculate(company, &stocks, command.period as usize)
+            })
+            .filter(|data| match &command.filter {
+                Some(filter) => filter.matches(data),
+                None => true,
+            })
+            .collect();
+        screened.sort_by(|a, b| compare(a, b, command.sort, command.descending));
+
+        let total = screened.len() as i64;
+        let items = screened
+            .into_iter()
+            .skip(command.offset() as usize)
+            .take(command.size as usize)
+            .collect();
+
+        Ok(ScreenerPageData {
+            total,
+            page: command.page,
+            size: command.size,
+            items,
+        })
+    }
+}
+
+/// 指定した項目で比較する(値が無い銘柄は末尾、同じ値の場合は証券コード順)
+fn compare(a: &ScreenerData, b: &ScreenerData, sort: ScreenerField, descending: bool) -> Ordering {
+    let ordering = if sort.is_text() {
+        let ordering = a.text(sort).cmp(&b.text(sort));
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    } else {
+        match (a.number(sort), b.number(sort)) {
+            (Some(a), Some(b)) => {
+                let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    };
+
+    ordering.then_with(|| a.stock_id.cmp(&b.stock_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        company::CompanyData,
+        corporate_action::CorporateActionData,
+        screener::{
+            InmemoryScreenerQueryServiceImpl, ScreenerField, ScreenerQueryCommand,
+            ScreenerQueryError, ScreenerQueryService,
+        },
+        stock::StockData,
+    };
+
+    fn setup() -> impl ScreenerQueryService {
+        let mut service = InmemoryScreenerQueryServiceImpl::new();
+        for (stock_id, name, sector, closes) in [
+            ("1111", "AGC", "化学", vec![1000, 1100, 1210]),
+            ("2222", "KDDI", "情報・通信業", vec![4000, 3800, 3900]),
+            (
+                "3333",
+                "T&Dホールディングス",
+                "保険業",
+                vec![2000, 2000, 2000],
+            ),
+            ("4444", "いすゞ自動車", "輸送用機器", vec![1500]),
+        ] {
+            service.companies.push(CompanyData {
+                name: name.to_string(),
+                stock_id: stock_id.to_string(),
+                sector: sector.to_string(),
+                industry: "".to_string(),
+            });
+            for (index, close) in closes.into_iter().enumerate() {
+                let mut stock = StockData::new();
+                stock.stock_id = stock_id.to_string();
+                stock.date = NaiveDate::from_ymd_opt(2023, 3, 1 + index as u32).unwrap();
+                stock.end_price = Decimal::from(close);
+                stock.high_price = Decimal::from(close);
+                stock.volume = 100 * (index as i64 + 1);
+                service.stocks.push(stock);
+            }
+        }
+        // 株価が無い銘柄
+        service.companies.push(CompanyData {
+            name: "りそなホールディングス".to_string(),
+            stock_id: "5555".to_string(),
+            sector: "銀行業".to_string(),
+            industry: "".to_string(),
+        });
+
+        service
+    }
+
+    fn stock_ids(items: &[crate::screener::ScreenerData]) -> Vec<&str> {
+        items.iter().map(|data| data.stock_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn screen_with_and_or_filter() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = ScreenerQueryCommand::new();
+        command.period = 2;
+        command.filter =
+            Some("name~ホールディングス or (last_close>=1000 and change_rate>5)".parse()?);
+
+        let result = service.screen(command).await?;
+
+        assert!(result.total == 2);
+        assert!(stock_ids(&result.items) == vec!["1111", "3333"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn screen_sort_with_missing_value_last() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = ScreenerQueryCommand::new();
+        command.period = 2;
+        command.sort = ScreenerField::ChangeRate;
+        command.descending = true;
+
+        let result = service.screen(command).await?;
+
+        // 株価が1件のみの銘柄は騰落率が無いため末尾
+        assert!(stock_ids(&result.items) == vec!["1111", "3333", "2222", "4444"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn screen_pagination() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = ScreenerQueryCommand::new();
+        command.sort = ScreenerField::LastClose;
+        command.page = 2;
+        command.size = 3;
+
+        let result = service.screen(command).await?;
+
+        assert!(result.total == 4);
+        assert!(stock_ids(&result.items) == vec!["2222"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn screen_adjusted_by_split() -> anyhow::Result<()> {
+        let mut service = InmemoryScreenerQueryServiceImpl::new();
+        service.companies.push(CompanyData {
+            name: "AGC".to_string(),
+            stock_id: "1111".to_string(),
+            sector: "化学".to_string(),
+            industry: "".to_string(),
+        });
+        for (day, close) in [(1, 1000), (2, 1100), (3, 540)] {
+            let mut stock = StockData::new();
+            stock.stock_id = "1111".to_string();
+            stock.date = NaiveDate::from_ymd_opt(2023, 3, day).unwrap();
+            stock.end_price = Decimal::from(close);
+            stock.high_price = Decimal::from(close);
+            stock.volume = 100;
+            service.stocks.push(stock);
+        }
+        // 3/3を権利落ち日とする1:2の株式分割
+        service.corporate_actions = vec![CorporateActionData::new(
+            "1111".to_string(),
+            NaiveDate::from_ymd_opt(2023, 3, 3).unwrap(),
+            1,
+            2,
+        )];
+        let mut command = ScreenerQueryCommand::new();
+        command.period = 2;
+
+        let result = service.screen(command).await?;
+
+        let data = &result.items[0];
+        assert!(data.change_rate == Some(8.0));
+        assert!(data.average_volume == Some(150.0));
+        assert!(data.year_high == Some(Decimal::from(550)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn screen_invalid_command_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = ScreenerQueryCommand::new();
+        command.size = 0;
+
+        let Err(ScreenerQueryError::InvalidParameter { name: "size", .. }) =
+            service.screen(command).await
+        else {
+            return Err(anyhow::anyhow!("unexpected screen result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::screener::{ComparisonOperator, ScreenerData, ScreenerField};
+
+/// 条件の比較対象の値
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScreenerValue {
+    Text(String),
+    Number(f64),
+}
+
+/// 項目と値を比較する条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenerCondition {
+    pub field: ScreenerField,
+    pub operator: ComparisonOperator,
+    pub value: ScreenerValue,
+}
+
+impl ScreenerCondition {
+    /// 条件を満たすか判定する
+    ///
+    /// 値が算出できない項目(株価の件数不足など)は条件を満たさない
+    pub fn matches(&self, data: &ScreenerData) -> bool {
+        match &self.value {
+            ScreenerValue::Text(value) => {
+                let Some(text) = data.text(self.field) else {
+                    return false;
+                };
+                match self.operator {
+                    ComparisonOperator::Contains => text.contains(value.as_str()),
+                    operator => operator.is_satisfied(text.cmp(value.as_str())),
+                }
+            }
+            ScreenerValue::Number(value) => {
+                let Some(number) = data.number(self.field) else {
+                    return false;
+                };
+                number
+                    .partial_cmp(value)
+                    .map(|ordering| self.operator.is_satisfied(ordering))
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use chrono::{Duration, NaiveDate};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::screener::ScreenerField;
+use crate::{company::CompanyData, stock::StockData};
+
+/// 52週高値の算出期間(日)
+pub const YEAR_HIGH_DAYS: i64 = 364;
+
+/// スクリーニング結果の銘柄と指標
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScreenerData {
+    pub stock_id: String,
+    pub name: String,
+    pub sector: String,
+    pub industry: String,
+    /// 最新の株価の日付
+    pub date: NaiveDate,
+    /// 最新の終値
+    pub last_close: Decimal,
+    /// 期間の騰落率(%)、期間より株価が少ない場合はNone
+    pub change_rate: Option<f64>,
+    /// 期間の平均出来高
+    pub average_volume: Option<f64>,
+    /// 52週高値
+    pub year_high: Option<Decimal>,
+    /// 52週高値からの乖離率(%)
+    pub year_high_distance: Option<f64>,
+    /// 期間の日次騰落率の標準偏差(%)、騰落率が2件未満の場合はNone
+    pub volatility: Option<f64>,
+}
+
+impl ScreenerData {
+    /// 日付の昇順に並んだ株価から指標を算出する(株価が無い場合はNone)
+    ///
+    /// periodは営業日数
+    pub fn calculate(company: &CompanyData, stocks: &[StockData], period: usize) -> Option<Self> {
+        let latest = stocks.last()?;
+        let count = stocks.len();
+
+        // 期間の騰落率
+        let change_rate = count
+            .checked_sub(period + 1)
+            .and_then(|index| return_of(stocks[index].end_price, latest.end_price))
+            .map(|r| r * 100.0);
+
+        // 期間の平均出来高
+        let recent = &stocks[count.saturating_sub(period)..];
+        let average_volume =
+            recent.iter().map(|s| s.volume as f64).sum::<f64>() / recent.len() as f64;
+
+        // 52週高値
+        let year_start = latest.date - Duration::days(YEAR_HIGH_DAYS);
+        let year_high = stocks
+            .iter()
+            .filter(|s| s.date >= year_start)
+            .map(|s| s.high_price)
+            .max();
+        let year_high_distance = year_high
+            .and_then(|high| return_of(high, latest.end_price))
+            .map(|r| r * 100.0);
+
+        // 期間の日次騰落率の標準偏差(標本標準偏差)
+        let returns: Vec<f64> = (count.saturating_sub(period).max(1)..count)
+            .filter_map(|index| return_of(stocks[index - 1].end_price, stocks[index].end_price))
+            .collect();
+        let volatility = if returns.len() >= 2 {
+            let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+            let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+                / (returns.len() - 1) as f64;
+            Some(variance.sqrt() * 100.0)
+        } else {
+            None
+        };
+
+        Some(Self {
+            stock_id: company.stock_id.clone(),
+            name: company.name.clone(),
+            sector: company.sector.clone(),
+            industry: company.industry.clone(),
+            date: latest.date,
+            last_close: latest.end_price,
+            change_rate,
+            average_volume: Some(average_volume),
+            year_high,
+            year_high_distance,
+            volatility,
+        })
+    }
+
+    /// 文字列の項目の値
+    pub fn text(&self, field: ScreenerField) -> Option<&str> {
+        match field {
+            ScreenerField::StockId => Some(&self.stock_id),
+            ScreenerField::Name => Some(&self.name),
+            ScreenerField::Sector => Some(&self.sector),
+            ScreenerField::Industry => Some(&self.industry),
+            _ => None,
+        }
+    }
+
+    /// 数値の項目の値
+    pub fn number(&self, field: ScreenerField) -> Option<f64> {
+        match field {
+            ScreenerField::LastClose => self.last_close.to_f64(),
+            ScreenerField::ChangeRate => self.change_rate,
+            ScreenerField::AverageVolume => self.average_volume,
+            ScreenerField::YearHigh => self.year_high.and_then(|high| high.to_f64()),
+            ScreenerField::YearHighDistance => self.year_high_distance,
+            ScreenerField::Volatility => self.volatility,
+            _ => None,
+        }
+    }
+}
+
+/// baseからの騰落率(baseが0の場合はNone)
+fn return_of(base: Decimal, value: Decimal) -> Option<f64> {
+    if base.is_zero() {
+        return None;
+    }
+    (value / base - Decimal::ONE).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{company::CompanyData, screener::ScreenerData, stock::StockData};
+
+    fn stocks(prices: &[(i32, i32, i64)]) -> Vec<StockData> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(index, (end_price, high_price, volume))| {
+                let mut stock = StockData::new();
+                stock.stock_id = "1234".to_string();
+                stock.date = NaiveDate::from_ymd_opt(2023, 3, 1 + index as u32).unwrap();
+                stock.end_price = Decimal::from(*end_price);
+                stock.high_price = Decimal::from(*high_price);
+                stock.volume = *volume;
+                stock
+            })
+            .collect()
+    }
+
+    fn company() -> CompanyData {
+        CompanyData {
+            name: "sample".to_string(),
+            stock_id: "1234".to_string(),
+            sector: "sector".to_string(),
+            industry: "industry".to_string(),
+        }
+    }
+
+    #[test]
+    fn calculate_metrics() -> anyhow::Result<()> {
+        let stocks = stocks(&[(100, 130, 10), (110, 110, 20), (99, 105, 30), (99, 100, 40)]);
+
+        let Some(data) = ScreenerData::calculate(&company(), &stocks, 2) else {
+            return Err(anyhow!("metrics not calculated"));
+        };
+
+        assert!(data.last_close == Decimal::from(99));
+        // 2営業日前の終値(110)からの騰落率
+        assert!((data.change_rate.unwrap() - (-10.0)).abs() < 1e-9);
+        assert!(data.average_volume == Some(35.0));
+        assert!(data.year_high == Some(Decimal::from(130)));
+        assert!((data.year_high_distance.unwrap() - (99.0 / 130.0 - 1.0) * 100.0).abs() < 1e-9);
+        // 日次騰落率 -10%, 0% の標本標準偏差
+        assert!((data.volatility.unwrap() - 50.0_f64.sqrt()).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculate_with_short_history() -> anyhow::Result<()> {
+        let stocks = stocks(&[(100, 100, 10), (110, 110, 20)]);
+
+        let Some(data) = ScreenerData::calculate(&company(), &stocks, 5) else {
+            return Err(anyhow!("metrics not calculated"));
+        };
+
+        assert!(data.change_rate.is_none());
+        assert!(data.volatility.is_none());
+        assert!(data.average_volume == Some(15.0));
+        assert!(ScreenerData::calculate(&company(), &[], 5).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+/// スクリーニングの条件・並び替えに使用できる項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScreenerField {
+    #[default]
+    StockId,
+    Name,
+    Sector,
+    Industry,
+    /// 最新の終値
+    LastClose,
+    /// 期間の騰落率(%)
+    ChangeRate,
+    /// 期間の平均出来高
+    AverageVolume,
+    /// 52週高値
+    YearHigh,
+    /// 52週高値からの乖離率(%)
+    YearHighDistance,
+    /// 期間の日次騰落率の標準偏差(%)
+    Volatility,
+}
+
+impl ScreenerField {
+    /// 項目名から作成する
+    pub fn new(name: &str) -> Option<Self> {
+        let field = match name {
+            "stock_id" => ScreenerField::StockId,
+            "name" => ScreenerField::Name,
+            "sector" => ScreenerField::Sector,
+            "industry" => ScreenerField::Industry,
+            "last_close" => ScreenerField::LastClose,
+            "change_rate" => ScreenerField::ChangeRate,
+            "average_volume" => ScreenerField::AverageVolume,
+            "year_high" => ScreenerField::YearHigh,
+            "year_high_distance" => ScreenerField::YearHighDistance,
+            "volatility" => ScreenerField::Volatility,
+            _ => return None,
+        };
+
+        Some(field)
+    }
+
+    /// 項目名(Postgresの列名と同じ)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreenerField::StockId => "stock_id",
+            ScreenerField::Name => "name",
+            ScreenerField::Sector => "sector",
+            ScreenerField::Industry => "industry",
+            ScreenerField::LastClose => "last_close",
+            ScreenerField::ChangeRate => "change_rate",
+            ScreenerField::AverageVolume => "average_volume",
+            ScreenerField::YearHigh => "year_high",
+            ScreenerField::YearHighDistance => "year_high_distance",
+            ScreenerField::Volatility => "volatility",
+        }
+    }
+
+    /// 文字列の項目の場合はtrue
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self,
+            ScreenerField::StockId
+                | ScreenerField::Name
+                | ScreenerField::Sector
+                | ScreenerField::Industry
+        )
+    }
+}
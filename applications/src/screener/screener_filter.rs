@@ -0,0 +1,385 @@
+use std::str::FromStr;
+
+use crate::screener::{
+    ComparisonOperator, ScreenerCondition, ScreenerData, ScreenerField, ScreenerQueryError,
+    ScreenerValue,
+};
+
+/// 1つの式に指定できる条件の最大数
+const MAX_CONDITION_COUNT: usize = 50;
+/// 括弧の入れ子の最大数(再帰が深くなりすぎないよう制限する)
+const MAX_NESTING_DEPTH: usize = 10;
+
+/// AND / ORで組み合わせたスクリーニング条件
+///
+/// 文字列表現の例: `sector=電気機器 and (last_close>=1000 or change_rate>5)`
+/// * 演算子: = / != / > / >= / < / <= / ~(文字列を含む)
+/// * 文字列の項目は = / != / ~ のみ、数値の項目は ~ 以外を使用できる
+/// * 空白や記号を含む値は"で囲む
+/// * andはorより優先して結合する
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScreenerFilter {
+    And(Vec<ScreenerFilter>),
+    Or(Vec<ScreenerFilter>),
+    Condition(ScreenerCondition),
+}
+
+impl ScreenerFilter {
+    /// 条件を満たすか判定する
+    pub fn matches(&self, data: &ScreenerData) -> bool {
+        match self {
+            ScreenerFilter::And(filters) => filters.iter().all(|f| f.matches(data)),
+            ScreenerFilter::Or(filters) => filters.iter().any(|f| f.matches(data)),
+            ScreenerFilter::Condition(condition) => condition.matches(data),
+        }
+    }
+}
+
+impl FromStr for ScreenerFilter {
+    type Err = ScreenerQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let condition_count = tokens
+            .iter()
+            .filter(|t| matches!(t, Token::Operator(_)))
+            .count();
+        if condition_count > MAX_CONDITION_COUNT {
+            return Err(invalid_filter(format!(
+                "too many conditions: {}",
+                condition_count
+            )));
+        }
+
+        let mut parser = Parser {
+            tokens,
+            index: 0,
+            depth: 0,
+        };
+        let filter = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            return Err(invalid_filter(format!("unexpected token: {:?}", token)));
+        }
+
+        Ok(filter)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    Operator(ComparisonOperator),
+    /// 項目名・値・and・or
+    Word(String),
+    /// "で囲まれた値
+    Quoted(String),
+}
+
+/// 式を字句に分割する
+fn tokenize(s: &str) -> Result<Vec<Token>, ScreenerQueryError> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LeftParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RightParen);
+            }
+            '=' | '!' | '>' | '<' | '~' => {
+                chars.next();
+                let mut symbol = c.to_string();
+                if c != '~' && c != '=' && chars.peek() == Some(&'=') {
+                    chars.next();
+                    symbol.push('=');
+                }
+                let Some(operator) = ComparisonOperator::new(&symbol) else {
+                    return Err(invalid_filter(format!("unknown operator: {}", symbol)));
+                };
+                tokens.push(Token::Operator(operator));
+            }
+            '"' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => value.push(c),
+                        None => return Err(invalid_filter("unterminated quote".to_string())),
+                    }
+                }
+                tokens.push(Token::Quoted(value));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "()=!><~\"".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// 再帰下降構文解析
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    /// 現在の括弧の入れ子の深さ
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    fn next_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword)) {
+            self.index += 1;
+            return true;
+        }
+        false
+    }
+
+    /// or_expr := and_expr ("or" and_expr)*
+    fn parse_or(&mut self) -> Result<ScreenerFilter, ScreenerQueryError> {
+        let mut filters = vec![self.parse_and()?];
+        while self.next_keyword("or") {
+            filters.push(self.parse_and()?);
+        }
+
+        Ok(flatten(filters, ScreenerFilter::Or))
+    }
+
+    /// and_expr := factor ("and" factor)*
+    fn parse_and(&mut self) -> Result<ScreenerFilter, ScreenerQueryError> {
+        let mut filters = vec![self.parse_factor()?];
+        while self.next_keyword("and") {
+            filters.push(self.parse_factor()?);
+        }
+
+        Ok(flatten(filters, ScreenerFilter::And))
+    }
+
+    /// factor := "(" or_expr ")" | field operator value
+    fn parse_factor(&mut self) -> Result<ScreenerFilter, ScreenerQueryError> {
+        match self.next() {
+            Some(Token::LeftParen) => {
+                if self.depth >= MAX_NESTING_DEPTH {
+                    return Err(invalid_filter("too deeply nested parentheses".to_string()));
+                }
+                self.depth += 1;
+                let filter = self.parse_or()?;
+                self.depth -= 1;
+                let Some(Token::RightParen) = self.next() else {
+                    return Err(invalid_filter("missing closing parenthesis".to_string()));
+                };
+                Ok(filter)
+            }
+            Some(Token::Word(name)) => {
+                let Some(field) = ScreenerField::new(&name) else {
+                    return Err(invalid_filter(format!("unknown field: {}", name)));
+                };
+                let Some(Token::Operator(operator)) = self.next() else {
+                    return Err(invalid_filter(format!("missing operator after {}", name)));
+                };
+                let value = match self.next() {
+                    Some(Token::Word(value)) | Some(Token::Quoted(value)) => value,
+                    _ => return Err(invalid_filter(format!("missing value for {}", name))),
+                };
+                let condition = new_condition(field, operator, value)?;
+
+                Ok(ScreenerFilter::Condition(condition))
+            }
+            token => Err(invalid_filter(format!("unexpected token: {:?}", token))),
+        }
+    }
+}
+
+/// 項目の型に合わせて条件を作成する
+fn new_condition(
+    field: ScreenerField,
+    operator: ComparisonOperator,
+    value: String,
+) -> Result<ScreenerCondition, ScreenerQueryError> {
+    let value = if field.is_text() {
+        if !matches!(
+            operator,
+            ComparisonOperator::Eq | ComparisonOperator::Ne | ComparisonOperator::Contains
+        ) {
+            return Err(invalid_filter(format!(
+                "operator {} is not supported for {}",
+                operator.as_str(),
+                field.as_str()
+            )));
+        }
+        ScreenerValue::Text(value)
+    } else {
+        let Ok(number) = value.parse::<f64>() else {
+            return Err(invalid_filter(format!("{} is not a number", value)));
+        };
+        if operator == ComparisonOperator::Contains || !number.is_finite() {
+            return Err(invalid_filter(format!(
+                "invalid condition for {}",
+                field.as_str()
+            )));
+        }
+        ScreenerValue::Number(number)
+    };
+
+    Ok(ScreenerCondition {
+        field,
+        operator,
+        value,
+    })
+}
+
+/// 要素が1つの場合はそのまま返す
+fn flatten(
+    mut filters: Vec<ScreenerFilter>,
+    combine: fn(Vec<ScreenerFilter>) -> ScreenerFilter,
+) -> ScreenerFilter {
+    if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        combine(filters)
+    }
+}
+
+fn invalid_filter(message: String) -> ScreenerQueryError {
+    ScreenerQueryError::InvalidParameter {
+        name: "filter",
+        value: message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::screener::{
+        ComparisonOperator, ScreenerCondition, ScreenerField, ScreenerFilter, ScreenerQueryError,
+        ScreenerValue,
+    };
+
+    fn condition(
+        field: ScreenerField,
+        operator: ComparisonOperator,
+        value: ScreenerValue,
+    ) -> ScreenerFilter {
+        ScreenerFilter::Condition(ScreenerCondition {
+            field,
+            operator,
+            value,
+        })
+    }
+
+    #[test]
+    fn parse_and_or_with_precedence() -> anyhow::Result<()> {
+        let filter: ScreenerFilter =
+            r#"sector="電気 機器" and last_close>=1000 OR change_rate<-5.5"#.parse()?;
+
+        let expected = ScreenerFilter::Or(vec![
+            ScreenerFilter::And(vec![
+                condition(
+                    ScreenerField::Sector,
+                    ComparisonOperator::Eq,
+                    ScreenerValue::Text("電気 機器".to_string()),
+                ),
+                condition(
+                    ScreenerField::LastClose,
+                    ComparisonOperator::Ge,
+                    ScreenerValue::Number(1000.0),
+                ),
+            ]),
+            condition(
+                ScreenerField::ChangeRate,
+                ComparisonOperator::Lt,
+                ScreenerValue::Number(-5.5),
+            ),
+        ]);
+        assert!(filter == expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_parenthesis() -> anyhow::Result<()> {
+        let filter: ScreenerFilter =
+            "name~ホールディングス and (volatility<2 or average_volume>100000)".parse()?;
+
+        let ScreenerFilter::And(filters) = filter else {
+            return Err(anyhow!("unexpected filter: {:?}", filter));
+        };
+        assert!(filters.len() == 2);
+        assert!(matches!(&filters[1], ScreenerFilter::Or(or) if or.len() == 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_filter_return_err() -> anyhow::Result<()> {
+        for filter in [
+            "",
+            "unknown=1",
+            "last_close>abc",
+            "sector>電気機器",
+            "last_close~1",
+            "(last_close>1",
+            "last_close>1 and",
+            "last_close>1 last_close<2",
+            "sector=\"電気機器",
+        ] {
+            let Err(ScreenerQueryError::InvalidParameter { name: "filter", .. }) =
+                filter.parse::<ScreenerFilter>()
+            else {
+                return Err(anyhow!("unexpected parse result: {}", filter));
+            };
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_nested_parenthesis() -> anyhow::Result<()> {
+        let nested = format!("{}last_close>1{}", "(".repeat(10), ")".repeat(10));
+        let filter: ScreenerFilter = nested.parse()?;
+        assert!(matches!(filter, ScreenerFilter::Condition(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_too_deeply_nested_filter_return_err() -> anyhow::Result<()> {
+        for depth in [11, 5000] {
+            let filter = format!("{}last_close>1{}", "(".repeat(depth), ")".repeat(depth));
+            let Err(ScreenerQueryError::InvalidParameter { name: "filter", .. }) =
+                filter.parse::<ScreenerFilter>()
+            else {
+                return Err(anyhow!("unexpected parse result: depth={}", depth));
+            };
+        }
+
+        Ok(())
+    }
+}
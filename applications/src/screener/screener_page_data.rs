@@ -0,0 +1,12 @@
+use crate::screener::ScreenerData;
+
+/// スクリーニング結果の1ページ
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScreenerPageData {
+    /// 条件を満たす銘柄の総数
+    pub total: i64,
+    /// ページ番号(1始まり)
+    pub page: i32,
+    pub size: i32,
+    pub items: Vec<ScreenerData>,
+}
@@ -0,0 +1,69 @@
+use crate::screener::{ScreenerField, ScreenerFilter, ScreenerQueryError, ScreenerQueryResult};
+
+/// 指標の算出期間の既定値(営業日)
+const DEFAULT_PERIOD: i32 = 20;
+/// 指標の算出期間の上限(営業日)
+const MAX_PERIOD: i32 = 250;
+/// ページサイズの既定値
+const DEFAULT_PAGE_SIZE: i32 = 50;
+/// ページサイズの上限
+const MAX_PAGE_SIZE: i32 = 500;
+
+/// スクリーニング条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenerQueryCommand {
+    /// Noneの場合は株価が登録されている全銘柄
+    pub filter: Option<ScreenerFilter>,
+    /// 騰落率・平均出来高・標準偏差の算出期間(営業日)
+    pub period: i32,
+    /// 並び替えの項目(値が無い銘柄は末尾)
+    pub sort: ScreenerField,
+    /// trueの場合は降順
+    pub descending: bool,
+    /// ページ番号(1始まり)
+    pub page: i32,
+    pub size: i32,
+}
+
+impl ScreenerQueryCommand {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self {
+            filter: None,
+            period: DEFAULT_PERIOD,
+            sort: ScreenerField::default(),
+            descending: false,
+            page: 1,
+            size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// パラメータチェック
+    pub fn validate(&self) -> ScreenerQueryResult<()> {
+        for (name, value, max) in [
+            ("period", self.period, MAX_PERIOD),
+            ("page", self.page, i32::MAX),
+            ("size", self.size, MAX_PAGE_SIZE),
+        ] {
+            if !(1..=max).contains(&value) {
+                return Err(ScreenerQueryError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 読み飛ばす件数
+    pub fn offset(&self) -> i64 {
+        (self.page as i64 - 1) * self.size as i64
+    }
+}
+
+impl Default for ScreenerQueryCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,9 @@
+use crate::screener::{ScreenerPageData, ScreenerQueryCommand, ScreenerQueryResult};
+
+#[async_trait::async_trait]
+pub trait ScreenerQueryService {
+    /// 条件を満たす銘柄を指定した項目で並び替えて取得する
+    ///
+    /// 株価が登録されていない銘柄は対象外
+    async fn screen(&self, command: ScreenerQueryCommand) -> ScreenerQueryResult<ScreenerPageData>;
+}
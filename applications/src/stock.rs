@@ -1,11 +1,35 @@
+mod indicator;
+mod indicator_data;
+mod indicator_query_command;
+mod indicator_service;
+mod indicator_service_impl;
 mod inmemory_stock_query_service_impl;
+mod quote_data;
+mod stock_cursor;
 mod stock_data;
+mod stock_import_error;
+mod stock_import_service;
+mod stock_interval;
+mod stock_page_data;
 mod stock_query_command;
 mod stock_query_error;
 mod stock_query_service;
 
+pub use indicator::Indicator;
+pub use indicator_data::IndicatorData;
+pub use indicator_data::IndicatorValue;
+pub use indicator_query_command::IndicatorQueryCommand;
+pub use indicator_service::IndicatorService;
+pub use indicator_service_impl::IndicatorServiceImpl;
 pub use inmemory_stock_query_service_impl::InmemoryStockQueryServiceImpl;
+pub use quote_data::QuoteData;
+pub use stock_cursor::StockCursor;
 pub use stock_data::StockData;
+pub use stock_import_error::StockImportError;
+pub use stock_import_error::StockImportResult;
+pub use stock_import_service::StockImportService;
+pub use stock_interval::StockInterval;
+pub use stock_page_data::StockPageData;
 pub use stock_query_command::StockQueryCommand;
 pub use stock_query_error::StockQueryError;
 pub use stock_query_error::StockQueryResult;
@@ -0,0 +1,443 @@
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::stock::{IndicatorQueryCommand, IndicatorValue, StockData, StockQueryError};
+
+/// テクニカル指標の種類と計算パラメータ
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Indicator {
+    /// 単純移動平均
+    Sma { window: usize },
+    /// 指数平滑移動平均
+    Ema { window: usize },
+    /// RSI(ワイルダーの平滑化)
+    Rsi { window: usize },
+    /// MACD
+    Macd {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    /// ボリンジャーバンド
+    BollingerBands { window: usize, sigma: f64 },
+    /// ATR(ワイルダーの平滑化)
+    Atr { window: usize },
+}
+
+impl TryFrom<&IndicatorQueryCommand> for Indicator {
+    type Error = StockQueryError;
+
+    /// 指定の無いパラメータは一般的な既定値を使う
+    fn try_from(value: &IndicatorQueryCommand) -> Result<Self, Self::Error> {
+        // パラメータチェック
+        let positive = |name: &'static str, param: Option<usize>, default: usize| match param {
+            Some(0) => Err(StockQueryError::InvalidParameter {
+                name,
+                value: "0".to_string(),
+            }),
+            Some(param) => Ok(param),
+            None => Ok(default),
+        };
+
+        let indicator = match value.kind.as_str() {
+            "sma" => Indicator::Sma {
+                window: positive("window", value.window, 25)?,
+            },
+            "ema" => Indicator::Ema {
+                window: positive("window", value.window, 25)?,
+            },
+            "rsi" => Indicator::Rsi {
+                window: positive("window", value.window, 14)?,
+            },
+            "macd" => {
+                let fast = positive("fast", value.fast, 12)?;
+                let slow = positive("slow", value.slow, 26)?;
+                if fast >= slow {
+                    return Err(StockQueryError::InvalidParameter {
+                        name: "fast",
+                        value: fast.to_string(),
+                    });
+                }
+                Indicator::Macd {
+                    fast,
+                    slow,
+                    signal: positive("signal", value.signal, 9)?,
+                }
+            }
+            "bollinger" => {
+                let sigma = value.sigma.unwrap_or(2.0);
+                if !sigma.is_finite() || sigma <= 0.0 {
+                    return Err(StockQueryError::InvalidParameter {
+                        name: "sigma",
+                        value: sigma.to_string(),
+                    });
+                }
+                Indicator::BollingerBands {
+                    window: positive("window", value.window, 20)?,
+                    sigma,
+                }
+            }
+            "atr" => Indicator::Atr {
+                window: positive("window", value.window, 14)?,
+            },
+            _ => {
+                return Err(StockQueryError::InvalidParameter {
+                    name: "kind",
+                    value: value.kind.clone(),
+                })
+            }
+        };
+
+        Ok(indicator)
+    }
+}
+
+impl Indicator {
+    /// 日付の昇順に並んだ株価から計算する
+    ///
+    /// 計算に必要な日数分のデータが揃っていない日はNone
+    pub fn calculate(&self, stocks: &[StockData]) -> Vec<Option<IndicatorValue>> {
+        let closes: Vec<f64> = stocks.iter().map(|s| to_f64(s.end_price)).collect();
+        let single = |values: Vec<Option<f64>>| {
+            values
+                .into_iter()
+                .map(|v| v.map(IndicatorValue::Single))
+                .collect()
+        };
+
+        match *self {
+            Indicator::Sma { window } => single(sma(&closes, window)),
+            Indicator::Ema { window } => single(ema(&closes, window)),
+            Indicator::Rsi { window } => single(rsi(&closes, window)),
+            Indicator::Macd { fast, slow, signal } => macd(&closes, fast, slow, signal),
+            Indicator::BollingerBands { window, sigma } => bollinger_bands(&closes, window, sigma),
+            Indicator::Atr { window } => single(atr(stocks, window)),
+        }
+    }
+}
+
+/// 先頭をNoneで埋めて元の系列と同じ長さにする
+fn pad<T>(len: usize, values: Vec<T>) -> Vec<Option<T>> {
+    let mut result: Vec<Option<T>> = Vec::with_capacity(len);
+    result.resize_with(len.saturating_sub(values.len()), || None);
+    result.extend(values.into_iter().map(Some));
+    result
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn sma(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    pad(values.len(), values.windows(window).map(mean).collect())
+}
+
+/// 最初の値は単純移動平均で初期化する
+fn ema(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    if values.len() < window {
+        return vec![None; values.len()];
+    }
+    let alpha = 2.0 / (window as f64 + 1.0);
+
+    let mut current = mean(&values[..window]);
+    let mut result = vec![current];
+    for value in values[window..].iter() {
+        current = alpha * value + (1.0 - alpha) * current;
+        result.push(current);
+    }
+    pad(values.len(), result)
+}
+
+/// ワイルダーの平滑化 (最初の値は単純平均で初期化する)
+fn wilder(values: &[f64], window: usize) -> Vec<f64> {
+    if values.len() < window {
+        return vec![];
+    }
+
+    let mut current = mean(&values[..window]);
+    let mut result = vec![current];
+    for value in values[window..].iter() {
+        current = (current * (window as f64 - 1.0) + value) / window as f64;
+        result.push(current);
+    }
+    result
+}
+
+fn rsi(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    let changes: Vec<f64> = values.windows(2).map(|v| v[1] - v[0]).collect();
+    let gains: Vec<f64> = changes.iter().map(|c| c.max(0.0)).collect();
+    let losses: Vec<f64> = changes.iter().map(|c| (-c).max(0.0)).collect();
+
+    let result = wilder(&gains, window)
+        .into_iter()
+        .zip(wilder(&losses, window))
+        .map(|(gain, loss)| {
+            if loss == 0.0 && gain == 0.0 {
+                50.0
+            } else if loss == 0.0 {
+                100.0
+            } else {
+                100.0 - 100.0 / (1.0 + gain / loss)
+            }
+        })
+        .collect();
+    pad(values.len(), result)
+}
+
+fn macd(values: &[f64], fast: usize, slow: usize, signal: usize) -> Vec<Option<IndicatorValue>> {
+    let line: Vec<f64> = ema(values, fast)
+        .into_iter()
+        .zip(ema(values, slow))
+        .filter_map(|(fast, slow)| Some(fast? - slow?))
+        .collect();
+    let signal_line: Vec<f64> = ema(&line, signal).into_iter().flatten().collect();
+
+    let result = line[line.len() - signal_line.len()..]
+        .iter()
+        .zip(signal_line)
+        .map(|(macd, signal)| IndicatorValue::Macd {
+            macd: *macd,
+            signal,
+            histogram: macd - signal,
+        })
+        .collect();
+    pad(values.len(), result)
+}
+
+/// 標準偏差は母標準偏差
+fn bollinger_bands(values: &[f64], window: usize, sigma: f64) -> Vec<Option<IndicatorValue>> {
+    let result = values
+        .windows(window)
+        .map(|values| {
+            let middle = mean(values);
+            let variance =
+                values.iter().map(|v| (v - middle).powi(2)).sum::<f64>() / values.len() as f64;
+            let width = variance.sqrt() * sigma;
+            IndicatorValue::BollingerBands {
+                middle,
+                upper: middle + width,
+                lower: middle - width,
+            }
+        })
+        .collect();
+    pad(values.len(), result)
+}
+
+/// 初日の真の値幅は高値と安値の差
+fn atr(stocks: &[StockData], window: usize) -> Vec<Option<f64>> {
+    let true_ranges: Vec<f64> = stocks
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let range = to_f64(s.high_price - s.low_price);
+            let Some(previous) = i.checked_sub(1).map(|i| stocks[i].end_price) else {
+                return range;
+            };
+            range
+                .max(to_f64((s.high_price - previous).abs()))
+                .max(to_f64((s.low_price - previous).abs()))
+        })
+        .collect();
+
+    pad(stocks.len(), wilder(&true_ranges, window))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use rust_decimal::Decimal;
+
+    use crate::stock::{
+        Indicator, IndicatorQueryCommand, IndicatorValue, StockData, StockQueryError,
+    };
+
+    fn stocks(closes: &[i32]) -> Vec<StockData> {
+        closes
+            .iter()
+            .map(|close| StockData {
+                end_price: Decimal::from(*close),
+                start_price: Decimal::from(*close),
+                high_price: Decimal::from(*close),
+                low_price: Decimal::from(*close),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn single(values: Vec<Option<IndicatorValue>>) -> Vec<Option<f64>> {
+        values
+            .into_iter()
+            .map(|v| match v {
+                Some(IndicatorValue::Single(v)) => Some(v),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn calculate_sma() {
+        let result = Indicator::Sma { window: 3 }.calculate(&stocks(&[1, 2, 3, 4, 5]));
+
+        assert!(single(result) == vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+    }
+
+    #[test]
+    fn calculate_ema() {
+        let result = Indicator::Ema { window: 3 }.calculate(&stocks(&[1, 2, 3, 4, 6]));
+
+        // 初期値は単純移動平均, 平滑化係数は2 / (3 + 1)
+        assert!(single(result) == vec![None, None, Some(2.0), Some(3.0), Some(4.5)]);
+    }
+
+    #[test]
+    fn calculate_rsi() {
+        let result = Indicator::Rsi { window: 2 }.calculate(&stocks(&[1, 2, 1, 2, 1]));
+        let result = single(result);
+
+        assert!(result[..2] == [None, None]);
+        assert!(approx(result[2].unwrap(), 50.0));
+        assert!(approx(result[3].unwrap(), 75.0));
+        assert!(approx(result[4].unwrap(), 37.5));
+    }
+
+    #[test]
+    fn calculate_macd() {
+        let result = Indicator::Macd {
+            fast: 1,
+            slow: 2,
+            signal: 2,
+        }
+        .calculate(&stocks(&[1, 2, 3, 4, 5]));
+
+        assert!(result[..2] == [None, None]);
+        for value in result[2..].iter() {
+            let Some(IndicatorValue::Macd {
+                macd,
+                signal,
+                histogram,
+            }) = value
+            else {
+                panic!("unexpected macd result");
+            };
+            assert!(approx(*macd, 0.5));
+            assert!(approx(*signal, 0.5));
+            assert!(approx(*histogram, 0.0));
+        }
+    }
+
+    #[test]
+    fn calculate_bollinger_bands() {
+        let result = Indicator::BollingerBands {
+            window: 3,
+            sigma: 2.0,
+        }
+        .calculate(&stocks(&[1, 2, 3]));
+
+        let Some(IndicatorValue::BollingerBands {
+            middle,
+            upper,
+            lower,
+        }) = result[2]
+        else {
+            panic!("unexpected bollinger bands result");
+        };
+        let width = 2.0 * (2.0_f64 / 3.0).sqrt();
+        assert!(approx(middle, 2.0));
+        assert!(approx(upper, 2.0 + width));
+        assert!(approx(lower, 2.0 - width));
+    }
+
+    #[test]
+    fn calculate_atr() {
+        let mut stocks = stocks(&[100, 100, 100]);
+        // 真の値幅: 10, 20(前日終値との差), 4
+        stocks[0].high_price = Decimal::from(105);
+        stocks[0].low_price = Decimal::from(95);
+        stocks[1].high_price = Decimal::from(120);
+        stocks[1].low_price = Decimal::from(110);
+        stocks[2].high_price = Decimal::from(102);
+        stocks[2].low_price = Decimal::from(98);
+
+        let result = single(Indicator::Atr { window: 2 }.calculate(&stocks));
+
+        assert!(result == vec![None, Some(15.0), Some(9.5)]);
+    }
+
+    #[test]
+    fn calculate_with_short_series() {
+        let stocks = stocks(&[1, 2]);
+
+        for indicator in [
+            Indicator::Sma { window: 3 },
+            Indicator::Ema { window: 3 },
+            Indicator::Rsi { window: 3 },
+            Indicator::Macd {
+                fast: 2,
+                slow: 3,
+                signal: 2,
+            },
+            Indicator::BollingerBands {
+                window: 3,
+                sigma: 2.0,
+            },
+            Indicator::Atr { window: 3 },
+        ] {
+            assert!(indicator.calculate(&stocks) == vec![None, None]);
+        }
+    }
+
+    #[test]
+    fn default_parameter() -> anyhow::Result<()> {
+        let mut command = IndicatorQueryCommand::new();
+        command.kind = "macd".to_string();
+
+        let indicator = Indicator::try_from(&command)?;
+        assert!(
+            indicator
+                == Indicator::Macd {
+                    fast: 12,
+                    slow: 26,
+                    signal: 9
+                }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_parameter_return_err() -> anyhow::Result<()> {
+        let mut command = IndicatorQueryCommand::new();
+        command.kind = "unknown".to_string();
+        let Err(StockQueryError::InvalidParameter { name: "kind", .. }) =
+            Indicator::try_from(&command)
+        else {
+            return Err(anyhow!("unexpected indicator result"));
+        };
+
+        command.kind = "sma".to_string();
+        command.window = Some(0);
+        let Err(StockQueryError::InvalidParameter { name: "window", .. }) =
+            Indicator::try_from(&command)
+        else {
+            return Err(anyhow!("unexpected indicator result"));
+        };
+
+        command.kind = "macd".to_string();
+        command.fast = Some(26);
+        command.slow = Some(12);
+        let Err(StockQueryError::InvalidParameter { name: "fast", .. }) =
+            Indicator::try_from(&command)
+        else {
+            return Err(anyhow!("unexpected indicator result"));
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+
+/// テクニカル指標の値
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorValue {
+    /// SMA, EMA, RSI, ATR
+    Single(f64),
+    /// MACD
+    Macd {
+        macd: f64,
+        signal: f64,
+        histogram: f64,
+    },
+    /// ボリンジャーバンド
+    BollingerBands { middle: f64, upper: f64, lower: f64 },
+}
+
+/// 日付ごとのテクニカル指標
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorData {
+    pub stock_id: String,
+    /// 日付
+    pub date: NaiveDate,
+    pub value: IndicatorValue,
+}
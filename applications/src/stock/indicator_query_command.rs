@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IndicatorQueryCommand {
+    pub stock_id: String,
+    /// 指標の種類(sma, ema, rsi, macd, bollinger, atr)
+    pub kind: String,
+    /// 期間(sma, ema, rsi, bollinger, atr)
+    pub window: Option<usize>,
+    /// 短期EMAの期間(macd)
+    pub fast: Option<usize>,
+    /// 長期EMAの期間(macd)
+    pub slow: Option<usize>,
+    /// シグナルの期間(macd)
+    pub signal: Option<usize>,
+    /// バンド幅の標準偏差の倍率(bollinger)
+    pub sigma: Option<f64>,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+    /// 株式分割・併合を考慮した修正株価で計算する
+    pub adjusted: bool,
+}
+
+impl IndicatorQueryCommand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
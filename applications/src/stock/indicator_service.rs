@@ -0,0 +1,6 @@
+use crate::stock::{IndicatorData, IndicatorQueryCommand, StockQueryResult};
+
+#[async_trait::async_trait]
+pub trait IndicatorService {
+    async fn find(&self, command: IndicatorQueryCommand) -> StockQueryResult<Vec<IndicatorData>>;
+}
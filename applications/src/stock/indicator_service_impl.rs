@@ -0,0 +1,141 @@
+use crate::stock::{
+    Indicator, IndicatorData, IndicatorQueryCommand, IndicatorService, StockQueryCommand,
+    StockQueryError, StockQueryResult, StockQueryService,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IndicatorServiceImpl<T>
+where
+    T: StockQueryService,
+{
+    stock_query_service: T,
+}
+
+impl<T> IndicatorServiceImpl<T>
+where
+    T: StockQueryService,
+{
+    /// コンストラクタ
+    pub fn new(stock_query_service: T) -> Self {
+        Self {
+            stock_query_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> IndicatorService for IndicatorServiceImpl<T>
+where
+    T: StockQueryService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn find(&self, command: IndicatorQueryCommand) -> StockQueryResult<Vec<IndicatorData>> {
+        // パラメータチェック
+        let indicator = Indicator::try_from(&command)?;
+        if let (Some(start), Some(end)) = (command.start, command.end) {
+            if start > end {
+                return Err(StockQueryError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        };
+
+        // 開始日時点で指標の値が揃うように開始日より前の株価も含めて計算する
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some(command.stock_id.clone());
+        param.end = command.end;
+        param.adjusted = command.adjusted;
+        let mut stocks = self.stock_query_service.find(param).await?;
+        stocks.sort_by_key(|s| s.date);
+
+        let values = indicator.calculate(&stocks);
+        let result = stocks
+            .into_iter()
+            .zip(values)
+            .filter(|(s, _)| command.start.is_none_or(|start| s.date >= start))
+            .filter_map(|(s, value)| {
+                Some(IndicatorData {
+                    stock_id: s.stock_id,
+                    date: s.date,
+                    value: value?,
+                })
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::stock::{
+        IndicatorQueryCommand, IndicatorService, IndicatorServiceImpl, IndicatorValue,
+        InmemoryStockQueryServiceImpl, StockData, StockQueryError,
+    };
+
+    const STOCK_ID: &str = "7203";
+
+    fn setup() -> impl IndicatorService {
+        let mut stock_query_service = InmemoryStockQueryServiceImpl::new();
+        // 日付の降順で登録しておく
+        for day in (1..=10).rev() {
+            stock_query_service.stocks.push(StockData {
+                stock_id: STOCK_ID.to_string(),
+                date: NaiveDate::from_ymd_opt(2022, 1, day).unwrap(),
+                end_price: Decimal::from(day * 10),
+                ..Default::default()
+            });
+        }
+
+        IndicatorServiceImpl::new(stock_query_service)
+    }
+
+    #[tokio::test]
+    async fn find_with_warm_up() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = IndicatorQueryCommand::new();
+        command.stock_id = STOCK_ID.to_string();
+        command.kind = "sma".to_string();
+        command.window = Some(3);
+        command.start = NaiveDate::from_ymd_opt(2022, 1, 2);
+        command.end = NaiveDate::from_ymd_opt(2022, 1, 5);
+
+        let result = service.find(command).await?;
+
+        // 開始日前のデータが足りない1/2は値なし
+        let dates: Vec<NaiveDate> = result.iter().map(|i| i.date).collect();
+        assert!(
+            dates
+                == vec![
+                    NaiveDate::from_ymd_opt(2022, 1, 3).unwrap(),
+                    NaiveDate::from_ymd_opt(2022, 1, 4).unwrap(),
+                    NaiveDate::from_ymd_opt(2022, 1, 5).unwrap(),
+                ]
+        );
+        assert!(result[0].value == IndicatorValue::Single(20.0));
+        assert!(result[2].value == IndicatorValue::Single(40.0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_invalid_range_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut command = IndicatorQueryCommand::new();
+        command.stock_id = STOCK_ID.to_string();
+        command.kind = "rsi".to_string();
+        command.start = NaiveDate::from_ymd_opt(2022, 1, 5);
+        command.end = NaiveDate::from_ymd_opt(2022, 1, 2);
+
+        let Err(StockQueryError::InvalidRangeOfDate { .. }) = service.find(command).await else {
+            return Err(anyhow!("unexpected find indicator result"));
+        };
+
+        Ok(())
+    }
+}
@@ -1,18 +1,26 @@
 use std::ops::Deref;
 
-use crate::stock::{
-    StockData, StockQueryCommand, StockQueryError, StockQueryResult, StockQueryService,
+use domain::trading_calendar::TradingCalendar;
+
+use crate::{
+    corporate_action::CorporateActionData,
+    stock::{
+        QuoteData, StockData, StockQueryCommand, StockQueryError, StockQueryResult,
+        StockQueryService,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct InmemoryStockQueryServiceImpl {
     pub stocks: Vec<StockData>,
+    pub corporate_actions: Vec<CorporateActionData>,
+    pub trading_calendar: TradingCalendar,
 }
 
 impl InmemoryStockQueryServiceImpl {
     /// コンストラクタ
     pub fn new() -> Self {
-        Self { stocks: Vec::new() }
+        Self::default()
     }
 }
 
@@ -20,26 +28,20 @@ impl InmemoryStockQueryServiceImpl {
 impl StockQueryService for InmemoryStockQueryServiceImpl {
     #[tracing::instrument(skip(self), err)]
     async fn find(&self, param: StockQueryCommand) -> StockQueryResult<Vec<StockData>> {
-        // パラメータチェック
-        if let (Some(start), Some(end)) = (param.start, param.end) {
-            if start > end {
-                return Err(StockQueryError::InvalidRangeOfDate {
-                    name: "end",
-                    value: end,
-                });
-            }
-        };
+        param.validate()?;
         // ID検索
         let find_by_id = |s: &StockData| {
             if let Some(id) = &param.stock_id {
-                &s.stock_id.deref() == &id
+                s.stock_id.deref() == id
             } else {
                 true
             }
         };
+        // 日付範囲指定(カーソルを指定した場合はその続き)
+        let (start, end) = param.date_range();
         // 日付範囲指定(下限)
         let find_by_date_from = |s: &StockData| {
-            if let Some(from) = &param.start {
+            if let Some(from) = &start {
                 &s.date >= from
             } else {
                 true
@@ -47,7 +49,7 @@ impl StockQueryService for InmemoryStockQueryServiceImpl {
         };
         // 日付範囲指定(上限)
         let find_by_date_to = |s: &StockData| {
-            if let Some(to) = &param.end {
+            if let Some(to) = &end {
                 &s.date <= to
             } else {
                 true
@@ -69,19 +71,45 @@ impl StockQueryService for InmemoryStockQueryServiceImpl {
 
         let iter = self
             .stocks
-            .to_vec()
+            .iter()
+            .filter(|s| find_by_id(s))
+            .filter(|s| find_by_date_from(s))
+            .filter(|s| find_by_date_to(s))
+            .cloned();
+
+        let mut result = iter.collect::<Vec<StockData>>();
+        // 修正株価
+        if param.adjusted {
+            result
+                .iter_mut()
+                .for_each(|s| s.adjust(&self.corporate_actions));
+        }
+        // 修正後の株価を集計単位ごとにまとめてからページングする
+        let mut result = param.interval.resample(result);
+        result.sort_by_key(|s| s.date);
+        if param.descending {
+            result.reverse();
+        }
+        let result = result
             .into_iter()
-            .filter(find_by_id)
-            .filter(find_by_date_from)
-            .filter(find_by_date_to)
             .skip(skip_count)
-            .take(page_size);
-
-        let result = iter.collect::<Vec<StockData>>();
+            .take(page_size)
+            .collect();
 
         Ok(result)
     }
 
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn count(&self, mut param: StockQueryCommand) -> StockQueryResult<i64> {
+        param.validate()?;
+        param.page = None;
+        param.size = None;
+
+        let result = self.find(param).await?;
+
+        Ok(result.len() as i64)
+    }
+
     #[tracing::instrument(skip(self), err, ret)]
     async fn find_latest(&self, stock_id: &str) -> StockQueryResult<StockData> {
         let mut command = StockQueryCommand::new();
@@ -96,17 +124,65 @@ impl StockQueryService for InmemoryStockQueryServiceImpl {
 
         Ok(latest)
     }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn find_latest_many(
+        &self,
+        stock_ids: Vec<String>,
+    ) -> StockQueryResult<Vec<QuoteData>> {
+        let mut result = Vec::new();
+        for stock_id in stock_ids {
+            let mut stocks: Vec<&StockData> = self
+                .stocks
+                .iter()
+                .filter(|s| s.stock_id == stock_id)
+                .collect();
+            stocks.sort_by_key(|s| std::cmp::Reverse(s.date));
+            let Some(latest) = stocks.first() else { continue };
+            // 休場日の株価は前営業日の終値として扱わない
+            let previous_trading_day = self.trading_calendar.previous_trading_day(latest.date);
+            // 前営業日の終値は最新の株価と同じ株数基準に修正する
+            let previous = stocks
+                .get(1)
+                .filter(|s| s.date <= previous_trading_day)
+                .map(|s| {
+                    let mut previous = (*s).clone();
+                    let corporate_actions: Vec<CorporateActionData> = self
+                        .corporate_actions
+                        .iter()
+                        .filter(|action| action.ex_date <= latest.date)
+                        .cloned()
+                        .collect();
+                    previous.adjust(&corporate_actions);
+                    previous
+                });
+
+            result.push(QuoteData::new((*latest).clone(), previous));
+        }
+
+        Ok(result)
+    }
+
+    fn trading_calendar(&self) -> &TradingCalendar {
+        &self.trading_calendar
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::ops::Deref;
 
-    use chrono::NaiveDate;
-
-    use crate::stock::{
-        inmemory_stock_query_service_impl::InmemoryStockQueryServiceImpl, stock_data::StockData,
-        stock_query_command::StockQueryCommand, stock_query_service::StockQueryService,
+    use anyhow::anyhow;
+    use chrono::{Datelike, NaiveDate};
+    use rust_decimal::Decimal;
+
+    use crate::{
+        corporate_action::CorporateActionData,
+        stock::{
+            inmemory_stock_query_service_impl::InmemoryStockQueryServiceImpl,
+            stock_data::StockData, stock_query_command::StockQueryCommand,
+            stock_query_service::StockQueryService, StockCursor, StockInterval, StockQueryError,
+        },
     };
 
     fn setup() -> InmemoryStockQueryServiceImpl {
@@ -117,13 +193,13 @@ mod test {
     async fn find_by_id() -> anyhow::Result<()> {
         let mut service = setup();
         let mut param = StockQueryCommand::new();
-        let target_id = "2";
+        let target_id = "1002";
         param.stock_id = Some(target_id.to_string());
 
         let mut stocks = Vec::new();
         for i in 0..3 {
             stocks.insert(i, StockData::new());
-            stocks[i].stock_id = i.to_string();
+            stocks[i].stock_id = format!("100{i}");
         }
         service.stocks = stocks;
 
@@ -135,6 +211,37 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn find_by_alphanumeric_id() -> anyhow::Result<()> {
+        let mut service = setup();
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some("130A".to_string());
+
+        let mut stock = StockData::new();
+        stock.stock_id = "130A".to_string();
+        service.stocks = vec![stock];
+
+        assert!(service.find(param).await?.len() == 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_by_invalid_id_return_err() -> anyhow::Result<()> {
+        let service = setup();
+
+        for stock_id in ["72O3", "720", "72030", "0203", "13B0", "130a"] {
+            let mut param = StockQueryCommand::new();
+            param.stock_id = Some(stock_id.to_string());
+
+            let Err(StockQueryError::InvalidParameter { name: "stock_id", .. }) = service.find(param).await else {
+                return Err(anyhow!("unexpected stock query result: {}", stock_id));
+            };
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn find_by_date_from() -> anyhow::Result<()> {
         let mut service = setup();
@@ -184,6 +291,7 @@ mod test {
     }
 
     #[tokio::test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
     async fn pagenation() -> anyhow::Result<()> {
         let mut service = setup();
         let mut param = StockQueryCommand::new();
@@ -207,6 +315,7 @@ mod test {
     }
 
     #[tokio::test]
+    #[allow(clippy::clone_on_copy)]
     async fn find_latest_stock_data() -> anyhow::Result<()> {
         let mut service = setup();
         let target_date = NaiveDate::from_ymd_opt(2099, 7, 12).unwrap();
@@ -232,4 +341,238 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn find_latest_of_many_stocks() -> anyhow::Result<()> {
+        let mut service = setup();
+        let split_date = NaiveDate::from_ymd_opt(2022, 7, 12).unwrap();
+        for (stock_id, date, price) in [
+            ("1234", NaiveDate::from_ymd_opt(2022, 7, 8).unwrap(), 900),
+            ("1234", NaiveDate::from_ymd_opt(2022, 7, 11).unwrap(), 1000),
+            ("1234", split_date, 550),
+            ("5678", split_date, 2000),
+        ] {
+            let mut stock = StockData::new();
+            stock.stock_id = stock_id.to_string();
+            stock.date = date;
+            stock.end_price = Decimal::from(price);
+            service.stocks.push(stock);
+        }
+        service.corporate_actions = vec![CorporateActionData::new(
+            "1234".to_string(),
+            split_date,
+            1,
+            2,
+        )];
+
+        let found = service
+            .find_latest_many(vec![
+                "1234".to_string(),
+                "5678".to_string(),
+                "9999".to_string(),
+            ])
+            .await?;
+
+        // 株価情報が無い銘柄は含まない
+        assert!(found.len() == 2);
+        // 前営業日の終値は分割後の株数基準
+        assert!(found[0].date == split_date);
+        assert!(found[0].previous_close == Some(Decimal::from(500)));
+        assert!(found[0].change() == Some(Decimal::from(50)));
+        assert!(found[1].stock_id == "5678");
+        assert!(found[1].previous_close.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_latest_many_ignore_price_on_holiday() -> anyhow::Result<()> {
+        let mut service = setup();
+        let holiday = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        for (date, price) in [
+            (NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(), 900),
+            (holiday, 1000),
+            (NaiveDate::from_ymd_opt(2024, 1, 4).unwrap(), 1100),
+        ] {
+            let mut stock = StockData::new();
+            stock.stock_id = "1234".to_string();
+            stock.date = date;
+            stock.end_price = Decimal::from(price);
+            service.stocks.push(stock);
+        }
+
+        // 休場日の株価は前営業日の終値にしない
+        let found = service.find_latest_many(vec!["1234".to_string()]).await?;
+        assert!(found[0].previous_close.is_none());
+
+        // 臨時営業日はカレンダーで上書きする
+        service.trading_calendar.set_trading_day(holiday, true);
+        let found = service.find_latest_many(vec!["1234".to_string()]).await?;
+        assert!(found[0].previous_close == Some(Decimal::from(1000)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_adjusted_by_split() -> anyhow::Result<()> {
+        let mut service = setup();
+        let stock_id = "1234";
+        let split_date = NaiveDate::from_ymd_opt(2022, 7, 12).unwrap();
+
+        let mut stocks = Vec::new();
+        for (i, date) in [split_date.pred_opt().unwrap(), split_date]
+            .iter()
+            .enumerate()
+        {
+            stocks.insert(i, StockData::new());
+            stocks[i].stock_id = stock_id.to_string();
+            stocks[i].date = *date;
+            stocks[i].start_price = Decimal::from(1000);
+            stocks[i].end_price = Decimal::from(1000);
+            stocks[i].high_price = Decimal::from(1000);
+            stocks[i].low_price = Decimal::from(1000);
+            stocks[i].volume = 100;
+        }
+        service.stocks = stocks.to_vec();
+        service.corporate_actions = vec![CorporateActionData::new(
+            stock_id.to_string(),
+            split_date,
+            1,
+            2,
+        )];
+
+        // 修正なし
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some(stock_id.to_string());
+        let found = service.find(param.clone()).await?;
+        assert!(found == stocks);
+
+        // 権利落ち日前日の株価のみ補正される
+        param.adjusted = true;
+        let found = service.find(param).await?;
+        assert!(found[0].end_price == Decimal::from(500));
+        assert!(found[0].volume == 200);
+        assert!(found[1] == stocks[1]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_by_month() -> anyhow::Result<()> {
+        let mut service = setup();
+        let stock_id = "1234";
+
+        let mut stocks = Vec::new();
+        for (i, (date, price)) in [
+            (NaiveDate::from_ymd_opt(2022, 6, 30).unwrap(), 900),
+            (NaiveDate::from_ymd_opt(2022, 7, 1).unwrap(), 1000),
+            (NaiveDate::from_ymd_opt(2022, 7, 29).unwrap(), 1100),
+            (NaiveDate::from_ymd_opt(2022, 8, 1).unwrap(), 1200),
+        ]
+        .iter()
+        .enumerate()
+        {
+            stocks.insert(i, StockData::new());
+            stocks[i].stock_id = stock_id.to_string();
+            stocks[i].date = *date;
+            stocks[i].start_price = Decimal::from(*price);
+            stocks[i].end_price = Decimal::from(*price);
+            stocks[i].high_price = Decimal::from(*price);
+            stocks[i].low_price = Decimal::from(*price);
+            stocks[i].volume = 100;
+        }
+        service.stocks = stocks;
+
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some(stock_id.to_string());
+        param.start = NaiveDate::from_ymd_opt(2022, 7, 1);
+        param.interval = StockInterval::Month;
+        let found = service.find(param.clone()).await?;
+
+        assert!(found.len() == 2);
+        assert!(found[0].date == NaiveDate::from_ymd_opt(2022, 7, 1).unwrap());
+        assert!(found[0].start_price == Decimal::from(1000));
+        assert!(found[0].end_price == Decimal::from(1100));
+        assert!(found[0].volume == 200);
+        assert!(found[1].date == NaiveDate::from_ymd_opt(2022, 8, 1).unwrap());
+
+        // ページングは集計後の件数に対して行う
+        param.page = Some(2);
+        param.size = Some(1);
+        let found = service.find(param.clone()).await?;
+
+        assert!(found.len() == 1);
+        assert!(found[0].date == NaiveDate::from_ymd_opt(2022, 8, 1).unwrap());
+        assert!(service.count(param).await? == 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_page_with_cursor() -> anyhow::Result<()> {
+        let mut service = setup();
+        for day in 1..=5 {
+            let mut stock = StockData::new();
+            stock.stock_id = "1234".to_string();
+            stock.date = NaiveDate::from_ymd_opt(2023, 3, day).unwrap();
+            service.stocks.push(stock);
+        }
+
+        for descending in [false, true] {
+            let mut param = StockQueryCommand::new();
+            param.stock_id = Some("1234".to_string());
+            param.size = Some(2);
+            param.descending = descending;
+
+            let mut days = vec![];
+            let mut pages = 0;
+            loop {
+                let page = service.find_page(param.clone()).await?;
+                days.extend(page.items.iter().map(|s| s.date.day()));
+                pages += 1;
+                let Some(cursor) = page.next_cursor else { break };
+                // 文字列表現を経由しても同じ位置から再開できる
+                param.cursor = Some(cursor.to_string().parse()?);
+            }
+
+            let mut expected = vec![1, 2, 3, 4, 5];
+            if descending {
+                expected.reverse();
+            }
+            assert!(days == expected);
+            assert!(pages == 3);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_page_with_cursor_of_other_stock_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = StockQueryCommand::new();
+        param.stock_id = Some("1234".to_string());
+        param.cursor = Some(StockCursor::new(
+            "5678".to_string(),
+            NaiveDate::from_ymd_opt(2023, 3, 1).unwrap(),
+        ));
+
+        let Err(StockQueryError::InvalidParameter { name: "cursor", .. }) = service.find_page(param).await else {
+            return Err(anyhow!("unexpected stock query result"));
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_page_size_return_err() -> anyhow::Result<()> {
+        let service = setup();
+        let mut param = StockQueryCommand::new();
+        param.size = Some(0);
+
+        let Err(StockQueryError::InvalidParameter { name: "size", .. }) = service.find(param).await else {
+            return Err(anyhow!("unexpected stock query result"));
+        };
+
+        Ok(())
+    }
 }
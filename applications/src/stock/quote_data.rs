@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::stock::StockData;
+
+/// 最新の株価と前日比
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QuoteData {
+    pub stock_id: String,
+    /// 最新の株価の日付
+    pub date: NaiveDate,
+    /// 終値
+    pub end_price: Decimal,
+    /// 前営業日の終値(株価情報が1日分しか無い場合や、直前の株価が休場日の場合はNone)
+    pub previous_close: Option<Decimal>,
+    /// 出来高
+    pub volume: i64,
+}
+
+impl QuoteData {
+    /// 最新の株価と前営業日の株価から作成する
+    ///
+    /// 前営業日の株価は最新の株価と同じ株数基準に修正しておくこと
+    pub fn new(latest: StockData, previous: Option<StockData>) -> Self {
+        Self {
+            stock_id: latest.stock_id,
+            date: latest.date,
+            end_price: latest.end_price,
+            previous_close: previous.map(|s| s.end_price),
+            volume: latest.volume,
+        }
+    }
+
+    /// 前日比
+    pub fn change(&self) -> Option<Decimal> {
+        self.previous_close.map(|close| self.end_price - close)
+    }
+
+    /// 前日比(%)
+    pub fn change_rate(&self) -> Option<f64> {
+        let close = self.previous_close.filter(|close| !close.is_zero())?;
+        ((self.end_price - close) / close * Decimal::ONE_HUNDRED).to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use rust_decimal::Decimal;
+
+    use crate::stock::{QuoteData, StockData};
+
+    fn stock(end_price: i64) -> StockData {
+        StockData {
+            stock_id: "7203".to_string(),
+            end_price: Decimal::from(end_price),
+            volume: 100,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn calculate_change_from_previous_close() -> anyhow::Result<()> {
+        let quote = QuoteData::new(stock(1100), Some(stock(1000)));
+
+        assert!(quote.previous_close == Some(Decimal::from(1000)));
+        assert!(quote.change() == Some(Decimal::from(100)));
+        let Some(rate) = quote.change_rate() else {
+            return Err(anyhow!("change rate not calculated"));
+        };
+        assert!((rate - 10.0).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_change_without_previous_close() {
+        let quote = QuoteData::new(stock(1100), None);
+
+        assert!(quote.change().is_none());
+        assert!(quote.change_rate().is_none());
+    }
+}
@@ -0,0 +1,90 @@
+use std::{fmt, str::FromStr};
+
+use base64::{
+    alphabet,
+    engine::fast_portable::{self, FastPortable},
+};
+use chrono::NaiveDate;
+
+use crate::stock::StockQueryError;
+
+/// URLにそのまま含められる形式(パディング無し)
+const CURSOR_ENGINE: FastPortable = FastPortable::from(&alphabet::URL_SAFE, fast_portable::NO_PAD);
+
+/// キーセットページングのカーソル(前のページの最後の株価)
+///
+/// 文字列表現は`<証券コード>:<日付>`をBase64(URL-safe)にしたもの
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StockCursor {
+    pub stock_id: String,
+    /// 集計単位ごとにまとめた場合は期間の初日
+    pub date: NaiveDate,
+}
+
+impl StockCursor {
+    /// コンストラクタ
+    pub fn new(stock_id: String, date: NaiveDate) -> Self {
+        Self { stock_id, date }
+    }
+}
+
+impl fmt::Display for StockCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = format!("{}:{}", self.stock_id, self.date.format("%Y-%m-%d"));
+        write!(f, "{}", base64::encode_engine(raw, &CURSOR_ENGINE))
+    }
+}
+
+impl FromStr for StockCursor {
+    type Err = StockQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || StockQueryError::InvalidParameter {
+            name: "cursor",
+            value: s.to_string(),
+        };
+
+        let raw = base64::decode_engine(s, &CURSOR_ENGINE).map_err(|_| invalid())?;
+        let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+        let (stock_id, date) = raw.rsplit_once(':').ok_or_else(invalid)?;
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| invalid())?;
+
+        Ok(Self::new(stock_id.to_string(), date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+
+    use crate::stock::{StockCursor, StockQueryError};
+
+    #[test]
+    fn cursor_round_trip() -> anyhow::Result<()> {
+        let cursor = StockCursor::new(
+            "7203".to_string(),
+            NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+        );
+
+        let encoded = cursor.to_string();
+
+        assert!(!encoded.contains("7203"));
+        assert!(encoded.parse::<StockCursor>()? == cursor);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_cursor_return_err() -> anyhow::Result<()> {
+        for value in ["not base64!", "NzIwMw"] {
+            let Err(StockQueryError::InvalidParameter { name: "cursor", .. }) =
+                value.parse::<StockCursor>()
+            else {
+                return Err(anyhow!("unexpected parse result: {}", value));
+            };
+        }
+
+        Ok(())
+    }
+}
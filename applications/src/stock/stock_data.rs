@@ -1,4 +1,12 @@
 use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+use crate::{
+    corporate_action::CorporateActionData,
+    stock::{StockImportError, StockImportResult},
+};
+use domain::money::Price;
+
 /// 株価情報
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct StockData {
@@ -6,19 +14,266 @@ pub struct StockData {
     /// 日付
     pub date: NaiveDate,
     /// 出来高
-    pub volume: i32,
+    pub volume: i64,
     /// 始値
-    pub start_price: i32,
+    pub start_price: Decimal,
     /// 終値
-    pub end_price: i32,
+    pub end_price: Decimal,
     /// 高値
-    pub high_price: i32,
+    pub high_price: Decimal,
     /// 安値
-    pub low_price: i32,
+    pub low_price: Decimal,
 }
 
 impl StockData {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// 登録データとしての妥当性チェック
+    pub fn validate(&self) -> StockImportResult<()> {
+        if self.stock_id.is_empty() || self.stock_id.chars().count() > 10 {
+            return Err(StockImportError::InvalidData {
+                name: "stock_id",
+                value: self.stock_id.clone(),
+            });
+        }
+        if self.volume < 0 {
+            return Err(StockImportError::InvalidData {
+                name: "volume",
+                value: self.volume.to_string(),
+            });
+        }
+        // 価格は全て正の値
+        for (name, price) in [
+            ("start_price", self.start_price),
+            ("end_price", self.end_price),
+            ("high_price", self.high_price),
+            ("low_price", self.low_price),
+        ] {
+            if price <= Decimal::ZERO {
+                return Err(StockImportError::InvalidData {
+                    name,
+                    value: price.to_string(),
+                });
+            }
+        }
+        // 高値と安値の範囲に始値と終値が含まれること
+        if self.high_price < self.low_price {
+            return Err(StockImportError::InvalidData {
+                name: "high_price",
+                value: self.high_price.to_string(),
+            });
+        }
+        for (name, price) in [
+            ("start_price", self.start_price),
+            ("end_price", self.end_price),
+        ] {
+            if price < self.low_price || price > self.high_price {
+                return Err(StockImportError::InvalidData {
+                    name,
+                    value: price.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 株式分割・併合の前の株価を現在の株数基準に修正する
+    ///
+    /// 権利落ち日が当日より後のコーポレートアクションの比率で価格と出来高を補正する
+    /// 価格は小数点以下2桁未満、出来高は1株未満を四捨五入する
+    pub fn adjust(&mut self, corporate_actions: &[CorporateActionData]) {
+        let (ratio_from, ratio_to) = corporate_actions
+            .iter()
+            .filter(|action| action.stock_id == self.stock_id && action.ex_date > self.date)
+            .fold((Decimal::ONE, Decimal::ONE), |(from, to), action| {
+                (from * Decimal::from(action.ratio_from), to * Decimal::from(action.ratio_to))
+            });
+        if ratio_from == ratio_to {
+            return;
+        }
+
+        let round = |value: Decimal, dp: u32| {
+            value
+                .round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero)
+                .normalize()
+        };
+        let adjust_price = |price: Decimal| round(price * ratio_from / ratio_to, Price::SCALE);
+        self.start_price = adjust_price(self.start_price);
+        self.end_price = adjust_price(self.end_price);
+        self.high_price = adjust_price(self.high_price);
+        self.low_price = adjust_price(self.low_price);
+        self.volume = round(Decimal::from(self.volume) * ratio_to / ratio_from, 0)
+            .to_i64()
+            .unwrap_or(i64::MAX);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        corporate_action::CorporateActionData,
+        stock::{StockData, StockImportError},
+    };
+
+    fn sample() -> StockData {
+        StockData {
+            stock_id: "7203".to_string(),
+            volume: 1000,
+            start_price: Decimal::from(100),
+            end_price: Decimal::from(110),
+            high_price: Decimal::from(120),
+            low_price: Decimal::from(90),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate_success() -> anyhow::Result<()> {
+        sample().validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_empty_stock_id_return_err() -> anyhow::Result<()> {
+        let mut stock = sample();
+        stock.stock_id = "".to_string();
+
+        let Err(StockImportError::InvalidData {
+            name: "stock_id", ..
+        }) = stock.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_negative_price_return_err() -> anyhow::Result<()> {
+        let mut stock = sample();
+        stock.low_price = Decimal::from(-1);
+
+        let Err(StockImportError::InvalidData {
+            name: "low_price", ..
+        }) = stock.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_high_below_low_return_err() -> anyhow::Result<()> {
+        let mut stock = sample();
+        stock.high_price = Decimal::from(80);
+
+        let Err(StockImportError::InvalidData {
+            name: "high_price", ..
+        }) = stock.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_end_price_out_of_range_return_err() -> anyhow::Result<()> {
+        let mut stock = sample();
+        stock.end_price = Decimal::from(130);
+
+        let Err(StockImportError::InvalidData {
+            name: "end_price", ..
+        }) = stock.validate()
+        else {
+            return Err(anyhow!("unexpected validate result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn adjust_before_split() {
+        let mut stock = sample();
+        stock.date = NaiveDate::from_ymd_opt(2021, 9, 28).unwrap();
+        let corporate_actions = vec![
+            // 権利落ち日が当日以前のものは補正しない
+            CorporateActionData::new(
+                "7203".to_string(),
+                NaiveDate::from_ymd_opt(2021, 9, 28).unwrap(),
+                1,
+                2,
+            ),
+            CorporateActionData::new(
+                "7203".to_string(),
+                NaiveDate::from_ymd_opt(2021, 9, 29).unwrap(),
+                1,
+                5,
+            ),
+            CorporateActionData::new(
+                "9999".to_string(),
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                1,
+                3,
+            ),
+        ];
+
+        stock.adjust(&corporate_actions);
+
+        assert!(stock.start_price == Decimal::from(20));
+        assert!(stock.end_price == Decimal::from(22));
+        assert!(stock.high_price == Decimal::from(24));
+        assert!(stock.low_price == Decimal::from(18));
+        assert!(stock.volume == 5000);
+    }
+
+    #[test]
+    fn validate_fractional_price_success() -> anyhow::Result<()> {
+        let mut stock = sample();
+        // 呼値の単位が0.1円の銘柄
+        stock.start_price = Decimal::new(1005, 1);
+        stock.end_price = Decimal::new(1098, 1);
+
+        stock.validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn adjust_before_split_and_reverse_split() {
+        let mut stock = sample();
+        stock.date = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
+        let corporate_actions = vec![
+            CorporateActionData::new(
+                "7203".to_string(),
+                NaiveDate::from_ymd_opt(2015, 1, 1).unwrap(),
+                10,
+                1,
+            ),
+            CorporateActionData::new(
+                "7203".to_string(),
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                1,
+                3,
+            ),
+        ];
+
+        stock.adjust(&corporate_actions);
+
+        // 10 / 3倍
+        assert!(stock.start_price == Decimal::new(33333, 2));
+        assert!(stock.end_price == Decimal::new(36667, 2));
+        assert!(stock.high_price == Decimal::from(400));
+        assert!(stock.low_price == Decimal::from(300));
+        assert!(stock.volume == 300);
+    }
 }
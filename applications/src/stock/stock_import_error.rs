@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StockImportError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid stock data: {name}={value}")]
+    InvalidData { name: &'static str, value: String },
+}
+
+pub type StockImportResult<T> = Result<T, StockImportError>;
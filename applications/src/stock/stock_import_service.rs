@@ -0,0 +1,7 @@
+use crate::stock::{StockData, StockImportResult};
+
+#[async_trait::async_trait]
+pub trait StockImportService {
+    /// 株価情報の登録(同一の証券コード・日付のデータは上書き)
+    async fn upsert(&self, stocks: Vec<StockData>) -> StockImportResult<u64>;
+}
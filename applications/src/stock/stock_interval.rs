@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::stock::{StockData, StockQueryError};
+
+/// 株価の集計単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StockInterval {
+    /// 日足
+    #[default]
+    Day,
+    /// 週足(月曜日始まり)
+    Week,
+    /// 月足
+    Month,
+    /// 年足
+    Year,
+}
+
+impl StockInterval {
+    /// PostgreSQLのdate_truncに指定する単位と同じ文字列
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockInterval::Day => "day",
+            StockInterval::Week => "week",
+            StockInterval::Month => "month",
+            StockInterval::Year => "year",
+        }
+    }
+
+    /// 日付を集計期間の初日に切り捨てる
+    pub fn truncate(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            StockInterval::Day => date,
+            StockInterval::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            StockInterval::Month => date.with_day(1).unwrap(),
+            StockInterval::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap(),
+        }
+    }
+
+    /// 日付を含む集計期間の次の期間の初日
+    pub fn next(&self, date: NaiveDate) -> NaiveDate {
+        let start = self.truncate(date);
+        match self {
+            StockInterval::Day => start + Duration::days(1),
+            StockInterval::Week => start + Duration::days(7),
+            StockInterval::Month if start.month() == 12 => {
+                NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap()
+            }
+            StockInterval::Month => start.with_month(start.month() + 1).unwrap(),
+            StockInterval::Year => NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap(),
+        }
+    }
+
+    /// 日足を集計期間ごとのローソク足にまとめる
+    ///
+    /// 日付は期間の初日とし、始値は最初の取引日、終値は最後の取引日の値を用いる
+    /// 出来高の合計がi64に収まらない場合は上限値で打ち切る
+    pub fn resample(&self, mut stocks: Vec<StockData>) -> Vec<StockData> {
+        if *self == StockInterval::Day {
+            return stocks;
+        }
+        stocks.sort_by(|s1, s2| (&s1.stock_id, s1.date).cmp(&(&s2.stock_id, s2.date)));
+
+        let mut result: Vec<StockData> = Vec::new();
+        for stock in stocks {
+            let date = self.truncate(stock.date);
+            match result.last_mut() {
+                Some(candle) if candle.stock_id == stock.stock_id && candle.date == date => {
+                    candle.volume = candle.volume.saturating_add(stock.volume);
+                    candle.end_price = stock.end_price;
+                    candle.high_price = candle.high_price.max(stock.high_price);
+                    candle.low_price = candle.low_price.min(stock.low_price);
+                }
+                _ => result.push(StockData { date, ..stock }),
+            }
+        }
+
+        result
+    }
+}
+
+impl FromStr for StockInterval {
+    type Err = StockQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(StockInterval::Day),
+            "week" => Ok(StockInterval::Week),
+            "month" => Ok(StockInterval::Month),
+            "year" => Ok(StockInterval::Year),
+            _ => Err(StockQueryError::InvalidParameter {
+                name: "interval",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::stock::{StockData, StockInterval, StockQueryError};
+
+    fn stock(stock_id: &str, date: NaiveDate, prices: [i32; 4], volume: i64) -> StockData {
+        StockData {
+            stock_id: stock_id.to_string(),
+            date,
+            volume,
+            start_price: Decimal::from(prices[0]),
+            high_price: Decimal::from(prices[1]),
+            low_price: Decimal::from(prices[2]),
+            end_price: Decimal::from(prices[3]),
+        }
+    }
+
+    #[test]
+    fn truncate_date() {
+        // 2023/3/15は水曜日
+        let date = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+
+        assert!(StockInterval::Day.truncate(date) == date);
+        assert!(
+            StockInterval::Week.truncate(date) == NaiveDate::from_ymd_opt(2023, 3, 13).unwrap()
+        );
+        assert!(
+            StockInterval::Month.truncate(date) == NaiveDate::from_ymd_opt(2023, 3, 1).unwrap()
+        );
+        assert!(StockInterval::Year.truncate(date) == NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn next_period_start() {
+        let date = NaiveDate::from_ymd_opt(2023, 12, 15).unwrap();
+
+        assert!(StockInterval::Day.next(date) == NaiveDate::from_ymd_opt(2023, 12, 16).unwrap());
+        assert!(StockInterval::Week.next(date) == NaiveDate::from_ymd_opt(2023, 12, 18).unwrap());
+        assert!(StockInterval::Month.next(date) == NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert!(StockInterval::Year.next(date) == NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn parse_invalid_interval_return_err() -> anyhow::Result<()> {
+        let Err(StockQueryError::InvalidParameter {
+            name: "interval", ..
+        }) = "hour".parse::<StockInterval>()
+        else {
+            return Err(anyhow!("unexpected parse result"));
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn resample_weekly() {
+        let date = |day| NaiveDate::from_ymd_opt(2023, 3, day).unwrap();
+        // 並び順によらず日付順に集計される
+        let stocks = vec![
+            stock("1234", date(15), [105, 130, 100, 120], 20),
+            stock("1234", date(13), [100, 110, 90, 105], 10),
+            stock("1234", date(17), [120, 125, 95, 98], 30),
+            stock("1234", date(20), [98, 99, 80, 85], 40),
+            stock("5678", date(14), [500, 500, 500, 500], 1),
+        ];
+
+        let candles = StockInterval::Week.resample(stocks);
+
+        assert!(
+            candles
+                == vec![
+                    stock("1234", date(13), [100, 130, 90, 98], 60),
+                    stock("1234", date(20), [98, 99, 80, 85], 40),
+                    stock("5678", date(13), [500, 500, 500, 500], 1),
+                ]
+        );
+    }
+
+    #[test]
+    fn resample_saturate_volume() {
+        let stocks = vec![
+            stock(
+                "1234",
+                NaiveDate::from_ymd_opt(2023, 1, 4).unwrap(),
+                [1, 1, 1, 1],
+                i64::MAX,
+            ),
+            stock(
+                "1234",
+                NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(),
+                [1, 1, 1, 1],
+                1,
+            ),
+        ];
+
+        let candles = StockInterval::Year.resample(stocks);
+
+        assert!(candles.len() == 1);
+        assert!(candles[0].volume == i64::MAX);
+    }
+}
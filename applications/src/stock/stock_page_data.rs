@@ -0,0 +1,9 @@
+use crate::stock::{StockCursor, StockData};
+
+/// キーセットページングの結果
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StockPageData {
+    pub items: Vec<StockData>,
+    /// 次のページが存在する場合は最後の株価のカーソル
+    pub next_cursor: Option<StockCursor>,
+}
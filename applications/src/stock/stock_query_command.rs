@@ -1,5 +1,8 @@
 use chrono::NaiveDate;
 
+use crate::stock::{StockCursor, StockInterval, StockQueryError, StockQueryResult};
+use domain::stock::StockId;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct StockQueryCommand {
     pub stock_id: Option<String>,
@@ -7,10 +10,78 @@ pub struct StockQueryCommand {
     pub end: Option<NaiveDate>,
     pub page: Option<i32>,
     pub size: Option<i32>,
+    /// 株式分割・併合を考慮した修正株価で取得する
+    pub adjusted: bool,
+    /// 集計単位
+    pub interval: StockInterval,
+    /// trueの場合は日付の降順
+    pub descending: bool,
+    /// 前のページの最後の株価(指定した場合は続きから取得する、pageとは同時に指定できない)
+    pub cursor: Option<StockCursor>,
 }
 
 impl StockQueryCommand {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// パラメータチェック
+    pub fn validate(&self) -> StockQueryResult<()> {
+        if let Some(stock_id) = &self.stock_id {
+            StockId::new(stock_id.clone())?;
+        }
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start > end {
+                return Err(StockQueryError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        }
+        if let Some(cursor) = &self.cursor {
+            if let Some(page) = self.page {
+                return Err(StockQueryError::InvalidParameter {
+                    name: "page",
+                    value: page.to_string(),
+                });
+            }
+            if self.stock_id.as_ref() != Some(&cursor.stock_id) {
+                return Err(StockQueryError::InvalidParameter {
+                    name: "cursor",
+                    value: cursor.to_string(),
+                });
+            }
+        }
+        for (name, value) in [("page", self.page), ("size", self.size)] {
+            if let Some(value) = value.filter(|value| *value < 1) {
+                return Err(StockQueryError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// カーソルの続きに絞り込んだ日足の日付範囲(開始, 終了)
+    pub fn date_range(&self) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        let Some(cursor) = &self.cursor else {
+            return (self.start, self.end);
+        };
+
+        if self.descending {
+            // カーソルの期間より前
+            let end = self.interval.truncate(cursor.date).pred_opt();
+            let end = match (self.end, end) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+            (self.start, end)
+        } else {
+            // カーソルの期間より後
+            let start = self.interval.next(cursor.date);
+            (Some(self.start.map_or(start, |s| s.max(start))), self.end)
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use thiserror::Error;
 
+use domain::stock::StockDomainError;
+
 #[derive(Error, Debug)]
 pub enum StockQueryError {
     #[error(transparent)]
@@ -17,3 +19,14 @@ pub enum StockQueryError {
 }
 
 pub type StockQueryResult<T> = Result<T, StockQueryError>;
+
+impl From<StockDomainError> for StockQueryError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
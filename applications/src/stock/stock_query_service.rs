@@ -1,7 +1,44 @@
-use crate::stock::{StockData, StockQueryCommand, StockQueryResult};
+use crate::stock::{
+    QuoteData, StockCursor, StockData, StockPageData, StockQueryCommand, StockQueryResult,
+};
+use domain::trading_calendar::TradingCalendar;
 
 #[async_trait::async_trait]
 pub trait StockQueryService {
     async fn find(&self, param: StockQueryCommand) -> StockQueryResult<Vec<StockData>>;
+    /// 条件に一致する株価の件数(集計単位ごとにまとめた後の件数、ページ番号・ページサイズは無視する)
+    async fn count(&self, param: StockQueryCommand) -> StockQueryResult<i64>;
     async fn find_latest(&self, stock_id: &str) -> StockQueryResult<StockData>;
+    /// 複数銘柄の最新の株価と前日比をまとめて取得する(株価情報が無い銘柄は含まない)
+    async fn find_latest_many(&self, stock_ids: Vec<String>) -> StockQueryResult<Vec<QuoteData>>;
+    /// 前営業日や期間の判定に使う営業日カレンダー
+    fn trading_calendar(&self) -> &TradingCalendar;
+
+    /// カーソルの続きから1ページ分の株価を取得する(カーソルを指定しない場合は先頭から)
+    ///
+    /// ページサイズを指定しない場合は全件を取得し、次のページは無い
+    async fn find_page(&self, mut param: StockQueryCommand) -> StockQueryResult<StockPageData> {
+        param.validate()?;
+        let Some(size) = param.size.filter(|_| param.page.is_none()) else {
+            let items = self.find(param).await?;
+            return Ok(StockPageData {
+                items,
+                next_cursor: None,
+            });
+        };
+
+        // 1件多く取得して次のページの有無を判定する
+        param.size = Some(size.saturating_add(1));
+        let mut items = self.find(param).await?;
+        let next_cursor = if items.len() > size as usize {
+            items.truncate(size as usize);
+            items
+                .last()
+                .map(|s| StockCursor::new(s.stock_id.clone(), s.date))
+        } else {
+            None
+        };
+
+        Ok(StockPageData { items, next_cursor })
+    }
 }
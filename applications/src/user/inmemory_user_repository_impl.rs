@@ -23,13 +23,13 @@ impl InmemoryUserRepositoryImpl {
 impl UserRepository for InmemoryUserRepositoryImpl {
     /// ユーザー削除
     async fn delete(&self, user: User) -> UserDomainResult<()> {
-        self.store.lock().unwrap().remove(&user.id().to_string());
+        self.store.lock().unwrap().remove(user.id().as_str());
 
         Ok(())
     }
 
     async fn find(&self, id: &UserId) -> UserDomainResult<Option<User>> {
-        if let Some(user) = self.store.lock().unwrap().get(&id.to_string()) {
+        if let Some(user) = self.store.lock().unwrap().get(id.as_str()) {
             Ok(Some(user.clone()))
         } else {
             Ok(None)
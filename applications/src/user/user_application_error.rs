@@ -10,6 +10,8 @@ pub enum UserApplicationError {
     UserAlreadyExist(String),
     #[error("user not exist: id={0}")]
     UserNotExist(String),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
 }
 
 impl From<UserDomainError> for UserApplicationError {
@@ -20,6 +22,14 @@ impl From<UserDomainError> for UserApplicationError {
                 Self::UserAlreadyExist(user_id.into())
             }
             UserDomainError::UserNotFound(user_id) => Self::UserNotExist(user_id.into()),
+            UserDomainError::InvalidUserEmail(value) => Self::InvalidParameter {
+                name: "email",
+                value,
+            },
+            UserDomainError::InvalidUserName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
         }
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use domain::user::{User, UserEmail, UserId, UserName};
+use domain::user::{User, UserDomainError, UserEmail, UserId, UserName};
 
 /// User Data Transfer Object
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
@@ -32,12 +32,14 @@ impl From<User> for UserData {
     }
 }
 
-impl Into<User> for UserData {
-    fn into(self) -> User {
-        let id = UserId::new(self.id);
-        let name = UserName::new(self.name);
-        let email = UserEmail::new(self.email);
+impl TryFrom<UserData> for User {
+    type Error = UserDomainError;
 
-        User::new(id, name, email)
+    fn try_from(value: UserData) -> Result<Self, Self::Error> {
+        let id = UserId::new(value.id);
+        let name = UserName::new(value.name)?;
+        let email = UserEmail::new(value.email)?;
+
+        Ok(User::new(id, name, email))
     }
 }
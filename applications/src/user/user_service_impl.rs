@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::user::{UserApplicationResult, UserData, UserService};
-use domain::user::{UserDomainError, UserDomainService, UserId, UserRepository};
+use domain::user::{User, UserDomainError, UserDomainService, UserId, UserRepository};
 
 /// User application service
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -38,7 +38,7 @@ where
             .user_repository
             .find(&UserId::new(id.to_string()))
             .await?
-            .map(|found| UserData::from(found));
+            .map(UserData::from);
 
         Ok(user)
     }
@@ -47,12 +47,13 @@ where
     #[tracing::instrument(skip(self), err, ret)]
     async fn save(&self, user: UserData) -> UserApplicationResult<()> {
         let user_id = UserId::new(user.id.clone());
+        let user = User::try_from(user)?;
 
-        if let Ok(_) = self.user_service.exists(&user_id).await {
+        if self.user_service.exists(&user_id).await.is_ok() {
             return Err(UserDomainError::UserAlreadyExist(user_id).into());
         }
 
-        self.user_repository.save(user.into()).await?;
+        self.user_repository.save(user).await?;
         Ok(())
     }
 
@@ -75,15 +76,15 @@ mod tests {
     mod user_usecase_tests {
         use std::sync::Arc;
 
+        use anyhow::anyhow;
+
         use crate::user::inmemory_user_repository_impl::InmemoryUserRepositoryImpl;
-        use crate::user::{UserData, UserService, UserServiceImpl};
+        use crate::user::{UserApplicationError, UserData, UserService, UserServiceImpl};
 
         // テストに必要なオブジェクトの初期化
         fn setup() -> UserServiceImpl<InmemoryUserRepositoryImpl> {
             let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
-            let user_application = UserServiceImpl::new(&user_repository);
-
-            user_application
+            UserServiceImpl::new(&user_repository)
         }
 
         #[tokio::test]
@@ -91,7 +92,7 @@ mod tests {
             let app_service = setup();
             let id = "1";
             let name = "hoge";
-            let email = "mail";
+            let email = "mail@example.com";
             let create_user = UserData::new(id, name, email);
 
             app_service.save(create_user.clone()).await?;
@@ -107,10 +108,10 @@ mod tests {
 
             let id = "1";
             let name1 = "hoge";
-            let email1 = "fuga";
+            let email1 = "fuga@example.com";
             let user1 = UserData::new(id, name1, email1);
             let name2 = "sample name";
-            let email2 = "abc";
+            let email2 = "abc@example.com";
             let user2 = UserData::new(id, name2, email2);
             let created_user = UserData::new(id, name1, email1);
 
@@ -123,6 +124,35 @@ mod tests {
             Ok(())
         }
 
+        #[tokio::test]
+        async fn create_user_with_invalid_email_return_err() -> anyhow::Result<()> {
+            let app_service = setup();
+
+            for email in ["mail", "@example.com", "hoge@", "hoge@example", "ho ge@example.com"] {
+                let user = UserData::new("1", "hoge", email);
+                let Err(UserApplicationError::InvalidParameter { name: "email", .. }) = app_service.save(user).await else {
+                    return Err(anyhow!("unexpected user save result: {}", email));
+                };
+            }
+            assert!(app_service.get("1").await?.is_none());
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn create_user_with_invalid_name_return_err() -> anyhow::Result<()> {
+            let app_service = setup();
+
+            for name in [" ".to_string(), "a".repeat(51)] {
+                let user = UserData::new("1", name.clone(), "mail@example.com");
+                let Err(UserApplicationError::InvalidParameter { name: "name", .. }) = app_service.save(user).await else {
+                    return Err(anyhow!("unexpected user save result: {}", name));
+                };
+            }
+
+            Ok(())
+        }
+
         #[tokio::test]
         async fn get_not_exist_user_return_none() -> anyhow::Result<()> {
             let app_service = setup();
@@ -138,7 +168,7 @@ mod tests {
             let app_service = setup();
             let id = "234";
             let name = "delete user";
-            let email = "hoge";
+            let email = "hoge@example.com";
             let created_user = UserData::new(id, name, email);
 
             app_service.save(created_user.clone()).await?;
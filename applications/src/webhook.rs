@@ -0,0 +1,27 @@
+mod inmemory_webhook_repository_impl;
+mod inmemory_webhook_sender_impl;
+mod webhook_application_error;
+mod webhook_data;
+mod webhook_delivery_data;
+mod webhook_request;
+mod webhook_sender;
+mod webhook_service;
+mod webhook_service_impl;
+mod webhook_signature;
+mod webhook_update_command;
+
+pub use inmemory_webhook_repository_impl::InmemoryWebhookRepositoryImpl;
+pub use inmemory_webhook_sender_impl::InmemoryWebhookSenderImpl;
+pub use webhook_application_error::WebhookApplicationError;
+pub use webhook_application_error::WebhookApplicationResult;
+pub use webhook_data::WebhookData;
+pub use webhook_delivery_data::WebhookDeliveryData;
+pub use webhook_request::WebhookRequest;
+pub use webhook_request::DELIVERY_HEADER;
+pub use webhook_request::EVENT_HEADER;
+pub use webhook_request::SIGNATURE_HEADER;
+pub use webhook_sender::WebhookSender;
+pub use webhook_service::WebhookService;
+pub use webhook_service_impl::WebhookServiceImpl;
+pub use webhook_signature::sign_payload;
+pub use webhook_update_command::WebhookUpdateCommand;
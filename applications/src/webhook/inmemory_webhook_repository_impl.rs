@@ -0,0 +1,159 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::NaiveDateTime;
+
+use domain::{
+    user::UserId,
+    webhook::{
+        DeliveryStatus, Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDomainResult,
+        WebhookId, WebhookRepository,
+    },
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct InmemoryWebhookRepositoryImpl {
+    pub webhooks: Arc<Mutex<Vec<Webhook>>>,
+    pub deliveries: Arc<Mutex<Vec<WebhookDelivery>>>,
+}
+
+impl InmemoryWebhookRepositoryImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for InmemoryWebhookRepositoryImpl {
+    async fn save_webhook(&self, webhook: Webhook) -> WebhookDomainResult<WebhookId> {
+        let mut webhooks = self.webhooks.lock().unwrap();
+        let id = if let Some(id) = webhook.id {
+            webhooks.retain(|target| target.id != Some(id));
+            id
+        } else {
+            // 採番
+            let max_id = webhooks.iter().filter_map(|w| w.id).map(|id| *id).max();
+            WebhookId::new(max_id.unwrap_or(0) + 1)
+        };
+        let mut webhook = webhook;
+        webhook.id = Some(id);
+        webhooks.push(webhook);
+
+        Ok(id)
+    }
+
+    async fn delete_webhook(
+        &self,
+        user_id: &UserId,
+        webhook_id: &WebhookId,
+    ) -> WebhookDomainResult<()> {
+        let mut webhooks = self.webhooks.lock().unwrap();
+        let Some(index) = webhooks
+            .iter()
+            .position(|target| target.id == Some(*webhook_id) && &target.user_id == user_id)
+        else {
+            return Ok(());
+        };
+        webhooks.remove(index);
+        self.deliveries
+            .lock()
+            .unwrap()
+            .retain(|target| target.webhook_id != *webhook_id);
+
+        Ok(())
+    }
+
+    async fn find_webhooks(&self, user_id: &UserId) -> WebhookDomainResult<Vec<Webhook>> {
+        let mut result = self
+            .webhooks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| &target.user_id == user_id)
+            .cloned()
+            .collect::<Vec<Webhook>>();
+        result.sort_by_key(|w| w.id.map(|id| *id));
+
+        Ok(result)
+    }
+
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> WebhookDomainResult<Option<Webhook>> {
+        let result = self
+            .webhooks
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|target| target.id == Some(*webhook_id))
+            .cloned();
+
+        Ok(result)
+    }
+
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> WebhookDomainResult<WebhookDeliveryId> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        // 採番
+        let max_id = deliveries.iter().filter_map(|d| d.id).map(|id| *id).max();
+        let id = WebhookDeliveryId::new(max_id.unwrap_or(0) + 1);
+        deliveries.push(WebhookDelivery {
+            id: Some(id),
+            ..delivery
+        });
+
+        Ok(id)
+    }
+
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> WebhookDomainResult<()> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        if let Some(target) = deliveries
+            .iter_mut()
+            .find(|target| target.id.is_some() && target.id == delivery.id)
+        {
+            *target = delivery;
+        }
+
+        Ok(())
+    }
+
+    async fn find_pending_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>> {
+        let mut result = self
+            .deliveries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| {
+                target.status == DeliveryStatus::Pending && target.next_attempt_at <= now
+            })
+            .cloned()
+            .collect::<Vec<WebhookDelivery>>();
+        result.sort_by_key(|d| (d.next_attempt_at, d.id.map(|id| *id)));
+        result.truncate(limit.max(0) as usize);
+
+        Ok(result)
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: &WebhookId,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>> {
+        let mut result = self
+            .deliveries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|target| target.webhook_id == *webhook_id)
+            .cloned()
+            .collect::<Vec<WebhookDelivery>>();
+        result.sort_by_key(|d| std::cmp::Reverse(d.id.map(|id| *id)));
+        result.truncate(limit.max(0) as usize);
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::{Arc, Mutex};
+
+use crate::webhook::{WebhookRequest, WebhookSender};
+
+#[derive(Debug, Clone)]
+pub struct InmemoryWebhookSenderImpl {
+    /// 送信した内容
+    pub requests: Arc<Mutex<Vec<WebhookRequest>>>,
+    /// 返すHTTPステータスコード(Noneの場合は接続エラー)
+    pub status_code: Arc<Mutex<Option<u16>>>,
+}
+
+impl InmemoryWebhookSenderImpl {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self {
+            requests: Arc::new(Mutex::new(vec![])),
+            status_code: Arc::new(Mutex::new(Some(200))),
+        }
+    }
+}
+
+impl Default for InmemoryWebhookSenderImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for InmemoryWebhookSenderImpl {
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<u16> {
+        self.requests.lock().unwrap().push(request);
+
+        let status_code = *self.status_code.lock().unwrap();
+        status_code.ok_or_else(|| anyhow::anyhow!("connection refused"))
+    }
+}
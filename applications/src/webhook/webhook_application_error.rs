@@ -0,0 +1,78 @@
+use thiserror::Error;
+
+use domain::{
+    favorite::FavoriteDomainError, stock::StockDomainError, user::UserDomainError,
+    webhook::WebhookDomainError,
+};
+
+#[derive(Error, Debug)]
+pub enum WebhookApplicationError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("user not found: id={0:?}")]
+    UserNotFound(String),
+    #[error("user is already exsist: id={0:?}")]
+    UserAlreadyExist(String),
+    #[error("webhook not found: id={0}")]
+    WebhookNotFound(i32),
+    #[error("invalid parameter: {name}={value}")]
+    InvalidParameter { name: &'static str, value: String },
+}
+
+pub type WebhookApplicationResult<T> = Result<T, WebhookApplicationError>;
+
+impl From<WebhookDomainError> for WebhookApplicationError {
+    fn from(value: WebhookDomainError) -> Self {
+        match value {
+            WebhookDomainError::Disconnect(e) => Self::Disconnect(e),
+            WebhookDomainError::InvalidUrl(value) => Self::InvalidParameter { name: "url", value },
+            WebhookDomainError::InvalidEvent(value) => Self::InvalidParameter {
+                name: "events",
+                value,
+            },
+            // 保存済みのデータが不正
+            WebhookDomainError::InvalidStatus(value) => {
+                Self::Disconnect(anyhow::anyhow!("invalid delivery status: {}", value))
+            }
+        }
+    }
+}
+
+impl From<StockDomainError> for WebhookApplicationError {
+    fn from(value: StockDomainError) -> Self {
+        match value {
+            StockDomainError::InvalidStockId(value) => Self::InvalidParameter {
+                name: "stock_id",
+                value,
+            },
+        }
+    }
+}
+
+impl From<UserDomainError> for WebhookApplicationError {
+    fn from(value: UserDomainError) -> Self {
+        match value {
+            UserDomainError::Disconnect(e) => Self::Disconnect(e),
+            UserDomainError::UserAlreadyExist(user_id) => Self::UserAlreadyExist(user_id.into()),
+            UserDomainError::UserNotFound(user_id) => Self::UserNotFound(user_id.into()),
+            UserDomainError::InvalidUserEmail(value) => Self::InvalidParameter {
+                name: "email",
+                value,
+            },
+            UserDomainError::InvalidUserName(value) => Self::InvalidParameter {
+                name: "name",
+                value,
+            },
+        }
+    }
+}
+
+impl From<FavoriteDomainError> for WebhookApplicationError {
+    fn from(value: FavoriteDomainError) -> Self {
+        match value {
+            FavoriteDomainError::Disconnect(e) => Self::Disconnect(e),
+            // 保存済みのデータが不正
+            e => Self::Disconnect(anyhow::anyhow!(e)),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use domain::{
+    user::UserId,
+    webhook::{Webhook, WebhookDomainError, WebhookEvent, WebhookId, WebhookUrl},
+};
+
+/// Webhookの登録情報
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookData {
+    /// 未登録の場合はNone
+    pub id: Option<i32>,
+    pub user_id: String,
+    pub url: String,
+    /// 署名(HMAC-SHA256)の鍵
+    pub secret: String,
+    /// favorite / portfolio / price
+    pub events: Vec<String>,
+    pub enabled: bool,
+}
+
+impl WebhookData {
+    /// コンストラクタ
+    pub fn new(user_id: String, url: String, events: Vec<String>) -> Self {
+        Self {
+            id: None,
+            user_id,
+            url,
+            secret: "".to_string(),
+            events,
+            enabled: true,
+        }
+    }
+}
+
+impl From<Webhook> for WebhookData {
+    fn from(value: Webhook) -> Self {
+        Self {
+            id: value.id.map(i32::from),
+            events: value
+                .events()
+                .iter()
+                .map(|e| e.as_str().to_string())
+                .collect(),
+            user_id: value.user_id.to_string(),
+            url: value.url.into(),
+            secret: value.secret,
+            enabled: value.enabled,
+        }
+    }
+}
+
+impl TryFrom<WebhookData> for Webhook {
+    type Error = WebhookDomainError;
+
+    fn try_from(value: WebhookData) -> Result<Self, Self::Error> {
+        let events = value
+            .events
+            .iter()
+            .map(|e| WebhookEvent::new(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut webhook = Webhook::new(
+            UserId::new(value.user_id),
+            WebhookUrl::new(value.url)?,
+            value.secret,
+            events,
+        )?;
+        webhook.id = value.id.map(WebhookId::new);
+        webhook.enabled = value.enabled;
+
+        Ok(webhook)
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDateTime;
+
+use domain::webhook::WebhookDelivery;
+
+/// Webhookの送信履歴
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookDeliveryData {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: String,
+    /// 送信するJSON
+    pub payload: String,
+    /// pending / succeeded / failed
+    pub status: String,
+    /// 送信を試行した回数
+    pub attempts: i32,
+    /// 次に送信する日時(UTC)
+    pub next_attempt_at: NaiveDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    /// 登録日時(UTC)
+    pub created_at: NaiveDateTime,
+    /// 送信に成功した日時(UTC)
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryData {
+    fn from(value: WebhookDelivery) -> Self {
+        Self {
+            id: value.id.map(i32::from).unwrap_or_default(),
+            webhook_id: *value.webhook_id,
+            event: value.event.as_str().to_string(),
+            payload: value.payload,
+            status: value.status.as_str().to_string(),
+            attempts: value.attempts,
+            next_attempt_at: value.next_attempt_at,
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: value.created_at,
+            delivered_at: value.delivered_at,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// 署名を設定するHTTPヘッダー
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+/// イベントの種別を設定するHTTPヘッダー
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+/// 送信履歴のIDを設定するHTTPヘッダー(再送時も同じ値)
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// Webhookの送信内容
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub event: String,
+    pub delivery_id: i32,
+    /// "sha256=" + bodyのHMAC-SHA256(16進数)
+    pub signature: String,
+    /// JSON
+    pub body: String,
+}
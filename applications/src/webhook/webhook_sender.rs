@@ -0,0 +1,9 @@
+use crate::webhook::WebhookRequest;
+
+#[async_trait::async_trait]
+pub trait WebhookSender {
+    /// POSTで送信し、受信したHTTPステータスコードを返す
+    ///
+    /// 接続できない・タイムアウトした場合はErrを返す
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<u16>;
+}
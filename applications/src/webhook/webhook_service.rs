@@ -0,0 +1,37 @@
+use crate::webhook::{
+    WebhookApplicationResult, WebhookData, WebhookDeliveryData, WebhookUpdateCommand,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookService {
+    /// 登録順に取得する
+    async fn get_webhooks(&self, user_id: &str) -> WebhookApplicationResult<Vec<WebhookData>>;
+    /// 署名の鍵を生成して登録する
+    async fn add_webhook(&self, webhook: WebhookData) -> WebhookApplicationResult<WebhookData>;
+    async fn update_webhook(
+        &self,
+        update_command: WebhookUpdateCommand,
+    ) -> WebhookApplicationResult<WebhookData>;
+    /// 送信履歴もあわせて削除する
+    async fn remove_webhook(&self, user_id: &str, webhook_id: i32) -> WebhookApplicationResult<()>;
+    /// 送信履歴を新しい順に取得する
+    async fn get_deliveries(
+        &self,
+        user_id: &str,
+        webhook_id: i32,
+    ) -> WebhookApplicationResult<Vec<WebhookDeliveryData>>;
+    /// イベントを通知するユーザーのWebhookの送信キューに登録し、登録した件数を返す
+    async fn publish(
+        &self,
+        user_id: &str,
+        event: &str,
+        data: serde_json::Value,
+    ) -> WebhookApplicationResult<usize>;
+    /// 指定した銘柄をお気に入りに登録しているユーザーに株価の取り込みを通知する
+    async fn publish_price_update(&self, stock_ids: Vec<String>)
+        -> WebhookApplicationResult<usize>;
+    /// 送信日時を過ぎた送信待ちを送信し、送信を試行した件数を返す
+    ///
+    /// 送信に失敗した場合は間隔を空けて再送する
+    async fn deliver_pending(&self) -> WebhookApplicationResult<usize>;
+}
@@ -0,0 +1,625 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use rand::{distributions::Alphanumeric, Rng};
+
+use crate::webhook::{
+    sign_payload, WebhookApplicationError, WebhookApplicationResult, WebhookData,
+    WebhookDeliveryData, WebhookRequest, WebhookSender, WebhookService, WebhookUpdateCommand,
+};
+use domain::{
+    favorite::FavoriteRepository,
+    stock::StockId,
+    user::{UserDomainService, UserId, UserRepository},
+    webhook::{Webhook, WebhookDelivery, WebhookEvent, WebhookId, WebhookRepository, WebhookUrl},
+};
+
+/// 署名の鍵の長さ
+const SECRET_LENGTH: usize = 32;
+/// 1回に送信する最大件数
+const DELIVERY_BATCH_SIZE: i64 = 100;
+/// 取得する送信履歴の最大件数
+const DELIVERY_LOG_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookServiceImpl<T, U, V, W>
+where
+    T: WebhookRepository,
+    U: FavoriteRepository,
+    V: UserRepository,
+    W: WebhookSender,
+{
+    webhook_repository: Arc<T>,
+    favorite_repository: Arc<U>,
+    webhook_sender: W,
+    user_domain_service: UserDomainService<V>,
+}
+
+impl<T, U, V, W> WebhookServiceImpl<T, U, V, W>
+where
+    T: WebhookRepository,
+    U: FavoriteRepository,
+    V: UserRepository,
+    W: WebhookSender,
+{
+    /// コンストラクタ
+    pub fn new(
+        webhook_repository: &Arc<T>,
+        favorite_repository: &Arc<U>,
+        webhook_sender: W,
+        user_domain_service: UserDomainService<V>,
+    ) -> Self {
+        Self {
+            webhook_repository: Arc::clone(webhook_repository),
+            favorite_repository: Arc::clone(favorite_repository),
+            webhook_sender,
+            user_domain_service,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, U, V, W> WebhookService for WebhookServiceImpl<T, U, V, W>
+where
+    T: WebhookRepository + std::fmt::Debug + Send + Sync,
+    U: FavoriteRepository + std::fmt::Debug + Send + Sync,
+    V: UserRepository + std::fmt::Debug + Send + Sync,
+    W: WebhookSender + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(skip(self), err)]
+    async fn get_webhooks(&self, user_id: &str) -> WebhookApplicationResult<Vec<WebhookData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let result = self
+            .webhook_repository
+            .find_webhooks(&user_id)
+            .await?
+            .into_iter()
+            .map(WebhookData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_webhook(&self, webhook: WebhookData) -> WebhookApplicationResult<WebhookData> {
+        let user_id = UserId::new(webhook.user_id.clone());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let mut webhook = Webhook::try_from(WebhookData {
+            id: None,
+            secret: generate_secret(),
+            enabled: true,
+            ..webhook
+        })?;
+        let id = self
+            .webhook_repository
+            .save_webhook(webhook.clone())
+            .await?;
+        webhook.id = Some(id);
+
+        Ok(webhook.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn update_webhook(
+        &self,
+        update_command: WebhookUpdateCommand,
+    ) -> WebhookApplicationResult<WebhookData> {
+        let user_id = UserId::new(update_command.user_id);
+        self.user_domain_service.exists(&user_id).await?;
+
+        let mut webhook = self
+            .find_webhook(&user_id, update_command.webhook_id)
+            .await?;
+        if let Some(url) = update_command.url {
+            webhook.url = WebhookUrl::new(url)?;
+        }
+        if let Some(events) = update_command.events {
+            let events = events
+                .iter()
+                .map(|e| WebhookEvent::new(e))
+                .collect::<Result<Vec<_>, _>>()?;
+            webhook.set_events(events)?;
+        }
+        if let Some(enabled) = update_command.enabled {
+            webhook.enabled = enabled;
+        }
+        self.webhook_repository
+            .save_webhook(webhook.clone())
+            .await?;
+
+        Ok(webhook.into())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_webhook(&self, user_id: &str, webhook_id: i32) -> WebhookApplicationResult<()> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        self.webhook_repository
+            .delete_webhook(&user_id, &WebhookId::new(webhook_id))
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_deliveries(
+        &self,
+        user_id: &str,
+        webhook_id: i32,
+    ) -> WebhookApplicationResult<Vec<WebhookDeliveryData>> {
+        let user_id = UserId::new(user_id.into());
+        self.user_domain_service.exists(&user_id).await?;
+
+        let webhook = self.find_webhook(&user_id, webhook_id).await?;
+        let Some(webhook_id) = webhook.id else {
+            return Err(WebhookApplicationError::WebhookNotFound(webhook_id));
+        };
+        let result = self
+            .webhook_repository
+            .find_deliveries(&webhook_id, DELIVERY_LOG_LIMIT)
+            .await?
+            .into_iter()
+            .map(WebhookDeliveryData::from)
+            .collect();
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self, data), err)]
+    async fn publish(
+        &self,
+        user_id: &str,
+        event: &str,
+        data: serde_json::Value,
+    ) -> WebhookApplicationResult<usize> {
+        let event = WebhookEvent::new(event)?;
+        self.enqueue(&UserId::new(user_id.into()), event, data)
+            .await
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn publish_price_update(
+        &self,
+        stock_ids: Vec<String>,
+    ) -> WebhookApplicationResult<usize> {
+        // 証券コードとして不正な銘柄はお気に入りに登録できないため除く
+        let stock_ids: Vec<StockId> = stock_ids
+            .into_iter()
+            .filter_map(|stock_id| StockId::new(stock_id).ok())
+            .collect();
+        let favorites = self.favorite_repository.find_by_stocks(&stock_ids).await?;
+
+        // ユーザーごとにお気に入りに登録している銘柄をまとめる
+        let mut watched: HashMap<String, Vec<String>> = HashMap::new();
+        for favorite in favorites {
+            watched
+                .entry(favorite.user_id.to_string())
+                .or_default()
+                .push(favorite.stock_id.to_string());
+        }
+
+        let mut count = 0;
+        for (user_id, mut stock_ids) in watched {
+            stock_ids.sort();
+            stock_ids.dedup();
+            let data = serde_json::json!({ "stock_ids": stock_ids });
+            count += self
+                .enqueue(&UserId::new(user_id), WebhookEvent::Price, data)
+                .await?;
+        }
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn deliver_pending(&self) -> WebhookApplicationResult<usize> {
+        let deliveries = self
+            .webhook_repository
+            .find_pending_deliveries(Utc::now().naive_utc(), DELIVERY_BATCH_SIZE)
+            .await?;
+
+        let mut webhooks: HashMap<i32, Option<Webhook>> = HashMap::new();
+        let mut count = 0;
+        for mut delivery in deliveries {
+            let webhook = match webhooks.get(&*delivery.webhook_id) {
+                Some(webhook) => webhook.clone(),
+                None => {
+                    let webhook = self
+                        .webhook_repository
+                        .find_webhook(&delivery.webhook_id)
+                        .await?;
+                    webhooks.insert(*delivery.webhook_id, webhook.clone());
+                    webhook
+                }
+            };
+
+            match webhook.filter(|w| w.enabled) {
+                Some(webhook) => {
+                    let request = WebhookRequest {
+                        url: webhook.url.as_str().to_string(),
+                        event: delivery.event.as_str().to_string(),
+                        delivery_id: delivery.id.map(i32::from).unwrap_or_default(),
+                        signature: sign_payload(&webhook.secret, &delivery.payload),
+                        body: delivery.payload.clone(),
+                    };
+                    let result = self.webhook_sender.send(request).await;
+                    let now = Utc::now().naive_utc();
+                    match result {
+                        Ok(status_code) if (200..300).contains(&status_code) => {
+                            delivery.succeed(status_code as i32, now)
+                        }
+                        Ok(status_code) => delivery.fail(
+                            Some(status_code as i32),
+                            format!("unexpected status code: {}", status_code),
+                            now,
+                        ),
+                        Err(e) => delivery.fail(None, e.to_string(), now),
+                    }
+                    count += 1;
+                }
+                None => delivery.discard("webhook is disabled".to_string()),
+            }
+            self.webhook_repository.update_delivery(delivery).await?;
+        }
+
+        Ok(count)
+    }
+}
+
+impl<T, U, V, W> WebhookServiceImpl<T, U, V, W>
+where
+    T: WebhookRepository + Send + Sync,
+    U: FavoriteRepository + Send + Sync,
+    V: UserRepository + Send + Sync,
+    W: WebhookSender + Send + Sync,
+{
+    async fn find_webhook(
+        &self,
+        user_id: &UserId,
+        webhook_id: i32,
+    ) -> WebhookApplicationResult<Webhook> {
+        self.webhook_repository
+            .find_webhooks(user_id)
+            .await?
+            .into_iter()
+            .find(|w| w.id.map(i32::from) == Some(webhook_id))
+            .ok_or(WebhookApplicationError::WebhookNotFound(webhook_id))
+    }
+
+    /// イベントを通知するWebhookごとに送信キューに登録する
+    async fn enqueue(
+        &self,
+        user_id: &UserId,
+        event: WebhookEvent,
+        data: serde_json::Value,
+    ) -> WebhookApplicationResult<usize> {
+        let webhooks = self.webhook_repository.find_webhooks(user_id).await?;
+        let now = Utc::now().naive_utc();
+        let payload = serde_json::json!({
+            "event": event.as_str(),
+            "created_at": now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            "data": data,
+        })
+        .to_string();
+
+        let mut count = 0;
+        for webhook in webhooks.into_iter().filter(|w| w.subscribes(event)) {
+            let Some(webhook_id) = webhook.id else {
+                continue;
+            };
+            let delivery =
+                WebhookDelivery::new(webhook_id, user_id.clone(), event, payload.clone(), now);
+            self.webhook_repository.add_delivery(delivery).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// 署名の鍵を生成する
+fn generate_secret() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(SECRET_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::{NaiveDate, Utc};
+
+    use crate::{
+        favorite::InmemoryFavoriteRepositoryImpl,
+        user::InmemoryUserRepositoryImpl,
+        webhook::{
+            sign_payload, InmemoryWebhookRepositoryImpl, InmemoryWebhookSenderImpl,
+            WebhookApplicationError, WebhookData, WebhookService, WebhookServiceImpl,
+            WebhookUpdateCommand,
+        },
+    };
+    use domain::{
+        favorite::{Favorite, FavoriteRepository, WatchlistId},
+        stock::StockId,
+        user::{User, UserDomainService, UserEmail, UserId, UserName, UserRepository},
+        webhook::{DeliveryStatus, MAX_DELIVERY_ATTEMPTS},
+    };
+
+    const USER_ID: &str = "sample user";
+    const URL: &str = "https://example.com/hook";
+
+    struct Setup {
+        service: WebhookServiceImpl<
+            InmemoryWebhookRepositoryImpl,
+            InmemoryFavoriteRepositoryImpl,
+            InmemoryUserRepositoryImpl,
+            InmemoryWebhookSenderImpl,
+        >,
+        webhook_repository: Arc<InmemoryWebhookRepositoryImpl>,
+        favorite_repository: Arc<InmemoryFavoriteRepositoryImpl>,
+        sender: InmemoryWebhookSenderImpl,
+    }
+
+    async fn setup() -> Setup {
+        let user_repository = Arc::new(InmemoryUserRepositoryImpl::new());
+        let sample_user = User::new(
+            UserId::new(USER_ID.to_string()),
+            UserName::default(),
+            UserEmail::default(),
+        );
+        user_repository.save(sample_user).await.unwrap();
+
+        let webhook_repository = Arc::new(InmemoryWebhookRepositoryImpl::new());
+        let favorite_repository = Arc::new(InmemoryFavoriteRepositoryImpl::new());
+        let sender = InmemoryWebhookSenderImpl::new();
+        let service = WebhookServiceImpl::new(
+            &webhook_repository,
+            &favorite_repository,
+            sender.clone(),
+            UserDomainService::new(&user_repository),
+        );
+
+        Setup {
+            service,
+            webhook_repository,
+            favorite_repository,
+            sender,
+        }
+    }
+
+    fn webhook(events: &[&str]) -> WebhookData {
+        WebhookData::new(
+            USER_ID.to_string(),
+            URL.to_string(),
+            events.iter().map(|e| e.to_string()).collect(),
+        )
+    }
+
+    /// 送信日時を過ぎた状態にする
+    fn make_due(setup: &Setup) {
+        for delivery in setup
+            .webhook_repository
+            .deliveries
+            .lock()
+            .unwrap()
+            .iter_mut()
+        {
+            delivery.next_attempt_at = NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn add_and_update_webhook() -> anyhow::Result<()> {
+        let setup = setup().await;
+        let service = &setup.service;
+
+        let added = service.add_webhook(webhook(&["favorite"])).await?;
+        let Some(webhook_id) = added.id else {
+            return Err(anyhow!("webhook id not assigned"));
+        };
+        assert!(added.secret.len() == 32);
+
+        let mut command = WebhookUpdateCommand::new(USER_ID.to_string(), webhook_id);
+        command.events = Some(vec!["price".to_string(), "portfolio".to_string()]);
+        command.enabled = Some(false);
+        let updated = service.update_webhook(command).await?;
+
+        assert!(updated.events == vec!["price", "portfolio"]);
+        assert!(!updated.enabled);
+        assert!(updated.secret == added.secret);
+        assert!(service.get_webhooks(USER_ID).await? == vec![updated]);
+
+        service.remove_webhook(USER_ID, webhook_id).await?;
+        assert!(service.get_webhooks(USER_ID).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_invalid_webhook_return_err() -> anyhow::Result<()> {
+        let setup = setup().await;
+        let service = &setup.service;
+
+        for url in [
+            "ftp://example.com",
+            "http://example.com/hook",
+            "https://localhost/hook",
+            "https://api.localhost./hook",
+            "https://127.0.0.1:8080/hook",
+            "https://user@10.0.0.1/hook",
+            "https://172.16.0.1/hook",
+            "https://192.168.1.1/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://0.0.0.0/hook",
+            "https://2130706433/hook",
+            "https://0x7f000001/hook",
+            "https://[::1]/hook",
+            "https://[fe80::1]/hook",
+            "https://[fd00::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+        ] {
+            let mut invalid_url = webhook(&["favorite"]);
+            invalid_url.url = url.to_string();
+            let Err(WebhookApplicationError::InvalidParameter { name: "url", .. }) =
+                service.add_webhook(invalid_url).await
+            else {
+                return Err(anyhow!("unexpected add result: {}", url));
+            };
+        }
+        let Err(WebhookApplicationError::InvalidParameter { name: "events", .. }) =
+            service.add_webhook(webhook(&["unknown"])).await
+        else {
+            return Err(anyhow!("unexpected add result"));
+        };
+        let Err(WebhookApplicationError::InvalidParameter { name: "events", .. }) =
+            service.add_webhook(webhook(&[])).await
+        else {
+            return Err(anyhow!("unexpected add result"));
+        };
+        let Err(WebhookApplicationError::WebhookNotFound(_)) =
+            service.get_deliveries(USER_ID, 1).await
+        else {
+            return Err(anyhow!("unexpected get result"));
+        };
+        for url in [
+            "https://example.com:8443/hook?token=1",
+            "https://hooks.example.co.jp",
+            "https://93.184.216.34/hook",
+            "https://[2606:2800:220:1::]/hook",
+        ] {
+            let mut valid_url = webhook(&["favorite"]);
+            valid_url.url = url.to_string();
+            service.add_webhook(valid_url).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn publish_and_deliver_signed_payload() -> anyhow::Result<()> {
+        let setup = setup().await;
+        let service = &setup.service;
+        let added = service.add_webhook(webhook(&["favorite"])).await?;
+        service.add_webhook(webhook(&["portfolio"])).await?;
+
+        let queued = service
+            .publish(
+                USER_ID,
+                "favorite",
+                serde_json::json!({ "stock_id": "1234" }),
+            )
+            .await?;
+        assert!(queued == 1);
+
+        let delivered = service.deliver_pending().await?;
+        assert!(delivered == 1);
+
+        let requests = setup.sender.requests.lock().unwrap().clone();
+        assert!(requests.len() == 1);
+        assert!(requests[0].url == URL);
+        assert!(requests[0].event == "favorite");
+        assert!(requests[0].signature == sign_payload(&added.secret, &requests[0].body));
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body)?;
+        assert!(body["event"] == "favorite");
+        assert!(body["data"]["stock_id"] == "1234");
+
+        let deliveries = service.get_deliveries(USER_ID, added.id.unwrap()).await?;
+        assert!(deliveries.len() == 1);
+        assert!(deliveries[0].status == "succeeded");
+        assert!(deliveries[0].attempts == 1);
+        assert!(deliveries[0].last_status_code == Some(200));
+
+        // 送信済みのものは再送しない
+        assert!(service.deliver_pending().await? == 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_failed_delivery_with_backoff() -> anyhow::Result<()> {
+        let setup = setup().await;
+        let service = &setup.service;
+        let added = service.add_webhook(webhook(&["portfolio"])).await?;
+        service
+            .publish(USER_ID, "portfolio", serde_json::json!({}))
+            .await?;
+
+        *setup.sender.status_code.lock().unwrap() = Some(500);
+        let started = Utc::now().naive_utc();
+        assert!(service.deliver_pending().await? == 1);
+
+        let deliveries = service.get_deliveries(USER_ID, added.id.unwrap()).await?;
+        assert!(deliveries[0].status == "pending");
+        assert!(deliveries[0].last_status_code == Some(500));
+        assert!(deliveries[0].next_attempt_at > started);
+        // 再送日時になるまでは送信しない
+        assert!(service.deliver_pending().await? == 0);
+
+        // 接続エラーが上限まで続いた場合は打ち切る
+        *setup.sender.status_code.lock().unwrap() = None;
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            make_due(&setup);
+            assert!(service.deliver_pending().await? == 1);
+        }
+        let deliveries = setup.webhook_repository.deliveries.lock().unwrap().clone();
+        assert!(deliveries[0].status == DeliveryStatus::Failed);
+        assert!(deliveries[0].attempts == MAX_DELIVERY_ATTEMPTS);
+        assert!(deliveries[0].last_error.is_some());
+
+        make_due(&setup);
+        assert!(service.deliver_pending().await? == 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn publish_price_update_to_watching_user() -> anyhow::Result<()> {
+        let setup = setup().await;
+        let service = &setup.service;
+        service.add_webhook(webhook(&["price"])).await?;
+        let disabled = service.add_webhook(webhook(&["price"])).await?;
+        let mut command = WebhookUpdateCommand::new(USER_ID.to_string(), disabled.id.unwrap());
+        command.enabled = Some(false);
+        service.update_webhook(command).await?;
+
+        for (watchlist_id, stock_id) in [(1, "1234"), (2, "1234"), (1, "5678")] {
+            let favorite = Favorite::new(
+                WatchlistId::new(watchlist_id),
+                UserId::new(USER_ID.to_string()),
+                StockId::new(stock_id.to_string())?,
+                Utc::now().naive_utc(),
+            );
+            setup.favorite_repository.save(favorite).await?;
+        }
+
+        let queued = service
+            .publish_price_update(vec!["1234".to_string(), "9999".to_string()])
+            .await?;
+        assert!(queued == 1);
+        assert!(
+            service
+                .publish_price_update(vec!["9999".to_string()])
+                .await?
+                == 0
+        );
+
+        service.deliver_pending().await?;
+        let requests = setup.sender.requests.lock().unwrap().clone();
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body)?;
+        assert!(body["event"] == "price");
+        assert!(body["data"]["stock_ids"] == serde_json::json!(["1234"]));
+
+        Ok(())
+    }
+}
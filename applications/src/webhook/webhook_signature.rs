@@ -0,0 +1,26 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// bodyのHMAC-SHA256を"sha256=<16進数>"の形式で返す
+pub fn sign_payload(secret: &str, body: &str) -> String {
+    // HMACは任意の長さの鍵を受け付ける
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(body.as_bytes());
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::webhook::sign_payload;
+
+    #[test]
+    fn sign_known_vector() {
+        let signature = sign_payload("key", "The quick brown fox jumps over the lazy dog");
+
+        assert!(
+            signature == "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+}
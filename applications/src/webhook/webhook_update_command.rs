@@ -0,0 +1,20 @@
+/// Webhookの登録情報の更新内容(Noneの項目は変更しない)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookUpdateCommand {
+    pub user_id: String,
+    pub webhook_id: i32,
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
+
+impl WebhookUpdateCommand {
+    /// コンストラクタ
+    pub fn new(user_id: String, webhook_id: i32) -> Self {
+        Self {
+            user_id,
+            webhook_id,
+            ..Default::default()
+        }
+    }
+}
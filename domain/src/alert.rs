@@ -0,0 +1,18 @@
+mod alert_condition;
+mod alert_domain_error;
+mod alert_event_id;
+mod alert_event_model;
+mod alert_id;
+mod alert_price;
+mod alert_repository;
+mod alert_rule_model;
+
+pub use alert_condition::AlertCondition;
+pub use alert_domain_error::AlertDomainError;
+pub use alert_domain_error::AlertDomainResult;
+pub use alert_event_id::AlertEventId;
+pub use alert_event_model::AlertEvent;
+pub use alert_id::AlertId;
+pub use alert_price::AlertPrice;
+pub use alert_repository::AlertRepository;
+pub use alert_rule_model::AlertRule;
@@ -0,0 +1,84 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    alert::{AlertDomainError, AlertDomainResult, AlertPrice},
+    money::Price,
+};
+
+/// アラートの発生条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertCondition {
+    /// 終値が指定価格を上抜けた
+    PriceAbove(Price),
+    /// 終値が指定価格を下抜けた
+    PriceBelow(Price),
+    /// 前日比(%)の絶対値が指定値以上
+    ChangeRate(Decimal),
+    /// 高値が52週高値を更新した
+    YearHigh,
+}
+
+impl AlertCondition {
+    /// 条件の種別と閾値から作成する
+    pub fn new(kind: &str, threshold: Option<Decimal>) -> AlertDomainResult<Self> {
+        if kind == "year_high" {
+            return Ok(AlertCondition::YearHigh);
+        }
+        // パラメータチェック
+        let Some(value) = threshold.filter(|value| value.is_sign_positive() && !value.is_zero())
+        else {
+            return Err(AlertDomainError::InvalidThreshold(threshold));
+        };
+        let price = || Price::new(value).map_err(|_| AlertDomainError::InvalidThreshold(threshold));
+        match kind {
+            "price_above" => Ok(AlertCondition::PriceAbove(price()?)),
+            "price_below" => Ok(AlertCondition::PriceBelow(price()?)),
+            "change_rate" => Ok(AlertCondition::ChangeRate(value)),
+            _ => Err(AlertDomainError::InvalidCondition(kind.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertCondition::PriceAbove(_) => "price_above",
+            AlertCondition::PriceBelow(_) => "price_below",
+            AlertCondition::ChangeRate(_) => "change_rate",
+            AlertCondition::YearHigh => "year_high",
+        }
+    }
+
+    pub fn threshold(&self) -> Option<Decimal> {
+        match self {
+            AlertCondition::PriceAbove(value) | AlertCondition::PriceBelow(value) => {
+                Some((*value).into())
+            }
+            AlertCondition::ChangeRate(value) => Some(*value),
+            AlertCondition::YearHigh => None,
+        }
+    }
+
+    /// 条件を満たすか判定する
+    ///
+    /// 価格の条件は前営業日の終値から指定価格をまたいだ場合のみ満たす
+    pub fn is_triggered(&self, price: &AlertPrice) -> bool {
+        match self {
+            AlertCondition::PriceAbove(value) => {
+                price.end_price >= *value && !matches!(price.previous_close, Some(p) if p >= *value)
+            }
+            AlertCondition::PriceBelow(value) => {
+                price.end_price <= *value && !matches!(price.previous_close, Some(p) if p <= *value)
+            }
+            AlertCondition::ChangeRate(value) => {
+                let Some(previous_close) = price.previous_close.filter(|p| !p.is_zero()) else {
+                    return false;
+                };
+                let rate =
+                    (*price.end_price - *previous_close) / *previous_close * Decimal::ONE_HUNDRED;
+                rate.abs() >= *value
+            }
+            AlertCondition::YearHigh => {
+                matches!(price.year_high, Some(year_high) if price.high_price > year_high)
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AlertDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid alert condition: {0}")]
+    InvalidCondition(String),
+    #[error("invalid alert threshold: {0:?}")]
+    InvalidThreshold(Option<Decimal>),
+}
+
+pub type AlertDomainResult<T> = Result<T, AlertDomainError>;
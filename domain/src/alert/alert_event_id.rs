@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct AlertEventId(i32);
+
+impl AlertEventId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for AlertEventId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<AlertEventId> for i32 {
+    fn from(value: AlertEventId) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{
+    alert::{AlertCondition, AlertEventId, AlertId},
+    money::Price,
+    stock::StockId,
+    user::UserId,
+};
+
+/// 条件を満たしたアラートの通知
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    /// 未登録の場合はNone
+    pub id: Option<AlertEventId>,
+    pub rule_id: AlertId,
+    pub user_id: UserId,
+    pub stock_id: StockId,
+    /// 通知時点のルールの条件
+    pub condition: AlertCondition,
+    /// 条件を満たした株価の日付
+    pub date: NaiveDate,
+    /// 終値
+    pub price: Price,
+    /// 既読の場合はtrue
+    pub read: bool,
+    /// 通知日時(UTC)
+    pub triggered_at: NaiveDateTime,
+}
@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct AlertId(i32);
+
+impl AlertId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for AlertId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<AlertId> for i32 {
+    fn from(value: AlertId) -> Self {
+        value.0
+    }
+}
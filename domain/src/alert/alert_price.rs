@@ -0,0 +1,14 @@
+use crate::money::Price;
+
+/// アラート条件の判定に使用する株価
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AlertPrice {
+    /// 終値
+    pub end_price: Price,
+    /// 前営業日の終値
+    pub previous_close: Option<Price>,
+    /// 高値
+    pub high_price: Price,
+    /// 前営業日までの52週間の高値
+    pub year_high: Option<Price>,
+}
@@ -0,0 +1,23 @@
+use crate::{
+    alert::{AlertDomainResult, AlertEvent, AlertEventId, AlertId, AlertRule},
+    stock::StockId,
+    user::UserId,
+};
+
+#[async_trait::async_trait]
+pub trait AlertRepository {
+    /// IDが未採番の場合は新規登録し、採番したIDを返す
+    async fn save_rule(&self, rule: AlertRule) -> AlertDomainResult<AlertId>;
+    /// 通知もあわせて削除する
+    async fn delete_rule(&self, user_id: &UserId, rule_id: &AlertId) -> AlertDomainResult<()>;
+    /// 登録順に取得する
+    async fn find_rules(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertRule>>;
+    /// 指定した銘柄の有効なルールを取得する
+    async fn find_enabled_rules(&self, stock_ids: &[StockId]) -> AlertDomainResult<Vec<AlertRule>>;
+    /// 同じルール・日付の通知が登録済みの場合は登録せずにNoneを返す
+    async fn add_event(&self, event: AlertEvent) -> AlertDomainResult<Option<AlertEventId>>;
+    /// 既読状態を更新する
+    async fn update_event(&self, event: AlertEvent) -> AlertDomainResult<()>;
+    /// 新しい順に取得する
+    async fn find_events(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertEvent>>;
+}
@@ -0,0 +1,30 @@
+use crate::{
+    alert::{AlertCondition, AlertId},
+    stock::StockId,
+    user::UserId,
+};
+
+/// アラートのルール
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    /// 未登録の場合はNone
+    pub id: Option<AlertId>,
+    pub user_id: UserId,
+    pub stock_id: StockId,
+    pub condition: AlertCondition,
+    /// falseの場合は判定しない
+    pub enabled: bool,
+}
+
+impl AlertRule {
+    /// コンストラクタ
+    pub fn new(user_id: UserId, stock_id: StockId, condition: AlertCondition) -> Self {
+        Self {
+            id: None,
+            user_id,
+            stock_id,
+            condition,
+            enabled: true,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod corporate_action_domain_error;
+mod corporate_action_model;
+mod corporate_action_repository;
+mod corporate_action_type;
+
+pub use corporate_action_domain_error::CorporateActionDomainError;
+pub use corporate_action_domain_error::CorporateActionDomainResult;
+pub use corporate_action_model::CorporateAction;
+pub use corporate_action_repository::CorporateActionRepository;
+pub use corporate_action_type::CorporateActionType;
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CorporateActionDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid split ratio: {from}:{to}")]
+    InvalidRatio { from: i32, to: i32 },
+}
+
+pub type CorporateActionDomainResult<T> = Result<T, CorporateActionDomainError>;
@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+
+use crate::{
+    corporate_action::{
+        CorporateActionDomainError, CorporateActionDomainResult, CorporateActionType,
+    },
+    stock::StockId,
+};
+
+/// 株式分割・併合
+///
+/// 1株をratio_to / ratio_from株にする(例: 1株を2株に分割する場合は1:2)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CorporateAction {
+    stock_id: StockId,
+    /// 権利落ち日(この日以降の株価は分割・併合後の株数基準)
+    ex_date: NaiveDate,
+    action_type: CorporateActionType,
+    /// 分割・併合前の株数
+    ratio_from: i32,
+    /// 分割・併合後の株数
+    ratio_to: i32,
+}
+
+impl CorporateAction {
+    /// コンストラクタ
+    ///
+    /// 分割・併合の種別は比率から決まる
+    pub fn new(
+        stock_id: StockId,
+        ex_date: NaiveDate,
+        ratio_from: i32,
+        ratio_to: i32,
+    ) -> CorporateActionDomainResult<Self> {
+        // パラメータチェック
+        if ratio_from <= 0 || ratio_to <= 0 || ratio_from == ratio_to {
+            return Err(CorporateActionDomainError::InvalidRatio {
+                from: ratio_from,
+                to: ratio_to,
+            });
+        }
+        let action_type = if ratio_to > ratio_from {
+            CorporateActionType::Split
+        } else {
+            CorporateActionType::ReverseSplit
+        };
+
+        Ok(Self {
+            stock_id,
+            ex_date,
+            action_type,
+            ratio_from,
+            ratio_to,
+        })
+    }
+
+    pub fn stock_id(&self) -> &StockId {
+        &self.stock_id
+    }
+
+    pub fn ex_date(&self) -> &NaiveDate {
+        &self.ex_date
+    }
+
+    pub fn action_type(&self) -> CorporateActionType {
+        self.action_type
+    }
+
+    pub fn ratio_from(&self) -> i32 {
+        self.ratio_from
+    }
+
+    pub fn ratio_to(&self) -> i32 {
+        self.ratio_to
+    }
+}
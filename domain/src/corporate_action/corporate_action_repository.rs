@@ -0,0 +1,15 @@
+use crate::{
+    corporate_action::{CorporateAction, CorporateActionDomainResult},
+    stock::StockId,
+};
+
+#[async_trait::async_trait]
+pub trait CorporateActionRepository {
+    /// 同じ銘柄・権利落ち日のコーポレートアクションは上書きする
+    async fn save(&self, corporate_action: CorporateAction) -> CorporateActionDomainResult<()>;
+    /// 権利落ち日の昇順で取得する
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+    ) -> CorporateActionDomainResult<Vec<CorporateAction>>;
+}
@@ -0,0 +1,18 @@
+/// コーポレートアクションの種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CorporateActionType {
+    /// 株式分割
+    #[default]
+    Split,
+    /// 株式併合
+    ReverseSplit,
+}
+
+impl CorporateActionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorporateActionType::Split => "split",
+            CorporateActionType::ReverseSplit => "reverse_split",
+        }
+    }
+}
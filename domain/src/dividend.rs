@@ -0,0 +1,8 @@
+mod dividend_domain_error;
+mod dividend_model;
+mod dividend_repository;
+
+pub use dividend_domain_error::DividendDomainError;
+pub use dividend_domain_error::DividendDomainResult;
+pub use dividend_model::Dividend;
+pub use dividend_repository::DividendRepository;
@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DividendDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid dividend amount: {0}")]
+    InvalidAmount(Decimal),
+    #[error("invalid order of dates: {name}={value}")]
+    InvalidDate {
+        name: &'static str,
+        value: NaiveDate,
+    },
+}
+
+pub type DividendDomainResult<T> = Result<T, DividendDomainError>;
@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+
+use crate::{
+    dividend::{DividendDomainError, DividendDomainResult},
+    money::Price,
+    stock::StockId,
+};
+
+/// 1株当たり配当金
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Dividend {
+    stock_id: StockId,
+    /// 権利落ち日(前営業日までに保有している株数が対象)
+    ex_date: NaiveDate,
+    /// 権利確定日
+    record_date: NaiveDate,
+    /// 支払開始日
+    payment_date: NaiveDate,
+    /// 1株当たり配当金(円)
+    amount_per_share: Price,
+}
+
+impl Dividend {
+    /// コンストラクタ
+    pub fn new(
+        stock_id: StockId,
+        ex_date: NaiveDate,
+        record_date: NaiveDate,
+        payment_date: NaiveDate,
+        amount_per_share: Price,
+    ) -> DividendDomainResult<Self> {
+        // パラメータチェック
+        if amount_per_share.is_zero() {
+            return Err(DividendDomainError::InvalidAmount(*amount_per_share));
+        }
+        if record_date < ex_date {
+            return Err(DividendDomainError::InvalidDate {
+                name: "record_date",
+                value: record_date,
+            });
+        }
+        if payment_date < record_date {
+            return Err(DividendDomainError::InvalidDate {
+                name: "payment_date",
+                value: payment_date,
+            });
+        }
+
+        Ok(Self {
+            stock_id,
+            ex_date,
+            record_date,
+            payment_date,
+            amount_per_share,
+        })
+    }
+
+    pub fn stock_id(&self) -> &StockId {
+        &self.stock_id
+    }
+
+    pub fn ex_date(&self) -> &NaiveDate {
+        &self.ex_date
+    }
+
+    pub fn record_date(&self) -> &NaiveDate {
+        &self.record_date
+    }
+
+    pub fn payment_date(&self) -> &NaiveDate {
+        &self.payment_date
+    }
+
+    pub fn amount_per_share(&self) -> Price {
+        self.amount_per_share
+    }
+}
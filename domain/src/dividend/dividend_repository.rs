@@ -0,0 +1,12 @@
+use crate::{
+    dividend::{Dividend, DividendDomainResult},
+    stock::StockId,
+};
+
+#[async_trait::async_trait]
+pub trait DividendRepository {
+    /// 同じ銘柄・権利落ち日の配当は上書きする
+    async fn save(&self, dividend: Dividend) -> DividendDomainResult<()>;
+    /// 権利落ち日の昇順で取得する
+    async fn find_all(&self, stock_id: &StockId) -> DividendDomainResult<Vec<Dividend>>;
+}
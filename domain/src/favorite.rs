@@ -1,8 +1,12 @@
 mod favorite_model;
 mod favorite_repository;
 mod favorite_domain_error;
+mod watchlist_id;
+mod watchlist_model;
 
 pub use favorite_model::Favorite;
 pub use favorite_repository::FavoriteRepository;
 pub use favorite_domain_error::FavoriteDomainError;
-pub use favorite_domain_error::FavoriteDomainResult;
\ No newline at end of file
+pub use favorite_domain_error::FavoriteDomainResult;
+pub use watchlist_id::WatchlistId;
+pub use watchlist_model::Watchlist;
@@ -4,6 +4,12 @@ use thiserror::Error;
 pub enum FavoriteDomainError {
     #[error(transparent)]
     Disconnect(#[from] anyhow::Error),
+    #[error("invalid watchlist name: {0}")]
+    InvalidWatchlistName(String),
+    #[error("invalid note: {0}")]
+    InvalidNote(String),
+    #[error("invalid tag: {0}")]
+    InvalidTag(String),
 }
 
 pub type FavoriteDomainResult<T> = Result<T, FavoriteDomainError>;
@@ -1,8 +1,81 @@
-use crate::{stock::StockId, user::UserId};
+use chrono::NaiveDateTime;
+
+use crate::{
+    favorite::{FavoriteDomainError, FavoriteDomainResult, WatchlistId},
+    stock::StockId,
+    user::UserId,
+};
+
+/// メモの最大文字数
+const MAX_NOTE_LENGTH: usize = 500;
+/// タグの最大文字数
+const MAX_TAG_LENGTH: usize = 20;
+/// 1銘柄に設定できるタグの最大数
+const MAX_TAG_COUNT: usize = 10;
 
 /// お気に入り登録情報
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Favorite {
+    pub watchlist_id: WatchlistId,
     pub user_id: UserId,
     pub stock_id: StockId,
+    /// ウォッチリスト内の表示順(昇順)
+    pub sort_order: i32,
+    pub note: String,
+    pub tags: Vec<String>,
+    /// 登録日時(UTC)
+    pub added_at: NaiveDateTime,
+}
+
+impl Favorite {
+    /// コンストラクタ
+    pub fn new(
+        watchlist_id: WatchlistId,
+        user_id: UserId,
+        stock_id: StockId,
+        added_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            watchlist_id,
+            user_id,
+            stock_id,
+            added_at,
+            ..Default::default()
+        }
+    }
+
+    pub fn update_note(&mut self, note: String) -> FavoriteDomainResult<()> {
+        // パラメータチェック
+        let note = note.trim();
+        if note.chars().count() > MAX_NOTE_LENGTH {
+            return Err(FavoriteDomainError::InvalidNote(note.to_string()));
+        }
+        self.note = note.to_string();
+
+        Ok(())
+    }
+
+    /// 前後の空白を除き、空のタグと重複は取り除く
+    pub fn update_tags(&mut self, tags: Vec<String>) -> FavoriteDomainResult<()> {
+        let mut result: Vec<String> = Vec::new();
+        for tag in tags
+            .iter()
+            .map(|tag| tag.trim())
+            .filter(|tag| !tag.is_empty())
+        {
+            // パラメータチェック
+            if tag.chars().count() > MAX_TAG_LENGTH {
+                return Err(FavoriteDomainError::InvalidTag(tag.to_string()));
+            }
+            if !result.iter().any(|t| t == tag) {
+                result.push(tag.to_string());
+            }
+        }
+        if result.len() > MAX_TAG_COUNT {
+            return Err(FavoriteDomainError::InvalidTag(result.join(",")));
+        }
+        self.tags = result;
+
+        Ok(())
+    }
 }
@@ -1,11 +1,30 @@
 use crate::{
-    favorite::{Favorite, FavoriteDomainResult},
+    favorite::{Favorite, FavoriteDomainResult, Watchlist, WatchlistId},
+    stock::StockId,
     user::UserId,
 };
 
 #[async_trait::async_trait]
 pub trait FavoriteRepository {
+    /// IDが未採番の場合は新規登録し、採番したIDを返す
+    async fn save_watchlist(&self, watchlist: Watchlist) -> FavoriteDomainResult<WatchlistId>;
+    /// 登録されている銘柄もあわせて削除する
+    async fn delete_watchlist(
+        &self,
+        user_id: &UserId,
+        watchlist_id: &WatchlistId,
+    ) -> FavoriteDomainResult<()>;
+    /// 登録順に取得する
+    async fn find_watchlists(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Watchlist>>;
+    /// 同じウォッチリスト・銘柄の登録情報は上書きする
     async fn save(&self, favorite: Favorite) -> FavoriteDomainResult<()>;
-    async fn delete(&self, favorite: Favorite) -> FavoriteDomainResult<()>;
-    async fn find_all(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Favorite>>;
+    async fn delete(
+        &self,
+        watchlist_id: &WatchlistId,
+        stock_id: &StockId,
+    ) -> FavoriteDomainResult<()>;
+    /// 表示順に取得する
+    async fn find_all(&self, watchlist_id: &WatchlistId) -> FavoriteDomainResult<Vec<Favorite>>;
+    /// 指定した銘柄を登録しているお気に入りを全ユーザー分取得する
+    async fn find_by_stocks(&self, stock_ids: &[StockId]) -> FavoriteDomainResult<Vec<Favorite>>;
 }
@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct WatchlistId(i32);
+
+impl WatchlistId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for WatchlistId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<WatchlistId> for i32 {
+    fn from(value: WatchlistId) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    favorite::{FavoriteDomainError, FavoriteDomainResult, WatchlistId},
+    user::UserId,
+};
+
+/// ウォッチリスト名の最大文字数
+const MAX_NAME_LENGTH: usize = 50;
+
+/// お気に入り銘柄をまとめるウォッチリスト
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Watchlist {
+    /// 未登録の場合はNone
+    id: Option<WatchlistId>,
+    user_id: UserId,
+    name: String,
+}
+
+impl Watchlist {
+    /// コンストラクタ
+    pub fn new(
+        id: Option<WatchlistId>,
+        user_id: UserId,
+        name: String,
+    ) -> FavoriteDomainResult<Self> {
+        let mut watchlist = Self {
+            id,
+            user_id,
+            name: String::new(),
+        };
+        watchlist.rename(name)?;
+
+        Ok(watchlist)
+    }
+
+    /// 登録時に採番したIDを設定する
+    pub fn with_id(self, id: WatchlistId) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    pub fn rename(&mut self, name: String) -> FavoriteDomainResult<()> {
+        // パラメータチェック
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(FavoriteDomainError::InvalidWatchlistName(name.to_string()));
+        }
+        self.name = name.to_string();
+
+        Ok(())
+    }
+
+    pub fn id(&self) -> Option<WatchlistId> {
+        self.id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
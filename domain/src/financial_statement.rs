@@ -0,0 +1,10 @@
+mod financial_statement_domain_error;
+mod financial_statement_model;
+mod financial_statement_repository;
+mod period_type;
+
+pub use financial_statement_domain_error::FinancialStatementDomainError;
+pub use financial_statement_domain_error::FinancialStatementDomainResult;
+pub use financial_statement_model::FinancialStatement;
+pub use financial_statement_repository::FinancialStatementRepository;
+pub use period_type::PeriodType;
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FinancialStatementDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid period type: {0}")]
+    InvalidPeriodType(String),
+}
+
+pub type FinancialStatementDomainResult<T> = Result<T, FinancialStatementDomainError>;
@@ -0,0 +1,48 @@
+use chrono::NaiveDate;
+
+use crate::{financial_statement::PeriodType, stock::StockId};
+
+/// 決算期間ごとの財務諸表の主要項目
+///
+/// 金額は円単位、開示されていない項目はNone
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FinancialStatement {
+    /// 株価ID
+    pub stock_id: StockId,
+    /// 決算期間の種別
+    pub period_type: PeriodType,
+    /// 期首日
+    pub period_start: NaiveDate,
+    /// 期末日
+    pub period_end: NaiveDate,
+    /// 売上高
+    pub revenue: Option<i64>,
+    /// 営業利益
+    pub operating_income: Option<i64>,
+    /// 親会社株主に帰属する当期純利益
+    pub net_income: Option<i64>,
+    /// 総資産
+    pub total_assets: Option<i64>,
+    /// 純資産
+    pub equity: Option<i64>,
+    /// 1株当たり当期純利益(円)
+    pub eps: Option<f64>,
+}
+
+impl FinancialStatement {
+    /// コンストラクタ
+    pub fn new(
+        stock_id: StockId,
+        period_type: PeriodType,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Self {
+        Self {
+            stock_id,
+            period_type,
+            period_start,
+            period_end,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    financial_statement::{FinancialStatement, FinancialStatementDomainResult, PeriodType},
+    stock::StockId,
+};
+
+#[async_trait::async_trait]
+pub trait FinancialStatementRepository {
+    /// 同じ銘柄・期間種別・期末日の財務諸表は上書きする
+    async fn save(&self, statement: FinancialStatement) -> FinancialStatementDomainResult<()>;
+    /// 期末日の昇順で取得する
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+        period_type: PeriodType,
+    ) -> FinancialStatementDomainResult<Vec<FinancialStatement>>;
+}
@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use crate::financial_statement::FinancialStatementDomainError;
+
+/// 決算期間の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PeriodType {
+    /// 通期(有価証券報告書)
+    #[default]
+    Annual,
+    /// 四半期・中間期(期首からの累計)
+    Quarterly,
+}
+
+impl PeriodType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PeriodType::Annual => "annual",
+            PeriodType::Quarterly => "quarterly",
+        }
+    }
+}
+
+impl FromStr for PeriodType {
+    type Err = FinancialStatementDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "annual" => Ok(PeriodType::Annual),
+            "quarterly" => Ok(PeriodType::Quarterly),
+            _ => Err(FinancialStatementDomainError::InvalidPeriodType(
+                s.to_string(),
+            )),
+        }
+    }
+}
@@ -1,4 +1,11 @@
+pub mod alert;
+pub mod corporate_action;
+pub mod dividend;
 pub mod favorite;
+pub mod financial_statement;
+pub mod money;
 pub mod portfolio;
 pub mod stock;
+pub mod trading_calendar;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,10 @@
+mod money_domain_error;
+mod money_model;
+mod price;
+mod quantity;
+
+pub use money_domain_error::MoneyDomainError;
+pub use money_domain_error::MoneyDomainResult;
+pub use money_model::Money;
+pub use price::Price;
+pub use quantity::Quantity;
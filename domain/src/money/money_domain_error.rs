@@ -0,0 +1,14 @@
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MoneyDomainError {
+    #[error("invalid price: {0}")]
+    InvalidPrice(Decimal),
+    #[error("invalid quantity: {0}")]
+    InvalidQuantity(i64),
+    #[error("amount overflow: {0}")]
+    Overflow(String),
+}
+
+pub type MoneyDomainResult<T> = Result<T, MoneyDomainError>;
@@ -0,0 +1,130 @@
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Deref, Neg, Sub, SubAssign},
+};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::money::{MoneyDomainError, MoneyDomainResult, Price, Quantity};
+
+/// 金額(円)
+///
+/// 損益を表すため負の値も取り得る
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+pub struct Money(Decimal);
+
+impl Money {
+    /// 按分した金額を丸める小数点以下の桁数
+    pub const SCALE: u32 = 2;
+
+    /// コンストラクタ
+    pub fn new(value: Decimal) -> Self {
+        Self(value)
+    }
+
+    /// 加算結果が上限を超える場合はエラー
+    pub fn checked_add(self, rhs: Self) -> MoneyDomainResult<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| MoneyDomainError::Overflow(format!("{self} + {rhs}")))
+    }
+
+    /// 減算結果が下限を超える場合はエラー
+    pub fn checked_sub(self, rhs: Self) -> MoneyDomainResult<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| MoneyDomainError::Overflow(format!("{self} - {rhs}")))
+    }
+
+    /// totalのうちquantity分の金額を按分する(小数点以下SCALE桁未満四捨五入)
+    pub fn prorate(&self, quantity: Quantity, total: Quantity) -> Self {
+        if *total == 0 {
+            return Self::default();
+        }
+        // 乗算が上限を超える場合は先に除算する
+        let (quantity, total) = (Decimal::from(*quantity), Decimal::from(*total));
+        let value = self
+            .0
+            .checked_mul(quantity)
+            .map_or_else(|| self.0 / total * quantity, |value| value / total);
+        Self(
+            value
+                .round_dp_with_strategy(Self::SCALE, RoundingStrategy::MidpointAwayFromZero)
+                .normalize(),
+        )
+    }
+
+    /// 1株当たりの価格(株数が0または金額が負の場合は0円)
+    pub fn per_unit(&self, quantity: Quantity) -> Price {
+        if *quantity == 0 {
+            return Price::default();
+        }
+        Price::new(self.0 / Decimal::from(*quantity))
+            .map(Price::round)
+            .unwrap_or_default()
+    }
+}
+
+impl Deref for Money {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Money> for Decimal {
+    fn from(value: Money) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Add for Money {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
@@ -0,0 +1,90 @@
+use std::{fmt, ops::Deref};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::money::{Money, MoneyDomainError, MoneyDomainResult, Quantity};
+
+/// 1株当たりの価格(円)
+///
+/// 呼値の単位や修正株価による1円未満の端数を保持する
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+pub struct Price(Decimal);
+
+impl Price {
+    /// 計算した価格を丸める小数点以下の桁数
+    pub const SCALE: u32 = 2;
+
+    /// コンストラクタ
+    pub fn new(value: Decimal) -> MoneyDomainResult<Self> {
+        // パラメータチェック
+        if value < Decimal::ZERO {
+            return Err(MoneyDomainError::InvalidPrice(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// 小数点以下SCALE桁未満を四捨五入する
+    pub fn round(self) -> Self {
+        Self(
+            self.0
+                .round_dp_with_strategy(Self::SCALE, RoundingStrategy::MidpointAwayFromZero)
+                .normalize(),
+        )
+    }
+
+    /// 価格×株数の金額(上限を超える場合はエラー)
+    pub fn checked_mul(self, rhs: Quantity) -> MoneyDomainResult<Money> {
+        self.0
+            .checked_mul(Decimal::from(*rhs))
+            .map(Money::new)
+            .ok_or_else(|| MoneyDomainError::Overflow(format!("{self} * {rhs}")))
+    }
+}
+
+impl Deref for Price {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Price> for Decimal {
+    fn from(value: Price) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::money::{MoneyDomainError, Price, Quantity};
+
+    #[test]
+    fn checked_mul() -> anyhow::Result<()> {
+        let price = Price::new(Decimal::new(12345, 1))?;
+
+        assert!(*price.checked_mul(Quantity::new(100)?)? == Decimal::from(123450));
+
+        Ok(())
+    }
+
+    #[test]
+    fn checked_mul_overflow() -> anyhow::Result<()> {
+        let price = Price::new(Decimal::MAX)?;
+
+        assert!(matches!(
+            price.checked_mul(Quantity::new(2)?),
+            Err(MoneyDomainError::Overflow(_))
+        ));
+
+        Ok(())
+    }
+}
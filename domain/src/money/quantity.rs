@@ -0,0 +1,69 @@
+use std::{fmt, ops::Deref};
+
+use crate::money::{MoneyDomainError, MoneyDomainResult};
+
+/// 株数・出来高
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+pub struct Quantity(i64);
+
+impl Quantity {
+    /// コンストラクタ
+    pub fn new(value: i64) -> MoneyDomainResult<Self> {
+        // パラメータチェック
+        if value < 0 {
+            return Err(MoneyDomainError::InvalidQuantity(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// 減算結果が負になる場合はNone
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 - rhs.0).ok()
+    }
+
+    /// 加算結果が上限を超える場合はエラー
+    pub fn checked_add(self, rhs: Self) -> MoneyDomainResult<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| MoneyDomainError::Overflow(format!("{self} + {rhs}")))
+    }
+}
+
+impl Deref for Quantity {
+    type Target = i64;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Quantity> for i64 {
+    fn from(value: Quantity) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::money::{MoneyDomainError, Quantity};
+
+    #[test]
+    fn checked_add_overflow() -> anyhow::Result<()> {
+        let quantity = Quantity::new(i64::MAX)?;
+
+        assert!(*quantity.checked_add(Quantity::default())? == i64::MAX);
+        assert!(matches!(
+            quantity.checked_add(Quantity::new(1)?),
+            Err(MoneyDomainError::Overflow(_))
+        ));
+
+        Ok(())
+    }
+}
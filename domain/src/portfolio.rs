@@ -1,8 +1,24 @@
+mod account_type;
+mod cost_basis_method;
+mod portfolio_account_model;
 mod portfolio_domain_error;
+mod portfolio_id;
 mod portfolio_model;
 mod portfolio_repository;
+mod position;
+mod transaction_id;
+mod transaction_model;
+mod transaction_type;
 
+pub use account_type::AccountType;
+pub use cost_basis_method::CostBasisMethod;
+pub use portfolio_account_model::PortfolioAccount;
 pub use portfolio_domain_error::PortfolioDomainError;
 pub use portfolio_domain_error::PortfolioDomainResult;
+pub use portfolio_id::PortfolioId;
 pub use portfolio_model::Portfolio;
 pub use portfolio_repository::PortfolioReposotory;
+pub use position::Position;
+pub use transaction_id::TransactionId;
+pub use transaction_model::Transaction;
+pub use transaction_type::TransactionType;
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use crate::portfolio::PortfolioDomainError;
+
+/// 口座の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AccountType {
+    /// NISA口座
+    Nisa,
+    /// 特定口座
+    Tokutei,
+    /// 一般口座
+    #[default]
+    General,
+}
+
+impl AccountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountType::Nisa => "nisa",
+            AccountType::Tokutei => "tokutei",
+            AccountType::General => "general",
+        }
+    }
+}
+
+impl FromStr for AccountType {
+    type Err = PortfolioDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nisa" => Ok(AccountType::Nisa),
+            "tokutei" => Ok(AccountType::Tokutei),
+            "general" => Ok(AccountType::General),
+            _ => Err(PortfolioDomainError::InvalidAccountType(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use crate::portfolio::PortfolioDomainError;
+
+/// 取得単価の計算方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CostBasisMethod {
+    /// 移動平均法(税法上の既定)
+    #[default]
+    MovingAverage,
+    /// 先入先出法
+    Fifo,
+}
+
+impl CostBasisMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CostBasisMethod::MovingAverage => "moving_average",
+            CostBasisMethod::Fifo => "fifo",
+        }
+    }
+}
+
+impl FromStr for CostBasisMethod {
+    type Err = PortfolioDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "moving_average" => Ok(CostBasisMethod::MovingAverage),
+            "fifo" => Ok(CostBasisMethod::Fifo),
+            _ => Err(PortfolioDomainError::InvalidCostBasisMethod(s.to_string())),
+        }
+    }
+}
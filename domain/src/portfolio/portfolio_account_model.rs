@@ -0,0 +1,76 @@
+use crate::{
+    portfolio::{AccountType, PortfolioDomainError, PortfolioDomainResult, PortfolioId},
+    user::UserId,
+};
+
+/// ポートフォリオ名の最大文字数
+const MAX_NAME_LENGTH: usize = 50;
+
+/// 保有銘柄をまとめるポートフォリオ(口座ごとに作成する)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PortfolioAccount {
+    /// 未登録の場合はNone
+    id: Option<PortfolioId>,
+    user_id: UserId,
+    name: String,
+    account_type: AccountType,
+}
+
+impl PortfolioAccount {
+    /// コンストラクタ
+    pub fn new(
+        id: Option<PortfolioId>,
+        user_id: UserId,
+        name: String,
+        account_type: AccountType,
+    ) -> PortfolioDomainResult<Self> {
+        let mut account = Self {
+            id,
+            user_id,
+            name: String::new(),
+            account_type,
+        };
+        account.rename(name)?;
+
+        Ok(account)
+    }
+
+    /// 登録時に採番したIDを設定する
+    pub fn with_id(self, id: PortfolioId) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    pub fn rename(&mut self, name: String) -> PortfolioDomainResult<()> {
+        // パラメータチェック
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(PortfolioDomainError::InvalidPortfolioName(name.to_string()));
+        }
+        self.name = name.to_string();
+
+        Ok(())
+    }
+
+    pub fn update_account_type(&mut self, account_type: AccountType) {
+        self.account_type = account_type;
+    }
+
+    pub fn id(&self) -> Option<PortfolioId> {
+        self.id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+}
@@ -1,9 +1,44 @@
+use chrono::NaiveDate;
 use thiserror::Error;
 
+use crate::money::MoneyDomainError;
+
 #[derive(Error, Debug)]
 pub enum PortfolioDomainError {
     #[error(transparent)]
     Disconnect(#[from] anyhow::Error),
+    #[error("invalid portfolio: {name}={value}")]
+    InvalidPortfolio { name: &'static str, value: String },
+    #[error("invalid transaction: {name}={value}")]
+    InvalidTransaction { name: &'static str, value: String },
+    #[error("invalid transaction type: {0}")]
+    InvalidTransactionType(String),
+    #[error("invalid cost basis method: {0}")]
+    InvalidCostBasisMethod(String),
+    #[error("invalid account type: {0}")]
+    InvalidAccountType(String),
+    #[error("invalid portfolio name: {0}")]
+    InvalidPortfolioName(String),
+    #[error("sell quantity exceeds holdings: id={stock_id}, date={date}")]
+    InsufficientStock { stock_id: String, date: NaiveDate },
+    #[error("amount overflow: {0}")]
+    AmountOverflow(String),
 }
 
 pub type PortfolioDomainResult<T> = Result<T, PortfolioDomainError>;
+
+impl From<MoneyDomainError> for PortfolioDomainError {
+    fn from(value: MoneyDomainError) -> Self {
+        match value {
+            MoneyDomainError::InvalidPrice(value) => Self::InvalidTransaction {
+                name: "price",
+                value: value.to_string(),
+            },
+            MoneyDomainError::InvalidQuantity(value) => Self::InvalidTransaction {
+                name: "quantity",
+                value: value.to_string(),
+            },
+            MoneyDomainError::Overflow(value) => Self::AmountOverflow(value),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct PortfolioId(i32);
+
+impl PortfolioId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for PortfolioId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<PortfolioId> for i32 {
+    fn from(value: PortfolioId) -> Self {
+        value.0
+    }
+}
@@ -1,35 +1,62 @@
-use crate::{stock::StockId, user::UserId};
+use crate::{
+    money::{Price, Quantity},
+    portfolio::{CostBasisMethod, PortfolioId, Position},
+    stock::StockId,
+    user::UserId,
+};
 
 // ポートフォリオ情報
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Portfolio {
+    /// 所属するポートフォリオ(口座)のID
+    pub portfolio_id: PortfolioId,
     /// ユーザーID
     pub user_id: UserId,
     /// 株価ID
     pub stock_id: StockId,
     /// 保有株数
-    pub stock_count: i32,
+    pub stock_count: Quantity,
     /// 1株当たり購入価格(円)
-    pub purchase: i32,
+    pub purchase: Price,
+    /// 取得単価の計算方法
+    pub cost_basis_method: CostBasisMethod,
 }
 
 impl Portfolio {
-    pub fn new(user_id: UserId, stock_id: StockId, stock_count: i32, purchase: i32) -> Self {
+    pub fn new(
+        portfolio_id: PortfolioId,
+        user_id: UserId,
+        stock_id: StockId,
+        stock_count: Quantity,
+        purchase: Price,
+    ) -> Self {
         Self {
+            portfolio_id,
             user_id,
             stock_id,
             stock_count,
             purchase,
+            cost_basis_method: CostBasisMethod::default(),
         }
     }
 }
 
 impl Portfolio {
-    pub fn update_stock_count(&mut self, stock_count: i32) {
+    pub fn update_stock_count(&mut self, stock_count: Quantity) {
         self.stock_count = stock_count;
     }
 
-    pub fn update_purchase(&mut self, purchase: i32) {
+    pub fn update_purchase(&mut self, purchase: Price) {
         self.purchase = purchase;
     }
+
+    pub fn update_cost_basis_method(&mut self, cost_basis_method: CostBasisMethod) {
+        self.cost_basis_method = cost_basis_method;
+    }
+
+    /// 売買履歴から算出した保有状況を反映する
+    pub fn apply_position(&mut self, position: &Position) {
+        self.stock_count = position.stock_count;
+        self.purchase = position.average_cost();
+    }
 }
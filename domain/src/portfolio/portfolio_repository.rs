@@ -1,17 +1,54 @@
 use crate::{
-    portfolio::{Portfolio, PortfolioDomainResult},
+    portfolio::{
+        Portfolio, PortfolioAccount, PortfolioDomainResult, PortfolioId, Transaction, TransactionId,
+    },
     stock::StockId,
     user::UserId,
 };
 
 #[async_trait::async_trait]
 pub trait PortfolioReposotory {
+    /// ポートフォリオ(口座)を登録・更新し、登録したIDを返す
+    async fn save_account(&self, account: PortfolioAccount) -> PortfolioDomainResult<PortfolioId>;
+    /// ポートフォリオ(口座)を保有銘柄・売買履歴ごと削除する
+    async fn delete_account(
+        &self,
+        user_id: &UserId,
+        portfolio_id: &PortfolioId,
+    ) -> PortfolioDomainResult<()>;
+    /// 登録順に取得する
+    async fn find_accounts(&self, user_id: &UserId)
+        -> PortfolioDomainResult<Vec<PortfolioAccount>>;
     async fn save(&self, portfolio: Portfolio) -> PortfolioDomainResult<()>;
-    async fn delete(&self, user_id: &UserId, stock_id: &StockId) -> PortfolioDomainResult<()>;
-    async fn find_all(&self, user_id: &UserId) -> PortfolioDomainResult<Vec<Portfolio>>;
+    async fn delete(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<()>;
+    async fn find_all(&self, portfolio_id: &PortfolioId) -> PortfolioDomainResult<Vec<Portfolio>>;
     async fn find(
         &self,
-        user_id: &UserId,
+        portfolio_id: &PortfolioId,
         stock_id: &StockId,
     ) -> PortfolioDomainResult<Option<Portfolio>>;
+    /// 売買履歴を登録・更新し、再計算した保有銘柄と同一トランザクションで保存する
+    ///
+    /// 売買履歴のIDを指定した順に返す
+    async fn save_transactions(
+        &self,
+        portfolio: Portfolio,
+        transactions: Vec<Transaction>,
+    ) -> PortfolioDomainResult<Vec<TransactionId>>;
+    /// 売買履歴を削除し、再計算した保有銘柄と同一トランザクションで保存する
+    async fn delete_transaction(
+        &self,
+        portfolio: Portfolio,
+        transaction_id: &TransactionId,
+    ) -> PortfolioDomainResult<()>;
+    /// 約定日順に取得する
+    async fn find_transactions(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<Vec<Transaction>>;
 }
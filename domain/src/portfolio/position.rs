@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use crate::{
+    corporate_action::CorporateAction,
+    money::{Money, Price, Quantity},
+    portfolio::{
+        CostBasisMethod, PortfolioDomainError, PortfolioDomainResult, Transaction, TransactionType,
+    },
+};
+
+/// 売買履歴から算出した保有状況
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Position {
+    /// 保有株数
+    pub stock_count: Quantity,
+    /// 保有株の取得価額(手数料込み, 円)
+    pub acquisition_cost: Money,
+    /// 実現損益(売却手数料控除後, 円)
+    pub realized_gain: Money,
+}
+
+impl Position {
+    /// 売買履歴と株式分割・併合を約定日・権利落ち日順に集計する
+    ///
+    /// 同じ約定日の取引は登録順に処理し、保有株数を超える売却はエラーとする。
+    /// 株式分割・併合は権利落ち日以降の取引より先に株数へ反映し(端数切り捨て)、取得価額は変えない。
+    /// 集計時点より後に権利落ちする株式分割・併合は呼び出し側で除いておくこと
+    pub fn calculate(
+        transactions: &[Transaction],
+        corporate_actions: &[CorporateAction],
+        method: CostBasisMethod,
+    ) -> PortfolioDomainResult<Self> {
+        let mut transactions = transactions.iter().collect::<Vec<&Transaction>>();
+        // 未登録の取引は同じ約定日の最後に処理する
+        transactions.sort_by_key(|t| (*t.date(), t.id().map_or(i32::MAX, |id| *id)));
+        let mut corporate_actions = corporate_actions.iter().collect::<Vec<&CorporateAction>>();
+        corporate_actions.sort_by_key(|action| *action.ex_date());
+        let mut corporate_actions = corporate_actions.into_iter().peekable();
+
+        let mut position = Self::default();
+        // 先入先出法で用いる買付単位ごとの(株数, 取得価額)
+        let mut lots: VecDeque<(Quantity, Money)> = VecDeque::new();
+        for transaction in transactions {
+            while let Some(action) =
+                corporate_actions.next_if(|action| action.ex_date() <= transaction.date())
+            {
+                position.split(&mut lots, action)?;
+            }
+            let quantity = transaction.quantity();
+            let amount = transaction.price().checked_mul(quantity)?;
+            match transaction.transaction_type() {
+                TransactionType::Buy => {
+                    let cost = amount.checked_add(transaction.fee())?;
+                    position.stock_count = position.stock_count.checked_add(quantity)?;
+                    position.acquisition_cost = position.acquisition_cost.checked_add(cost)?;
+                    lots.push_back((quantity, cost));
+                }
+                TransactionType::Sell => {
+                    let Some(stock_count) = position.stock_count.checked_sub(quantity) else {
+                        return Err(PortfolioDomainError::InsufficientStock {
+                            stock_id: transaction.stock_id().to_string(),
+                            date: *transaction.date(),
+                        });
+                    };
+                    let cost = match method {
+                        CostBasisMethod::MovingAverage => position
+                            .acquisition_cost
+                            .prorate(quantity, position.stock_count),
+                        CostBasisMethod::Fifo => consume_lots(&mut lots, quantity),
+                    };
+                    position.stock_count = stock_count;
+                    position.acquisition_cost = position.acquisition_cost.checked_sub(cost)?;
+                    position.realized_gain = position
+                        .realized_gain
+                        .checked_add(amount.checked_sub(transaction.fee())?.checked_sub(cost)?)?;
+                }
+            }
+        }
+        for action in corporate_actions {
+            position.split(&mut lots, action)?;
+        }
+
+        Ok(position)
+    }
+
+    /// 株式分割・併合を保有株数と買付単位ごとの株数に反映する
+    fn split(
+        &mut self,
+        lots: &mut VecDeque<(Quantity, Money)>,
+        action: &CorporateAction,
+    ) -> PortfolioDomainResult<()> {
+        self.stock_count = split_quantity(self.stock_count, action)?;
+        for (quantity, _) in lots.iter_mut() {
+            *quantity = split_quantity(*quantity, action)?;
+        }
+
+        Ok(())
+    }
+
+    /// 1株当たり取得単価(小数点以下2桁未満四捨五入)
+    pub fn average_cost(&self) -> Price {
+        self.acquisition_cost.per_unit(self.stock_count)
+    }
+}
+
+/// 分割・併合後の株数(端数切り捨て)
+fn split_quantity(quantity: Quantity, action: &CorporateAction) -> PortfolioDomainResult<Quantity> {
+    let value =
+        i128::from(*quantity) * i128::from(action.ratio_to()) / i128::from(action.ratio_from());
+    let Ok(value) = i64::try_from(value) else {
+        return Err(PortfolioDomainError::AmountOverflow(format!(
+            "{quantity} * {} / {}",
+            action.ratio_to(),
+            action.ratio_from()
+        )));
+    };
+
+    Ok(Quantity::new(value)?)
+}
+
+/// 古い買付から順に売却株数分を取り崩し、その取得価額を返す
+fn consume_lots(lots: &mut VecDeque<(Quantity, Money)>, mut quantity: Quantity) -> Money {
+    let mut cost = Money::default();
+    while *quantity > 0 {
+        let Some((lot_quantity, lot_cost)) = lots.front_mut() else {
+            break;
+        };
+        if let Some(remaining) = quantity.checked_sub(*lot_quantity) {
+            quantity = remaining;
+            cost += *lot_cost;
+            lots.pop_front();
+        } else {
+            let partial = lot_cost.prorate(quantity, *lot_quantity);
+            *lot_quantity = lot_quantity.checked_sub(quantity).unwrap_or_default();
+            *lot_cost -= partial;
+            cost += partial;
+            quantity = Quantity::default();
+        }
+    }
+
+    cost
+}
@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct TransactionId(i32);
+
+impl TransactionId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for TransactionId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<TransactionId> for i32 {
+    fn from(value: TransactionId) -> Self {
+        value.0
+    }
+}
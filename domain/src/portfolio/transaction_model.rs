@@ -0,0 +1,122 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::{
+    money::{Money, Price, Quantity},
+    portfolio::{
+        PortfolioDomainError, PortfolioDomainResult, PortfolioId, TransactionId, TransactionType,
+    },
+    stock::StockId,
+    user::UserId,
+};
+
+/// 売買履歴
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Transaction {
+    /// 未登録の場合はNone
+    id: Option<TransactionId>,
+    portfolio_id: PortfolioId,
+    user_id: UserId,
+    stock_id: StockId,
+    transaction_type: TransactionType,
+    /// 約定日
+    date: NaiveDate,
+    /// 株数
+    quantity: Quantity,
+    /// 1株当たり約定価格(円)
+    price: Price,
+    /// 手数料(円)
+    fee: Money,
+}
+
+impl Transaction {
+    /// コンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Option<TransactionId>,
+        portfolio_id: PortfolioId,
+        user_id: UserId,
+        stock_id: StockId,
+        transaction_type: TransactionType,
+        date: NaiveDate,
+        quantity: Quantity,
+        price: Price,
+        fee: Money,
+    ) -> PortfolioDomainResult<Self> {
+        // パラメータチェック
+        if *quantity == 0 {
+            return Err(PortfolioDomainError::InvalidTransaction {
+                name: "quantity",
+                value: quantity.to_string(),
+            });
+        }
+        if price.is_zero() {
+            return Err(PortfolioDomainError::InvalidTransaction {
+                name: "price",
+                value: price.to_string(),
+            });
+        }
+        if *fee < Decimal::ZERO {
+            return Err(PortfolioDomainError::InvalidTransaction {
+                name: "fee",
+                value: fee.to_string(),
+            });
+        }
+
+        Ok(Self {
+            id,
+            portfolio_id,
+            user_id,
+            stock_id,
+            transaction_type,
+            date,
+            quantity,
+            price,
+            fee,
+        })
+    }
+
+    /// 登録時に採番したIDを設定する
+    pub fn with_id(self, id: TransactionId) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    pub fn id(&self) -> Option<TransactionId> {
+        self.id
+    }
+
+    pub fn portfolio_id(&self) -> PortfolioId {
+        self.portfolio_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn stock_id(&self) -> &StockId {
+        &self.stock_id
+    }
+
+    pub fn transaction_type(&self) -> TransactionType {
+        self.transaction_type
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    pub fn price(&self) -> Price {
+        self.price
+    }
+
+    pub fn fee(&self) -> Money {
+        self.fee
+    }
+}
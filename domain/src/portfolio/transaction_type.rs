@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use crate::portfolio::PortfolioDomainError;
+
+/// 売買の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransactionType {
+    /// 買付
+    #[default]
+    Buy,
+    /// 売却
+    Sell,
+}
+
+impl TransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Buy => "buy",
+            TransactionType::Sell => "sell",
+        }
+    }
+}
+
+impl FromStr for TransactionType {
+    type Err = PortfolioDomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "buy" => Ok(TransactionType::Buy),
+            "sell" => Ok(TransactionType::Sell),
+            _ => Err(PortfolioDomainError::InvalidTransactionType(s.to_string())),
+        }
+    }
+}
@@ -1,3 +1,6 @@
+mod stock_domain_error;
 mod stock_id;
 
+pub use stock_domain_error::StockDomainError;
+pub use stock_domain_error::StockDomainResult;
 pub use stock_id::StockId;
\ No newline at end of file
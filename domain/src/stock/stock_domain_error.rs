@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StockDomainError {
+    #[error("invalid stock id: {0}")]
+    InvalidStockId(String),
+}
+
+pub type StockDomainResult<T> = Result<T, StockDomainError>;
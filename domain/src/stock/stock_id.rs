@@ -1,13 +1,44 @@
 use std::ops::Deref;
 
+use crate::stock::{StockDomainError, StockDomainResult};
+
+/// 英字を含む証券コードで使われない文字(数字と紛らわしいもの)
+const EXCLUDED_LETTERS: &str = "BEIOQVZ";
+
 #[derive(PartialEq, Eq, Clone, Debug, Default)]
 pub struct StockId(String);
 
 impl StockId {
     /// コンストラクタ
-    pub fn new(value: String) -> Self {
+    ///
+    /// 東証の証券コード(数字4桁、または2桁目・4桁目に英大文字を含む4桁)のみ受け付ける
+    pub fn new(value: String) -> StockDomainResult<Self> {
+        if !Self::is_valid(&value) {
+            return Err(StockDomainError::InvalidStockId(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// 保存済みの値から復元する
+    ///
+    /// 検証を導入する前に保存したデータも読み込めるよう、形式はチェックしない
+    pub fn restore(value: String) -> Self {
         Self(value)
     }
+
+    fn is_valid(value: &str) -> bool {
+        let chars: Vec<char> = value.chars().collect();
+        if chars.len() != 4 {
+            return false;
+        }
+
+        chars.iter().enumerate().all(|(index, c)| match index {
+            0 => ('1'..='9').contains(c),
+            2 => c.is_ascii_digit(),
+            _ => c.is_ascii_digit() || (c.is_ascii_uppercase() && !EXCLUDED_LETTERS.contains(*c)),
+        })
+    }
 }
 
 impl Deref for StockId {
@@ -17,8 +48,61 @@ impl Deref for StockId {
     }
 }
 
-impl Into<String> for StockId {
-    fn into(self) -> String {
-        self.0
+impl From<StockId> for String {
+    fn from(value: StockId) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::stock::{StockDomainError, StockId};
+
+    #[test]
+    fn accept_numeric_and_alphanumeric_codes() {
+        for value in ["1301", "7203", "9999", "130A", "1A3C", "2Y4Y"] {
+            assert!(StockId::new(value.to_string()).is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_codes() -> anyhow::Result<()> {
+        for value in [
+            // 1桁目が0
+            "0301",
+            // 3桁目が英字
+            "13A1",
+            // 数字と紛らわしい英字
+            "13B1",
+            "1E31",
+            "130I",
+            "1O30",
+            "130Q",
+            "1V30",
+            "130Z",
+            // 英小文字
+            "130a",
+            // 桁数
+            "130",
+            "13011",
+            "",
+            // 全角数字
+            "１３０１",
+        ] {
+            let Err(StockDomainError::InvalidStockId(rejected)) = StockId::new(value.to_string())
+            else {
+                return Err(anyhow!("accepted invalid stock id: {value}"));
+            };
+            assert!(rejected == value);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn restore_without_validation() {
+        assert!(*StockId::restore("0000".to_string()) == "0000");
     }
 }
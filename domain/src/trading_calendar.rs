@@ -0,0 +1,8 @@
+mod japanese_holiday;
+mod trading_calendar_domain_error;
+mod trading_calendar_model;
+
+pub use japanese_holiday::is_national_holiday;
+pub use trading_calendar_domain_error::TradingCalendarDomainError;
+pub use trading_calendar_domain_error::TradingCalendarDomainResult;
+pub use trading_calendar_model::TradingCalendar;
@@ -0,0 +1,235 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// 皇室の慶弔行事などによる一度限りの祝日
+const SPECIAL_HOLIDAYS: [(i32, u32, u32); 5] = [
+    // 昭和天皇の大喪の礼
+    (1989, 2, 24),
+    // 即位礼正殿の儀
+    (1990, 11, 12),
+    // 皇太子徳仁親王の結婚の儀
+    (1993, 6, 9),
+    // 天皇の即位の日
+    (2019, 5, 1),
+    // 即位礼正殿の儀
+    (2019, 10, 22),
+];
+
+/// 国民の祝日・振替休日・国民の休日かどうか(1989年以降の祝日法に対応)
+pub fn is_national_holiday(date: NaiveDate) -> bool {
+    is_public_holiday(date) || is_substitute_holiday(date) || is_citizens_holiday(date)
+}
+
+/// 国民の祝日(振替休日・国民の休日を除く)
+fn is_public_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let (month, day) = (date.month(), date.day());
+
+    if SPECIAL_HOLIDAYS.contains(&(year, month, day)) {
+        return true;
+    }
+
+    match month {
+        // 元日, 成人の日
+        1 => day == 1 || (year >= 2000 && is_nth_monday(date, 2)) || (year < 2000 && day == 15),
+        // 建国記念の日, 天皇誕生日
+        2 => day == 11 || (year >= 2020 && day == 23),
+        // 春分の日
+        3 => vernal_equinox_day(year) == Some(day),
+        // 昭和の日(1989年から2006年はみどりの日, 1988年以前は天皇誕生日)
+        4 => day == 29,
+        // 憲法記念日, みどりの日(2006年以前は国民の休日), こどもの日
+        5 => day == 3 || (year >= 2007 && day == 4) || day == 5,
+        // 海の日, スポーツの日(東京オリンピックの開催に伴う移動)
+        7 => match year {
+            2020 => day == 23 || day == 24,
+            2021 => day == 22 || day == 23,
+            2003.. => is_nth_monday(date, 3),
+            1996..=2002 => day == 20,
+            _ => false,
+        },
+        // 山の日
+        8 => match year {
+            2020 => day == 10,
+            2021 => day == 8,
+            2016.. => day == 11,
+            _ => false,
+        },
+        // 敬老の日, 秋分の日
+        9 => {
+            (year >= 2003 && is_nth_monday(date, 3))
+                || (year < 2003 && day == 15)
+                || autumnal_equinox_day(year) == Some(day)
+        }
+        // スポーツの日(1999年以前は体育の日)
+        10 => match year {
+            2020 | 2021 => false,
+            2000.. => is_nth_monday(date, 2),
+            _ => day == 10,
+        },
+        // 文化の日, 勤労感謝の日
+        11 => day == 3 || day == 23,
+        // 天皇誕生日
+        12 => (1989..=2018).contains(&year) && day == 23,
+        _ => false,
+    }
+}
+
+/// 振替休日
+fn is_substitute_holiday(date: NaiveDate) -> bool {
+    if is_public_holiday(date) {
+        return false;
+    }
+    if date.year() >= 2007 {
+        // 日曜日の祝日以降で、直近の祝日でない日
+        let mut previous = date - Duration::days(1);
+        while is_public_holiday(previous) {
+            if previous.weekday() == Weekday::Sun {
+                return true;
+            }
+            previous -= Duration::days(1);
+        }
+        false
+    } else {
+        // 2006年以前は日曜日の祝日の翌日(月曜日)のみ
+        date.weekday() == Weekday::Mon && is_public_holiday(date - Duration::days(1))
+    }
+}
+
+/// 国民の休日(前日と翌日が国民の祝日である日)
+fn is_citizens_holiday(date: NaiveDate) -> bool {
+    date.weekday() != Weekday::Sun
+        && !is_public_holiday(date)
+        && is_public_holiday(date - Duration::days(1))
+        && is_public_holiday(date + Duration::days(1))
+}
+
+/// 第n月曜日かどうか
+fn is_nth_monday(date: NaiveDate, n: u32) -> bool {
+    date.weekday() == Weekday::Mon && (date.day() - 1) / 7 + 1 == n
+}
+
+/// 春分の日(1900年から2150年まで)
+fn vernal_equinox_day(year: i32) -> Option<u32> {
+    let base = match year {
+        1900..=1979 => 20.8357,
+        1980..=2099 => 20.8431,
+        2100..=2150 => 21.8510,
+        _ => return None,
+    };
+    Some(equinox_day(year, base))
+}
+
+/// 秋分の日(1900年から2150年まで)
+fn autumnal_equinox_day(year: i32) -> Option<u32> {
+    let base = match year {
+        1900..=1979 => 23.2588,
+        1980..=2099 => 23.2488,
+        2100..=2150 => 24.2488,
+        _ => return None,
+    };
+    Some(equinox_day(year, base))
+}
+
+/// 1980年を基準とした近似式で春分・秋分の日を求める
+fn equinox_day(year: i32, base: f64) -> u32 {
+    let elapsed = year - 1980;
+    let leap_days = if year < 1980 {
+        (year - 1983) / 4
+    } else {
+        elapsed / 4
+    };
+    (base + 0.242194 * elapsed as f64 - leap_days as f64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{autumnal_equinox_day, is_national_holiday, vernal_equinox_day};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn substitute_holiday() {
+        // 元日が日曜日
+        assert!(is_national_holiday(date(2023, 1, 2)));
+        assert!(!is_national_holiday(date(2023, 1, 3)));
+        // 2007年以降は直近の祝日でない日(こどもの日の翌日)
+        assert!(is_national_holiday(date(2008, 5, 6)));
+        assert!(!is_national_holiday(date(2008, 5, 7)));
+        // 2006年以前は日曜日の祝日の翌日のみ
+        assert!(is_national_holiday(date(2006, 1, 2)));
+        assert!(is_national_holiday(date(2003, 11, 24)));
+    }
+
+    #[test]
+    fn citizens_holiday() {
+        // 敬老の日と秋分の日に挟まれた日
+        assert!(is_national_holiday(date(2015, 9, 22)));
+        assert!(!is_national_holiday(date(2016, 9, 21)));
+        // みどりの日が祝日になる前の5月4日
+        assert!(is_national_holiday(date(2005, 5, 4)));
+        // 日曜日は国民の休日にならない
+        assert!(!is_national_holiday(date(2003, 5, 4)));
+    }
+
+    #[test]
+    fn enthronement_holidays_2019() {
+        assert!(is_national_holiday(date(2019, 5, 1)));
+        assert!(is_national_holiday(date(2019, 10, 22)));
+        // 前後の国民の休日
+        assert!(is_national_holiday(date(2019, 4, 30)));
+        assert!(is_national_holiday(date(2019, 5, 2)));
+        // 天皇誕生日の移動
+        assert!(!is_national_holiday(date(2019, 12, 23)));
+        assert!(is_national_holiday(date(2018, 12, 23)));
+        assert!(is_national_holiday(date(2020, 2, 23)));
+        assert!(is_national_holiday(date(2020, 2, 24)));
+    }
+
+    #[test]
+    fn moved_holidays_2020_2021() {
+        for holiday in [
+            date(2020, 7, 23),
+            date(2020, 7, 24),
+            date(2020, 8, 10),
+            date(2021, 7, 22),
+            date(2021, 7, 23),
+            date(2021, 8, 8),
+            date(2021, 8, 9),
+        ] {
+            assert!(is_national_holiday(holiday), "{holiday}");
+        }
+        // 本来の日付は祝日にならない
+        for weekday in [
+            date(2020, 7, 20),
+            date(2020, 8, 11),
+            date(2020, 10, 12),
+            date(2021, 7, 19),
+            date(2021, 8, 11),
+            date(2021, 10, 11),
+        ] {
+            assert!(!is_national_holiday(weekday), "{weekday}");
+        }
+        assert!(is_national_holiday(date(2022, 10, 10)));
+    }
+
+    #[test]
+    fn equinox_days() {
+        assert!(vernal_equinox_day(2023) == Some(21));
+        assert!(vernal_equinox_day(2024) == Some(20));
+        assert!(autumnal_equinox_day(2012) == Some(22));
+        assert!(autumnal_equinox_day(2023) == Some(23));
+        assert!(autumnal_equinox_day(2024) == Some(22));
+        assert!(vernal_equinox_day(2151).is_none());
+        assert!(autumnal_equinox_day(1899).is_none());
+
+        assert!(is_national_holiday(date(2024, 3, 20)));
+        assert!(!is_national_holiday(date(2024, 3, 21)));
+        assert!(is_national_holiday(date(2024, 9, 22)));
+        // 秋分の日が日曜日の場合の振替休日
+        assert!(is_national_holiday(date(2024, 9, 23)));
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TradingCalendarDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid trading calendar line {line}: {value}")]
+    InvalidFormat { line: usize, value: String },
+}
+
+pub type TradingCalendarDomainResult<T> = Result<T, TradingCalendarDomainError>;
@@ -0,0 +1,239 @@
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::trading_calendar::{
+    is_national_holiday, TradingCalendarDomainError, TradingCalendarDomainResult,
+};
+
+/// 東京証券取引所の営業日カレンダー
+///
+/// 土日・国民の祝日・年末年始(12/31〜1/3)を休場日とする
+/// 臨時休場・臨時営業などは日付ごとの上書きで指定する
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TradingCalendar {
+    /// 日付ごとの上書き(true: 営業日, false: 休場日)
+    overrides: BTreeMap<NaiveDate, bool>,
+}
+
+impl TradingCalendar {
+    /// コンストラクタ
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 上書きを記載したファイルから読み込む
+    pub fn load(path: impl AsRef<Path>) -> TradingCalendarDomainResult<Self> {
+        let text = std::fs::read_to_string(path).map_err(|e| anyhow::anyhow!(e))?;
+        text.parse()
+    }
+
+    /// 日付の営業日・休場日を上書きする
+    pub fn set_trading_day(&mut self, date: NaiveDate, is_trading_day: bool) {
+        self.overrides.insert(date, is_trading_day);
+    }
+
+    /// 営業日かどうか
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        if let Some(is_trading_day) = self.overrides.get(&date) {
+            return *is_trading_day;
+        }
+
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+            && !is_year_end_holiday(date)
+            && !is_national_holiday(date)
+    }
+
+    /// 翌営業日(指定日を含まない)
+    pub fn next_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut date = date + Duration::days(1);
+        while !self.is_trading_day(date) {
+            date += Duration::days(1);
+        }
+        date
+    }
+
+    /// 前営業日(指定日を含まない)
+    pub fn previous_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut date = date - Duration::days(1);
+        while !self.is_trading_day(date) {
+            date -= Duration::days(1);
+        }
+        date
+    }
+
+    /// n営業日後の日付(負の値はn営業日前)
+    pub fn add_trading_days(&self, date: NaiveDate, days: i64) -> NaiveDate {
+        let mut date = date;
+        for _ in 0..days.abs() {
+            date = if days > 0 {
+                self.next_trading_day(date)
+            } else {
+                self.previous_trading_day(date)
+            };
+        }
+        date
+    }
+
+    /// 期間内の営業日(開始日・終了日を含む)
+    pub fn trading_days(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .filter(|date| self.is_trading_day(*date))
+            .collect()
+    }
+
+    /// startからendまでの営業日数(startを含まずendを含む, endがstartより前の場合は負の値)
+    pub fn trading_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end < start {
+            return -self.trading_days_between(end, start);
+        }
+        if end == start {
+            return 0;
+        }
+
+        self.trading_days(start + Duration::days(1), end).len() as i64
+    }
+}
+
+impl FromStr for TradingCalendar {
+    type Err = TradingCalendarDomainError;
+
+    /// 1行に「日付,open(営業日)またはclosed(休場日)」を記載した上書きを読み込む
+    /// 空行と#以降はコメントとして無視する
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut calendar = Self::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let content = line.split('#').next().unwrap_or_default().trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            let invalid_format = || TradingCalendarDomainError::InvalidFormat {
+                line: index + 1,
+                value: line.to_string(),
+            };
+            let Some((date, status)) = content.split_once(',') else {
+                return Err(invalid_format());
+            };
+            let Ok(date) = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d") else {
+                return Err(invalid_format());
+            };
+            let is_trading_day = match status.trim() {
+                "open" => true,
+                "closed" => false,
+                _ => return Err(invalid_format()),
+            };
+
+            calendar.set_trading_day(date, is_trading_day);
+        }
+
+        Ok(calendar)
+    }
+}
+
+/// 年末年始の休場日(12/31〜1/3)
+fn is_year_end_holiday(date: NaiveDate) -> bool {
+    matches!((date.month(), date.day()), (12, 31) | (1, 1..=3))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+
+    use crate::trading_calendar::{TradingCalendar, TradingCalendarDomainError};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn year_end_holidays() {
+        let calendar = TradingCalendar::new();
+
+        assert!(calendar.is_trading_day(date(2024, 12, 30)));
+        assert!(!calendar.is_trading_day(date(2024, 12, 31)));
+        assert!(!calendar.is_trading_day(date(2025, 1, 1)));
+        assert!(!calendar.is_trading_day(date(2025, 1, 2)));
+        assert!(!calendar.is_trading_day(date(2025, 1, 3)));
+        assert!(calendar.is_trading_day(date(2025, 1, 6)));
+    }
+
+    #[test]
+    fn weekends_and_holidays() {
+        let calendar = TradingCalendar::new();
+
+        assert!(!calendar.is_trading_day(date(2024, 5, 4)));
+        assert!(!calendar.is_trading_day(date(2024, 5, 5)));
+        assert!(!calendar.is_trading_day(date(2024, 5, 6)));
+        assert!(calendar.is_trading_day(date(2024, 5, 7)));
+    }
+
+    #[test]
+    fn next_and_previous_trading_day() {
+        let calendar = TradingCalendar::new();
+
+        assert!(calendar.next_trading_day(date(2024, 12, 27)) == date(2024, 12, 30));
+        assert!(calendar.next_trading_day(date(2024, 12, 30)) == date(2025, 1, 6));
+        assert!(calendar.previous_trading_day(date(2025, 1, 6)) == date(2024, 12, 30));
+        assert!(calendar.next_trading_day(date(2024, 5, 2)) == date(2024, 5, 7));
+        assert!(calendar.previous_trading_day(date(2024, 5, 7)) == date(2024, 5, 2));
+        assert!(calendar.add_trading_days(date(2024, 12, 27), 2) == date(2025, 1, 6));
+        assert!(calendar.add_trading_days(date(2025, 1, 6), -2) == date(2024, 12, 27));
+        assert!(calendar.add_trading_days(date(2025, 1, 4), 0) == date(2025, 1, 4));
+    }
+
+    #[test]
+    fn trading_days_between() {
+        let calendar = TradingCalendar::new();
+
+        assert!(calendar.trading_days_between(date(2024, 12, 27), date(2025, 1, 6)) == 2);
+        assert!(calendar.trading_days_between(date(2025, 1, 6), date(2024, 12, 27)) == -2);
+        assert!(calendar.trading_days_between(date(2025, 1, 6), date(2025, 1, 6)) == 0);
+        // 休場日から数える
+        assert!(calendar.trading_days_between(date(2025, 1, 4), date(2025, 1, 7)) == 2);
+        assert!(
+            calendar.trading_days(date(2024, 12, 27), date(2025, 1, 6))
+                == vec![date(2024, 12, 27), date(2024, 12, 30), date(2025, 1, 6)]
+        );
+    }
+
+    #[test]
+    fn parse_overrides() -> anyhow::Result<()> {
+        let text = "# 臨時休場・臨時営業\n\
+            2024-12-30,closed\n\
+            \n\
+            2025-01-04 , open # 臨時営業\n";
+
+        let calendar: TradingCalendar = text.parse()?;
+
+        assert!(!calendar.is_trading_day(date(2024, 12, 30)));
+        assert!(calendar.is_trading_day(date(2025, 1, 4)));
+        assert!(calendar.next_trading_day(date(2024, 12, 27)) == date(2025, 1, 4));
+        assert!(calendar.trading_days_between(date(2024, 12, 27), date(2025, 1, 6)) == 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_overrides_return_err() -> anyhow::Result<()> {
+        for (text, line) in [
+            ("2024-12-30", 1),
+            ("# comment\n2024/12/30,closed", 2),
+            ("2024-12-30,closed\n\n2025-01-04,holiday", 3),
+            ("2024-02-30,closed", 1),
+        ] {
+            let Err(TradingCalendarDomainError::InvalidFormat { line: actual, .. }) =
+                text.parse::<TradingCalendar>()
+            else {
+                return Err(anyhow!("unexpected parse result: {text}"));
+            };
+            assert!(actual == line);
+        }
+
+        Ok(())
+    }
+}
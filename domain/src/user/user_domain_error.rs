@@ -10,6 +10,10 @@ pub enum UserDomainError {
     UserAlreadyExist(UserId),
     #[error("user not exist: id={0:?}")]
     UserNotFound(UserId),
+    #[error("invalid user email: {0}")]
+    InvalidUserEmail(String),
+    #[error("invalid user name: {0}")]
+    InvalidUserName(String),
 }
 
 pub type UserDomainResult<T> = Result<T, UserDomainError>;
@@ -1,13 +1,58 @@
 use std::ops::Deref;
 
+use crate::user::{UserDomainError, UserDomainResult};
+
+/// メールアドレスの最大文字数
+const MAX_LENGTH: usize = 254;
+/// ローカル部(@より前)の最大文字数
+const MAX_LOCAL_LENGTH: usize = 64;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct UserEmail(String);
 
 impl UserEmail {
     /// コンストラクタ
-    pub fn new(value: String) -> Self {
+    pub fn new(value: String) -> UserDomainResult<Self> {
+        if !Self::is_valid(&value) {
+            return Err(UserDomainError::InvalidUserEmail(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// 保存済みの値から復元する(形式はチェックしない)
+    pub fn restore(value: String) -> Self {
         Self(value)
     }
+
+    /// ローカル部@ドメイン の形式のみ受け付ける(引用符やIPアドレスのドメインは扱わない)
+    fn is_valid(value: &str) -> bool {
+        if value.len() > MAX_LENGTH {
+            return false;
+        }
+        let Some((local, domain)) = value.split_once('@') else {
+            return false;
+        };
+
+        let local_valid = !local.is_empty()
+            && local.len() <= MAX_LOCAL_LENGTH
+            && local.split('.').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c))
+            });
+        // ドメインはドットで区切った2つ以上のラベル
+        let domain_valid = domain.contains('.')
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+
+        local_valid && domain_valid
+    }
 }
 
 impl Deref for UserEmail {
@@ -16,3 +61,63 @@ impl Deref for UserEmail {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::user::{UserDomainError, UserEmail};
+
+    #[test]
+    fn accept_valid_emails() {
+        for value in [
+            "user@example.com",
+            "first.last+tag@mail.example.co.jp",
+            "a!#$%&'*+-/=?^_`{|}~@example-domain.com",
+        ] {
+            assert!(UserEmail::new(value.to_string()).is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_emails() -> anyhow::Result<()> {
+        for value in [
+            "",
+            "user",
+            "@example.com",
+            "user@",
+            "user@localhost",
+            "user@@example.com",
+            ".user@example.com",
+            "first..last@example.com",
+            "user name@example.com",
+            "user@-example.com",
+            "user@example-.com",
+            "user@example..com",
+            "ユーザー@example.com",
+        ] {
+            let Err(UserDomainError::InvalidUserEmail(rejected)) =
+                UserEmail::new(value.to_string())
+            else {
+                return Err(anyhow!("accepted invalid email: {value}"));
+            };
+            assert!(rejected == value);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn email_length_limits() {
+        // ローカル部は64文字まで
+        let local = "a".repeat(64);
+        assert!(UserEmail::new(format!("{local}@example.com")).is_ok());
+        assert!(UserEmail::new(format!("{local}a@example.com")).is_err());
+
+        // 全体は254文字まで
+        let domain = [63, 63, 63, 56].map(|length| "b".repeat(length)).join(".") + ".com";
+        assert!(domain.len() == 252);
+        assert!(UserEmail::new(format!("a@{domain}")).is_ok());
+        assert!(UserEmail::new(format!("aa@{domain}")).is_err());
+    }
+}
@@ -18,8 +18,8 @@ impl Deref for UserId {
     }
 }
 
-impl Into<String> for UserId {
-    fn into(self) -> String {
-        self.0
+impl From<UserId> for String {
+    fn from(value: UserId) -> Self {
+        value.0
     }
 }
@@ -2,12 +2,26 @@
 
 use std::ops::Deref;
 
+use crate::user::{UserDomainError, UserDomainResult};
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct UserName(String);
 
 impl UserName {
+    /// ユーザー名の最大文字数
+    pub const MAX_LENGTH: usize = 50;
+
     /// コンストラクタ
-    pub fn new(value: String) -> Self {
+    pub fn new(value: String) -> UserDomainResult<Self> {
+        if value.trim().is_empty() || value.chars().count() > Self::MAX_LENGTH {
+            return Err(UserDomainError::InvalidUserName(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// 保存済みの値から復元する(文字数はチェックしない)
+    pub fn restore(value: String) -> Self {
         Self(value)
     }
 }
@@ -19,3 +33,38 @@ impl Deref for UserName {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::user::{UserDomainError, UserName};
+
+    #[test]
+    fn name_length_limits() {
+        assert!(UserName::new("a".to_string()).is_ok());
+        // 文字数で数える
+        assert!(UserName::new("あ".repeat(UserName::MAX_LENGTH)).is_ok());
+        assert!(UserName::new("あ".repeat(UserName::MAX_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn reject_blank_name() -> anyhow::Result<()> {
+        for value in ["", " ", "\u{3000}\t"] {
+            let Err(UserDomainError::InvalidUserName(rejected)) = UserName::new(value.to_string())
+            else {
+                return Err(anyhow!("accepted blank name: {value:?}"));
+            };
+            assert!(rejected == value);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn restore_without_validation() {
+        let value = "a".repeat(UserName::MAX_LENGTH + 1);
+
+        assert!(*UserName::restore(value.clone()) == value);
+    }
+}
@@ -0,0 +1,22 @@
+mod delivery_status;
+mod webhook_delivery_id;
+mod webhook_delivery_model;
+mod webhook_domain_error;
+mod webhook_event;
+mod webhook_id;
+mod webhook_model;
+mod webhook_repository;
+mod webhook_url;
+
+pub use delivery_status::DeliveryStatus;
+pub use webhook_delivery_id::WebhookDeliveryId;
+pub use webhook_delivery_model::WebhookDelivery;
+pub use webhook_delivery_model::MAX_DELIVERY_ATTEMPTS;
+pub use webhook_domain_error::WebhookDomainError;
+pub use webhook_domain_error::WebhookDomainResult;
+pub use webhook_event::WebhookEvent;
+pub use webhook_id::WebhookId;
+pub use webhook_model::Webhook;
+pub use webhook_repository::WebhookRepository;
+pub use webhook_url::is_public_address;
+pub use webhook_url::WebhookUrl;
@@ -0,0 +1,33 @@
+use crate::webhook::{WebhookDomainError, WebhookDomainResult};
+
+/// Webhookの送信状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeliveryStatus {
+    /// 送信待ち(再送待ちを含む)
+    #[default]
+    Pending,
+    /// 送信成功
+    Succeeded,
+    /// 再送の上限に達した
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// 状態の文字列から作成する
+    pub fn new(status: &str) -> WebhookDomainResult<Self> {
+        match status {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "succeeded" => Ok(DeliveryStatus::Succeeded),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(WebhookDomainError::InvalidStatus(status.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::ops::Deref;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct WebhookDeliveryId(i32);
+
+impl WebhookDeliveryId {
+    /// コンストラクタ
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for WebhookDeliveryId {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<WebhookDeliveryId> for i32 {
+    fn from(value: WebhookDeliveryId) -> Self {
+        value.0
+    }
+}
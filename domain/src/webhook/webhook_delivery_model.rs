@@ -0,0 +1,100 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::{
+    user::UserId,
+    webhook::{DeliveryStatus, WebhookDeliveryId, WebhookEvent, WebhookId},
+};
+
+/// 送信の最大試行回数
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 8;
+/// 初回の再送間隔(秒)
+const RETRY_BASE_SECONDS: i64 = 30;
+/// 再送間隔の上限(秒)
+const RETRY_MAX_SECONDS: i64 = 6 * 60 * 60;
+
+/// Webhookの送信キュー・送信履歴
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    /// 未登録の場合はNone
+    pub id: Option<WebhookDeliveryId>,
+    pub webhook_id: WebhookId,
+    pub user_id: UserId,
+    pub event: WebhookEvent,
+    /// 送信するJSON
+    pub payload: String,
+    pub status: DeliveryStatus,
+    /// 送信を試行した回数
+    pub attempts: i32,
+    /// 次に送信する日時(UTC)
+    pub next_attempt_at: NaiveDateTime,
+    /// 最後に受信したHTTPステータスコード
+    pub last_status_code: Option<i32>,
+    /// 最後に失敗した理由
+    pub last_error: Option<String>,
+    /// 登録日時(UTC)
+    pub created_at: NaiveDateTime,
+    /// 送信に成功した日時(UTC)
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+impl WebhookDelivery {
+    /// コンストラクタ
+    pub fn new(
+        webhook_id: WebhookId,
+        user_id: UserId,
+        event: WebhookEvent,
+        payload: String,
+        now: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id: None,
+            webhook_id,
+            user_id,
+            event,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status_code: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    /// 送信に成功した
+    pub fn succeed(&mut self, status_code: i32, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Succeeded;
+        self.last_status_code = Some(status_code);
+        self.last_error = None;
+        self.delivered_at = Some(now);
+    }
+
+    /// 送信に失敗した
+    ///
+    /// 試行回数が上限に達するまでは指数的に間隔を空けて再送する
+    pub fn fail(&mut self, status_code: Option<i32>, error: String, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.last_status_code = status_code;
+        self.last_error = Some(error);
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = now + Self::retry_interval(self.attempts);
+        }
+    }
+
+    /// 再送せずに送信を打ち切る
+    pub fn discard(&mut self, error: String) {
+        self.status = DeliveryStatus::Failed;
+        self.last_error = Some(error);
+    }
+
+    /// 指定回数失敗した後の再送間隔
+    pub fn retry_interval(attempts: i32) -> Duration {
+        let exponent = (attempts - 1).clamp(0, 30) as u32;
+        let seconds = RETRY_BASE_SECONDS.saturating_mul(2_i64.saturating_pow(exponent));
+        Duration::seconds(seconds.min(RETRY_MAX_SECONDS))
+    }
+}
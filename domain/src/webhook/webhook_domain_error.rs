@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebhookDomainError {
+    #[error(transparent)]
+    Disconnect(#[from] anyhow::Error),
+    #[error("invalid webhook url: {0}")]
+    InvalidUrl(String),
+    #[error("invalid webhook event: {0}")]
+    InvalidEvent(String),
+    #[error("invalid delivery status: {0}")]
+    InvalidStatus(String),
+}
+
+pub type WebhookDomainResult<T> = Result<T, WebhookDomainError>;
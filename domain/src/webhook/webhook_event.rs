@@ -0,0 +1,32 @@
+use crate::webhook::{WebhookDomainError, WebhookDomainResult};
+
+/// Webhookで通知するイベントの種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEvent {
+    /// お気に入りの登録・更新・削除
+    Favorite,
+    /// ポートフォリオ・取引履歴の登録・更新・削除
+    Portfolio,
+    /// お気に入りに登録した銘柄の株価の取り込み
+    Price,
+}
+
+impl WebhookEvent {
+    /// 種別の文字列から作成する
+    pub fn new(kind: &str) -> WebhookDomainResult<Self> {
+        match kind {
+            "favorite" => Ok(WebhookEvent::Favorite),
+            "portfolio" => Ok(WebhookEvent::Portfolio),
+            "price" => Ok(WebhookEvent::Price),
+            _ => Err(WebhookDomainError::InvalidEvent(kind.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::Favorite => "favorite",
+            WebhookEvent::Portfolio => "portfolio",
+            WebhookEvent::Price => "price",
+        }
+    }
+}
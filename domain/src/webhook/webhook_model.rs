@@ -0,0 +1,67 @@
+use crate::{
+    user::UserId,
+    webhook::{WebhookDomainError, WebhookDomainResult, WebhookEvent, WebhookId, WebhookUrl},
+};
+
+/// Webhookの登録情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct Webhook {
+    /// 未登録の場合はNone
+    pub id: Option<WebhookId>,
+    pub user_id: UserId,
+    pub url: WebhookUrl,
+    /// 署名(HMAC-SHA256)の鍵
+    pub secret: String,
+    /// 通知するイベントの種別
+    events: Vec<WebhookEvent>,
+    /// falseの場合は通知しない
+    pub enabled: bool,
+}
+
+impl Webhook {
+    /// コンストラクタ
+    pub fn new(
+        user_id: UserId,
+        url: WebhookUrl,
+        secret: String,
+        events: Vec<WebhookEvent>,
+    ) -> WebhookDomainResult<Self> {
+        let mut webhook = Self {
+            id: None,
+            user_id,
+            url,
+            secret,
+            events: vec![],
+            enabled: true,
+        };
+        webhook.set_events(events)?;
+
+        Ok(webhook)
+    }
+
+    pub fn events(&self) -> &[WebhookEvent] {
+        &self.events
+    }
+
+    /// 通知するイベントの種別を変更する(1件以上必要)
+    pub fn set_events(&mut self, mut events: Vec<WebhookEvent>) -> WebhookDomainResult<()> {
+        // パラメータチェック
+        if events.is_empty() {
+            return Err(WebhookDomainError::InvalidEvent("".to_string()));
+        }
+        let mut unique = Vec::with_capacity(events.len());
+        for event in events.drain(..) {
+            if !unique.contains(&event) {
+                unique.push(event);
+            }
+        }
+        self.events = unique;
+
+        Ok(())
+    }
+
+    /// 指定したイベントを通知するか判定する
+    pub fn subscribes(&self, event: WebhookEvent) -> bool {
+        self.enabled && self.events.contains(&event)
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    user::UserId,
+    webhook::{Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDomainResult, WebhookId},
+};
+
+#[async_trait::async_trait]
+pub trait WebhookRepository {
+    /// IDが未採番の場合は新規登録し、採番したIDを返す
+    async fn save_webhook(&self, webhook: Webhook) -> WebhookDomainResult<WebhookId>;
+    /// 送信履歴もあわせて削除する
+    async fn delete_webhook(
+        &self,
+        user_id: &UserId,
+        webhook_id: &WebhookId,
+    ) -> WebhookDomainResult<()>;
+    /// 登録順に取得する
+    async fn find_webhooks(&self, user_id: &UserId) -> WebhookDomainResult<Vec<Webhook>>;
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> WebhookDomainResult<Option<Webhook>>;
+    /// 送信キューに登録し、採番したIDを返す
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> WebhookDomainResult<WebhookDeliveryId>;
+    /// 送信結果を更新する
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> WebhookDomainResult<()>;
+    /// 送信日時を過ぎた送信待ちを古い順に取得する
+    async fn find_pending_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>>;
+    /// 送信履歴を新しい順に取得する
+    async fn find_deliveries(
+        &self,
+        webhook_id: &WebhookId,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>>;
+}
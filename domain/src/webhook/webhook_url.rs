@@ -0,0 +1,120 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::webhook::{WebhookDomainError, WebhookDomainResult};
+
+/// URLの最大長
+const MAX_URL_LENGTH: usize = 500;
+
+/// Webhookの送信先URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookUrl(String);
+
+impl WebhookUrl {
+    /// コンストラクタ
+    ///
+    /// https のURLのみ登録できる
+    /// 内部ネットワークへ送信させないため、localhost・ループバック・プライベート・リンクローカル等のアドレスは登録できない
+    pub fn new(url: String) -> WebhookDomainResult<Self> {
+        // パラメータチェック
+        let host = url
+            .strip_prefix("https://")
+            .and_then(|rest| rest.split(['/', '?', '#']).next())
+            .map(|authority| {
+                authority
+                    .rsplit_once('@')
+                    .map_or(authority, |(_, host)| host)
+            })
+            .and_then(|authority| match authority.strip_prefix('[') {
+                Some(ipv6) => ipv6.split_once(']').map(|(host, _)| host),
+                None => authority.split(':').next(),
+            })
+            .unwrap_or_default()
+            .trim_end_matches('.')
+            .to_lowercase();
+        if host.is_empty()
+            || !is_public_host(&host)
+            || url.len() > MAX_URL_LENGTH
+            || url.chars().any(char::is_whitespace)
+        {
+            return Err(WebhookDomainError::InvalidUrl(url));
+        }
+
+        Ok(Self(url))
+    }
+
+    /// 保存済みの値から復元する
+    ///
+    /// 送信先の制限を導入する前に保存したデータも読み込めるよう、形式はチェックしない
+    /// (送信時に送信先のアドレスをチェックする)
+    pub fn restore(url: String) -> Self {
+        Self(url)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<WebhookUrl> for String {
+    fn from(value: WebhookUrl) -> Self {
+        value.0
+    }
+}
+
+/// 外部に公開されたホストか
+fn is_public_host(host: &str) -> bool {
+    if host == "localhost" || host.ends_with(".localhost") {
+        return false;
+    }
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_public_address(ip);
+    }
+    // 数値のみ・16進数のホストはIPv4アドレスとして解釈されるため登録できない
+    let last_label = host.rsplit('.').next().unwrap_or_default();
+    !(last_label.starts_with("0x") || last_label.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Webhookの送信先として許可するアドレスか
+///
+/// ループバック・プライベート・リンクローカル・未指定・マルチキャスト等の外部に公開されていないアドレスは許可しない
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ipv4) => is_public_ipv4(ipv4),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8
+        || first == 0
+        // 共有アドレス 100.64.0.0/10
+        || (first == 100 && (second & 0xc0) == 64)
+        // ベンチマーク 198.18.0.0/15
+        || (first == 198 && (second & 0xfe) == 18)
+        // 予約済み 240.0.0.0/4
+        || first >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let [first, second, ..] = ip.segments();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // ユニークローカル fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // リンクローカル fe80::/10
+        || (first & 0xffc0) == 0xfe80
+        // ドキュメント 2001:db8::/32
+        || (first == 0x2001 && second == 0x0db8))
+}
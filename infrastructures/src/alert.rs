@@ -0,0 +1,3 @@
+mod postgres_alert_repository;
+
+pub use postgres_alert_repository::PostgresAlertRepositoryImpl;
@@ -0,0 +1,233 @@
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, types::time::PrimitiveDateTime};
+
+use crate::sql_time::{from_sql_date, from_sql_datetime, into_sql_date, into_sql_datetime};
+use domain::{
+    alert::{
+        AlertCondition, AlertDomainError, AlertDomainResult, AlertEvent, AlertEventId, AlertId,
+        AlertRepository, AlertRule,
+    },
+    money::Price,
+    stock::StockId,
+    user::UserId,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresAlertRepositoryImpl {
+    connection: PgPool,
+}
+
+impl PostgresAlertRepositoryImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl AlertRepository for PostgresAlertRepositoryImpl {
+    async fn save_rule(&self, rule: AlertRule) -> AlertDomainResult<AlertId> {
+        let id = if let Some(id) = rule.id {
+            sqlx::query!(
+                r#"
+                update alert_rules set (condition, threshold, enabled) = ($3, $4, $5)
+                where id=$1 and user_id=$2
+                "#,
+                *id,
+                rule.user_id.as_str(),
+                rule.condition.as_str(),
+                rule.condition.threshold(),
+                rule.enabled,
+            )
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+            *id
+        } else {
+            sqlx::query_scalar!(
+                r#"
+                insert into alert_rules (user_id, stock_id, condition, threshold, enabled)
+                values ($1, $2, $3, $4, $5)
+                returning id
+                "#,
+                rule.user_id.as_str(),
+                rule.stock_id.as_str(),
+                rule.condition.as_str(),
+                rule.condition.threshold(),
+                rule.enabled,
+            )
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+        };
+
+        Ok(AlertId::new(id))
+    }
+
+    async fn delete_rule(&self, user_id: &UserId, rule_id: &AlertId) -> AlertDomainResult<()> {
+        sqlx::query!(
+            r#"delete from alert_rules where id=$1 and user_id=$2"#,
+            **rule_id,
+            user_id.as_str(),
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_rules(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertRule>> {
+        let result = sqlx::query_as!(
+            AlertRuleModel,
+            r#"
+            select id, user_id, stock_id, condition, threshold, enabled
+            from alert_rules
+            where user_id=$1
+            order by id
+            "#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(AlertRule::try_from).collect()
+    }
+
+    async fn find_enabled_rules(&self, stock_ids: &[StockId]) -> AlertDomainResult<Vec<AlertRule>> {
+        let stock_ids: Vec<String> = stock_ids.iter().map(|id| id.to_string()).collect();
+        let result = sqlx::query_as!(
+            AlertRuleModel,
+            r#"
+            select id, user_id, stock_id, condition, threshold, enabled
+            from alert_rules
+            where enabled and stock_id=any($1)
+            order by id
+            "#,
+            &stock_ids
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(AlertRule::try_from).collect()
+    }
+
+    async fn add_event(&self, event: AlertEvent) -> AlertDomainResult<Option<AlertEventId>> {
+        let id = sqlx::query_scalar!(
+            r#"
+            insert into alert_events
+            (rule_id, user_id, stock_id, condition, threshold, date, price, read, triggered_at)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            on conflict (rule_id, date) do nothing
+            returning id
+            "#,
+            *event.rule_id,
+            event.user_id.as_str(),
+            event.stock_id.as_str(),
+            event.condition.as_str(),
+            event.condition.threshold(),
+            into_sql_date(&event.date)?,
+            *event.price,
+            event.read,
+            into_sql_datetime(&event.triggered_at)?,
+        )
+        .fetch_optional(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(id.map(AlertEventId::new))
+    }
+
+    async fn update_event(&self, event: AlertEvent) -> AlertDomainResult<()> {
+        let Some(id) = event.id else { return Ok(()) };
+        sqlx::query!(
+            r#"update alert_events set read=$3 where id=$1 and user_id=$2"#,
+            *id,
+            event.user_id.as_str(),
+            event.read,
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_events(&self, user_id: &UserId) -> AlertDomainResult<Vec<AlertEvent>> {
+        let result = sqlx::query_as!(
+            AlertEventModel,
+            r#"
+            select id, rule_id, user_id, stock_id, condition, threshold, date, price, read, triggered_at
+            from alert_events
+            where user_id=$1
+            order by id desc
+            "#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(AlertEvent::try_from).collect()
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct AlertRuleModel {
+    id: i32,
+    user_id: String,
+    stock_id: String,
+    condition: String,
+    threshold: Option<Decimal>,
+    enabled: bool,
+}
+
+impl TryFrom<AlertRuleModel> for AlertRule {
+    type Error = AlertDomainError;
+
+    fn try_from(value: AlertRuleModel) -> Result<Self, Self::Error> {
+        Ok(AlertRule {
+            id: Some(AlertId::new(value.id)),
+            user_id: UserId::new(value.user_id),
+            stock_id: StockId::restore(value.stock_id),
+            condition: AlertCondition::new(&value.condition, value.threshold)?,
+            enabled: value.enabled,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct AlertEventModel {
+    id: i32,
+    rule_id: i32,
+    user_id: String,
+    stock_id: String,
+    condition: String,
+    threshold: Option<Decimal>,
+    date: sqlx::types::time::Date,
+    price: Decimal,
+    read: bool,
+    triggered_at: PrimitiveDateTime,
+}
+
+impl TryFrom<AlertEventModel> for AlertEvent {
+    type Error = AlertDomainError;
+
+    fn try_from(value: AlertEventModel) -> Result<Self, Self::Error> {
+        let date = from_sql_date(value.date);
+
+        Ok(AlertEvent {
+            id: Some(AlertEventId::new(value.id)),
+            rule_id: AlertId::new(value.rule_id),
+            user_id: UserId::new(value.user_id),
+            stock_id: StockId::restore(value.stock_id),
+            condition: AlertCondition::new(&value.condition, value.threshold)?,
+            date,
+            price: Price::new(value.price).map_err(|e| anyhow::anyhow!(e))?,
+            read: value.read,
+            triggered_at: from_sql_datetime(&value.triggered_at),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 use crate::auth::OICDClient;
 use applications::user::UserData;
+use domain::user::UserName;
 use presentation::auth::{OICDData, OICDError, OICDResult, OICDService};
 
 #[derive(Debug, Clone)]
@@ -36,18 +37,46 @@ impl OICDService for OICDserviceImpl {
             .oicd_client
             .verify(verify_info, code, state)
             .await
-            .map_err(|e| OICDError::VerifyError(e))?;
+            .map_err(OICDError::VerifyError)?;
 
-        let mut email = claims
+        let email = claims
             .email()
             .ok_or(OICDError::EmailNotRegisterd)?
             .to_string();
-
-        // ユーザー名はメールアドレスのドメインとする
-        let email_domain_offset = email.find('@').unwrap_or(email.len());
-        let name: String = email.drain(..email_domain_offset).collect();
         let id = claims.subject().to_string();
 
-        Ok(UserData::new(id, name, email))
+        Ok(user_data(id, email))
+    }
+}
+
+/// 認証情報のユーザー
+///
+/// ユーザー名はメールアドレスの@より前の部分とする(最大文字数を超える分は切り捨てる)
+fn user_data(id: String, email: String) -> UserData {
+    let local_part = email.split('@').next().unwrap_or_default();
+    let name: String = local_part.chars().take(UserName::MAX_LENGTH).collect();
+
+    UserData::new(id, name, email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::user_data;
+
+    #[test]
+    fn user_data_keeps_full_email() {
+        let user = user_data("1".to_string(), "hoge@example.com".to_string());
+
+        assert!(user.name == "hoge");
+        assert!(user.email == "hoge@example.com");
+    }
+
+    #[test]
+    fn user_data_truncates_long_name() {
+        let email = format!("{}@example.com", "a".repeat(60));
+        let user = user_data("1".to_string(), email.clone());
+
+        assert!(user.name == "a".repeat(50));
+        assert!(user.email == email);
     }
 }
@@ -1,3 +1,5 @@
+mod postgres_company_import_service;
 mod postgres_company_query_service;
 
+pub use postgres_company_import_service::PostgresCompanyImportServiceImpl;
 pub use postgres_company_query_service::PostgresCompanyQueryServiceImpl;
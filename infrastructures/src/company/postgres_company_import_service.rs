@@ -0,0 +1,87 @@
+use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
+
+use applications::company::{
+    normalize_search_text, CompanyData, CompanyImportResult, CompanyImportService,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresCompanyImportServiceImpl {
+    connection: PgPool,
+}
+
+impl PostgresCompanyImportServiceImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanyImportService for PostgresCompanyImportServiceImpl {
+    #[tracing::instrument(skip(self, companies), err, ret)]
+    async fn upsert(&self, companies: Vec<CompanyData>) -> CompanyImportResult<u64> {
+        if companies.is_empty() {
+            return Ok(0);
+        }
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "insert into companies (stock_id, name, sector, industry, search_name) ",
+        );
+        query.push_values(companies, |mut row, company| {
+            let search_name = normalize_search_text(&company.name);
+            row.push_bind(company.stock_id)
+                .push_bind(company.name)
+                .push_bind(company.sector)
+                .push_bind(company.industry)
+                .push_bind(search_name);
+        });
+        query.push(
+            r#"
+            on conflict (stock_id)
+            do update set (name, sector, industry, search_name)
+                = (excluded.name, excluded.sector, excluded.industry, excluded.search_name)
+            "#,
+        );
+
+        let result = query
+            .build()
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[tracing::instrument(skip(self), err, ret)]
+    async fn refresh_search_names(&self) -> CompanyImportResult<u64> {
+        let companies = sqlx::query!(r#"select stock_id, name, search_name from companies"#)
+            .fetch_all(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let (stock_ids, search_names): (Vec<String>, Vec<String>) = companies
+            .into_iter()
+            .filter_map(|company| {
+                let search_name = normalize_search_text(&company.name);
+                (search_name != company.search_name).then_some((company.stock_id, search_name))
+            })
+            .unzip();
+        if stock_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            update companies set search_name = data.search_name
+            from unnest($1::varchar[], $2::varchar[]) as data(stock_id, search_name)
+            where companies.stock_id = data.stock_id
+            "#,
+            &stock_ids,
+            &search_names
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.rows_affected())
+    }
+}
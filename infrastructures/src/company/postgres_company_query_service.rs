@@ -1,8 +1,10 @@
 use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
 
 use applications::company::{
-    CompanyData, CompanyQueryCommand, CompanyQueryResult, CompanyQueryService,
+    normalize_search_text, CompanyData, CompanyQueryCommand, CompanyQueryError, CompanyQueryResult,
+    CompanyQueryService, CompanySuggestCommand,
 };
+use domain::stock::StockId;
 
 #[derive(Clone, Debug)]
 pub struct PostgresCompanyQueryServiceImpl {
@@ -18,24 +20,32 @@ impl PostgresCompanyQueryServiceImpl {
 #[async_trait::async_trait]
 impl CompanyQueryService for PostgresCompanyQueryServiceImpl {
     async fn find(&self, param: CompanyQueryCommand) -> CompanyQueryResult<Vec<CompanyData>> {
-        let mut query: QueryBuilder<Postgres> =
-            QueryBuilder::new("select * from companies where true");
-
-        if let Some(stock_id) = &param.stock_id {
-            query.push(" and stock_id=");
-            query.push_bind(stock_id);
-        }
-        if let Some(name) = &param.name {
-            query.push(" and name=");
-            query.push_bind(name);
+        param.validate()?;
+        // ページサイズを指定しない場合は1ページに全件
+        if param.size.is_none() && param.page.unwrap_or(1) > 1 {
+            return Ok(vec![]);
         }
-        if let Some(sector) = &param.sector {
-            query.push(" and sector=");
-            query.push_bind(sector);
+
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new("select stock_id, name, sector, industry from companies where true");
+        push_conditions(&mut query, &param);
+
+        match (param.sort, &param.name) {
+            // 指定した項目順(同じ場合は証券コード順)
+            (Some(sort), _) => {
+                query.push(format!(r#" order by {} collate "C", stock_id"#, sort.as_str()));
+            }
+            // 一致度の高い順
+            (None, Some(name)) => push_rank_order(&mut query, normalize_search_text(name)),
+            (None, None) => {
+                query.push(" order by stock_id");
+            }
         }
-        if let Some(industry) = &param.industry {
-            query.push(" and industry=");
-            query.push_bind(industry);
+        if let Some(size) = param.size {
+            query.push(" limit ");
+            query.push_bind(size as i64);
+            query.push(" offset ");
+            query.push_bind((param.page.unwrap_or(1) as i64 - 1) * size as i64);
         }
 
         let query = query.build_query_as();
@@ -48,15 +58,33 @@ impl CompanyQueryService for PostgresCompanyQueryServiceImpl {
         Ok(result)
     }
 
+    async fn count(&self, param: CompanyQueryCommand) -> CompanyQueryResult<i64> {
+        param.validate()?;
+
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new("select count(*) from companies where true");
+        push_conditions(&mut query, &param);
+
+        let (count,): (i64,) = query
+            .build_query_as()
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(count)
+    }
+
     async fn find_by_id(&self, stock_id: &str) -> CompanyQueryResult<CompanyData> {
+        StockId::new(stock_id.to_string())?;
         let result = sqlx::query_as!(
             CompanyData,
-            r#"select * from companies where stock_id=$1"#,
+            r#"select stock_id, name, sector, industry from companies where stock_id=$1"#,
             stock_id,
         )
-        .fetch_one(&self.connection)
+        .fetch_optional(&self.connection)
         .await
-        .map_err(|e| anyhow::anyhow!(e))?;
+        .map_err(|e| anyhow::anyhow!(e))?
+        .ok_or(CompanyQueryError::CompanyNotFound(stock_id.to_string()))?;
 
         Ok(result)
     }
@@ -66,8 +94,9 @@ impl CompanyQueryService for PostgresCompanyQueryServiceImpl {
             return Ok(vec![]);
         }
 
-        let mut query_builder: QueryBuilder<Postgres> =
-            QueryBuilder::new("select * from companies where stock_id in (");
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "select stock_id, name, sector, industry from companies where stock_id in (",
+        );
 
         // パラメータをクエリに追加
         let mut query_params = query_builder.separated(", ");
@@ -86,6 +115,90 @@ impl CompanyQueryService for PostgresCompanyQueryServiceImpl {
         let result = result.into_iter().map(|c| c.into()).collect();
         Ok(result)
     }
+
+    async fn suggest(
+        &self,
+        command: CompanySuggestCommand,
+    ) -> CompanyQueryResult<Vec<CompanyData>> {
+        command.validate()?;
+
+        // 企業名は正規化した値、証券コードは大文字にした値と比較する
+        let keyword = normalize_search_text(&command.keyword);
+        let pattern = escape_like(&keyword);
+        let stock_id = command.stock_id_prefix();
+        let stock_id_pattern = format!("{}%", escape_like(&stock_id));
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "select stock_id, name, sector, industry from companies where search_name like ",
+        );
+        query.push_bind(format!("%{}%", pattern));
+        query.push(" or stock_id like ");
+        query.push_bind(stock_id_pattern.clone());
+        // 証券コードの前方一致は企業名の前方一致と同じ一致度
+        query.push(" order by least(case when stock_id = ");
+        query.push_bind(stock_id);
+        query.push(" then 0 when stock_id like ");
+        query.push_bind(stock_id_pattern);
+        query.push(" then 1 else 2 end, ");
+        push_rank(&mut query, keyword);
+        query.push("), char_length(name), stock_id limit ");
+        query.push_bind(command.limit as i64);
+
+        let result: Vec<CompanyModel> = query
+            .build_query_as()
+            .fetch_all(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let result = result.into_iter().map(|c| c.into()).collect();
+        Ok(result)
+    }
+}
+
+/// 検索条件をwhere句に追加する
+fn push_conditions(query: &mut QueryBuilder<Postgres>, param: &CompanyQueryCommand) {
+    if let Some(stock_id) = &param.stock_id {
+        query.push(" and stock_id=");
+        query.push_bind(stock_id.clone());
+    }
+    // 企業名は正規化して部分一致
+    if let Some(name) = &param.name {
+        query.push(" and search_name like ");
+        query.push_bind(format!("%{}%", escape_like(&normalize_search_text(name))));
+    }
+    if let Some(sector) = &param.sector {
+        query.push(" and sector=");
+        query.push_bind(sector.clone());
+    }
+    if let Some(industry) = &param.industry {
+        query.push(" and industry=");
+        query.push_bind(industry.clone());
+    }
+}
+
+/// LIKEの特殊文字をエスケープする
+fn escape_like(keyword: &str) -> String {
+    keyword
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 企業名の一致度(0: 完全一致, 1: 前方一致, 2: 部分一致)
+fn push_rank(query: &mut QueryBuilder<Postgres>, keyword: String) {
+    let prefix = format!("{}%", escape_like(&keyword));
+    query.push("case when search_name = ");
+    query.push_bind(keyword);
+    query.push(" then 0 when search_name like ");
+    query.push_bind(prefix);
+    query.push(" then 1 else 2 end");
+}
+
+/// 一致度の高い順(同じ場合は企業名の短い順)
+fn push_rank_order(query: &mut QueryBuilder<Postgres>, keyword: String) {
+    query.push(" order by ");
+    push_rank(query, keyword);
+    query.push(", char_length(name), stock_id");
 }
 
 #[derive(sqlx::FromRow, Debug, Clone)]
@@ -96,13 +209,13 @@ struct CompanyModel {
     industry: String,
 }
 
-impl Into<CompanyData> for CompanyModel {
-    fn into(self) -> CompanyData {
+impl From<CompanyModel> for CompanyData {
+    fn from(value: CompanyModel) -> Self {
         CompanyData {
-            name: self.name,
-            stock_id: self.stock_id,
-            sector: self.sector,
-            industry: self.industry,
+            name: value.name,
+            stock_id: value.stock_id,
+            sector: value.sector,
+            industry: value.industry,
         }
     }
 }
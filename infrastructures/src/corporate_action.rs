@@ -0,0 +1,3 @@
+mod postgres_corporate_action_repository;
+
+pub use postgres_corporate_action_repository::PostgresCorporateActionRepositoryImpl;
@@ -0,0 +1,89 @@
+use sqlx::postgres::PgPool;
+
+use crate::sql_time::{from_sql_date, into_sql_date};
+use domain::{
+    corporate_action::{
+        CorporateAction, CorporateActionDomainError, CorporateActionDomainResult,
+        CorporateActionRepository,
+    },
+    stock::StockId,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresCorporateActionRepositoryImpl {
+    connection: PgPool,
+}
+
+impl PostgresCorporateActionRepositoryImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl CorporateActionRepository for PostgresCorporateActionRepositoryImpl {
+    async fn save(&self, corporate_action: CorporateAction) -> CorporateActionDomainResult<()> {
+        let ex_date = into_sql_date(corporate_action.ex_date())?;
+
+        sqlx::query!(
+            r#"
+            insert into corporate_actions values ($1, $2, $3, $4, $5)
+            on conflict (stock_id, ex_date)
+            do update set (action_type, ratio_from, ratio_to) = ($3, $4, $5)
+            "#,
+            corporate_action.stock_id().as_str(),
+            ex_date,
+            corporate_action.action_type().as_str(),
+            corporate_action.ratio_from(),
+            corporate_action.ratio_to(),
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+    ) -> CorporateActionDomainResult<Vec<CorporateAction>> {
+        let result = sqlx::query_as!(
+            CorporateActionModel,
+            r#"
+            select stock_id, ex_date, ratio_from, ratio_to from corporate_actions
+            where stock_id=$1
+            order by ex_date
+            "#,
+            stock_id.as_str(),
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(CorporateAction::try_from).collect()
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct CorporateActionModel {
+    stock_id: String,
+    ex_date: sqlx::types::time::Date,
+    ratio_from: i32,
+    ratio_to: i32,
+}
+
+impl TryFrom<CorporateActionModel> for CorporateAction {
+    type Error = CorporateActionDomainError;
+
+    fn try_from(value: CorporateActionModel) -> Result<Self, Self::Error> {
+        let ex_date = from_sql_date(value.ex_date);
+
+        CorporateAction::new(
+            StockId::restore(value.stock_id),
+            ex_date,
+            value.ratio_from,
+            value.ratio_to,
+        )
+    }
+}
@@ -0,0 +1,3 @@
+mod postgres_dividend_repository;
+
+pub use postgres_dividend_repository::PostgresDividendRepositoryImpl;
@@ -0,0 +1,87 @@
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+
+use crate::sql_time::{from_sql_date, into_sql_date};
+use domain::{
+    dividend::{Dividend, DividendDomainError, DividendDomainResult, DividendRepository},
+    money::Price,
+    stock::StockId,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresDividendRepositoryImpl {
+    connection: PgPool,
+}
+
+impl PostgresDividendRepositoryImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl DividendRepository for PostgresDividendRepositoryImpl {
+    async fn save(&self, dividend: Dividend) -> DividendDomainResult<()> {
+        let ex_date = into_sql_date(dividend.ex_date())?;
+        let record_date = into_sql_date(dividend.record_date())?;
+        let payment_date = into_sql_date(dividend.payment_date())?;
+
+        sqlx::query!(
+            r#"
+            insert into dividends values ($1, $2, $3, $4, $5)
+            on conflict (stock_id, ex_date)
+            do update set (record_date, payment_date, amount_per_share) = ($3, $4, $5)
+            "#,
+            dividend.stock_id().as_str(),
+            ex_date,
+            record_date,
+            payment_date,
+            *dividend.amount_per_share(),
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_all(&self, stock_id: &StockId) -> DividendDomainResult<Vec<Dividend>> {
+        let result = sqlx::query_as!(
+            DividendModel,
+            r#"
+            select stock_id, ex_date, record_date, payment_date, amount_per_share from dividends
+            where stock_id=$1
+            order by ex_date
+            "#,
+            stock_id.as_str(),
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(Dividend::try_from).collect()
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct DividendModel {
+    stock_id: String,
+    ex_date: sqlx::types::time::Date,
+    record_date: sqlx::types::time::Date,
+    payment_date: sqlx::types::time::Date,
+    amount_per_share: Decimal,
+}
+
+impl TryFrom<DividendModel> for Dividend {
+    type Error = DividendDomainError;
+
+    fn try_from(value: DividendModel) -> Result<Self, Self::Error> {
+        Dividend::new(
+            StockId::restore(value.stock_id),
+            from_sql_date(value.ex_date),
+            from_sql_date(value.record_date),
+            from_sql_date(value.payment_date),
+            Price::new(value.amount_per_share).map_err(|e| anyhow::anyhow!(e))?,
+        )
+    }
+}
@@ -1,7 +1,11 @@
-use sqlx::postgres::PgPool;
+use sqlx::{postgres::PgPool, types::time::PrimitiveDateTime};
 
+use crate::sql_time::{from_sql_datetime, into_sql_datetime};
 use domain::{
-    favorite::{Favorite, FavoriteDomainResult, FavoriteRepository},
+    favorite::{
+        Favorite, FavoriteDomainError, FavoriteDomainResult, FavoriteRepository, Watchlist,
+        WatchlistId,
+    },
     stock::StockId,
     user::UserId,
 };
@@ -19,13 +23,79 @@ impl PostgresFavoriteRepositoryImpl {
 
 #[async_trait::async_trait]
 impl FavoriteRepository for PostgresFavoriteRepositoryImpl {
+    async fn save_watchlist(&self, watchlist: Watchlist) -> FavoriteDomainResult<WatchlistId> {
+        let id = if let Some(id) = watchlist.id() {
+            sqlx::query!(
+                r#"update watchlists set name=$3 where id=$1 and user_id=$2"#,
+                *id,
+                watchlist.user_id().as_str(),
+                watchlist.name(),
+            )
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+            *id
+        } else {
+            sqlx::query_scalar!(
+                r#"insert into watchlists (user_id, name) values ($1, $2) returning id"#,
+                watchlist.user_id().as_str(),
+                watchlist.name(),
+            )
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+        };
+
+        Ok(WatchlistId::new(id))
+    }
+
+    async fn delete_watchlist(
+        &self,
+        user_id: &UserId,
+        watchlist_id: &WatchlistId,
+    ) -> FavoriteDomainResult<()> {
+        sqlx::query!(
+            r#"delete from watchlists where id=$1 and user_id=$2"#,
+            **watchlist_id,
+            user_id.as_str(),
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_watchlists(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Watchlist>> {
+        let result = sqlx::query_as!(
+            WatchlistModel,
+            r#"select id, user_id, name from watchlists where user_id=$1 order by id"#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(Watchlist::try_from).collect()
+    }
+
     async fn save(&self, favorite: Favorite) -> FavoriteDomainResult<()> {
         sqlx::query!(
             r#"
-            insert into favorites values ($1, $2)
+            insert into favorites
+            (watchlist_id, user_id, stock_id, sort_order, note, tags, added_at)
+            values ($1, $2, $3, $4, $5, $6, $7)
+            on conflict (watchlist_id, stock_id)
+            do update set (sort_order, note, tags) = ($4, $5, $6)
             "#,
+            *favorite.watchlist_id,
             favorite.user_id.as_str(),
             favorite.stock_id.as_str(),
+            favorite.sort_order,
+            favorite.note,
+            &favorite.tags,
+            into_sql_datetime(&favorite.added_at)?,
         )
         .execute(&self.connection)
         .await
@@ -34,10 +104,15 @@ impl FavoriteRepository for PostgresFavoriteRepositoryImpl {
         Ok(())
     }
 
-    async fn delete(&self, favorite: Favorite) -> FavoriteDomainResult<()> {
+    async fn delete(
+        &self,
+        watchlist_id: &WatchlistId,
+        stock_id: &StockId,
+    ) -> FavoriteDomainResult<()> {
         sqlx::query!(
-            r#"delete from favorites where user_id=$1"#,
-            favorite.user_id.as_str(),
+            r#"delete from favorites where watchlist_id=$1 and stock_id=$2"#,
+            **watchlist_id,
+            stock_id.as_str(),
         )
         .execute(&self.connection)
         .await
@@ -46,11 +121,36 @@ impl FavoriteRepository for PostgresFavoriteRepositoryImpl {
         Ok(())
     }
 
-    async fn find_all(&self, user_id: &UserId) -> FavoriteDomainResult<Vec<Favorite>> {
+    async fn find_all(&self, watchlist_id: &WatchlistId) -> FavoriteDomainResult<Vec<Favorite>> {
         let result = sqlx::query_as!(
             FavoriteModel,
-            r#"select * from favorites where user_id=$1"#,
-            user_id.as_str()
+            r#"
+            select watchlist_id, user_id, stock_id, sort_order, note, tags, added_at
+            from favorites
+            where watchlist_id=$1
+            order by sort_order, added_at
+            "#,
+            **watchlist_id
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        let result = result.into_iter().map(|favo| favo.into()).collect();
+        Ok(result)
+    }
+
+    async fn find_by_stocks(&self, stock_ids: &[StockId]) -> FavoriteDomainResult<Vec<Favorite>> {
+        let stock_ids: Vec<String> = stock_ids.iter().map(|id| id.to_string()).collect();
+        let result = sqlx::query_as!(
+            FavoriteModel,
+            r#"
+            select watchlist_id, user_id, stock_id, sort_order, note, tags, added_at
+            from favorites
+            where stock_id=any($1)
+            order by user_id, watchlist_id, sort_order
+            "#,
+            &stock_ids
         )
         .fetch_all(&self.connection)
         .await
@@ -61,17 +161,46 @@ impl FavoriteRepository for PostgresFavoriteRepositoryImpl {
     }
 }
 
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct WatchlistModel {
+    id: i32,
+    user_id: String,
+    name: String,
+}
+
+impl TryFrom<WatchlistModel> for Watchlist {
+    type Error = FavoriteDomainError;
+
+    fn try_from(value: WatchlistModel) -> Result<Self, Self::Error> {
+        Watchlist::new(
+            Some(WatchlistId::new(value.id)),
+            UserId::new(value.user_id),
+            value.name,
+        )
+    }
+}
+
 #[derive(sqlx::FromRow, Debug, Clone)]
 struct FavoriteModel {
+    watchlist_id: i32,
     user_id: String,
     stock_id: String,
+    sort_order: i32,
+    note: String,
+    tags: Vec<String>,
+    added_at: PrimitiveDateTime,
 }
 
-impl Into<Favorite> for FavoriteModel {
-    fn into(self) -> Favorite {
+impl From<FavoriteModel> for Favorite {
+    fn from(value: FavoriteModel) -> Self {
         Favorite {
-            user_id: UserId::new(self.user_id),
-            stock_id: StockId::new(self.stock_id),
+            watchlist_id: WatchlistId::new(value.watchlist_id),
+            user_id: UserId::new(value.user_id),
+            stock_id: StockId::restore(value.stock_id),
+            sort_order: value.sort_order,
+            note: value.note,
+            tags: value.tags,
+            added_at: from_sql_datetime(&value.added_at),
         }
     }
 }
@@ -0,0 +1,7 @@
+mod edinet_filing_parser;
+mod postgres_financial_statement_repository;
+
+pub use edinet_filing_parser::parse_edinet_csv;
+pub use edinet_filing_parser::parse_edinet_xbrl;
+pub use edinet_filing_parser::read_edinet_filing;
+pub use postgres_financial_statement_repository::PostgresFinancialStatementRepositoryImpl;
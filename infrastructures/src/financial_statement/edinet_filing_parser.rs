@@ -0,0 +1,285 @@
+//! EDINETの提出書類(XBRLインスタンス・XBRLをCSVに変換したファイル)から財務諸表を読み込む
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Context};
+use chrono::NaiveDate;
+
+use applications::financial_statement::FinancialStatementData;
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// 売上高
+const REVENUE: &[&str] = &[
+    "jppfs_cor:NetSales",
+    "jppfs_cor:OperatingRevenue1",
+    "jppfs_cor:OperatingRevenue2",
+    "jpigp_cor:RevenueIFRS",
+    "jpcrp_cor:NetSalesSummaryOfBusinessResults",
+    "jpcrp_cor:OperatingRevenue1SummaryOfBusinessResults",
+    "jpcrp_cor:RevenueIFRSSummaryOfBusinessResults",
+];
+/// 営業利益
+const OPERATING_INCOME: &[&str] = &[
+    "jppfs_cor:OperatingIncome",
+    "jpigp_cor:OperatingProfitLossIFRS",
+];
+/// 当期純利益(連結は親会社株主に帰属する当期純利益)
+const NET_INCOME: &[&str] = &[
+    "jppfs_cor:ProfitLossAttributableToOwnersOfParent",
+    "jpigp_cor:ProfitLossAttributableToOwnersOfParentIFRS",
+    "jppfs_cor:ProfitLoss",
+    "jpcrp_cor:ProfitLossAttributableToOwnersOfParentSummaryOfBusinessResults",
+    "jpcrp_cor:ProfitLossAttributableToOwnersOfParentIFRSSummaryOfBusinessResults",
+    "jpcrp_cor:NetIncomeLossSummaryOfBusinessResults",
+];
+/// 総資産
+const TOTAL_ASSETS: &[&str] = &[
+    "jppfs_cor:Assets",
+    "jpigp_cor:AssetsIFRS",
+    "jpcrp_cor:TotalAssetsSummaryOfBusinessResults",
+    "jpcrp_cor:TotalAssetsIFRSSummaryOfBusinessResults",
+];
+/// 純資産
+const EQUITY: &[&str] = &[
+    "jppfs_cor:NetAssets",
+    "jpigp_cor:EquityIFRS",
+    "jpcrp_cor:NetAssetsSummaryOfBusinessResults",
+    "jpcrp_cor:TotalEquityIFRSSummaryOfBusinessResults",
+];
+/// 1株当たり当期純利益
+const EPS: &[&str] = &[
+    "jpcrp_cor:BasicEarningsLossPerShareSummaryOfBusinessResults",
+    "jppfs_cor:BasicEarningsLossPerShare",
+    "jpigp_cor:BasicEarningsLossPerShareIFRS",
+    "jpcrp_cor:BasicEarningsLossPerShareIFRSSummaryOfBusinessResults",
+];
+
+/// 拡張子からファイル形式を判別して読み込む
+pub fn read_edinet_filing(path: &Path) -> anyhow::Result<FinancialStatementData> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("xbrl") | Some("xml") => {
+            let text = std::str::from_utf8(&bytes)
+                .with_context(|| format!("failed to decode {}", path.display()))?;
+            parse_edinet_xbrl(text)
+        }
+        Some("csv") => parse_edinet_csv(&bytes),
+        _ => Err(anyhow!("unsupported file type: {}", path.display())),
+    }
+}
+
+/// XBRLインスタンス文書を読み込む
+pub fn parse_edinet_xbrl(text: &str) -> anyhow::Result<FinancialStatementData> {
+    let document = roxmltree::Document::parse(text)?;
+    let mut facts = Facts::default();
+
+    for node in document
+        .root_element()
+        .children()
+        .filter(|n| n.is_element())
+    {
+        // コンテキストを持たない要素(context, unit等)は値ではない
+        let Some(context) = node.attribute("contextRef") else {
+            continue;
+        };
+        if node.attribute((XSI_NAMESPACE, "nil")) == Some("true") {
+            continue;
+        }
+        let name = node.tag_name();
+        let prefix = name
+            .namespace()
+            .and_then(|namespace| node.lookup_prefix(namespace))
+            .unwrap_or_default();
+        let value = node.text().unwrap_or_default();
+
+        facts.insert(format!("{}:{}", prefix, name.name()), context, value);
+    }
+
+    facts.into_financial_statement()
+}
+
+/// XBRLをCSVに変換したファイル(UTF-16, タブ区切り)を読み込む
+pub fn parse_edinet_csv(bytes: &[u8]) -> anyhow::Result<FinancialStatementData> {
+    let (encoding, _) = encoding_rs::Encoding::for_bom(bytes).unwrap_or((encoding_rs::UTF_16LE, 0));
+    let (text, _, had_errors) = encoding.decode(bytes);
+    if had_errors {
+        return Err(anyhow!("invalid {} sequence", encoding.name()));
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b'\t')
+        .flexible(true)
+        .from_reader(text.as_bytes());
+    let headers = reader.headers()?.clone();
+    let index = |header: &str| {
+        headers
+            .iter()
+            .position(|h| h == header)
+            .ok_or_else(|| anyhow!("column not found: {header}"))
+    };
+    let (element_index, context_index, value_index) =
+        (index("要素ID")?, index("コンテキストID")?, index("値")?);
+
+    let mut facts = Facts::default();
+    for record in reader.records() {
+        let record = record?;
+        let (Some(element), Some(context), Some(value)) = (
+            record.get(element_index),
+            record.get(context_index),
+            record.get(value_index),
+        ) else {
+            continue;
+        };
+
+        facts.insert(element.to_string(), context, value);
+    }
+
+    facts.into_financial_statement()
+}
+
+/// 要素IDごとの(コンテキストID, 値)
+#[derive(Debug, Default)]
+struct Facts(HashMap<String, Vec<(String, String)>>);
+
+impl Facts {
+    fn insert(&mut self, element: String, context: &str, value: &str) {
+        self.0
+            .entry(element)
+            .or_default()
+            .push((context.to_string(), value.trim().to_string()));
+    }
+
+    /// 提出書類情報(DEI)の値
+    fn dei(&self, element: &str) -> anyhow::Result<&str> {
+        self.0
+            .get(element)
+            .and_then(|values| values.first())
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| anyhow!("{element} not found"))
+    }
+
+    fn dei_date(&self, element: &str) -> anyhow::Result<NaiveDate> {
+        let value = self.dei(element)?;
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .with_context(|| format!("invalid date: {element}={value}"))
+    }
+
+    /// 連結の値を優先し、連結財務諸表がない場合は個別の値を使う
+    fn find<T: std::str::FromStr>(&self, elements: &[&str], context: &str) -> Option<T> {
+        let non_consolidated = format!("{context}_NonConsolidatedMember");
+        let value = [context, non_consolidated.as_str()]
+            .into_iter()
+            .flat_map(|context| elements.iter().map(move |element| (*element, context)))
+            .find_map(|(element, context)| {
+                self.0
+                    .get(element)?
+                    .iter()
+                    .filter(|(c, _)| c == context)
+                    .find_map(|(_, value)| value.parse().ok())
+            });
+
+        value
+    }
+
+    fn into_financial_statement(self) -> anyhow::Result<FinancialStatementData> {
+        // 証券コードは末尾にチェック用の1桁が付いた5桁
+        let security_code = self.dei("jpdei_cor:SecurityCodeDEI")?;
+        let stock_id = match security_code.char_indices().nth(4) {
+            Some((index, _)) => &security_code[..index],
+            None => security_code,
+        };
+        let period = self.dei("jpdei_cor:TypeOfCurrentPeriodDEI")?;
+        let (period_type, duration, instant) = match period {
+            "FY" => ("annual", "CurrentYearDuration", "CurrentYearInstant"),
+            "Q1" | "Q2" | "Q3" => ("quarterly", "CurrentYTDDuration", "CurrentQuarterInstant"),
+            "HY" => ("quarterly", "InterimDuration", "InterimInstant"),
+            _ => return Err(anyhow!("unsupported period type: {period}")),
+        };
+
+        let mut statement =
+            FinancialStatementData::new(stock_id.to_string(), period_type.to_string());
+        statement.period_start = self.dei_date("jpdei_cor:CurrentFiscalYearStartDateDEI")?;
+        statement.period_end = self.dei_date("jpdei_cor:CurrentPeriodEndDateDEI")?;
+        statement.revenue = self.find(REVENUE, duration);
+        statement.operating_income = self.find(OPERATING_INCOME, duration);
+        statement.net_income = self.find(NET_INCOME, duration);
+        statement.total_assets = self.find(TOTAL_ASSETS, instant);
+        statement.equity = self.find(EQUITY, instant);
+        statement.eps = self.find(EPS, duration);
+
+        if statement.revenue.is_none()
+            && statement.operating_income.is_none()
+            && statement.net_income.is_none()
+            && statement.total_assets.is_none()
+            && statement.equity.is_none()
+            && statement.eps.is_none()
+        {
+            return Err(anyhow!("financial data not found: stock_id={stock_id}"));
+        }
+
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{parse_edinet_csv, parse_edinet_xbrl};
+
+    #[test]
+    fn parse_annual_xbrl() -> anyhow::Result<()> {
+        let text = include_str!("../../test_data/edinet/sample_annual.xbrl");
+        let result = parse_edinet_xbrl(text)?;
+
+        assert!(result.stock_id == "9999");
+        assert!(result.period_type == "annual");
+        assert!(result.period_start == NaiveDate::from_ymd_opt(2022, 4, 1).unwrap());
+        assert!(result.period_end == NaiveDate::from_ymd_opt(2023, 3, 31).unwrap());
+        // 前期・個別の値より当期・連結の値を優先する
+        assert!(result.revenue == Some(120_500_000_000));
+        assert!(result.operating_income == Some(9_800_000_000));
+        assert!(result.net_income == Some(-6_500_000_000));
+        assert!(result.total_assets == Some(210_300_000_000));
+        assert!(result.equity == Some(95_400_000_000));
+        // 連結の値がnilの場合は個別の値を使う
+        assert!(result.eps == Some(-81.25));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_quarterly_csv() -> anyhow::Result<()> {
+        let bytes = include_bytes!("../../test_data/edinet/sample_quarterly.csv");
+        let result = parse_edinet_csv(bytes)?;
+
+        assert!(result.stock_id == "9998");
+        assert!(result.period_type == "quarterly");
+        assert!(result.period_start == NaiveDate::from_ymd_opt(2022, 4, 1).unwrap());
+        assert!(result.period_end == NaiveDate::from_ymd_opt(2022, 9, 30).unwrap());
+        // 四半期は期首からの累計
+        assert!(result.revenue == Some(60_250_000_000));
+        assert!(result.operating_income == Some(4_120_000_000));
+        assert!(result.net_income == Some(2_870_000_000));
+        assert!(result.total_assets == Some(150_000_000_000));
+        assert!(result.equity == Some(72_000_000_000));
+        assert!(result.eps == Some(35.87));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_without_dei_return_err() {
+        let text = include_str!("../../test_data/edinet/sample_annual.xbrl")
+            .replace("jpdei_cor:SecurityCodeDEI", "jpdei_cor:UnknownDEI");
+
+        assert!(parse_edinet_xbrl(&text).is_err());
+    }
+}
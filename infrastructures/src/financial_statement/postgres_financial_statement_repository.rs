@@ -0,0 +1,111 @@
+use sqlx::postgres::PgPool;
+
+use crate::sql_time::{from_sql_date, into_sql_date};
+use domain::{
+    financial_statement::{
+        FinancialStatement, FinancialStatementDomainError, FinancialStatementDomainResult,
+        FinancialStatementRepository, PeriodType,
+    },
+    stock::StockId,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresFinancialStatementRepositoryImpl {
+    connection: PgPool,
+}
+
+impl PostgresFinancialStatementRepositoryImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl FinancialStatementRepository for PostgresFinancialStatementRepositoryImpl {
+    async fn save(&self, statement: FinancialStatement) -> FinancialStatementDomainResult<()> {
+        let period_start = into_sql_date(&statement.period_start)?;
+        let period_end = into_sql_date(&statement.period_end)?;
+
+        sqlx::query!(
+            r#"
+            insert into financial_statements values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            on conflict (stock_id, period_type, period_end)
+            do update set (period_start, revenue, operating_income, net_income, total_assets, equity, eps)
+                = ($3, $5, $6, $7, $8, $9, $10)
+            "#,
+            statement.stock_id.as_str(),
+            statement.period_type.as_str(),
+            period_start,
+            period_end,
+            statement.revenue,
+            statement.operating_income,
+            statement.net_income,
+            statement.total_assets,
+            statement.equity,
+            statement.eps,
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        stock_id: &StockId,
+        period_type: PeriodType,
+    ) -> FinancialStatementDomainResult<Vec<FinancialStatement>> {
+        let result = sqlx::query_as!(
+            FinancialStatementModel,
+            r#"
+            select * from financial_statements
+            where stock_id=$1 and period_type=$2
+            order by period_end
+            "#,
+            stock_id.as_str(),
+            period_type.as_str(),
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result
+            .into_iter()
+            .map(FinancialStatement::try_from)
+            .collect()
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct FinancialStatementModel {
+    stock_id: String,
+    period_type: String,
+    period_start: sqlx::types::time::Date,
+    period_end: sqlx::types::time::Date,
+    revenue: Option<i64>,
+    operating_income: Option<i64>,
+    net_income: Option<i64>,
+    total_assets: Option<i64>,
+    equity: Option<i64>,
+    eps: Option<f64>,
+}
+
+impl TryFrom<FinancialStatementModel> for FinancialStatement {
+    type Error = FinancialStatementDomainError;
+
+    fn try_from(value: FinancialStatementModel) -> Result<Self, Self::Error> {
+        Ok(FinancialStatement {
+            stock_id: StockId::restore(value.stock_id),
+            period_type: value.period_type.parse()?,
+            period_start: from_sql_date(value.period_start),
+            period_end: from_sql_date(value.period_end),
+            revenue: value.revenue,
+            operating_income: value.operating_income,
+            net_income: value.net_income,
+            total_assets: value.total_assets,
+            equity: value.equity,
+            eps: value.eps,
+        })
+    }
+}
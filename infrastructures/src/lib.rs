@@ -1,7 +1,15 @@
+pub mod alert;
 pub mod auth;
 pub mod company;
+pub mod corporate_action;
+pub mod dividend;
+pub mod financial_statement;
+pub mod market_index;
 pub mod portfolio;
+pub mod screener;
 pub mod session;
 pub mod stock;
 pub mod user;
-pub mod favorite;
\ No newline at end of file
+pub mod webhook;
+pub mod favorite;
+mod sql_time;
\ No newline at end of file
@@ -0,0 +1,5 @@
+mod postgres_market_index_import_service;
+mod postgres_market_index_query_service;
+
+pub use postgres_market_index_import_service::PostgresMarketIndexImportServiceImpl;
+pub use postgres_market_index_query_service::PostgresMarketIndexQueryServiceImpl;
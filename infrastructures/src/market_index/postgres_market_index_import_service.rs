@@ -0,0 +1,87 @@
+use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
+
+use crate::sql_time::into_sql_date;
+use applications::market_index::{
+    IndexPriceData, MarketIndexData, MarketIndexImportError, MarketIndexImportResult,
+    MarketIndexImportService,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresMarketIndexImportServiceImpl {
+    connection: PgPool,
+}
+
+impl PostgresMarketIndexImportServiceImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl MarketIndexImportService for PostgresMarketIndexImportServiceImpl {
+    #[tracing::instrument(skip(self, indices), err, ret)]
+    async fn upsert_indices(&self, indices: Vec<MarketIndexData>) -> MarketIndexImportResult<u64> {
+        if indices.is_empty() {
+            return Ok(0);
+        }
+
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new("insert into market_indices (index_id, name) ");
+        query.push_values(indices, |mut row, index| {
+            row.push_bind(index.index_id).push_bind(index.name);
+        });
+        query.push(" on conflict (index_id) do update set name = excluded.name");
+
+        let result = query
+            .build()
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[tracing::instrument(skip(self, prices), err, ret)]
+    async fn upsert_prices(&self, prices: Vec<IndexPriceData>) -> MarketIndexImportResult<u64> {
+        if prices.is_empty() {
+            return Ok(0);
+        }
+
+        let mut rows = Vec::with_capacity(prices.len());
+        for price in prices {
+            let date =
+                into_sql_date(&price.date).map_err(|_| MarketIndexImportError::InvalidData {
+                    name: "date",
+                    value: price.date.to_string(),
+                })?;
+            rows.push((price, date));
+        }
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "insert into index_prices (index_id, date, start_price, end_price, high_price, low_price) ",
+        );
+        query.push_values(rows, |mut row, (price, date)| {
+            row.push_bind(price.index_id)
+                .push_bind(date)
+                .push_bind(price.start_price)
+                .push_bind(price.end_price)
+                .push_bind(price.high_price)
+                .push_bind(price.low_price);
+        });
+        query.push(
+            r#"
+            on conflict (index_id, date)
+            do update set (start_price, end_price, high_price, low_price)
+                = (excluded.start_price, excluded.end_price, excluded.high_price, excluded.low_price)
+            "#,
+        );
+
+        let result = query
+            .build()
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.rows_affected())
+    }
+}
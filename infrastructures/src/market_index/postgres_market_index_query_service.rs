@@ -0,0 +1,133 @@
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
+
+use crate::sql_time::{from_sql_date, into_sql_date};
+use applications::market_index::{
+    IndexPriceData, MarketIndexData, MarketIndexQueryCommand, MarketIndexQueryError,
+    MarketIndexQueryResult, MarketIndexQueryService,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresMarketIndexQueryServiceImpl {
+    connection: PgPool,
+}
+
+impl PostgresMarketIndexQueryServiceImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl MarketIndexQueryService for PostgresMarketIndexQueryServiceImpl {
+    async fn find_all(&self) -> MarketIndexQueryResult<Vec<MarketIndexData>> {
+        let result = sqlx::query_as!(
+            MarketIndexModel,
+            r#"select index_id, name from market_indices order by index_id"#
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .into_iter()
+        .map(|i| i.into())
+        .collect();
+
+        Ok(result)
+    }
+
+    async fn find_prices(
+        &self,
+        param: MarketIndexQueryCommand,
+    ) -> MarketIndexQueryResult<Vec<IndexPriceData>> {
+        // パラメータチェック
+        if let (Some(start), Some(end)) = (param.start, param.end) {
+            if start > end {
+                return Err(MarketIndexQueryError::InvalidRangeOfDate {
+                    name: "end",
+                    value: end,
+                });
+            }
+        };
+        sqlx::query!(
+            r#"select index_id from market_indices where index_id=$1"#,
+            param.index_id
+        )
+        .fetch_optional(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .ok_or(MarketIndexQueryError::MarketIndexNotFound(
+            param.index_id.clone(),
+        ))?;
+
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new("select * from index_prices where index_id=");
+        query.push_bind(&param.index_id);
+        if let Some(start) = &param.start {
+            let date =
+                into_sql_date(start).map_err(|_| MarketIndexQueryError::InvalidRangeOfDate {
+                    name: "start",
+                    value: *start,
+                })?;
+            query.push(" and date>=");
+            query.push_bind(date);
+        }
+        if let Some(end) = &param.end {
+            let date =
+                into_sql_date(end).map_err(|_| MarketIndexQueryError::InvalidRangeOfDate {
+                    name: "end",
+                    value: *end,
+                })?;
+            query.push(" and date<=");
+            query.push_bind(date);
+        }
+        query.push(" order by date");
+
+        let result: Vec<IndexPriceModel> = query
+            .build_query_as()
+            .fetch_all(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct MarketIndexModel {
+    index_id: String,
+    name: String,
+}
+
+impl From<MarketIndexModel> for MarketIndexData {
+    fn from(value: MarketIndexModel) -> Self {
+        MarketIndexData {
+            index_id: value.index_id,
+            name: value.name,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct IndexPriceModel {
+    index_id: String,
+    date: sqlx::types::time::Date,
+    start_price: Decimal,
+    end_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
+}
+
+impl From<IndexPriceModel> for IndexPriceData {
+    fn from(value: IndexPriceModel) -> Self {
+        let date = from_sql_date(value.date);
+
+        IndexPriceData {
+            index_id: value.index_id,
+            date,
+            start_price: value.start_price,
+            end_price: value.end_price,
+            high_price: value.high_price,
+            low_price: value.low_price,
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use sqlx::postgres::PgPool;
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgExecutor, PgPool};
 
+use crate::sql_time::{from_sql_date, into_sql_date};
 use domain::{
-    portfolio::{Portfolio, PortfolioDomainResult, PortfolioReposotory},
+    money::{Money, Price, Quantity},
+    portfolio::{
+        Portfolio, PortfolioAccount, PortfolioDomainError, PortfolioDomainResult, PortfolioId,
+        PortfolioReposotory, Transaction, TransactionId,
+    },
     stock::StockId,
     user::UserId,
 };
@@ -19,17 +25,44 @@ impl PostgresPortfolioRepositoryImpl {
 
 #[async_trait::async_trait]
 impl PortfolioReposotory for PostgresPortfolioRepositoryImpl {
-    async fn save(&self, portfolio: Portfolio) -> PortfolioDomainResult<()> {
+    async fn save_account(&self, account: PortfolioAccount) -> PortfolioDomainResult<PortfolioId> {
+        let id = if let Some(id) = account.id() {
+            sqlx::query!(
+                r#"update portfolios set (name, account_type) = ($3, $4) where id=$1 and user_id=$2"#,
+                *id,
+                account.user_id().as_str(),
+                account.name(),
+                account.account_type().as_str(),
+            )
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+            *id
+        } else {
+            sqlx::query_scalar!(
+                r#"insert into portfolios (user_id, name, account_type) values ($1, $2, $3) returning id"#,
+                account.user_id().as_str(),
+                account.name(),
+                account.account_type().as_str(),
+            )
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+        };
+
+        Ok(PortfolioId::new(id))
+    }
+
+    async fn delete_account(
+        &self,
+        user_id: &UserId,
+        portfolio_id: &PortfolioId,
+    ) -> PortfolioDomainResult<()> {
         sqlx::query!(
-            r#"
-            insert into portfolio values ($1, $2, $3, $4)
-            on conflict (user_id, stock_id)
-            do update set (stock_count, purchase) = ($3, $4)
-            "#,
-            portfolio.user_id.as_str(),
-            portfolio.stock_id.as_str(),
-            portfolio.stock_count,
-            portfolio.purchase,
+            r#"delete from portfolios where id=$1 and user_id=$2"#,
+            **portfolio_id,
+            user_id.as_str(),
         )
         .execute(&self.connection)
         .await
@@ -38,10 +71,34 @@ impl PortfolioReposotory for PostgresPortfolioRepositoryImpl {
         Ok(())
     }
 
-    async fn delete(&self, user_id: &UserId, stock_id: &StockId) -> PortfolioDomainResult<()> {
+    async fn find_accounts(
+        &self,
+        user_id: &UserId,
+    ) -> PortfolioDomainResult<Vec<PortfolioAccount>> {
+        let result = sqlx::query_as!(
+            PortfolioAccountModel,
+            r#"select id, user_id, name, account_type from portfolios where user_id=$1 order by id"#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(PortfolioAccount::try_from).collect()
+    }
+
+    async fn save(&self, portfolio: Portfolio) -> PortfolioDomainResult<()> {
+        save_portfolio(&self.connection, &portfolio).await
+    }
+
+    async fn delete(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<()> {
         sqlx::query!(
-            r#"delete from portfolio where user_id=$1 and stock_id=$2"#,
-            user_id.as_str(),
+            r#"delete from portfolio where portfolio_id=$1 and stock_id=$2"#,
+            **portfolio_id,
             stock_id.as_str(),
         )
         .execute(&self.connection)
@@ -51,57 +108,268 @@ impl PortfolioReposotory for PostgresPortfolioRepositoryImpl {
         Ok(())
     }
 
-    async fn find_all(&self, user_id: &UserId) -> PortfolioDomainResult<Vec<Portfolio>> {
+    async fn find_all(&self, portfolio_id: &PortfolioId) -> PortfolioDomainResult<Vec<Portfolio>> {
         let result = sqlx::query_as!(
             PortfolioModel,
-            r#"select * from portfolio where user_id=$1"#,
-            user_id.as_str()
+            r#"select portfolio_id, user_id, stock_id, stock_count, purchase, cost_basis_method from portfolio where portfolio_id=$1"#,
+            **portfolio_id
         )
         .fetch_all(&self.connection)
         .await
         .map_err(|e| anyhow::anyhow!(e))?;
 
-        let result = result.into_iter().map(|p| p.into()).collect();
-        Ok(result)
+        result.into_iter().map(Portfolio::try_from).collect()
     }
 
     async fn find(
         &self,
-        user_id: &UserId,
+        portfolio_id: &PortfolioId,
         stock_id: &StockId,
     ) -> PortfolioDomainResult<Option<Portfolio>> {
         let result = sqlx::query_as!(
             PortfolioModel,
-            r#"select * from portfolio where user_id=$1 and stock_id=$2"#,
-            user_id.as_str(),
+            r#"select portfolio_id, user_id, stock_id, stock_count, purchase, cost_basis_method from portfolio where portfolio_id=$1 and stock_id=$2"#,
+            **portfolio_id,
             stock_id.as_str()
         )
         .fetch_optional(&self.connection)
         .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.map(Portfolio::try_from).transpose()
+    }
+
+    async fn save_transactions(
+        &self,
+        portfolio: Portfolio,
+        transactions: Vec<Transaction>,
+    ) -> PortfolioDomainResult<Vec<TransactionId>> {
+        let mut tx = self
+            .connection
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        // 売買履歴は保有銘柄を参照するため先に保存する
+        save_portfolio(&mut tx, &portfolio).await?;
+        let mut ids = Vec::new();
+        for transaction in transactions {
+            ids.push(save_transaction(&mut tx, &transaction).await?);
+        }
+
+        tx.commit().await.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(ids)
+    }
+
+    async fn delete_transaction(
+        &self,
+        portfolio: Portfolio,
+        transaction_id: &TransactionId,
+    ) -> PortfolioDomainResult<()> {
+        let mut tx = self
+            .connection
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        sqlx::query!(
+            r#"delete from portfolio_transactions where id=$1 and portfolio_id=$2"#,
+            **transaction_id,
+            *portfolio.portfolio_id,
+        )
+        .execute(&mut tx)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+        save_portfolio(&mut tx, &portfolio).await?;
+
+        tx.commit().await.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_transactions(
+        &self,
+        portfolio_id: &PortfolioId,
+        stock_id: &StockId,
+    ) -> PortfolioDomainResult<Vec<Transaction>> {
+        let result = sqlx::query_as!(
+            TransactionModel,
+            r#"
+            select id, portfolio_id, user_id, stock_id, transaction_type, date, quantity, price, fee
+            from portfolio_transactions
+            where portfolio_id=$1 and stock_id=$2
+            order by date, id
+            "#,
+            **portfolio_id,
+            stock_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+}
+
+/// 保有銘柄を登録・更新する
+async fn save_portfolio<'e>(
+    executor: impl PgExecutor<'e>,
+    portfolio: &Portfolio,
+) -> PortfolioDomainResult<()> {
+    sqlx::query!(
+        r#"
+        insert into portfolio
+        (portfolio_id, user_id, stock_id, stock_count, purchase, cost_basis_method)
+        values ($1, $2, $3, $4, $5, $6)
+        on conflict (portfolio_id, stock_id)
+        do update set (stock_count, purchase, cost_basis_method) = ($4, $5, $6)
+        "#,
+        *portfolio.portfolio_id,
+        portfolio.user_id.as_str(),
+        portfolio.stock_id.as_str(),
+        *portfolio.stock_count,
+        *portfolio.purchase,
+        portfolio.cost_basis_method.as_str(),
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| anyhow::anyhow!(e))?;
+
+    Ok(())
+}
+
+/// 売買履歴を登録・更新し、登録したIDを返す
+async fn save_transaction<'e>(
+    executor: impl PgExecutor<'e>,
+    transaction: &Transaction,
+) -> PortfolioDomainResult<TransactionId> {
+    let date = into_sql_date(transaction.date())?;
+    let id = if let Some(id) = transaction.id() {
+        sqlx::query!(
+            r#"
+            update portfolio_transactions
+            set (transaction_type, date, quantity, price, fee) = ($3, $4, $5, $6, $7)
+            where id=$1 and portfolio_id=$2
+            "#,
+            *id,
+            *transaction.portfolio_id(),
+            transaction.transaction_type().as_str(),
+            date,
+            *transaction.quantity(),
+            *transaction.price(),
+            *transaction.fee(),
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        *id
+    } else {
+        sqlx::query_scalar!(
+            r#"
+            insert into portfolio_transactions
+            (portfolio_id, user_id, stock_id, transaction_type, date, quantity, price, fee)
+            values ($1, $2, $3, $4, $5, $6, $7, $8)
+            returning id
+            "#,
+            *transaction.portfolio_id(),
+            transaction.user_id().as_str(),
+            transaction.stock_id().as_str(),
+            transaction.transaction_type().as_str(),
+            date,
+            *transaction.quantity(),
+            *transaction.price(),
+            *transaction.fee(),
+        )
+        .fetch_one(executor)
+        .await
         .map_err(|e| anyhow::anyhow!(e))?
-        .map(|p| p.into());
+    };
 
-        Ok(result)
+    Ok(TransactionId::new(id))
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct PortfolioAccountModel {
+    id: i32,
+    user_id: String,
+    name: String,
+    account_type: String,
+}
+
+impl TryFrom<PortfolioAccountModel> for PortfolioAccount {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: PortfolioAccountModel) -> Result<Self, Self::Error> {
+        PortfolioAccount::new(
+            Some(PortfolioId::new(value.id)),
+            UserId::new(value.user_id),
+            value.name,
+            value.account_type.parse()?,
+        )
     }
 }
 
 #[derive(sqlx::FromRow, Debug, Clone)]
 struct PortfolioModel {
+    portfolio_id: i32,
     user_id: String,
     stock_id: String,
-    stock_count: i32,
-    purchase: i32,
+    stock_count: i64,
+    purchase: Decimal,
+    cost_basis_method: String,
 }
 
-impl Into<Portfolio> for PortfolioModel {
-    fn into(self) -> Portfolio {
-        let user_id = UserId::new(self.user_id);
-        let stock_id = StockId::new(self.stock_id);
-        Portfolio {
+impl TryFrom<PortfolioModel> for Portfolio {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: PortfolioModel) -> Result<Self, Self::Error> {
+        let user_id = UserId::new(value.user_id);
+        let stock_id = StockId::restore(value.stock_id);
+        Ok(Portfolio {
+            portfolio_id: PortfolioId::new(value.portfolio_id),
             user_id,
             stock_id,
-            stock_count: self.stock_count,
-            purchase: self.purchase,
-        }
+            stock_count: Quantity::new(value.stock_count).map_err(|e| anyhow::anyhow!(e))?,
+            purchase: Price::new(value.purchase).map_err(|e| anyhow::anyhow!(e))?,
+            cost_basis_method: value
+                .cost_basis_method
+                .parse()
+                .map_err(|e: PortfolioDomainError| anyhow::anyhow!(e))?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct TransactionModel {
+    id: i32,
+    portfolio_id: i32,
+    user_id: String,
+    stock_id: String,
+    transaction_type: String,
+    date: sqlx::types::time::Date,
+    quantity: i64,
+    price: Decimal,
+    fee: Decimal,
+}
+
+impl TryFrom<TransactionModel> for Transaction {
+    type Error = PortfolioDomainError;
+
+    fn try_from(value: TransactionModel) -> Result<Self, Self::Error> {
+        let date = from_sql_date(value.date);
+
+        Transaction::new(
+            Some(TransactionId::new(value.id)),
+            PortfolioId::new(value.portfolio_id),
+            UserId::new(value.user_id),
+            StockId::restore(value.stock_id),
+            value.transaction_type.parse()?,
+            date,
+            Quantity::new(value.quantity).map_err(|e| anyhow::anyhow!(e))?,
+            Price::new(value.price).map_err(|e| anyhow::anyhow!(e))?,
+            Money::new(value.fee),
+        )
     }
 }
@@ -0,0 +1,3 @@
+mod postgres_screener_query_service;
+
+pub use postgres_screener_query_service::PostgresScreenerQueryServiceImpl;
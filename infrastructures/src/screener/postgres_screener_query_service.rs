@@ -0,0 +1,228 @@
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
+
+use crate::sql_time::from_sql_date;
+use applications::screener::{
+    ComparisonOperator, ScreenerData, ScreenerFilter, ScreenerPageData, ScreenerQueryCommand,
+    ScreenerQueryResult, ScreenerQueryService, ScreenerValue, YEAR_HIGH_DAYS,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresScreenerQueryServiceImpl {
+    connection: PgPool,
+}
+
+impl PostgresScreenerQueryServiceImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl ScreenerQueryService for PostgresScreenerQueryServiceImpl {
+    async fn screen(&self, command: ScreenerQueryCommand) -> ScreenerQueryResult<ScreenerPageData> {
+        command.validate()?;
+
+        // 並び替え・ページング(件数は同じクエリのウィンドウ関数で求める)
+        let mut query = screener_query(&command, "*, count(*) over () as total");
+        query.push(" order by ");
+        query.push(command.sort.as_str());
+        if command.sort.is_text() {
+            query.push(r#" collate "C""#);
+        }
+        query.push(if command.descending { " desc" } else { " asc" });
+        query.push(r#" nulls last, stock_id collate "C" limit "#);
+        query.push_bind(command.size as i64);
+        query.push(" offset ");
+        query.push_bind(command.offset());
+
+        let result: Vec<ScreenerModel> = query
+            .build_query_as()
+            .fetch_all(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        // 最終ページより後を指定された場合のみ件数を別に数える
+        let total = match result.first() {
+            Some(first) => first.total,
+            None if command.offset() > 0 => {
+                let mut query = screener_query(&command, "count(*)");
+                let (total,): (i64,) = query
+                    .build_query_as()
+                    .fetch_one(&self.connection)
+                    .await
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                total
+            }
+            None => 0,
+        };
+
+        Ok(ScreenerPageData {
+            total,
+            page: command.page,
+            size: command.size,
+            items: result.into_iter().map(|s| s.into()).collect(),
+        })
+    }
+}
+
+/// 指標を算出して条件で絞り込むクエリを生成する
+fn screener_query<'a>(command: &ScreenerQueryCommand, columns: &str) -> QueryBuilder<'a, Postgres> {
+    let period = command.period as i64;
+
+    // 株価・出来高は権利落ち日が当日より後の株式分割・併合の比率で現在の株数基準に修正する
+    let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+        r#"with adjusted as (
+            select s.stock_id, s.date,
+                coalesce(trim_scale(round(s.end_price * a.ratio_from / a.ratio_to, 2)), s.end_price) as end_price,
+                coalesce(trim_scale(round(s.high_price * a.ratio_from / a.ratio_to, 2)), s.high_price) as high_price,
+                coalesce(round(s.volume * a.ratio_to / a.ratio_from)::int8, s.volume) as volume
+            from stocks s
+            left join lateral (
+                select round(exp(sum(ln(ratio_from::numeric)))) as ratio_from,
+                    round(exp(sum(ln(ratio_to::numeric)))) as ratio_to
+                from corporate_actions ca
+                where ca.stock_id = s.stock_id and ca.ex_date > s.date
+            ) a on true
+        ), ranked as (
+            select stock_id, date, end_price, high_price, volume,
+                row_number() over (partition by stock_id order by date desc) as rn,
+                max(date) over (partition by stock_id) as latest_date,
+                end_price::float8 / nullif(lag(end_price) over (partition by stock_id order by date), 0) - 1 as daily_return
+            from adjusted
+        ), metrics as (
+            select stock_id, max(date) as date,
+                max(end_price) filter (where rn = 1) as last_close,
+                max(end_price) filter (where rn = "#,
+    );
+    query.push_bind(period + 1);
+    query.push(
+        r#") as base_close,
+                avg(volume::float8) filter (where rn <= "#,
+    );
+    query.push_bind(period);
+    query.push(
+        r#") as average_volume,
+                stddev_samp(daily_return) filter (where rn <= "#,
+    );
+    query.push_bind(period);
+    query.push(
+        r#") * 100 as volatility,
+                max(high_price) filter (where date >= latest_date - "#,
+    );
+    query.push_bind(YEAR_HIGH_DAYS as i32);
+    query.push(
+        r#") as year_high
+            from ranked
+            group by stock_id
+        ), screener as (
+            select c.stock_id, c.name, c.sector, c.industry, m.date, m.last_close,
+                (m.last_close::float8 / nullif(m.base_close, 0) - 1) * 100 as change_rate,
+                m.average_volume, m.year_high,
+                (m.last_close::float8 / nullif(m.year_high, 0) - 1) * 100 as year_high_distance,
+                m.volatility
+            from companies c
+            join metrics m on m.stock_id = c.stock_id
+        )
+        select "#,
+    );
+    query.push(columns);
+    query.push(" from screener where ");
+    match &command.filter {
+        Some(filter) => push_filter(&mut query, filter),
+        None => {
+            query.push("true");
+        }
+    }
+
+    query
+}
+
+/// 条件式をwhere句に変換する
+///
+/// 項目名はScreenerFieldの列名のみのため、値以外は直接クエリに埋め込む
+fn push_filter(query: &mut QueryBuilder<Postgres>, filter: &ScreenerFilter) {
+    match filter {
+        ScreenerFilter::And(filters) | ScreenerFilter::Or(filters) => {
+            let separator = if matches!(filter, ScreenerFilter::And(_)) {
+                " and "
+            } else {
+                " or "
+            };
+            query.push("(");
+            for (index, filter) in filters.iter().enumerate() {
+                if index > 0 {
+                    query.push(separator);
+                }
+                push_filter(query, filter);
+            }
+            query.push(")");
+        }
+        ScreenerFilter::Condition(condition) => {
+            let column = condition.field.as_str();
+            match &condition.value {
+                ScreenerValue::Text(value) => {
+                    if condition.operator == ComparisonOperator::Contains {
+                        query.push(format!("strpos({}, ", column));
+                        query.push_bind(value.clone());
+                        query.push(") > 0");
+                    } else {
+                        query.push(format!(
+                            r#"{} collate "C" {} "#,
+                            column,
+                            sql_operator(condition.operator)
+                        ));
+                        query.push_bind(value.clone());
+                    }
+                }
+                ScreenerValue::Number(value) => {
+                    query.push(format!("{} {} ", column, sql_operator(condition.operator)));
+                    query.push_bind(*value);
+                }
+            }
+        }
+    }
+}
+
+fn sql_operator(operator: ComparisonOperator) -> &'static str {
+    match operator {
+        ComparisonOperator::Ne => "<>",
+        operator => operator.as_str(),
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct ScreenerModel {
+    stock_id: String,
+    name: String,
+    sector: String,
+    industry: String,
+    date: sqlx::types::time::Date,
+    last_close: Decimal,
+    change_rate: Option<f64>,
+    average_volume: Option<f64>,
+    year_high: Option<Decimal>,
+    year_high_distance: Option<f64>,
+    volatility: Option<f64>,
+    total: i64,
+}
+
+impl From<ScreenerModel> for ScreenerData {
+    fn from(value: ScreenerModel) -> Self {
+        let date = from_sql_date(value.date);
+
+        ScreenerData {
+            stock_id: value.stock_id,
+            name: value.name,
+            sector: value.sector,
+            industry: value.industry,
+            date,
+            last_close: value.last_close,
+            change_rate: value.change_rate,
+            average_volume: value.average_volume,
+            year_high: value.year_high,
+            year_high_distance: value.year_high_distance,
+            volatility: value.volatility,
+        }
+    }
+}
@@ -32,7 +32,7 @@ impl<T: SessionStore> SessionRepository for SessionRepositoryImpl<T> {
         self.store
             .destroy_session(session_id)
             .await
-            .map_err(|e| SessionError::Disconnect(e))
+            .map_err(SessionError::Disconnect)
     }
 
     /// Session取得
@@ -41,7 +41,7 @@ impl<T: SessionStore> SessionRepository for SessionRepositoryImpl<T> {
             .store
             .load_session(session_id.clone().into())
             .await
-            .map_err(|e| SessionError::Disconnect(e))?
+            .map_err(SessionError::Disconnect)?
         {
             session.set_cookie_value(session_id.into());
             Ok(Some(session.into()))
@@ -56,7 +56,7 @@ impl<T: SessionStore> SessionRepository for SessionRepositoryImpl<T> {
             .store
             .store_session(session.into())
             .await
-            .map_err(|e| SessionError::Disconnect(e))?
+            .map_err(SessionError::Disconnect)?
             .ok_or(SessionError::IntoSessionIdError)?;
 
         Ok(SessionId::new(session_id))
@@ -74,9 +74,7 @@ mod tests {
     use super::SessionRepositoryImpl;
 
     fn setup() -> impl SessionRepository {
-        let repo = SessionRepositoryImpl::new(MemoryStore::new());
-
-        repo
+        SessionRepositoryImpl::new(MemoryStore::new())
     }
 
     #[tokio::test]
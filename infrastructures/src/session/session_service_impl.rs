@@ -43,9 +43,7 @@ where
     async fn find_or_create(&self, session_id: Option<SessionId>) -> SessionResult<SessionStatus> {
         let status = if let Some(session_id) = session_id {
             if let Some(session) = self.session_repository.find(session_id).await? {
-                let status = SessionStatus::Found(session.into_session_id()?);
-
-                status
+                SessionStatus::Found(session.into_session_id()?)
             } else {
                 self.create().await?
             }
@@ -124,9 +122,7 @@ mod tests {
     fn setup() -> SessionServiceImpl<SessionRepositoryImpl<MemoryStore>> {
         let store = MemoryStore::new();
         let session_repository = Arc::new(SessionRepositoryImpl::new(store));
-        let session_service = SessionServiceImpl::new(&session_repository);
-
-        session_service
+        SessionServiceImpl::new(&session_repository)
     }
 
     #[tokio::test]
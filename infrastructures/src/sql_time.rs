@@ -0,0 +1,62 @@
+//! chronoの日付・日時とPostgreSQLの日付・日時(sqlx::types::time)の変換
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use sqlx::types::time::{Date, PrimitiveDateTime, Time};
+use time::Month;
+
+pub(crate) fn into_sql_date(date: &NaiveDate) -> anyhow::Result<Date> {
+    let month = Month::try_from(date.month() as u8)?;
+    let date = Date::from_calendar_date(date.year(), month, date.day() as u8)?;
+
+    Ok(date)
+}
+
+pub(crate) fn from_sql_date(date: Date) -> NaiveDate {
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u8::from(date.month()) as u32,
+        date.day() as u32,
+    )
+    .unwrap_or_default()
+}
+
+pub(crate) fn into_sql_datetime(datetime: &NaiveDateTime) -> anyhow::Result<PrimitiveDateTime> {
+    let date = into_sql_date(&datetime.date())?;
+    let time = Time::from_hms_nano(
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+        datetime.nanosecond(),
+    )?;
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+pub(crate) fn from_sql_datetime(datetime: &PrimitiveDateTime) -> NaiveDateTime {
+    from_sql_date(datetime.date())
+        .and_hms_nano_opt(
+            datetime.hour() as u32,
+            datetime.minute() as u32,
+            datetime.second() as u32,
+            datetime.nanosecond(),
+        )
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{from_sql_date, from_sql_datetime, into_sql_date, into_sql_datetime};
+
+    #[test]
+    fn convert_date_and_datetime() -> anyhow::Result<()> {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let datetime = date.and_hms_nano_opt(23, 59, 58, 123_456_789).unwrap();
+
+        assert!(from_sql_date(into_sql_date(&date)?) == date);
+        assert!(from_sql_datetime(&into_sql_datetime(&datetime)?) == datetime);
+
+        Ok(())
+    }
+}
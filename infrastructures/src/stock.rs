@@ -1,3 +1,5 @@
+mod postgres_stock_import_service;
 mod postgres_stock_query_service;
 
+pub use postgres_stock_import_service::PostgresStockImportServiceImpl;
 pub use postgres_stock_query_service::PostgresStockQueryServiceImpl;
@@ -0,0 +1,103 @@
+use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
+
+use crate::sql_time::into_sql_date;
+use applications::stock::{StockData, StockImportError, StockImportResult, StockImportService};
+
+#[derive(Clone, Debug)]
+pub struct PostgresStockImportServiceImpl {
+    connection: PgPool,
+}
+
+impl PostgresStockImportServiceImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl StockImportService for PostgresStockImportServiceImpl {
+    #[tracing::instrument(skip(self, stocks), err, ret)]
+    async fn upsert(&self, stocks: Vec<StockData>) -> StockImportResult<u64> {
+        if stocks.is_empty() {
+            return Ok(0);
+        }
+
+        let mut stock_ids: Vec<String> = stocks.iter().map(|s| s.stock_id.clone()).collect();
+        stock_ids.sort();
+        stock_ids.dedup();
+
+        let mut rows = Vec::with_capacity(stocks.len());
+        for stock in stocks {
+            let date = into_sql_date(&stock.date).map_err(|_| StockImportError::InvalidData {
+                name: "date",
+                value: stock.date.to_string(),
+            })?;
+            rows.push((stock, date));
+        }
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "insert into stocks (stock_id, date, start_price, end_price, high_price, low_price, volume) ",
+        );
+        query.push_values(rows, |mut row, (stock, date)| {
+            row.push_bind(stock.stock_id)
+                .push_bind(date)
+                .push_bind(stock.start_price)
+                .push_bind(stock.end_price)
+                .push_bind(stock.high_price)
+                .push_bind(stock.low_price)
+                .push_bind(stock.volume);
+        });
+        query.push(
+            r#"
+            on conflict (stock_id, date)
+            do update set (start_price, end_price, high_price, low_price, volume)
+                = (excluded.start_price, excluded.end_price, excluded.high_price, excluded.low_price, excluded.volume)
+            "#,
+        );
+
+        let mut tx = self
+            .connection
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let result = query
+            .build()
+            .execute(&mut tx)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        // 取り込んだ銘柄の最新の株価と前営業日の終値を更新する
+        sqlx::query!(
+            r#"
+            insert into latest_quotes
+                (stock_id, date, start_price, end_price, high_price, low_price, volume, previous_date, previous_close)
+            select
+                latest.stock_id, latest.date, latest.start_price, latest.end_price,
+                latest.high_price, latest.low_price, latest.volume,
+                previous.date, previous.end_price
+            from unnest($1::varchar[]) as ids(stock_id)
+            join lateral (
+                select * from stocks where stocks.stock_id=ids.stock_id order by date desc limit 1
+            ) as latest on true
+            left join lateral (
+                select date, end_price from stocks
+                where stocks.stock_id=ids.stock_id and stocks.date<latest.date
+                order by date desc limit 1
+            ) as previous on true
+            on conflict (stock_id)
+            do update set (date, start_price, end_price, high_price, low_price, volume, previous_date, previous_close)
+                = (excluded.date, excluded.start_price, excluded.end_price, excluded.high_price,
+                    excluded.low_price, excluded.volume, excluded.previous_date, excluded.previous_close)
+            "#,
+            &stock_ids
+        )
+        .execute(&mut tx)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        tx.commit().await.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,64 +1,77 @@
-use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
 use sqlx::{postgres::PgPool, Postgres, QueryBuilder};
-use time::Month;
 
-use applications::stock::{
-    StockData, StockQueryCommand, StockQueryError, StockQueryResult, StockQueryService,
+use crate::sql_time::{from_sql_date, into_sql_date};
+use applications::{
+    corporate_action::CorporateActionData,
+    stock::{
+        QuoteData, StockData, StockInterval, StockQueryCommand, StockQueryError, StockQueryResult,
+        StockQueryService,
+    },
 };
+use domain::trading_calendar::TradingCalendar;
 
 #[derive(Clone, Debug)]
 pub struct PostgresStockQueryServiceImpl {
     connection: PgPool,
+    trading_calendar: TradingCalendar,
 }
 
 impl PostgresStockQueryServiceImpl {
-    pub fn new(connection: PgPool) -> Self {
-        Self { connection }
+    pub fn new(connection: PgPool, trading_calendar: TradingCalendar) -> Self {
+        Self {
+            connection,
+            trading_calendar,
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl StockQueryService for PostgresStockQueryServiceImpl {
     async fn find(&self, param: StockQueryCommand) -> StockQueryResult<Vec<StockData>> {
-        let mut query: QueryBuilder<Postgres> =
-            QueryBuilder::new("select * from stocks where stock_id=");
-        query.push_bind(&param.stock_id);
-
-        if let Some(start) = &param.start {
-            let date = sqlx::types::time::Date::from_calendar_date(
-                start.year(),
-                Month::try_from(start.month() as u8).map_err(|_| {
-                    StockQueryError::InvalidRangeOfDate {
-                        name: "start",
-                        value: start.clone(),
-                    }
-                })?,
-                start.day() as u8,
-            )
-            .map_err(|_| StockQueryError::InvalidRangeOfDate {
-                name: "start",
-                value: start.clone(),
-            })?;
-            query.push(" and date>=");
-            query.push_bind(date);
+        param.validate()?;
+        // ページサイズを指定しない場合は1ページに全件
+        if param.size.is_none() && param.page.unwrap_or(1) > 1 {
+            return Ok(vec![]);
         }
-        if let Some(end) = &param.end {
-            let date = sqlx::types::time::Date::from_calendar_date(
-                end.year(),
-                Month::try_from(end.month() as u8).map_err(|_| {
-                    StockQueryError::InvalidRangeOfDate {
-                        name: "end",
-                        value: end.clone(),
-                    }
-                })?,
-                end.day() as u8,
-            )
-            .map_err(|_| StockQueryError::InvalidRangeOfDate {
-                name: "end",
-                value: end.clone(),
-            })?;
-            query.push(" and date<=");
-            query.push_bind(date);
+
+        // 修正株価は権利落ち日によって期間内でも補正率が変わるため、日足を補正してから集計する
+        let aggregate_in_sql = param.interval != StockInterval::Day && !param.adjusted;
+        // 修正株価を集計する場合は、集計後にページングする
+        let page_in_sql = param.interval == StockInterval::Day || !param.adjusted;
+
+        let mut query: QueryBuilder<Postgres> = if aggregate_in_sql {
+            let mut query = QueryBuilder::new("select stock_id, date_trunc(");
+            query.push_bind(param.interval.as_str());
+            query.push(
+                ", date::timestamp)::date as date, \
+                least(sum(volume), 9223372036854775807)::bigint as volume, \
+                (array_agg(start_price order by date))[1] as start_price, \
+                (array_agg(end_price order by date desc))[1] as end_price, \
+                max(high_price) as high_price, \
+                min(low_price) as low_price \
+                from stocks where true",
+            );
+            query
+        } else {
+            QueryBuilder::new("select * from stocks where true")
+        };
+        push_conditions(&mut query, &param)?;
+
+        if aggregate_in_sql {
+            query.push(" group by stock_id, 2");
+        }
+        // 修正株価を集計する場合は、集計後に並び替える
+        if param.descending && page_in_sql {
+            query.push(" order by date desc");
+        } else {
+            query.push(" order by date");
+        }
+        if let (true, Some(size)) = (page_in_sql, param.size) {
+            query.push(" limit ");
+            query.push_bind(size as i64);
+            query.push(" offset ");
+            query.push_bind((param.page.unwrap_or(1) as i64 - 1) * size as i64);
         }
 
         let query = query.build_query_as();
@@ -67,53 +80,250 @@ impl StockQueryService for PostgresStockQueryServiceImpl {
             .await
             .map_err(|e| anyhow::anyhow!(e))?;
 
-        let result = result.into_iter().map(|s| s.into()).collect();
+        let mut result: Vec<StockData> = result.into_iter().map(|s| s.into()).collect();
+        // 修正株価
+        if param.adjusted {
+            let corporate_actions: Vec<CorporateActionData> = sqlx::query_as!(
+                CorporateActionModel,
+                r#"select stock_id, ex_date, ratio_from, ratio_to from corporate_actions where stock_id=$1"#,
+                param.stock_id
+            )
+            .fetch_all(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+            .into_iter()
+            .map(|c| c.into())
+            .collect();
+
+            result.iter_mut().for_each(|s| s.adjust(&corporate_actions));
+            result = param.interval.resample(result);
+        }
+        if !page_in_sql {
+            if param.descending {
+                result.reverse();
+            }
+            if let Some(size) = param.size {
+                result = result
+                    .into_iter()
+                    .skip((param.page.unwrap_or(1) as usize - 1) * size as usize)
+                    .take(size as usize)
+                    .collect();
+            }
+        }
+
         Ok(result)
     }
 
+    async fn count(&self, param: StockQueryCommand) -> StockQueryResult<i64> {
+        param.validate()?;
+
+        // 集計単位ごとにまとめた後の件数
+        let mut query: QueryBuilder<Postgres> = if param.interval == StockInterval::Day {
+            QueryBuilder::new("select count(*) from stocks where true")
+        } else {
+            let mut query = QueryBuilder::new("select count(distinct date_trunc(");
+            query.push_bind(param.interval.as_str());
+            query.push(", date::timestamp)) from stocks where true");
+            query
+        };
+        push_conditions(&mut query, &param)?;
+
+        let (count,): (i64,) = query
+            .build_query_as()
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(count)
+    }
+
     async fn find_latest(&self, stock_id: &str) -> StockQueryResult<StockData> {
         let result = sqlx::query_as!(
             StockModel,
-            r#"select * from stocks where date=(select max(date) from stocks where stock_id=$1)"#,
+            r#"
+            select stock_id, date, volume, start_price, end_price, high_price, low_price
+            from latest_quotes
+            where stock_id=$1
+            "#,
             stock_id
         )
-        .fetch_one(&self.connection)
+        .fetch_optional(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .ok_or(StockQueryError::StockDataNotFound(stock_id.into()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_latest_many(
+        &self,
+        stock_ids: Vec<String>,
+    ) -> StockQueryResult<Vec<QuoteData>> {
+        // 銘柄ごとの最新の株価と前営業日の終値は取り込み時に集計済み
+        let quotes = sqlx::query_as!(
+            QuoteModel,
+            r#"
+            select stock_id, date, end_price, volume, previous_date, previous_close
+            from latest_quotes
+            where stock_id=any($1)
+            order by stock_id
+            "#,
+            &stock_ids
+        )
+        .fetch_all(&self.connection)
         .await
-        .map(|s| s.into())
         .map_err(|e| anyhow::anyhow!(e))?;
 
+        let corporate_actions: Vec<CorporateActionData> = sqlx::query_as!(
+            CorporateActionModel,
+            r#"select stock_id, ex_date, ratio_from, ratio_to from corporate_actions where stock_id=any($1)"#,
+            &stock_ids
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .into_iter()
+        .map(|c| c.into())
+        .collect();
+
+        let result = quotes
+            .into_iter()
+            .map(|quote| quote.into_quote_data(&corporate_actions, &self.trading_calendar))
+            .collect();
+
         Ok(result)
     }
+
+    fn trading_calendar(&self) -> &TradingCalendar {
+        &self.trading_calendar
+    }
 }
 
 #[derive(sqlx::FromRow, Debug, Clone)]
 struct StockModel {
     stock_id: String,
     date: sqlx::types::time::Date,
-    volume: i32,
-    start_price: i32,
-    end_price: i32,
-    high_price: i32,
-    low_price: i32,
+    volume: i64,
+    start_price: Decimal,
+    end_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
 }
 
-impl Into<StockData> for StockModel {
-    fn into(self) -> StockData {
-        let date = NaiveDate::from_ymd_opt(
-            self.date.year(),
-            u8::from(self.date.month()) as u32,
-            self.date.day() as u32,
-        )
-        .unwrap();
+impl From<StockModel> for StockData {
+    fn from(value: StockModel) -> Self {
+        let date = from_sql_date(value.date);
 
         StockData {
-            stock_id: self.stock_id,
+            stock_id: value.stock_id,
             date,
-            volume: self.volume,
-            start_price: self.start_price,
+            volume: value.volume,
+            start_price: value.start_price,
+            end_price: value.end_price,
+            high_price: value.high_price,
+            low_price: value.low_price,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct QuoteModel {
+    stock_id: String,
+    date: sqlx::types::time::Date,
+    end_price: Decimal,
+    volume: i64,
+    previous_date: Option<sqlx::types::time::Date>,
+    previous_close: Option<Decimal>,
+}
+
+impl QuoteModel {
+    #[allow(clippy::wrong_self_convention)]
+    fn into_quote_data(
+        self,
+        corporate_actions: &[CorporateActionData],
+        trading_calendar: &TradingCalendar,
+    ) -> QuoteData {
+        let latest = StockData {
+            stock_id: self.stock_id.clone(),
+            date: from_sql_date(self.date),
             end_price: self.end_price,
-            high_price: self.high_price,
-            low_price: self.low_price,
+            volume: self.volume,
+            ..Default::default()
+        };
+        // 休場日の株価は前営業日の終値として扱わない
+        let previous_trading_day = trading_calendar.previous_trading_day(latest.date);
+        let previous = self
+            .previous_date
+            .map(from_sql_date)
+            .zip(self.previous_close)
+            .filter(|(date, _)| *date <= previous_trading_day)
+            .map(|(date, end_price)| {
+                let mut previous = StockData {
+                    stock_id: self.stock_id,
+                    date,
+                    end_price,
+                    ..Default::default()
+                };
+                // 前営業日の終値は最新の株価と同じ株数基準に修正する
+                let corporate_actions: Vec<CorporateActionData> = corporate_actions
+                    .iter()
+                    .filter(|action| action.ex_date <= latest.date)
+                    .cloned()
+                    .collect();
+                previous.adjust(&corporate_actions);
+                previous
+            });
+
+        QuoteData::new(latest, previous)
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct CorporateActionModel {
+    stock_id: String,
+    ex_date: sqlx::types::time::Date,
+    ratio_from: i32,
+    ratio_to: i32,
+}
+
+impl From<CorporateActionModel> for CorporateActionData {
+    fn from(value: CorporateActionModel) -> Self {
+        let ex_date = from_sql_date(value.ex_date);
+
+        CorporateActionData {
+            stock_id: value.stock_id,
+            ex_date,
+            ratio_from: value.ratio_from,
+            ratio_to: value.ratio_to,
         }
     }
 }
+
+/// 証券コード・日付範囲の条件をwhere句に追加する(カーソルを指定した場合はその続き)
+fn push_conditions(
+    query: &mut QueryBuilder<Postgres>,
+    param: &StockQueryCommand,
+) -> StockQueryResult<()> {
+    query.push(" and stock_id=");
+    query.push_bind(param.stock_id.clone());
+
+    let (start, end) = param.date_range();
+    if let Some(start) = &start {
+        let date = into_sql_date(start).map_err(|_| StockQueryError::InvalidRangeOfDate {
+            name: "start",
+            value: *start,
+        })?;
+        query.push(" and date>=");
+        query.push_bind(date);
+    }
+    if let Some(end) = &end {
+        let date = into_sql_date(end).map_err(|_| StockQueryError::InvalidRangeOfDate {
+            name: "end",
+            value: *end,
+        })?;
+        query.push(" and date<=");
+        query.push_bind(date);
+    }
+
+    Ok(())
+}
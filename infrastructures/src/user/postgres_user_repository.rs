@@ -70,11 +70,11 @@ struct UserModel {
     email: String,
 }
 
-impl Into<User> for UserModel {
-    fn into(self) -> User {
-        let id = UserId::new(self.id);
-        let name = UserName::new(self.name);
-        let email = UserEmail::new(self.email);
+impl From<UserModel> for User {
+    fn from(value: UserModel) -> Self {
+        let id = UserId::new(value.id);
+        let name = UserName::restore(value.name);
+        let email = UserEmail::restore(value.email);
 
         User::new(id, name, email)
     }
@@ -0,0 +1,5 @@
+mod http_webhook_sender;
+mod postgres_webhook_repository;
+
+pub use http_webhook_sender::HttpWebhookSenderImpl;
+pub use postgres_webhook_repository::PostgresWebhookRepositoryImpl;
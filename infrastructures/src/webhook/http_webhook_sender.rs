@@ -0,0 +1,252 @@
+use std::{io, net::IpAddr, sync::Arc, time::Duration};
+
+use anyhow::anyhow;
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+use applications::webhook::{
+    WebhookRequest, WebhookSender, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER,
+};
+use domain::webhook::is_public_address;
+
+/// 送信のタイムアウト(秒)
+const TIMEOUT_SECONDS: u64 = 10;
+
+/// 名前解決したアドレスが外部に公開されていない場合はエラーにするリゾルバ
+///
+/// 名前解決後にチェックすることで、内部ネットワークのアドレスを返すドメインへの送信を防ぐ
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .collect::<Vec<_>>();
+            if addrs.is_empty() || addrs.iter().any(|addr| !is_public_address(addr.ip())) {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("webhook host is not public: {}", host),
+                )
+                .into());
+            }
+
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpWebhookSenderImpl {
+    client: reqwest::Client,
+    /// 外部に公開されていないアドレスへの送信を許可するか(テスト用)
+    allow_private_address: bool,
+}
+
+impl HttpWebhookSenderImpl {
+    /// コンストラクタ
+    pub fn new() -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(TIMEOUT_SECONDS))
+            // 登録されたURL以外に送信しない
+            .redirect(reqwest::redirect::Policy::none())
+            // プロキシ経由では送信先のアドレスをチェックできないため使用しない
+            .no_proxy()
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            .build()?;
+
+        Ok(Self {
+            client,
+            allow_private_address: false,
+        })
+    }
+
+    /// 送信先のホストがIPアドレスの場合、外部に公開されたアドレスかチェックする
+    ///
+    /// ドメインの場合は名前解決時にチェックする
+    fn check_host(&self, url: &str) -> anyhow::Result<()> {
+        let url = reqwest::Url::parse(url)?;
+        let Some(host) = url.host_str() else {
+            return Err(anyhow!("webhook url has no host: {}", url));
+        };
+        if let Ok(ip) = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+        {
+            if !self.allow_private_address && !is_public_address(ip) {
+                return Err(anyhow!("webhook host is not public: {}", host));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for HttpWebhookSenderImpl {
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<u16> {
+        self.check_host(&request.url)?;
+
+        let response = self
+            .client
+            .post(&request.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, &request.signature)
+            .header(EVENT_HEADER, &request.event)
+            .header(DELIVERY_HEADER, request.delivery_id.to_string())
+            .body(request.body)
+            .send()
+            .await?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use applications::webhook::{sign_payload, WebhookRequest, WebhookSender};
+
+    use super::HttpWebhookSenderImpl;
+
+    /// ローカルの受信サーバーに送信できるSender
+    fn local_sender() -> anyhow::Result<HttpWebhookSenderImpl> {
+        Ok(HttpWebhookSenderImpl {
+            allow_private_address: true,
+            ..HttpWebhookSenderImpl::new()?
+        })
+    }
+
+    /// 1件のリクエストを受信して指定したステータスを返すHTTPサーバー
+    async fn spawn_receiver(status: &'static str) -> anyhow::Result<(String, JoinHandle<String>)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/hook", listener.local_addr()?);
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0; 1024];
+            loop {
+                let size = socket.read(&mut buffer).await.unwrap();
+                received.extend_from_slice(&buffer[..size]);
+                let text = String::from_utf8_lossy(&received).to_string();
+                // ヘッダーとContent-Length分のbodyを受信したら終了
+                if let Some(index) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if received.len() >= index + 4 + length {
+                        break;
+                    }
+                }
+                if size == 0 {
+                    break;
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                status
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+
+            String::from_utf8_lossy(&received).to_string()
+        });
+
+        Ok((url, handle))
+    }
+
+    fn request(url: String) -> WebhookRequest {
+        let body = r#"{"event":"favorite","data":{"stock_id":"1234"}}"#.to_string();
+        WebhookRequest {
+            url,
+            event: "favorite".to_string(),
+            delivery_id: 7,
+            signature: sign_payload("secret", &body),
+            body,
+        }
+    }
+
+    #[tokio::test]
+    async fn send_signed_request() -> anyhow::Result<()> {
+        let (url, handle) = spawn_receiver("200 OK").await?;
+        let sender = local_sender()?;
+        let request = request(url);
+
+        let status = sender.send(request.clone()).await?;
+        let received = handle.await?.to_lowercase();
+
+        assert!(status == 200);
+        assert!(received.starts_with("post /hook "));
+        assert!(received.contains("content-type: application/json"));
+        assert!(received.contains(&format!("x-webhook-signature: {}", request.signature)));
+        assert!(received.contains("x-webhook-event: favorite"));
+        assert!(received.contains("x-webhook-delivery: 7"));
+        let Some((_, body)) = received.split_once("\r\n\r\n") else {
+            return Err(anyhow!("body not received"));
+        };
+        assert!(body == request.body);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_return_error_status() -> anyhow::Result<()> {
+        let (url, handle) = spawn_receiver("503 Service Unavailable").await?;
+        let sender = local_sender()?;
+
+        let status = sender.send(request(url)).await?;
+        handle.await?;
+
+        assert!(status == 503);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_to_closed_port_return_err() -> anyhow::Result<()> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/hook", listener.local_addr()?);
+        drop(listener);
+        let sender = local_sender()?;
+
+        assert!(sender.send(request(url)).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_to_private_address_return_err() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let local_url = format!("http://{}/hook", listener.local_addr()?);
+        let sender = HttpWebhookSenderImpl::new()?;
+
+        for url in [
+            local_url.as_str(),
+            "https://localhost/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://2130706433/hook",
+            "https://[::1]/hook",
+            "https://[::ffff:10.0.0.1]/hook",
+        ] {
+            assert!(sender.send(request(url.to_string())).await.is_err());
+        }
+        // 送信されていない
+        let accepted =
+            tokio::time::timeout(std::time::Duration::from_millis(100), listener.accept()).await;
+        assert!(accepted.is_err());
+
+        Ok(())
+    }
+}
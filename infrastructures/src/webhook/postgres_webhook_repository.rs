@@ -0,0 +1,296 @@
+use chrono::NaiveDateTime;
+use sqlx::{postgres::PgPool, types::time::PrimitiveDateTime};
+
+use crate::sql_time::{from_sql_datetime, into_sql_datetime};
+use domain::{
+    user::UserId,
+    webhook::{
+        DeliveryStatus, Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDomainError,
+        WebhookDomainResult, WebhookEvent, WebhookId, WebhookRepository, WebhookUrl,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresWebhookRepositoryImpl {
+    connection: PgPool,
+}
+
+impl PostgresWebhookRepositoryImpl {
+    pub fn new(connection: PgPool) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for PostgresWebhookRepositoryImpl {
+    async fn save_webhook(&self, webhook: Webhook) -> WebhookDomainResult<WebhookId> {
+        let events: Vec<String> = webhook
+            .events()
+            .iter()
+            .map(|e| e.as_str().to_string())
+            .collect();
+        let id = if let Some(id) = webhook.id {
+            sqlx::query!(
+                r#"
+                update webhooks set (url, events, enabled) = ($3, $4, $5)
+                where id=$1 and user_id=$2
+                "#,
+                *id,
+                webhook.user_id.as_str(),
+                webhook.url.as_str(),
+                &events,
+                webhook.enabled,
+            )
+            .execute(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+            *id
+        } else {
+            sqlx::query_scalar!(
+                r#"
+                insert into webhooks (user_id, url, secret, events, enabled)
+                values ($1, $2, $3, $4, $5)
+                returning id
+                "#,
+                webhook.user_id.as_str(),
+                webhook.url.as_str(),
+                webhook.secret,
+                &events,
+                webhook.enabled,
+            )
+            .fetch_one(&self.connection)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+        };
+
+        Ok(WebhookId::new(id))
+    }
+
+    async fn delete_webhook(
+        &self,
+        user_id: &UserId,
+        webhook_id: &WebhookId,
+    ) -> WebhookDomainResult<()> {
+        sqlx::query!(
+            r#"delete from webhooks where id=$1 and user_id=$2"#,
+            **webhook_id,
+            user_id.as_str(),
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_webhooks(&self, user_id: &UserId) -> WebhookDomainResult<Vec<Webhook>> {
+        let result = sqlx::query_as!(
+            WebhookModel,
+            r#"
+            select id, user_id, url, secret, events, enabled
+            from webhooks
+            where user_id=$1
+            order by id
+            "#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(Webhook::try_from).collect()
+    }
+
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> WebhookDomainResult<Option<Webhook>> {
+        let result = sqlx::query_as!(
+            WebhookModel,
+            r#"
+            select id, user_id, url, secret, events, enabled
+            from webhooks
+            where id=$1
+            "#,
+            **webhook_id
+        )
+        .fetch_optional(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.map(Webhook::try_from).transpose()
+    }
+
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> WebhookDomainResult<WebhookDeliveryId> {
+        let id = sqlx::query_scalar!(
+            r#"
+            insert into webhook_deliveries
+            (webhook_id, user_id, event, payload, status, attempts, next_attempt_at, created_at)
+            values ($1, $2, $3, $4, $5, $6, $7, $8)
+            returning id
+            "#,
+            *delivery.webhook_id,
+            delivery.user_id.as_str(),
+            delivery.event.as_str(),
+            delivery.payload,
+            delivery.status.as_str(),
+            delivery.attempts,
+            into_sql_datetime(&delivery.next_attempt_at)?,
+            into_sql_datetime(&delivery.created_at)?,
+        )
+        .fetch_one(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(WebhookDeliveryId::new(id))
+    }
+
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> WebhookDomainResult<()> {
+        let Some(id) = delivery.id else { return Ok(()) };
+        let delivered_at = delivery
+            .delivered_at
+            .as_ref()
+            .map(into_sql_datetime)
+            .transpose()?;
+        sqlx::query!(
+            r#"
+            update webhook_deliveries
+            set (status, attempts, next_attempt_at, last_status_code, last_error, delivered_at)
+            = ($2, $3, $4, $5, $6, $7)
+            where id=$1
+            "#,
+            *id,
+            delivery.status.as_str(),
+            delivery.attempts,
+            into_sql_datetime(&delivery.next_attempt_at)?,
+            delivery.last_status_code,
+            delivery.last_error,
+            delivered_at,
+        )
+        .execute(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(())
+    }
+
+    async fn find_pending_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>> {
+        let result = sqlx::query_as!(
+            WebhookDeliveryModel,
+            r#"
+            select id, webhook_id, user_id, event, payload, status, attempts, next_attempt_at,
+            last_status_code, last_error, created_at, delivered_at
+            from webhook_deliveries
+            where status='pending' and next_attempt_at<=$1
+            order by next_attempt_at, id
+            limit $2
+            "#,
+            into_sql_datetime(&now)?,
+            limit
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: &WebhookId,
+        limit: i64,
+    ) -> WebhookDomainResult<Vec<WebhookDelivery>> {
+        let result = sqlx::query_as!(
+            WebhookDeliveryModel,
+            r#"
+            select id, webhook_id, user_id, event, payload, status, attempts, next_attempt_at,
+            last_status_code, last_error, created_at, delivered_at
+            from webhook_deliveries
+            where webhook_id=$1
+            order by id desc
+            limit $2
+            "#,
+            **webhook_id,
+            limit
+        )
+        .fetch_all(&self.connection)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct WebhookModel {
+    id: i32,
+    user_id: String,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+    enabled: bool,
+}
+
+impl TryFrom<WebhookModel> for Webhook {
+    type Error = WebhookDomainError;
+
+    fn try_from(value: WebhookModel) -> Result<Self, Self::Error> {
+        let events = value
+            .events
+            .iter()
+            .map(|e| WebhookEvent::new(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut webhook = Webhook::new(
+            UserId::new(value.user_id),
+            WebhookUrl::restore(value.url),
+            value.secret,
+            events,
+        )?;
+        webhook.id = Some(WebhookId::new(value.id));
+        webhook.enabled = value.enabled;
+
+        Ok(webhook)
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+struct WebhookDeliveryModel {
+    id: i32,
+    webhook_id: i32,
+    user_id: String,
+    event: String,
+    payload: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: PrimitiveDateTime,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    created_at: PrimitiveDateTime,
+    delivered_at: Option<PrimitiveDateTime>,
+}
+
+impl TryFrom<WebhookDeliveryModel> for WebhookDelivery {
+    type Error = WebhookDomainError;
+
+    fn try_from(value: WebhookDeliveryModel) -> Result<Self, Self::Error> {
+        Ok(WebhookDelivery {
+            id: Some(WebhookDeliveryId::new(value.id)),
+            webhook_id: WebhookId::new(value.webhook_id),
+            user_id: UserId::new(value.user_id),
+            event: WebhookEvent::new(&value.event)?,
+            payload: value.payload,
+            status: DeliveryStatus::new(&value.status)?,
+            attempts: value.attempts,
+            next_attempt_at: from_sql_datetime(&value.next_attempt_at),
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: from_sql_datetime(&value.created_at),
+            delivered_at: value.delivered_at.as_ref().map(from_sql_datetime),
+        })
+    }
+}
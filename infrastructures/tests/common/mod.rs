@@ -0,0 +1,66 @@
+//! 結合テストの共通処理
+//!
+//! 環境変数DATABASE_URLのPostgreSQLにテストごとの専用スキーマを作成してマイグレーションを適用する
+//! 既存のテーブルには触れず、終了時にスキーマごと削除する
+
+use std::{path::PathBuf, str::FromStr};
+
+use sqlx::{
+    postgres::{PgConnectOptions, PgPool},
+    Executor,
+};
+
+pub struct TestDatabase {
+    pub pool: PgPool,
+    schema: String,
+}
+
+impl TestDatabase {
+    /// テスト名から専用スキーマを作成して接続する
+    pub async fn connect(name: &str) -> anyhow::Result<Self> {
+        let url = std::env::var("DATABASE_URL")?;
+        let schema = format!("test_{}_{}", name, std::process::id());
+
+        let pool = PgPool::connect(&url).await?;
+        pool.execute(
+            format!("drop schema if exists {schema} cascade; create schema {schema}").as_str(),
+        )
+        .await?;
+        pool.close().await;
+
+        // 拡張機能(pg_trgm)はpublicスキーマのものを使う
+        let options = PgConnectOptions::from_str(&url)?
+            .options([("search_path", format!("{schema},public"))]);
+        let pool = PgPool::connect_with(options).await?;
+        for path in migrations()? {
+            pool.execute(std::fs::read_to_string(path)?.as_str())
+                .await?;
+        }
+
+        Ok(Self { pool, schema })
+    }
+
+    /// 専用スキーマを削除する
+    pub async fn drop(self) -> anyhow::Result<()> {
+        self.pool
+            .execute(format!("drop schema {} cascade", self.schema).as_str())
+            .await?;
+        self.pool.close().await;
+
+        Ok(())
+    }
+}
+
+/// 適用順(ファイル名順)のマイグレーション
+fn migrations() -> anyhow::Result<Vec<PathBuf>> {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../migrations");
+    let mut paths = std::fs::read_dir(directory)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<anyhow::Result<Vec<PathBuf>>>()?
+        .into_iter()
+        .filter(|path| path.to_string_lossy().ends_with(".up.sql"))
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+
+    Ok(paths)
+}
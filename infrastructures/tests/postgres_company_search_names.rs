@@ -0,0 +1,105 @@
+//! 企業名の検索用の値の再作成と入力補完の結合テスト
+//!
+//! 環境変数DATABASE_URLのPostgreSQLを使うため既定では実行しない(`cargo test -- --ignored`で実行する)
+//! テストごとに専用のスキーマを作成し、終了時に削除する
+
+mod common;
+
+use applications::company::{
+    normalize_search_text, CompanyData, CompanyImportService, CompanyQueryService,
+    CompanySuggestCommand,
+};
+use infrastructures::company::{PostgresCompanyImportServiceImpl, PostgresCompanyQueryServiceImpl};
+
+use common::TestDatabase;
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn refresh_search_names_normalize_existing_companies() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("refresh_search_names").await?;
+    let pool = db.pool.clone();
+    let names = ["ﾄﾖﾀ自動車_T18", "とよた　ＫＤＤＩ_T18"];
+    let stock_ids = vec!["T18A01".to_string(), "T18A02".to_string()];
+
+    let service = PostgresCompanyImportServiceImpl::new(pool.clone());
+    let companies = stock_ids
+        .iter()
+        .zip(names)
+        .map(|(stock_id, name)| CompanyData {
+            name: name.to_string(),
+            stock_id: stock_id.clone(),
+            sector: "test".to_string(),
+            industry: "test".to_string(),
+        })
+        .collect();
+    service.upsert(companies).await?;
+
+    let result = async {
+        // 検索機能の追加時のマイグレーションと同じく小文字化のみの状態にする
+        sqlx::query("update companies set search_name = lower(name) where stock_id=any($1)")
+            .bind(&stock_ids)
+            .execute(&pool)
+            .await?;
+
+        let updated = service.refresh_search_names().await?;
+        let search_names: Vec<String> = sqlx::query_scalar(
+            "select search_name from companies where stock_id=any($1) order by stock_id",
+        )
+        .bind(&stock_ids)
+        .fetch_all(&pool)
+        .await?;
+        // 正規化済みの場合は更新しない
+        let unchanged = service.refresh_search_names().await?;
+
+        anyhow::Ok((updated, search_names, unchanged))
+    }
+    .await;
+    db.drop().await?;
+
+    let (updated, search_names, unchanged) = result?;
+    assert!(updated == 2);
+    assert!(search_names == names.map(normalize_search_text));
+    assert!(search_names[0] == "トヨタ自動車_t18");
+    assert!(unchanged == 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn suggest_by_alphanumeric_stock_id() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("suggest_by_stock_id").await?;
+    let pool = db.pool.clone();
+    let companies = [("130A", "Veritas In Silico"), ("1301", "極洋")]
+        .into_iter()
+        .map(|(stock_id, name)| CompanyData {
+            name: name.to_string(),
+            stock_id: stock_id.to_string(),
+            sector: "test".to_string(),
+            industry: "test".to_string(),
+        })
+        .collect();
+    PostgresCompanyImportServiceImpl::new(pool.clone())
+        .upsert(companies)
+        .await?;
+
+    let result = async {
+        let service = PostgresCompanyQueryServiceImpl::new(pool.clone());
+        let mut found = Vec::new();
+        // 証券コードは企業名の正規化(小文字化)をせず大文字で比較する
+        for keyword in ["130A", " 130a"] {
+            let result = service
+                .suggest(CompanySuggestCommand::new(keyword.to_string()))
+                .await?;
+            found.push(result.into_iter().map(|c| c.stock_id).collect::<Vec<_>>());
+        }
+
+        anyhow::Ok(found)
+    }
+    .await;
+    db.drop().await?;
+
+    assert!(result? == [vec!["130A"], vec!["130A"]]);
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+//! 最新株価の読み取りモデル(latest_quotes)の結合テスト
+//!
+//! 環境変数DATABASE_URLのPostgreSQLを使うため既定では実行しない(`cargo test -- --ignored`で実行する)
+//! テストごとに専用のスキーマを作成し、終了時に削除する
+
+mod common;
+
+use anyhow::anyhow;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+
+use common::TestDatabase;
+
+use applications::{
+    company::{CompanyData, CompanyImportService},
+    stock::{StockData, StockImportService, StockQueryError, StockQueryService},
+};
+use domain::trading_calendar::TradingCalendar;
+use infrastructures::{
+    company::PostgresCompanyImportServiceImpl,
+    stock::{PostgresStockImportServiceImpl, PostgresStockQueryServiceImpl},
+};
+
+/// テスト用の企業を登録する
+async fn setup(pool: &PgPool, stock_ids: &[&str]) -> anyhow::Result<()> {
+    let companies = stock_ids
+        .iter()
+        .map(|stock_id| CompanyData {
+            name: format!("latest_quotes_test_{stock_id}"),
+            stock_id: stock_id.to_string(),
+            sector: "test".to_string(),
+            industry: "test".to_string(),
+        })
+        .collect();
+    PostgresCompanyImportServiceImpl::new(pool.clone())
+        .upsert(companies)
+        .await?;
+
+    Ok(())
+}
+
+fn stock(stock_id: &str, date: &str, end_price: i64) -> anyhow::Result<StockData> {
+    Ok(StockData {
+        stock_id: stock_id.to_string(),
+        date: NaiveDate::parse_from_str(date, "%Y-%m-%d")?,
+        volume: 100,
+        start_price: Decimal::from(end_price - 10),
+        end_price: Decimal::from(end_price),
+        high_price: Decimal::from(end_price + 20),
+        low_price: Decimal::from(end_price - 20),
+    })
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn find_latest_returns_requested_stock() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("find_latest_returns_requested_stock").await?;
+    let pool = db.pool.clone();
+    let stock_ids = ["T21A01", "T21A02"];
+    setup(&pool, &stock_ids).await?;
+
+    // 同じ日付に複数銘柄の株価があり、他の銘柄の方が新しい日付まである
+    let stocks = vec![
+        stock("T21A01", "2023-04-03", 1000)?,
+        stock("T21A01", "2023-04-04", 1100)?,
+        stock("T21A02", "2023-04-04", 5000)?,
+        stock("T21A02", "2023-04-05", 5100)?,
+    ];
+    PostgresStockImportServiceImpl::new(pool.clone())
+        .upsert(stocks)
+        .await?;
+
+    let service = PostgresStockQueryServiceImpl::new(pool.clone(), TradingCalendar::new());
+    let first = service.find_latest("T21A01").await;
+    let second = service.find_latest("T21A02").await;
+    db.drop().await?;
+
+    assert!(first? == stock("T21A01", "2023-04-04", 1100)?);
+    assert!(second? == stock("T21A02", "2023-04-05", 5100)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn find_latest_without_stock_data() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("find_latest_without_stock_data").await?;
+    let pool = db.pool.clone();
+    let stock_ids = ["T21B01"];
+    setup(&pool, &stock_ids).await?;
+
+    let service = PostgresStockQueryServiceImpl::new(pool.clone(), TradingCalendar::new());
+    let result = service.find_latest("T21B01").await;
+    db.drop().await?;
+
+    let Err(StockQueryError::StockDataNotFound(stock_id)) = result else {
+        return Err(anyhow!("unexpected result: {:?}", result));
+    };
+    assert!(stock_id == "T21B01");
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn find_latest_many_with_previous_close() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("find_latest_many_with_previous_close").await?;
+    let pool = db.pool.clone();
+    let stock_ids = ["T21C01", "T21C02"];
+    setup(&pool, &stock_ids).await?;
+
+    let stocks = vec![
+        stock("T21C01", "2023-04-03", 1000)?,
+        stock("T21C01", "2023-04-04", 1100)?,
+        stock("T21C01", "2023-04-05", 1050)?,
+        stock("T21C02", "2023-04-05", 3000)?,
+    ];
+    PostgresStockImportServiceImpl::new(pool.clone())
+        .upsert(stocks)
+        .await?;
+
+    let service = PostgresStockQueryServiceImpl::new(pool.clone(), TradingCalendar::new());
+    let result = service
+        .find_latest_many(stock_ids.iter().map(|s| s.to_string()).collect())
+        .await;
+    db.drop().await?;
+
+    let quotes = result?;
+    assert!(quotes.len() == 2);
+    assert!(quotes[0].stock_id == "T21C01");
+    assert!(quotes[0].end_price == Decimal::from(1050));
+    assert!(quotes[0].previous_close == Some(Decimal::from(1100)));
+    assert!(quotes[0].change() == Some(Decimal::from(-50)));
+    assert!(quotes[1].stock_id == "T21C02");
+    assert!(quotes[1].end_price == Decimal::from(3000));
+    assert!(quotes[1].previous_close.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn latest_quote_is_updated_on_import() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("latest_quote_is_updated_on_import").await?;
+    let pool = db.pool.clone();
+    let stock_ids = ["T21D01"];
+    setup(&pool, &stock_ids).await?;
+
+    let import_service = PostgresStockImportServiceImpl::new(pool.clone());
+    let query_service = PostgresStockQueryServiceImpl::new(pool.clone(), TradingCalendar::new());
+
+    import_service
+        .upsert(vec![stock("T21D01", "2023-04-04", 2000)?])
+        .await?;
+    let first = query_service
+        .find_latest_many(vec!["T21D01".to_string()])
+        .await;
+
+    // 新しい日付の株価を取り込むと最新株価が進み、前営業日の終値が入る
+    import_service
+        .upsert(vec![stock("T21D01", "2023-04-05", 2100)?])
+        .await?;
+    let second = query_service
+        .find_latest_many(vec!["T21D01".to_string()])
+        .await;
+
+    // 過去の日付を取り込んでも最新株価は変わらず、前営業日の終値は訂正される
+    import_service
+        .upsert(vec![
+            stock("T21D01", "2023-04-03", 1900)?,
+            stock("T21D01", "2023-04-04", 2050)?,
+        ])
+        .await?;
+    let third = query_service
+        .find_latest_many(vec!["T21D01".to_string()])
+        .await;
+    db.drop().await?;
+
+    let first = first?;
+    assert!(first[0].end_price == Decimal::from(2000));
+    assert!(first[0].previous_close.is_none());
+
+    let second = second?;
+    assert!(second[0].end_price == Decimal::from(2100));
+    assert!(second[0].previous_close == Some(Decimal::from(2000)));
+
+    let third = third?;
+    assert!(third[0].date == NaiveDate::parse_from_str("2023-04-05", "%Y-%m-%d")?);
+    assert!(third[0].end_price == Decimal::from(2100));
+    assert!(third[0].previous_close == Some(Decimal::from(2050)));
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! 売買履歴と保有銘柄の保存の結合テスト
+//!
+//! 環境変数DATABASE_URLのPostgreSQLを使うため既定では実行しない(`cargo test -- --ignored`で実行する)
+//! テストごとに専用のスキーマを作成し、終了時に削除する
+
+mod common;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+
+use common::TestDatabase;
+
+use domain::{
+    money::{Money, Price, Quantity},
+    portfolio::{
+        AccountType, Portfolio, PortfolioAccount, PortfolioId, PortfolioReposotory, Transaction,
+        TransactionType,
+    },
+    stock::StockId,
+    user::UserId,
+};
+use infrastructures::portfolio::PostgresPortfolioRepositoryImpl;
+
+/// テスト用のユーザー・企業・ポートフォリオ(口座)を登録する
+async fn setup(
+    repository: &PostgresPortfolioRepositoryImpl,
+    pool: &PgPool,
+    user_id: &str,
+    stock_id: &str,
+) -> anyhow::Result<PortfolioId> {
+    sqlx::query("insert into users (id, name, email) values ($1, $1, $1 || '@example.com')")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    sqlx::query(
+        "insert into companies (stock_id, name, sector, industry) values ($1, $1, 'test', 'test')",
+    )
+    .bind(stock_id)
+    .execute(pool)
+    .await?;
+    let account = PortfolioAccount::new(
+        None,
+        UserId::new(user_id.to_string()),
+        "test".to_string(),
+        AccountType::default(),
+    )?;
+
+    Ok(repository.save_account(account).await?)
+}
+
+fn portfolio(
+    portfolio_id: PortfolioId,
+    user_id: &str,
+    stock_id: &str,
+    stock_count: i64,
+) -> anyhow::Result<Portfolio> {
+    Ok(Portfolio::new(
+        portfolio_id,
+        UserId::new(user_id.to_string()),
+        StockId::new(stock_id.to_string())?,
+        Quantity::new(stock_count)?,
+        Price::new(Decimal::from(1000))?,
+    ))
+}
+
+fn buy(
+    portfolio_id: PortfolioId,
+    user_id: &str,
+    stock_id: &str,
+    quantity: i64,
+) -> anyhow::Result<Transaction> {
+    Ok(Transaction::new(
+        None,
+        portfolio_id,
+        UserId::new(user_id.to_string()),
+        StockId::new(stock_id.to_string())?,
+        TransactionType::Buy,
+        NaiveDate::from_ymd_opt(2023, 4, 3).unwrap(),
+        Quantity::new(quantity)?,
+        Price::new(Decimal::from(1000))?,
+        Money::default(),
+    )?)
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn save_and_delete_transactions_with_portfolio() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("save_and_delete_transactions_with_portfolio").await?;
+    let pool = db.pool.clone();
+    let repository = PostgresPortfolioRepositoryImpl::new(pool.clone());
+    let (user_id, stock_id) = ("portfolio_transactions_test_a", "9T6A");
+    let portfolio_id = setup(&repository, &pool, user_id, stock_id).await?;
+
+    let result = async {
+        let ids = repository
+            .save_transactions(
+                portfolio(portfolio_id, user_id, stock_id, 300)?,
+                vec![
+                    buy(portfolio_id, user_id, stock_id, 100)?,
+                    buy(portfolio_id, user_id, stock_id, 200)?,
+                ],
+            )
+            .await?;
+        let target = StockId::new(stock_id.to_string())?;
+        let saved = repository.find(&portfolio_id, &target).await?;
+        let transactions = repository.find_transactions(&portfolio_id, &target).await?;
+
+        repository
+            .delete_transaction(portfolio(portfolio_id, user_id, stock_id, 200)?, &ids[0])
+            .await?;
+        let deleted = repository.find(&portfolio_id, &target).await?;
+        let remaining = repository.find_transactions(&portfolio_id, &target).await?;
+
+        anyhow::Ok((ids, saved, transactions, deleted, remaining))
+    }
+    .await;
+    db.drop().await?;
+
+    let (ids, saved, transactions, deleted, remaining) = result?;
+    assert!(ids.len() == 2);
+    assert!(saved.map(|p| *p.stock_count) == Some(300));
+    assert!(
+        transactions.iter().map(|t| t.id()).collect::<Vec<_>>() == [Some(ids[0]), Some(ids[1])]
+    );
+    assert!(deleted.map(|p| *p.stock_count) == Some(200));
+    assert!(remaining.len() == 1);
+    assert!(remaining[0].id() == Some(ids[1]));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn save_transactions_rollback_on_error() -> anyhow::Result<()> {
+    let db = TestDatabase::connect("save_transactions_rollback_on_error").await?;
+    let pool = db.pool.clone();
+    let repository = PostgresPortfolioRepositoryImpl::new(pool.clone());
+    let (user_id, stock_id) = ("portfolio_transactions_test_b", "9T6C");
+    let portfolio_id = setup(&repository, &pool, user_id, stock_id).await?;
+
+    let result = async {
+        // 2件目は保有銘柄のない証券コードのため保存できない
+        let saved = repository
+            .save_transactions(
+                portfolio(portfolio_id, user_id, stock_id, 100)?,
+                vec![
+                    buy(portfolio_id, user_id, stock_id, 100)?,
+                    buy(portfolio_id, user_id, "9T6D", 100)?,
+                ],
+            )
+            .await;
+        let target = StockId::new(stock_id.to_string())?;
+        let portfolio = repository.find(&portfolio_id, &target).await?;
+        let transactions = repository.find_transactions(&portfolio_id, &target).await?;
+
+        anyhow::Ok((saved.is_err(), portfolio, transactions))
+    }
+    .await;
+    db.drop().await?;
+
+    let (failed, portfolio, transactions) = result?;
+    assert!(failed);
+    assert!(portfolio.is_none());
+    assert!(transactions.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+-- Add up migration script here
+create table if not exists financial_statements(
+    stock_id varchar(10) not null,
+    period_type varchar(10) not null check (period_type in ('annual', 'quarterly')),
+    period_start date not null,
+    period_end date not null,
+    revenue bigint,
+    operating_income bigint,
+    net_income bigint,
+    total_assets bigint,
+    equity bigint,
+    eps double precision,
+    foreign key (stock_id) references companies(stock_id) on delete cascade,
+    primary key (stock_id, period_type, period_end)
+);
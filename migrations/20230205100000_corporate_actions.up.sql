@@ -0,0 +1,10 @@
+-- Add up migration script here
+create table if not exists corporate_actions(
+    stock_id varchar(10) not null,
+    ex_date date not null,
+    action_type varchar(20) not null check (action_type in ('split', 'reverse_split')),
+    ratio_from integer not null check (ratio_from > 0),
+    ratio_to integer not null check (ratio_to > 0),
+    foreign key (stock_id) references companies(stock_id) on delete cascade,
+    primary key (stock_id, ex_date)
+);
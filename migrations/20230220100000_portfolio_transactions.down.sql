@@ -0,0 +1,4 @@
+-- Add down migration script here
+drop table if exists portfolio_transactions;
+
+alter table portfolio drop column if exists cost_basis_method;
@@ -0,0 +1,20 @@
+-- Add up migration script here
+alter table portfolio
+    add column if not exists cost_basis_method varchar(20) not null default 'moving_average'
+    check (cost_basis_method in ('moving_average', 'fifo'));
+
+create table if not exists portfolio_transactions(
+    id serial not null,
+    user_id varchar(50) not null,
+    stock_id varchar(10) not null,
+    transaction_type varchar(10) not null check (transaction_type in ('buy', 'sell')),
+    date date not null,
+    quantity integer not null check (quantity > 0),
+    price integer not null check (price > 0),
+    fee integer not null default 0 check (fee >= 0),
+    foreign key (user_id, stock_id) references portfolio(user_id, stock_id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists portfolio_transactions_user_stock_idx
+    on portfolio_transactions(user_id, stock_id, date);
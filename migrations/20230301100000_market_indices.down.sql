@@ -0,0 +1,3 @@
+-- Add down migration script here
+drop table if exists index_prices;
+drop table if exists market_indices;
@@ -0,0 +1,17 @@
+-- Add up migration script here
+create table if not exists market_indices(
+    index_id varchar(20) not null,
+    name varchar(100) not null,
+    primary key (index_id)
+);
+
+create table if not exists index_prices(
+    index_id varchar(20) not null,
+    date date not null,
+    start_price double precision not null,
+    end_price double precision not null,
+    high_price double precision not null,
+    low_price double precision not null,
+    foreign key (index_id) references market_indices(index_id) on delete cascade,
+    primary key (index_id, date)
+);
@@ -0,0 +1,20 @@
+-- Add down migration script here
+-- 既定のポートフォリオ以外の保有銘柄は削除される
+delete from portfolio
+where portfolio_id <> (select min(id) from portfolios where portfolios.user_id = portfolio.user_id);
+
+alter table portfolio_transactions
+    drop constraint if exists portfolio_transactions_portfolio_id_stock_id_fkey;
+drop index if exists portfolio_transactions_portfolio_stock_idx;
+alter table portfolio_transactions drop column if exists portfolio_id;
+
+alter table portfolio drop constraint if exists portfolio_pkey;
+alter table portfolio drop column if exists portfolio_id;
+alter table portfolio add primary key (user_id, stock_id);
+
+alter table portfolio_transactions
+    add foreign key (user_id, stock_id) references portfolio(user_id, stock_id) on delete cascade;
+create index if not exists portfolio_transactions_user_stock_idx
+    on portfolio_transactions(user_id, stock_id, date);
+
+drop table if exists portfolios;
@@ -0,0 +1,43 @@
+-- Add up migration script here
+create table if not exists portfolios(
+    id serial not null,
+    user_id varchar(50) not null,
+    name varchar(50) not null,
+    account_type varchar(10) not null default 'general'
+    check (account_type in ('nisa', 'tokutei', 'general')),
+    foreign key (user_id) references users(id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists portfolios_user_idx on portfolios(user_id, id);
+
+-- 既存の保有銘柄はユーザーごとの既定のポートフォリオに移す
+insert into portfolios (user_id, name, account_type)
+select distinct user_id, 'default', 'general' from portfolio order by user_id;
+
+alter table portfolio add column if not exists portfolio_id integer;
+update portfolio
+set portfolio_id = (select min(id) from portfolios where portfolios.user_id = portfolio.user_id);
+
+alter table portfolio_transactions add column if not exists portfolio_id integer;
+update portfolio_transactions
+set portfolio_id = (
+    select min(id) from portfolios where portfolios.user_id = portfolio_transactions.user_id
+);
+
+alter table portfolio_transactions
+    drop constraint if exists portfolio_transactions_user_id_stock_id_fkey;
+drop index if exists portfolio_transactions_user_stock_idx;
+
+alter table portfolio drop constraint if exists portfolio_pkey;
+alter table portfolio
+    alter column portfolio_id set not null,
+    add foreign key (portfolio_id) references portfolios(id) on delete cascade,
+    add primary key (portfolio_id, stock_id);
+
+alter table portfolio_transactions
+    alter column portfolio_id set not null,
+    add foreign key (portfolio_id, stock_id) references portfolio(portfolio_id, stock_id) on delete cascade;
+
+create index if not exists portfolio_transactions_portfolio_stock_idx
+    on portfolio_transactions(portfolio_id, stock_id, date);
@@ -0,0 +1,11 @@
+-- Add up migration script here
+create table if not exists dividends(
+    stock_id varchar(10) not null,
+    ex_date date not null,
+    record_date date not null,
+    payment_date date not null,
+    amount_per_share double precision not null check (amount_per_share > 0),
+    foreign key (stock_id) references companies(stock_id) on delete cascade,
+    primary key (stock_id, ex_date),
+    check (ex_date <= record_date and record_date <= payment_date)
+);
@@ -0,0 +1,15 @@
+-- Add down migration script here
+-- 既定のウォッチリスト以外のお気に入りは削除される
+delete from favorites
+where watchlist_id <> (select min(id) from watchlists where watchlists.user_id = favorites.user_id);
+
+alter table favorites drop constraint if exists favorites_pkey;
+alter table favorites
+    drop column if exists watchlist_id,
+    drop column if exists sort_order,
+    drop column if exists note,
+    drop column if exists tags,
+    drop column if exists added_at,
+    add primary key (user_id, stock_id);
+
+drop table if exists watchlists;
@@ -0,0 +1,36 @@
+-- Add up migration script here
+create table if not exists watchlists(
+    id serial not null,
+    user_id varchar(50) not null,
+    name varchar(50) not null,
+    foreign key (user_id) references users(id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists watchlists_user_idx on watchlists(user_id, id);
+
+-- 既存のお気に入りはユーザーごとの既定のウォッチリストに移す
+insert into watchlists (user_id, name)
+select distinct user_id, 'default' from favorites order by user_id;
+
+alter table favorites
+    add column if not exists watchlist_id integer,
+    add column if not exists sort_order integer not null default 0,
+    add column if not exists note varchar(500) not null default '',
+    add column if not exists tags text[] not null default '{}',
+    add column if not exists added_at timestamp not null default (now() at time zone 'utc');
+
+update favorites
+set watchlist_id = (select min(id) from watchlists where watchlists.user_id = favorites.user_id),
+    sort_order = ordered.sort_order
+from (
+    select user_id, stock_id, row_number() over (partition by user_id order by stock_id) as sort_order
+    from favorites
+) as ordered
+where favorites.user_id = ordered.user_id and favorites.stock_id = ordered.stock_id;
+
+alter table favorites drop constraint if exists favorites_pkey;
+alter table favorites
+    alter column watchlist_id set not null,
+    add foreign key (watchlist_id) references watchlists(id) on delete cascade,
+    add primary key (watchlist_id, stock_id);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+drop table if exists alert_events;
+drop table if exists alert_rules;
@@ -0,0 +1,34 @@
+-- Add up migration script here
+create table if not exists alert_rules(
+    id serial not null,
+    user_id varchar(50) not null,
+    stock_id varchar(10) not null,
+    condition varchar(20) not null,
+    threshold double precision,
+    enabled boolean not null default true,
+    foreign key (user_id) references users(id) on delete cascade,
+    foreign key (stock_id) references companies(stock_id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists alert_rules_user_idx on alert_rules(user_id, id);
+create index if not exists alert_rules_stock_idx on alert_rules(stock_id) where enabled;
+
+create table if not exists alert_events(
+    id serial not null,
+    rule_id integer not null,
+    user_id varchar(50) not null,
+    stock_id varchar(10) not null,
+    condition varchar(20) not null,
+    threshold double precision,
+    date date not null,
+    price integer not null,
+    read boolean not null default false,
+    triggered_at timestamp not null default (now() at time zone 'utc'),
+    foreign key (rule_id) references alert_rules(id) on delete cascade,
+    foreign key (user_id) references users(id) on delete cascade,
+    unique (rule_id, date),
+    primary key (id)
+);
+
+create index if not exists alert_events_user_idx on alert_events(user_id, id desc);
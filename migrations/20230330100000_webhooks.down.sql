@@ -0,0 +1,3 @@
+-- Add down migration script here
+drop table if exists webhook_deliveries;
+drop table if exists webhooks;
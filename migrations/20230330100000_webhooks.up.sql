@@ -0,0 +1,34 @@
+-- Add up migration script here
+create table if not exists webhooks(
+    id serial not null,
+    user_id varchar(50) not null,
+    url varchar(500) not null,
+    secret varchar(100) not null,
+    events varchar(20)[] not null,
+    enabled boolean not null default true,
+    foreign key (user_id) references users(id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists webhooks_user_idx on webhooks(user_id, id);
+
+create table if not exists webhook_deliveries(
+    id serial not null,
+    webhook_id integer not null,
+    user_id varchar(50) not null,
+    event varchar(20) not null,
+    payload text not null,
+    status varchar(20) not null default 'pending',
+    attempts integer not null default 0,
+    next_attempt_at timestamp not null,
+    last_status_code integer,
+    last_error text,
+    created_at timestamp not null default (now() at time zone 'utc'),
+    delivered_at timestamp,
+    foreign key (webhook_id) references webhooks(id) on delete cascade,
+    foreign key (user_id) references users(id) on delete cascade,
+    primary key (id)
+);
+
+create index if not exists webhook_deliveries_pending_idx on webhook_deliveries(next_attempt_at) where status = 'pending';
+create index if not exists webhook_deliveries_webhook_idx on webhook_deliveries(webhook_id, id desc);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+drop index if exists companies_search_name_trgm_idx;
+alter table companies drop column if exists search_name;
@@ -0,0 +1,9 @@
+-- Add up migration script here
+create extension if not exists pg_trgm;
+
+-- 企業名を検索用に正規化した値(全角・半角, ひらがな・カタカナ, 大文字・小文字の違いを吸収)
+-- 正規化はアプリケーションで行うため、既存データは import search-names を実行するまで小文字化のみ
+alter table companies add column if not exists search_name varchar(200) not null default '';
+update companies set search_name = lower(name);
+
+create index if not exists companies_search_name_trgm_idx on companies using gin (search_name gin_trgm_ops);
@@ -0,0 +1,24 @@
+-- Add up migration script here
+-- 銘柄ごとの最新の株価と前営業日の終値(株価の取り込み時に更新する)
+create table if not exists latest_quotes(
+    stock_id varchar(10) not null,
+    date date not null,
+    start_price integer not null,
+    end_price integer not null,
+    high_price integer not null,
+    low_price integer not null,
+    volume integer not null,
+    previous_date date,
+    previous_close integer,
+    primary key (stock_id),
+    foreign key (stock_id) references companies(stock_id) on delete cascade
+);
+
+insert into latest_quotes (stock_id, date, start_price, end_price, high_price, low_price, volume, previous_date, previous_close)
+select distinct on (stock_id)
+    stock_id, date, start_price, end_price, high_price, low_price, volume,
+    lag(date) over w, lag(end_price) over w
+from stocks
+window w as (partition by stock_id order by date)
+order by stock_id, date desc
+on conflict (stock_id) do nothing;
@@ -0,0 +1,28 @@
+-- Add down migration script here
+-- 1円未満の端数は四捨五入する
+alter table alert_events
+    alter column price type integer using round(price);
+
+alter table portfolio_transactions
+    alter column quantity type integer,
+    alter column price type integer using round(price),
+    alter column fee type integer using round(fee);
+
+alter table portfolio
+    alter column stock_count type integer,
+    alter column purchase type integer using round(purchase);
+
+alter table latest_quotes
+    alter column start_price type integer using round(start_price),
+    alter column end_price type integer using round(end_price),
+    alter column high_price type integer using round(high_price),
+    alter column low_price type integer using round(low_price),
+    alter column volume type integer,
+    alter column previous_close type integer using round(previous_close);
+
+alter table stocks
+    alter column start_price type integer using round(start_price),
+    alter column end_price type integer using round(end_price),
+    alter column high_price type integer using round(high_price),
+    alter column low_price type integer using round(low_price),
+    alter column volume type integer;
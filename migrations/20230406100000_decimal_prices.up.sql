@@ -0,0 +1,28 @@
+-- Add up migration script here
+-- 1円未満の価格を扱えるよう価格・金額をnumeric、出来高・株数をbigintにする
+alter table stocks
+    alter column start_price type numeric,
+    alter column end_price type numeric,
+    alter column high_price type numeric,
+    alter column low_price type numeric,
+    alter column volume type bigint;
+
+alter table latest_quotes
+    alter column start_price type numeric,
+    alter column end_price type numeric,
+    alter column high_price type numeric,
+    alter column low_price type numeric,
+    alter column volume type bigint,
+    alter column previous_close type numeric;
+
+alter table portfolio
+    alter column stock_count type bigint,
+    alter column purchase type numeric;
+
+alter table portfolio_transactions
+    alter column quantity type bigint,
+    alter column price type numeric,
+    alter column fee type numeric;
+
+alter table alert_events
+    alter column price type numeric;
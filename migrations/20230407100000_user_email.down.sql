@@ -0,0 +1,3 @@
+-- Add down migration script here
+update users set email = substr(email, strpos(email, '@')) where strpos(email, '@') > 1;
+alter table users alter column email type varchar(50);
@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- メールアドレスの@より前の部分がユーザー名として切り出され、ドメインだけが保存されていたため復元する
+alter table users alter column email type varchar(254);
+update users set email = name || email where email like '@%';
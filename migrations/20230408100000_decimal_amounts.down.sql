@@ -0,0 +1,17 @@
+-- Add down migration script here
+alter table alert_events
+    alter column threshold type double precision;
+
+alter table alert_rules
+    alter column threshold type double precision;
+
+alter table index_prices
+    alter column start_price type double precision,
+    alter column end_price type double precision,
+    alter column high_price type double precision,
+    alter column low_price type double precision;
+
+alter table dividends
+    drop constraint if exists dividends_amount_per_share_check,
+    alter column amount_per_share type double precision,
+    add constraint dividends_amount_per_share_check check (amount_per_share > 0);
@@ -0,0 +1,18 @@
+-- Add up migration script here
+-- 配当金・株価指数・アラートの閾値も誤差無く扱えるようnumericにする
+alter table dividends
+    drop constraint if exists dividends_amount_per_share_check,
+    alter column amount_per_share type numeric,
+    add constraint dividends_amount_per_share_check check (amount_per_share > 0);
+
+alter table index_prices
+    alter column start_price type numeric,
+    alter column end_price type numeric,
+    alter column high_price type numeric,
+    alter column low_price type numeric;
+
+alter table alert_rules
+    alter column threshold type numeric;
+
+alter table alert_events
+    alter column threshold type numeric;
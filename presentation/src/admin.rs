@@ -0,0 +1,9 @@
+mod admin_authorized;
+mod admin_controller;
+mod admin_error;
+mod data_quality_report_response;
+
+pub use admin_authorized::AdminAuthorized;
+pub use admin_controller::admin_controller;
+pub use admin_error::AdminError;
+pub use data_quality_report_response::{DataQualityIssueResponse, DataQualityReportResponse};
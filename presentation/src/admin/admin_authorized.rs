@@ -0,0 +1,50 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+
+use crate::{
+    admin::AdminError,
+    common::{ApiError, AppState},
+};
+
+/// 管理者用APIの認証済みであることを表す
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdminAuthorized;
+
+// 管理者用APIのハンドラに引数で渡すことで
+// Authorizationヘッダーの管理者トークンのチェックを自動で行う
+#[axum::async_trait]
+impl<S> FromRequestParts<S> for AdminAuthorized
+where
+    S: AppState + Send + Sync,
+{
+    // エラー時の戻り値の型
+    type Rejection = ApiError;
+
+    #[tracing::instrument(skip(parts, state), err, ret)]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        // 管理者トークンが設定されていない場合は管理者用APIを使用できない
+        let Some(admin_token) = state.admin_token().filter(|token| !token.is_empty()) else {
+            return Err(AdminError::Disabled.into());
+        };
+        let Some(token) = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return Err(AdminError::TokenRequired.into());
+        };
+        if !constant_time_eq(token.as_bytes(), admin_token.as_bytes()) {
+            return Err(AdminError::InvalidToken.into());
+        }
+
+        Ok(AdminAuthorized)
+    }
+}
+
+/// 比較にかかる時間からトークンを推測されないように、全てのバイトを比較する
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
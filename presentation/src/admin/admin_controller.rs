@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::NaiveDate;
+
+use crate::{
+    admin::{AdminAuthorized, DataQualityReportResponse},
+    common::{ApiResult, AppState, AppStateImpl},
+};
+use applications::data_quality::{DataQualityApplicationError, DataQualityCheckCommand};
+
+pub fn admin_controller(state: AppStateImpl) -> Router {
+    Router::new()
+        .route("/data-quality", get(get_data_quality_report))
+        .with_state(state)
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_data_quality_report(
+    state: State<AppStateImpl>,
+    _admin: AdminAuthorized,
+    Query(queries): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = DataQualityCheckCommand::new();
+    // クエリパラメータ取得
+    command.stock_id = queries.get("stock_id").cloned();
+    if let Some(date) = queries.get("start") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(DataQualityApplicationError::InvalidParameter { name: "start", value: date.clone() }.into())};
+        command.start = Some(date);
+    }
+    if let Some(date) = queries.get("end") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(DataQualityApplicationError::InvalidParameter { name: "end", value: date.clone() }.into())};
+        command.end = Some(date);
+    }
+    if let Some(rate) = queries.get("max_change_rate") {
+        let Ok(rate) = rate.parse() else { return Err(DataQualityApplicationError::InvalidParameter { name: "max_change_rate", value: rate.clone() }.into())};
+        command.max_change_rate = rate;
+    }
+
+    let result = state.data_quality_service().check(command).await?;
+
+    Ok(Json(DataQualityReportResponse::from(result)).into_response())
+}
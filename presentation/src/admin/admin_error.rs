@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AdminError {
+    #[error("admin token required")]
+    TokenRequired,
+    #[error("invalid admin token")]
+    InvalidToken,
+    #[error("admin api is disabled")]
+    Disabled,
+}
@@ -0,0 +1,50 @@
+use applications::data_quality::{DataQualityIssueData, DataQualityReportData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DataQualityReportResponse {
+    start: Option<String>,
+    end: Option<String>,
+    max_change_rate: f64,
+    checked_stocks: usize,
+    checked_rows: usize,
+    issue_count: usize,
+    issues: Vec<DataQualityIssueResponse>,
+}
+
+impl From<DataQualityReportData> for DataQualityReportResponse {
+    fn from(value: DataQualityReportData) -> Self {
+        Self {
+            start: value.start.map(|d| d.format("%Y-%m-%d").to_string()),
+            end: value.end.map(|d| d.format("%Y-%m-%d").to_string()),
+            max_change_rate: value.max_change_rate,
+            checked_stocks: value.checked_stocks,
+            checked_rows: value.checked_rows,
+            issue_count: value.issues.len(),
+            issues: value
+                .issues
+                .into_iter()
+                .map(DataQualityIssueResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DataQualityIssueResponse {
+    stock_id: String,
+    date: String,
+    kind: String,
+    message: String,
+}
+
+impl From<DataQualityIssueData> for DataQualityIssueResponse {
+    fn from(value: DataQualityIssueData) -> Self {
+        Self {
+            stock_id: value.stock_id,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            kind: value.kind.as_str().to_string(),
+            message: value.message,
+        }
+    }
+}
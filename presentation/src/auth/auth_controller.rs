@@ -75,13 +75,13 @@ async fn auth_verify_google(
     match auth_type {
         AuthType::Login => {
             // ユーザー未登録
-            if let None = state.user_application_service().get(&auth_user.id).await? {
+            if state.user_application_service().get(&auth_user.id).await?.is_none() {
                 return Err(UserApplicationError::UserNotExist(auth_user.id.clone()).into());
             }
         }
         AuthType::Singin => {
             // ユーザーが既に存在するため新規追加不可
-            if let Some(_) = state.user_application_service().get(&auth_user.id).await? {
+            if state.user_application_service().get(&auth_user.id).await?.is_some() {
                 return Err(UserApplicationError::UserAlreadyExist(auth_user.id.clone()).into());
             } else {
                 state
@@ -20,3 +20,9 @@ impl OICDData {
         }
     }
 }
+
+impl Default for OICDData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
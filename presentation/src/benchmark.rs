@@ -0,0 +1,7 @@
+mod benchmark_query;
+mod benchmark_response;
+mod benchmark_value_response;
+
+pub(crate) use benchmark_query::benchmark_query_command;
+pub use benchmark_response::BenchmarkResponse;
+pub use benchmark_value_response::BenchmarkValueResponse;
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::common::ApiResult;
+use applications::benchmark::{BenchmarkApplicationError, BenchmarkQueryCommand};
+
+/// 指数比較のクエリパラメータ取得
+///
+/// 比較する指数(index)は必須
+pub(crate) fn benchmark_query_command(
+    queries: &HashMap<String, String>,
+) -> ApiResult<BenchmarkQueryCommand> {
+    let mut params = BenchmarkQueryCommand::new();
+
+    let Some(index_id) = queries.get("index") else { return Err(BenchmarkApplicationError::InvalidParameter { name: "index", value: String::new() }.into())};
+    params.index_id = index_id.clone();
+    params.start = if let Some(date) = queries.get("start") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(BenchmarkApplicationError::InvalidParameter { name: "start", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+    params.end = if let Some(date) = queries.get("end") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(BenchmarkApplicationError::InvalidParameter { name: "end", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+
+    Ok(params)
+}
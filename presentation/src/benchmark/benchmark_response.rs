@@ -0,0 +1,35 @@
+use applications::benchmark::BenchmarkData;
+use serde::{Deserialize, Serialize};
+
+use crate::benchmark::BenchmarkValueResponse;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BenchmarkResponse {
+    index_id: String,
+    target_return: Option<f64>,
+    benchmark_return: Option<f64>,
+    relative_return: Option<f64>,
+    beta: Option<f64>,
+    alpha: Option<f64>,
+    correlation: Option<f64>,
+    history: Vec<BenchmarkValueResponse>,
+}
+
+impl From<BenchmarkData> for BenchmarkResponse {
+    fn from(value: BenchmarkData) -> Self {
+        Self {
+            index_id: value.index_id,
+            target_return: value.target_return,
+            benchmark_return: value.benchmark_return,
+            relative_return: value.relative_return,
+            beta: value.beta,
+            alpha: value.alpha,
+            correlation: value.correlation,
+            history: value
+                .history
+                .into_iter()
+                .map(BenchmarkValueResponse::from)
+                .collect(),
+        }
+    }
+}
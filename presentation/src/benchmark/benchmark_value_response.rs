@@ -0,0 +1,19 @@
+use applications::benchmark::BenchmarkValueData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BenchmarkValueResponse {
+    date: String,
+    target_return: f64,
+    benchmark_return: f64,
+}
+
+impl From<BenchmarkValueData> for BenchmarkValueResponse {
+    fn from(value: BenchmarkValueData) -> Self {
+        Self {
+            date: value.date.format("%Y-%m-%d").to_string(),
+            target_return: value.target_return,
+            benchmark_return: value.benchmark_return,
+        }
+    }
+}
@@ -2,9 +2,12 @@ mod api_controllers;
 mod api_error;
 mod app_state;
 mod app_state_impl;
+mod pagination_headers;
 
 pub use api_controllers::api_controllers;
 pub use api_error::ApiError;
 pub use api_error::ApiResult;
 pub use app_state::AppState;
 pub use app_state_impl::AppStateImpl;
+pub use pagination_headers::insert_next_cursor_header;
+pub use pagination_headers::pagination_headers;
@@ -1,18 +1,24 @@
 use axum::Router;
 
 use crate::{
-    auth::auth_controller, common::AppStateImpl, company::company_controller,
-    stock::stock_controller, user::user_controller,
+    admin::admin_controller, auth::auth_controller, common::AppStateImpl,
+    company::company_controller, financial_statement::financial_statement_controller,
+    market_index::market_index_controller, screener::screener_controller, stock::stock_controller,
+    user::user_controller,
 };
 
 pub fn api_controllers(state: AppStateImpl) -> Router {
     let api_routes = Router::new()
         .nest("/auth", auth_controller(state.clone()))
         .nest("/stocks", stock_controller(state.clone()))
-        .nest("/companies", company_controller(state.clone()))
-        .nest("/users", user_controller(state));
+        .nest(
+            "/companies",
+            company_controller(state.clone()).merge(financial_statement_controller(state.clone())),
+        )
+        .nest("/indices", market_index_controller(state.clone()))
+        .nest("/screener", screener_controller(state.clone()))
+        .nest("/users", user_controller(state.clone()))
+        .nest("/admin", admin_controller(state));
 
-    let root = Router::new().nest("/api", api_routes);
-
-    root
+    Router::new().nest("/api", api_routes)
 }
@@ -5,10 +5,13 @@ use axum::{
 };
 use thiserror::Error;
 
-use crate::{auth::OICDError, session::SessionError};
+use crate::{admin::AdminError, auth::OICDError, session::SessionError};
 use applications::{
-    company::CompanyQueryError, favorite::FavoriteApplicationError,
-    portfolio::PortfolioApplicationError, stock::StockQueryError, user::UserApplicationError,
+    alert::AlertApplicationError, benchmark::BenchmarkApplicationError, company::CompanyQueryError,
+    data_quality::DataQualityApplicationError, favorite::FavoriteApplicationError,
+    financial_statement::FinancialStatementApplicationError, market_index::MarketIndexQueryError,
+    portfolio::PortfolioApplicationError, screener::ScreenerQueryError, stock::StockQueryError,
+    user::UserApplicationError, webhook::WebhookApplicationError,
 };
 
 #[derive(Error, Debug)]
@@ -24,9 +27,25 @@ pub enum ApiError {
     #[error(transparent)]
     StockQueryError(#[from] StockQueryError),
     #[error(transparent)]
+    FinancialStatementApplicationError(#[from] FinancialStatementApplicationError),
+    #[error(transparent)]
+    MarketIndexQueryError(#[from] MarketIndexQueryError),
+    #[error(transparent)]
+    BenchmarkApplicationError(#[from] BenchmarkApplicationError),
+    #[error(transparent)]
+    AlertApplicationError(#[from] AlertApplicationError),
+    #[error(transparent)]
+    WebhookApplicationError(#[from] WebhookApplicationError),
+    #[error(transparent)]
+    ScreenerQueryError(#[from] ScreenerQueryError),
+    #[error(transparent)]
+    DataQualityApplicationError(#[from] DataQualityApplicationError),
+    #[error(transparent)]
     SessionError(#[from] SessionError),
     #[error(transparent)]
     OICDError(#[from] OICDError),
+    #[error(transparent)]
+    AdminError(#[from] AdminError),
 }
 
 pub type ApiResult<T> = Result<T, ApiError>;
@@ -38,20 +57,28 @@ impl IntoResponse for ApiError {
                 UserApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 UserApplicationError::UserAlreadyExist(_) => StatusCode::BAD_REQUEST,
                 UserApplicationError::UserNotExist(_) => StatusCode::NOT_FOUND,
+                UserApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
             },
             ApiError::FavoriteApplicationError(e) => match e {
                 FavoriteApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 FavoriteApplicationError::UserAlreadyExist(_) => StatusCode::BAD_REQUEST,
                 FavoriteApplicationError::UserNotFound(_) => StatusCode::NOT_FOUND,
+                FavoriteApplicationError::WatchlistNotFound(_) => StatusCode::NOT_FOUND,
+                FavoriteApplicationError::FavoriteNotFound(_) => StatusCode::NOT_FOUND,
+                FavoriteApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
             },
             ApiError::PortfolioApplicationError(e) => match e {
                 PortfolioApplicationError::PortfolioNotFound(_) => StatusCode::NOT_FOUND,
+                PortfolioApplicationError::PortfolioAccountNotFound(_) => StatusCode::NOT_FOUND,
                 PortfolioApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 PortfolioApplicationError::UserNotFound(_) => StatusCode::NOT_FOUND,
                 PortfolioApplicationError::UserAlreadyExist(_) => StatusCode::BAD_REQUEST,
                 PortfolioApplicationError::InvalidRangeOfDate { .. } => StatusCode::BAD_REQUEST,
                 PortfolioApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
                 PortfolioApplicationError::StockDataNotFound(_) => StatusCode::NOT_FOUND,
+                PortfolioApplicationError::TransactionNotFound(_) => StatusCode::NOT_FOUND,
+                PortfolioApplicationError::InsufficientStock { .. } => StatusCode::BAD_REQUEST,
+                PortfolioApplicationError::TransactionsExist(_) => StatusCode::CONFLICT,
             },
             ApiError::CompanyQueryError(e) => match e {
                 CompanyQueryError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -64,6 +91,55 @@ impl IntoResponse for ApiError {
                 StockQueryError::StockDataNotFound(_) => StatusCode::NOT_FOUND,
                 StockQueryError::InvalidRangeOfDate { .. } => StatusCode::BAD_REQUEST,
             },
+            ApiError::FinancialStatementApplicationError(e) => match e {
+                FinancialStatementApplicationError::Disconnect(_) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+                FinancialStatementApplicationError::InvalidParameter { .. } => {
+                    StatusCode::BAD_REQUEST
+                }
+                FinancialStatementApplicationError::CompanyNotFound(_) => StatusCode::NOT_FOUND,
+            },
+            ApiError::MarketIndexQueryError(e) => match e {
+                MarketIndexQueryError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                MarketIndexQueryError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+                MarketIndexQueryError::InvalidRangeOfDate { .. } => StatusCode::BAD_REQUEST,
+                MarketIndexQueryError::MarketIndexNotFound(_) => StatusCode::NOT_FOUND,
+            },
+            ApiError::BenchmarkApplicationError(e) => match e {
+                BenchmarkApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                BenchmarkApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+                BenchmarkApplicationError::InvalidRangeOfDate { .. } => StatusCode::BAD_REQUEST,
+                BenchmarkApplicationError::MarketIndexNotFound(_) => StatusCode::NOT_FOUND,
+                BenchmarkApplicationError::PortfolioAccountNotFound(_) => StatusCode::NOT_FOUND,
+                BenchmarkApplicationError::StockDataNotFound(_) => StatusCode::NOT_FOUND,
+                BenchmarkApplicationError::UserNotFound(_) => StatusCode::NOT_FOUND,
+            },
+            ApiError::AlertApplicationError(e) => match e {
+                AlertApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                AlertApplicationError::UserNotFound(_) => StatusCode::NOT_FOUND,
+                AlertApplicationError::UserAlreadyExist(_) => StatusCode::BAD_REQUEST,
+                AlertApplicationError::AlertRuleNotFound(_) => StatusCode::NOT_FOUND,
+                AlertApplicationError::AlertEventNotFound(_) => StatusCode::NOT_FOUND,
+                AlertApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+            },
+            ApiError::WebhookApplicationError(e) => match e {
+                WebhookApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                WebhookApplicationError::UserNotFound(_) => StatusCode::NOT_FOUND,
+                WebhookApplicationError::UserAlreadyExist(_) => StatusCode::BAD_REQUEST,
+                WebhookApplicationError::WebhookNotFound(_) => StatusCode::NOT_FOUND,
+                WebhookApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+            },
+            ApiError::ScreenerQueryError(e) => match e {
+                ScreenerQueryError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                ScreenerQueryError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+            },
+            ApiError::DataQualityApplicationError(e) => match e {
+                DataQualityApplicationError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                DataQualityApplicationError::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
+                DataQualityApplicationError::InvalidRangeOfDate { .. } => StatusCode::BAD_REQUEST,
+                DataQualityApplicationError::StockDataNotFound(_) => StatusCode::NOT_FOUND,
+            },
             ApiError::SessionError(e) => match e {
                 SessionError::Disconnect(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 SessionError::ItemNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -77,6 +153,11 @@ impl IntoResponse for ApiError {
                 OICDError::EmailNotRegisterd => StatusCode::BAD_REQUEST,
                 OICDError::AuthenticationRequired => StatusCode::UNAUTHORIZED,
             },
+            ApiError::AdminError(e) => match e {
+                AdminError::TokenRequired => StatusCode::UNAUTHORIZED,
+                AdminError::InvalidToken => StatusCode::FORBIDDEN,
+                AdminError::Disabled => StatusCode::FORBIDDEN,
+            },
         };
         let message = if code == StatusCode::INTERNAL_SERVER_ERROR {
             "internal server error".to_string()
@@ -88,8 +169,6 @@ impl IntoResponse for ApiError {
                 "message": message
             }
         });
-        let res = (code, Json(body)).into_response();
-
-        res
+        (code, Json(body)).into_response()
     }
 }
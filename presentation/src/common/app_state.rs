@@ -2,8 +2,18 @@ use std::sync::Arc;
 
 use crate::{auth::OICDService, session::SessionService};
 use applications::{
-    company::CompanyQueryService, favorite::FavoriteService, portfolio::PortfolioService,
-    stock::StockQueryService, user::UserService,
+    alert::AlertService,
+    benchmark::BenchmarkService,
+    company::CompanyQueryService,
+    data_quality::DataQualityService,
+    favorite::FavoriteService,
+    financial_statement::FinancialStatementService,
+    market_index::MarketIndexQueryService,
+    portfolio::PortfolioService,
+    screener::ScreenerQueryService,
+    stock::{IndicatorService, StockQueryService},
+    user::UserService,
+    webhook::WebhookService,
 };
 
 #[async_trait::async_trait]
@@ -12,7 +22,17 @@ pub trait AppState {
     fn session_service(&self) -> &Arc<dyn SessionService + Send + Sync>;
     fn oicd_service(&self) -> &Arc<dyn OICDService + Send + Sync>;
     fn stock_query_service(&self) -> &Arc<dyn StockQueryService + Send + Sync>;
+    fn indicator_service(&self) -> &Arc<dyn IndicatorService + Send + Sync>;
     fn company_query_service(&self) -> &Arc<dyn CompanyQueryService + Send + Sync>;
     fn favorite_service(&self) -> &Arc<dyn FavoriteService + Send + Sync>;
     fn portfolio_service(&self) -> &Arc<dyn PortfolioService + Send + Sync>;
+    fn financial_statement_service(&self) -> &Arc<dyn FinancialStatementService + Send + Sync>;
+    fn market_index_query_service(&self) -> &Arc<dyn MarketIndexQueryService + Send + Sync>;
+    fn benchmark_service(&self) -> &Arc<dyn BenchmarkService + Send + Sync>;
+    fn alert_service(&self) -> &Arc<dyn AlertService + Send + Sync>;
+    fn webhook_service(&self) -> &Arc<dyn WebhookService + Send + Sync>;
+    fn screener_query_service(&self) -> &Arc<dyn ScreenerQueryService + Send + Sync>;
+    fn data_quality_service(&self) -> &Arc<dyn DataQualityService + Send + Sync>;
+    /// 管理者用APIの認証トークン(Noneの場合は管理者用APIを使用できない)
+    fn admin_token(&self) -> Option<&str>;
 }
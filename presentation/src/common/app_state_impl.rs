@@ -2,8 +2,18 @@ use std::sync::Arc;
 
 use crate::{auth::OICDService, common::AppState, session::SessionService};
 use applications::{
-    company::CompanyQueryService, favorite::FavoriteService, portfolio::PortfolioService,
-    stock::StockQueryService, user::UserService,
+    alert::AlertService,
+    benchmark::BenchmarkService,
+    company::CompanyQueryService,
+    data_quality::DataQualityService,
+    favorite::FavoriteService,
+    financial_statement::FinancialStatementService,
+    market_index::MarketIndexQueryService,
+    portfolio::PortfolioService,
+    screener::ScreenerQueryService,
+    stock::{IndicatorService, StockQueryService},
+    user::UserService,
+    webhook::WebhookService,
 };
 
 #[derive(Clone)]
@@ -12,29 +22,57 @@ pub struct AppStateImpl {
     session_service: Arc<dyn SessionService + Send + Sync>,
     oicd_service: Arc<dyn OICDService + Send + Sync>,
     stock_query_service: Arc<dyn StockQueryService + Send + Sync>,
+    indicator_service: Arc<dyn IndicatorService + Send + Sync>,
     company_query_service: Arc<dyn CompanyQueryService + Send + Sync>,
     favorite_service: Arc<dyn FavoriteService + Send + Sync>,
     portfolio_service: Arc<dyn PortfolioService + Send + Sync>,
+    financial_statement_service: Arc<dyn FinancialStatementService + Send + Sync>,
+    market_index_query_service: Arc<dyn MarketIndexQueryService + Send + Sync>,
+    benchmark_service: Arc<dyn BenchmarkService + Send + Sync>,
+    alert_service: Arc<dyn AlertService + Send + Sync>,
+    webhook_service: Arc<dyn WebhookService + Send + Sync>,
+    screener_query_service: Arc<dyn ScreenerQueryService + Send + Sync>,
+    data_quality_service: Arc<dyn DataQualityService + Send + Sync>,
+    admin_token: Option<String>,
 }
 
 impl AppStateImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_application_service: Arc<dyn UserService + Send + Sync>,
         session_service: Arc<dyn SessionService + Send + Sync>,
         oicd_service: Arc<dyn OICDService + Send + Sync>,
         stock_query_service: Arc<dyn StockQueryService + Send + Sync>,
+        indicator_service: Arc<dyn IndicatorService + Send + Sync>,
         company_query_service: Arc<dyn CompanyQueryService + Send + Sync>,
         favorite_service: Arc<dyn FavoriteService + Send + Sync>,
         portfolio_service: Arc<dyn PortfolioService + Send + Sync>,
+        financial_statement_service: Arc<dyn FinancialStatementService + Send + Sync>,
+        market_index_query_service: Arc<dyn MarketIndexQueryService + Send + Sync>,
+        benchmark_service: Arc<dyn BenchmarkService + Send + Sync>,
+        alert_service: Arc<dyn AlertService + Send + Sync>,
+        webhook_service: Arc<dyn WebhookService + Send + Sync>,
+        screener_query_service: Arc<dyn ScreenerQueryService + Send + Sync>,
+        data_quality_service: Arc<dyn DataQualityService + Send + Sync>,
+        admin_token: Option<String>,
     ) -> Self {
         Self {
             user_application_service,
             session_service,
             oicd_service,
             stock_query_service,
+            indicator_service,
             company_query_service,
             favorite_service,
             portfolio_service,
+            financial_statement_service,
+            market_index_query_service,
+            benchmark_service,
+            alert_service,
+            webhook_service,
+            screener_query_service,
+            data_quality_service,
+            admin_token,
         }
     }
 }
@@ -57,6 +95,10 @@ impl AppState for AppStateImpl {
         &self.stock_query_service
     }
 
+    fn indicator_service(&self) -> &Arc<dyn IndicatorService + Send + Sync> {
+        &self.indicator_service
+    }
+
     fn company_query_service(&self) -> &Arc<dyn CompanyQueryService + Send + Sync> {
         &self.company_query_service
     }
@@ -68,4 +110,36 @@ impl AppState for AppStateImpl {
     fn portfolio_service(&self) -> &Arc<dyn PortfolioService + Send + Sync> {
         &self.portfolio_service
     }
+
+    fn financial_statement_service(&self) -> &Arc<dyn FinancialStatementService + Send + Sync> {
+        &self.financial_statement_service
+    }
+
+    fn market_index_query_service(&self) -> &Arc<dyn MarketIndexQueryService + Send + Sync> {
+        &self.market_index_query_service
+    }
+
+    fn benchmark_service(&self) -> &Arc<dyn BenchmarkService + Send + Sync> {
+        &self.benchmark_service
+    }
+
+    fn alert_service(&self) -> &Arc<dyn AlertService + Send + Sync> {
+        &self.alert_service
+    }
+
+    fn webhook_service(&self) -> &Arc<dyn WebhookService + Send + Sync> {
+        &self.webhook_service
+    }
+
+    fn screener_query_service(&self) -> &Arc<dyn ScreenerQueryService + Send + Sync> {
+        &self.screener_query_service
+    }
+
+    fn data_quality_service(&self) -> &Arc<dyn DataQualityService + Send + Sync> {
+        &self.data_quality_service
+    }
+
+    fn admin_token(&self) -> Option<&str> {
+        self.admin_token.as_deref()
+    }
 }
@@ -0,0 +1,38 @@
+use axum::http::{HeaderMap, HeaderValue};
+
+/// 条件に一致する件数
+const TOTAL_COUNT_HEADER: &str = "x-total-count";
+/// ページ番号
+const PAGE_HEADER: &str = "x-page";
+/// ページサイズ
+const PAGE_SIZE_HEADER: &str = "x-page-size";
+/// 次のページが存在する場合はtrue
+const HAS_NEXT_HEADER: &str = "x-has-next";
+/// 次のページのカーソル(キーセットページング)
+const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+/// ページングの情報をレスポンスヘッダーにする
+///
+/// ページサイズを指定しない場合は1ページに全件
+pub fn pagination_headers(total: i64, page: Option<i32>, size: Option<i32>) -> HeaderMap {
+    let page = page.unwrap_or(1) as i64;
+    let size = size.map(|size| size as i64).unwrap_or(total);
+    let has_next = size > 0 && page * size < total;
+
+    let mut headers = HeaderMap::new();
+    for (name, value) in [
+        (TOTAL_COUNT_HEADER, total.to_string()),
+        (PAGE_HEADER, page.to_string()),
+        (PAGE_SIZE_HEADER, size.to_string()),
+        (HAS_NEXT_HEADER, has_next.to_string()),
+    ] {
+        headers.insert(name, HeaderValue::from_str(&value).unwrap());
+    }
+
+    headers
+}
+
+/// 次のページのカーソルをレスポンスヘッダーに追加する
+pub fn insert_next_cursor_header(headers: &mut HeaderMap, cursor: &str) {
+    headers.insert(NEXT_CURSOR_HEADER, HeaderValue::from_str(cursor).unwrap());
+}
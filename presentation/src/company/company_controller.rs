@@ -8,14 +8,17 @@ use axum::{
 };
 
 use crate::{
-    common::{ApiResult, AppState, AppStateImpl},
+    common::{pagination_headers, ApiResult, AppState, AppStateImpl},
     company::CompanyResponse,
 };
-use applications::company::{CompanyQueryCommand, CompanyQueryError};
+use applications::company::{
+    CompanyQueryCommand, CompanyQueryError, CompanySortKey, CompanySuggestCommand,
+};
 
 pub fn company_controller(state: AppStateImpl) -> Router {
     Router::new()
         .route("/", get(get_companies))
+        .route("/suggest", get(get_suggestions))
         .with_state(state)
 }
 
@@ -42,13 +45,45 @@ async fn get_companies(
     } else {
         None
     };
+    params.sort = if let Some(sort) = queries.get("sort") {
+        let Some(sort) = CompanySortKey::new(sort) else { return Err(CompanyQueryError::InvalidParameter { name: "sort", value: sort.clone() }.into())};
+        Some(sort)
+    } else {
+        None
+    };
 
+    let total = state.company_query_service().count(params.clone()).await?;
+    let headers = pagination_headers(total, params.page, params.size);
     let result: Vec<CompanyResponse> = state
         .company_query_service()
         .find(params)
         .await?
         .into_iter()
-        .map(|c| CompanyResponse::from(c))
+        .map(CompanyResponse::from)
+        .collect();
+
+    Ok((headers, Json(result)).into_response())
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_suggestions(
+    state: State<AppStateImpl>,
+    Query(queries): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    // クエリパラメータ取得
+    let Some(keyword) = queries.get("q") else { return Err(CompanyQueryError::InvalidParameter { name: "q", value: "".to_string() }.into())};
+    let mut params = CompanySuggestCommand::new(keyword.clone());
+    if let Some(limit) = queries.get("limit") {
+        let Ok(limit) = limit.parse() else { return Err(CompanyQueryError::InvalidParameter { name: "limit", value: limit.clone() }.into())};
+        params.limit = limit;
+    }
+
+    let result: Vec<CompanyResponse> = state
+        .company_query_service()
+        .suggest(params)
+        .await?
+        .into_iter()
+        .map(CompanyResponse::from)
         .collect();
 
     Ok(Json(result).into_response())
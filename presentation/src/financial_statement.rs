@@ -0,0 +1,5 @@
+mod financial_statement_controller;
+mod financial_statement_response;
+
+pub use financial_statement_controller::financial_statement_controller;
+pub use financial_statement_response::FinancialStatementResponse;
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+
+use crate::{
+    common::{ApiResult, AppState, AppStateImpl},
+    financial_statement::FinancialStatementResponse,
+};
+
+/// 企業ごとの財務諸表 (/companies配下にマージする)
+pub fn financial_statement_controller(state: AppStateImpl) -> Router {
+    Router::new()
+        .route("/:stock_id/financials", get(get_financial_statements))
+        .with_state(state)
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_financial_statements(
+    state: State<AppStateImpl>,
+    Query(queries): Query<HashMap<String, String>>,
+    Path(stock_id): Path<String>,
+) -> ApiResult<Response> {
+    // クエリパラメータ取得
+    let period = queries.get("period").map_or("annual", |s| s.as_str());
+
+    let result: Vec<FinancialStatementResponse> = state
+        .financial_statement_service()
+        .get_all(&stock_id, period)
+        .await?
+        .into_iter()
+        .map(FinancialStatementResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
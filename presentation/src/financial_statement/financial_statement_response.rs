@@ -0,0 +1,33 @@
+use applications::financial_statement::FinancialStatementData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FinancialStatementResponse {
+    stock_id: String,
+    period_type: String,
+    period_start: String,
+    period_end: String,
+    revenue: Option<i64>,
+    operating_income: Option<i64>,
+    net_income: Option<i64>,
+    total_assets: Option<i64>,
+    equity: Option<i64>,
+    eps: Option<f64>,
+}
+
+impl From<FinancialStatementData> for FinancialStatementResponse {
+    fn from(value: FinancialStatementData) -> Self {
+        Self {
+            stock_id: value.stock_id,
+            period_type: value.period_type,
+            period_start: value.period_start.format("%Y-%m-%d").to_string(),
+            period_end: value.period_end.format("%Y-%m-%d").to_string(),
+            revenue: value.revenue,
+            operating_income: value.operating_income,
+            net_income: value.net_income,
+            total_assets: value.total_assets,
+            equity: value.equity,
+            eps: value.eps,
+        }
+    }
+}
@@ -1,6 +1,11 @@
+pub mod admin;
 pub mod auth;
+pub mod benchmark;
 pub mod common;
 pub mod company;
+pub mod financial_statement;
+pub mod market_index;
+pub mod screener;
 pub mod session;
 pub mod stock;
 pub mod user;
\ No newline at end of file
@@ -0,0 +1,7 @@
+mod index_price_response;
+mod market_index_controller;
+mod market_index_response;
+
+pub use index_price_response::IndexPriceResponse;
+pub use market_index_controller::market_index_controller;
+pub use market_index_response::MarketIndexResponse;
@@ -0,0 +1,26 @@
+use applications::market_index::IndexPriceData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct IndexPriceResponse {
+    index_id: String,
+    date: String,
+    start_price: Decimal,
+    end_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
+}
+
+impl From<IndexPriceData> for IndexPriceResponse {
+    fn from(value: IndexPriceData) -> Self {
+        Self {
+            index_id: value.index_id,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            start_price: value.start_price,
+            end_price: value.end_price,
+            high_price: value.high_price,
+            low_price: value.low_price,
+        }
+    }
+}
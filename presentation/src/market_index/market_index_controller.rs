@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::NaiveDate;
+
+use crate::{
+    common::{ApiResult, AppState, AppStateImpl},
+    market_index::{IndexPriceResponse, MarketIndexResponse},
+};
+use applications::market_index::{MarketIndexQueryCommand, MarketIndexQueryError};
+
+pub fn market_index_controller(state: AppStateImpl) -> Router {
+    Router::new()
+        .route("/", get(get_indices))
+        .route("/:index_id", get(get_index_prices))
+        .with_state(state)
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_indices(state: State<AppStateImpl>) -> ApiResult<Response> {
+    let result: Vec<MarketIndexResponse> = state
+        .market_index_query_service()
+        .find_all()
+        .await?
+        .into_iter()
+        .map(MarketIndexResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_index_prices(
+    state: State<AppStateImpl>,
+    queries: Query<HashMap<String, String>>,
+    Path(index_id): Path<String>,
+) -> ApiResult<Response> {
+    let mut params = MarketIndexQueryCommand::new();
+
+    params.index_id = index_id;
+    // クエリパラメータ取得
+    params.start = if let Some(date) = queries.get("start") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(MarketIndexQueryError::InvalidParameter { name: "start", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+    params.end = if let Some(date) = queries.get("end") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(MarketIndexQueryError::InvalidParameter { name: "end", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+
+    let result: Vec<IndexPriceResponse> = state
+        .market_index_query_service()
+        .find_prices(params)
+        .await?
+        .into_iter()
+        .map(IndexPriceResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
@@ -0,0 +1,17 @@
+use applications::market_index::MarketIndexData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MarketIndexResponse {
+    index_id: String,
+    name: String,
+}
+
+impl From<MarketIndexData> for MarketIndexResponse {
+    fn from(value: MarketIndexData) -> Self {
+        Self {
+            index_id: value.index_id,
+            name: value.name,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod screener_controller;
+mod screener_response;
+
+pub use screener_controller::screener_controller;
+pub use screener_response::{ScreenerItemResponse, ScreenerResponse};
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+
+use crate::{
+    common::{ApiResult, AppState, AppStateImpl},
+    screener::ScreenerResponse,
+};
+use applications::screener::{ScreenerField, ScreenerQueryCommand, ScreenerQueryError};
+
+pub fn screener_controller(state: AppStateImpl) -> Router {
+    Router::new()
+        .route("/", get(get_screener))
+        .with_state(state)
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_screener(
+    state: State<AppStateImpl>,
+    Query(queries): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut params = ScreenerQueryCommand::new();
+    // クエリパラメータ取得
+    if let Some(filter) = queries.get("filter") {
+        params.filter = Some(filter.parse()?);
+    }
+    if let Some(period) = queries.get("period") {
+        let Ok(period) = period.parse() else { return Err(ScreenerQueryError::InvalidParameter { name: "period", value: period.clone() }.into())};
+        params.period = period;
+    }
+    if let Some(sort) = queries.get("sort") {
+        let Some(sort) = ScreenerField::new(sort) else { return Err(ScreenerQueryError::InvalidParameter { name: "sort", value: sort.clone() }.into())};
+        params.sort = sort;
+    }
+    if let Some(order) = queries.get("order") {
+        params.descending = match order.as_str() {
+            "asc" => false,
+            "desc" => true,
+            _ => return Err(ScreenerQueryError::InvalidParameter { name: "order", value: order.clone() }.into()),
+        };
+    }
+    if let Some(page) = queries.get("page") {
+        let Ok(page) = page.parse() else { return Err(ScreenerQueryError::InvalidParameter { name: "page", value: page.clone() }.into())};
+        params.page = page;
+    }
+    if let Some(size) = queries.get("size") {
+        let Ok(size) = size.parse() else { return Err(ScreenerQueryError::InvalidParameter { name: "size", value: size.clone() }.into())};
+        params.size = size;
+    }
+
+    let result = state.screener_query_service().screen(params).await?;
+
+    Ok(Json(ScreenerResponse::from(result)).into_response())
+}
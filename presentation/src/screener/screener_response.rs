@@ -0,0 +1,59 @@
+use applications::screener::{ScreenerData, ScreenerPageData};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScreenerResponse {
+    total: i64,
+    page: i32,
+    size: i32,
+    items: Vec<ScreenerItemResponse>,
+}
+
+impl From<ScreenerPageData> for ScreenerResponse {
+    fn from(value: ScreenerPageData) -> Self {
+        Self {
+            total: value.total,
+            page: value.page,
+            size: value.size,
+            items: value
+                .items
+                .into_iter()
+                .map(ScreenerItemResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScreenerItemResponse {
+    stock_id: String,
+    name: String,
+    sector: String,
+    industry: String,
+    date: String,
+    last_close: Decimal,
+    change_rate: Option<f64>,
+    average_volume: Option<f64>,
+    year_high: Option<Decimal>,
+    year_high_distance: Option<f64>,
+    volatility: Option<f64>,
+}
+
+impl From<ScreenerData> for ScreenerItemResponse {
+    fn from(value: ScreenerData) -> Self {
+        Self {
+            stock_id: value.stock_id,
+            name: value.name,
+            sector: value.sector,
+            industry: value.industry,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            last_close: value.last_close,
+            change_rate: value.change_rate,
+            average_volume: value.average_volume,
+            year_high: value.year_high,
+            year_high_distance: value.year_high_distance,
+            volatility: value.volatility,
+        }
+    }
+}
@@ -36,20 +36,18 @@ impl SessionData {
     /// Sessionへの保存は行われない
     pub fn insert_item(&mut self, item: SessionItem) -> SessionResult<()> {
         self.0
-            .insert(&item.key(), item)
+            .insert(item.key(), item)
             .map_err(|_| SessionError::SavingItemError)
     }
 
     /// 値の取得
     pub fn item(&self, item: &SessionItem) -> Option<SessionItem> {
-        let item = self.0.get(&item.key());
-
-        item
+        self.0.get(item.key())
     }
 
     /// 値の削除
     pub fn remove_item(&mut self, item: &SessionItem) {
-        self.0.remove(&item.key())
+        self.0.remove(item.key())
     }
 
     /// Session期限取得
@@ -63,9 +61,9 @@ impl SessionData {
     }
 }
 
-impl Into<Session> for SessionData {
-    fn into(self) -> Session {
-        self.0
+impl From<SessionData> for Session {
+    fn from(value: SessionData) -> Self {
+        value.0
     }
 }
 
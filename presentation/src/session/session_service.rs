@@ -21,11 +21,11 @@ pub enum SessionStatus {
     Created(SessionId),
 }
 
-impl Into<SessionId> for SessionStatus {
-    fn into(self) -> SessionId {
-        match self {
-            Self::Found(session_id) => session_id,
-            Self::Created(session_id) => session_id,
+impl From<SessionStatus> for SessionId {
+    fn from(value: SessionStatus) -> Self {
+        match value {
+            SessionStatus::Found(session_id) => session_id,
+            SessionStatus::Created(session_id) => session_id,
         }
     }
 }
@@ -1,5 +1,9 @@
+mod indicator_response;
+mod quote_response;
 mod stock_controller;
 mod stock_response;
 
+pub use indicator_response::IndicatorResponse;
+pub use quote_response::QuoteResponse;
 pub use stock_controller::stock_controller;
 pub use stock_response::StockResponse;
\ No newline at end of file
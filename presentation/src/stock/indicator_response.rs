@@ -0,0 +1,61 @@
+use applications::stock::{IndicatorData, IndicatorValue};
+use serde::{Deserialize, Serialize};
+
+/// 指標の種類により出力される項目が異なる
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct IndicatorResponse {
+    stock_id: String,
+    date: String,
+    /// SMA, EMA, RSI, ATR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    macd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signal: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    histogram: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    middle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upper: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lower: Option<f64>,
+}
+
+impl From<IndicatorData> for IndicatorResponse {
+    fn from(value: IndicatorData) -> Self {
+        let response = Self {
+            stock_id: value.stock_id,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            ..Default::default()
+        };
+
+        match value.value {
+            IndicatorValue::Single(value) => Self {
+                value: Some(value),
+                ..response
+            },
+            IndicatorValue::Macd {
+                macd,
+                signal,
+                histogram,
+            } => Self {
+                macd: Some(macd),
+                signal: Some(signal),
+                histogram: Some(histogram),
+                ..response
+            },
+            IndicatorValue::BollingerBands {
+                middle,
+                upper,
+                lower,
+            } => Self {
+                middle: Some(middle),
+                upper: Some(upper),
+                lower: Some(lower),
+                ..response
+            },
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use applications::stock::QuoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct QuoteResponse {
+    stock_id: String,
+    date: String,
+    end_price: Decimal,
+    previous_close: Option<Decimal>,
+    change: Option<Decimal>,
+    change_rate: Option<f64>,
+    volume: i64,
+}
+
+impl From<QuoteData> for QuoteResponse {
+    fn from(value: QuoteData) -> Self {
+        Self {
+            change: value.change(),
+            change_rate: value.change_rate(),
+            stock_id: value.stock_id,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            end_price: value.end_price,
+            previous_close: value.previous_close,
+            volume: value.volume,
+        }
+    }
+}
@@ -2,20 +2,30 @@ use std::collections::HashMap;
 
 use axum::{
     extract::{Path, Query, State},
+    http::HeaderMap,
     response::{IntoResponse, Response},
     routing::get,
     Json, Router,
 };
 use chrono::NaiveDate;
 
-use crate::common::{ApiResult, AppState, AppStateImpl};
-use applications::stock::{StockQueryCommand, StockQueryError};
+use crate::{
+    benchmark::{benchmark_query_command, BenchmarkResponse},
+    common::{insert_next_cursor_header, pagination_headers, ApiResult, AppState, AppStateImpl},
+};
+use applications::stock::{IndicatorQueryCommand, StockQueryCommand, StockQueryError};
+
+use super::{IndicatorResponse, QuoteResponse, StockResponse};
 
-use super::StockResponse;
+/// 1回の最新株価取得で指定できる銘柄数の上限
+const MAX_LATEST_STOCK_COUNT: usize = 100;
 
 pub fn stock_controller(state: AppStateImpl) -> Router {
     Router::new()
+        .route("/latest", get(get_latest_stocks))
         .route("/:stock_id", get(get_stocks))
+        .route("/:stock_id/indicators", get(get_indicators))
+        .route("/:stock_id/benchmark", get(get_benchmark))
         .with_state(state)
 }
 
@@ -53,14 +63,143 @@ async fn get_stocks(
     } else {
         None
     };
+    params.adjusted = if let Some(adjusted) = queries.get("adjusted") {
+        let Ok(adjusted) = adjusted.parse() else { return Err(StockQueryError::InvalidParameter { name: "adjusted", value: adjusted.clone() }.into())};
+        adjusted
+    } else {
+        false
+    };
+    if let Some(interval) = queries.get("interval") {
+        params.interval = interval.parse()?;
+    }
+    if let Some(order) = queries.get("order") {
+        params.descending = match order.as_str() {
+            "asc" => false,
+            "desc" => true,
+            _ => return Err(StockQueryError::InvalidParameter { name: "order", value: order.clone() }.into()),
+        };
+    }
+    if let Some(cursor) = queries.get("cursor") {
+        params.cursor = Some(cursor.parse()?);
+    }
+
+    // カーソルを指定した場合は件数を数えない
+    let mut headers = if params.cursor.is_none() {
+        let total = state.stock_query_service().count(params.clone()).await?;
+        pagination_headers(total, params.page, params.size)
+    } else {
+        HeaderMap::new()
+    };
+    let page = state.stock_query_service().find_page(params).await?;
+    if let Some(cursor) = page.next_cursor {
+        insert_next_cursor_header(&mut headers, &cursor.to_string());
+    }
+    let result: Vec<StockResponse> = page.items.into_iter().map(StockResponse::from).collect();
+
+    Ok((headers, Json(result)).into_response())
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_latest_stocks(
+    state: State<AppStateImpl>,
+    queries: Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    // カンマ区切りの証券コード(重複は除く)
+    let ids = queries.get("ids").cloned().unwrap_or_default();
+    let mut stock_ids: Vec<String> = Vec::new();
+    for stock_id in ids.split(',').map(|id| id.trim()).filter(|id| !id.is_empty()) {
+        if !stock_ids.iter().any(|id| id == stock_id) {
+            stock_ids.push(stock_id.to_string());
+        }
+    }
+    if stock_ids.is_empty() || stock_ids.len() > MAX_LATEST_STOCK_COUNT {
+        return Err(StockQueryError::InvalidParameter { name: "ids", value: ids }.into());
+    }
 
-    let result: Vec<StockResponse> = state
+    let result: Vec<QuoteResponse> = state
         .stock_query_service()
+        .find_latest_many(stock_ids)
+        .await?
+        .into_iter()
+        .map(QuoteResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_indicators(
+    state: State<AppStateImpl>,
+    queries: Query<HashMap<String, String>>,
+    Path(stock_id): Path<String>,
+) -> ApiResult<Response> {
+    let mut params = IndicatorQueryCommand::new();
+
+    params.stock_id = stock_id;
+    // クエリパラメータ取得
+    params.kind = queries.get("kind").cloned().unwrap_or_default();
+    for (name, param) in [
+        ("window", &mut params.window),
+        ("fast", &mut params.fast),
+        ("slow", &mut params.slow),
+        ("signal", &mut params.signal),
+    ] {
+        *param = if let Some(value) = queries.get(name) {
+            let Ok(value) = value.parse() else { return Err(StockQueryError::InvalidParameter { name, value: value.clone() }.into())};
+            Some(value)
+        } else {
+            None
+        };
+    }
+    params.sigma = if let Some(sigma) = queries.get("sigma") {
+        let Ok(sigma) = sigma.parse() else { return Err(StockQueryError::InvalidParameter { name: "sigma", value: sigma.clone() }.into())};
+        Some(sigma)
+    } else {
+        None
+    };
+    params.start = if let Some(date) = queries.get("start") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(StockQueryError::InvalidParameter { name: "start", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+    params.end = if let Some(date) = queries.get("end") {
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { return Err(StockQueryError::InvalidParameter { name: "end", value: date.clone() }.into())};
+        Some(date)
+    } else {
+        None
+    };
+    params.adjusted = if let Some(adjusted) = queries.get("adjusted") {
+        let Ok(adjusted) = adjusted.parse() else { return Err(StockQueryError::InvalidParameter { name: "adjusted", value: adjusted.clone() }.into())};
+        adjusted
+    } else {
+        false
+    };
+
+    let result: Vec<IndicatorResponse> = state
+        .indicator_service()
         .find(params)
         .await?
         .into_iter()
-        .map(|s| StockResponse::from(s))
+        .map(IndicatorResponse::from)
         .collect();
 
     Ok(Json(result).into_response())
 }
+
+#[tracing::instrument(skip(state, queries), err)]
+async fn get_benchmark(
+    state: State<AppStateImpl>,
+    Query(queries): Query<HashMap<String, String>>,
+    Path(stock_id): Path<String>,
+) -> ApiResult<Response> {
+    let params = benchmark_query_command(&queries)?;
+
+    let result = state
+        .benchmark_service()
+        .compare_stock(&stock_id, params)
+        .await?;
+    let result = BenchmarkResponse::from(result);
+
+    Ok(Json(result).into_response())
+}
@@ -1,15 +1,16 @@
 use applications::stock::StockData;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct StockResponse {
     stock_id: String,
     date: String,
-    volume: i32,
-    start_price: i32,
-    end_price: i32,
-    high_price: i32,
-    low_price: i32,
+    volume: i64,
+    start_price: Decimal,
+    end_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
 }
 
 impl From<StockData> for StockResponse {
@@ -1,11 +1,35 @@
+mod alert_event_response;
+mod alert_rule_response;
+mod annual_dividend_response;
+mod dividend_calendar_response;
 mod favorite_response;
 mod login_user_id;
+mod portfolio_account_response;
+mod portfolio_history_response;
 mod portfolio_response;
+mod portfolio_summary_response;
+mod portfolio_value_response;
+mod transaction_response;
 mod user_controller;
 mod user_response;
+mod watchlist_response;
+mod webhook_delivery_response;
+mod webhook_response;
 
+pub use alert_event_response::AlertEventResponse;
+pub use alert_rule_response::AlertRuleResponse;
+pub use annual_dividend_response::AnnualDividendResponse;
+pub use dividend_calendar_response::DividendCalendarResponse;
 pub use favorite_response::FavoriteResponse;
 pub use login_user_id::LoginUserId;
+pub use portfolio_account_response::PortfolioAccountResponse;
+pub use portfolio_history_response::PortfolioHistoryResponse;
 pub use portfolio_response::PortfolioResponse;
+pub use portfolio_summary_response::PortfolioSummaryResponse;
+pub use portfolio_value_response::PortfolioValueResponse;
+pub use transaction_response::TransactionResponse;
 pub use user_controller::user_controller;
 pub use user_response::UserResponse;
+pub use watchlist_response::WatchlistResponse;
+pub use webhook_delivery_response::WebhookDeliveryResponse;
+pub use webhook_response::WebhookResponse;
@@ -0,0 +1,32 @@
+use applications::alert::AlertEventData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AlertEventResponse {
+    id: i32,
+    alert_id: i32,
+    stock_id: String,
+    condition: String,
+    threshold: Option<Decimal>,
+    date: String,
+    price: Decimal,
+    read: bool,
+    triggered_at: String,
+}
+
+impl From<AlertEventData> for AlertEventResponse {
+    fn from(value: AlertEventData) -> Self {
+        Self {
+            id: value.id,
+            alert_id: value.rule_id,
+            stock_id: value.stock_id,
+            condition: value.condition,
+            threshold: value.threshold,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            price: value.price,
+            read: value.read,
+            triggered_at: value.triggered_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        }
+    }
+}
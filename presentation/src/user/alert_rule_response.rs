@@ -0,0 +1,24 @@
+use applications::alert::AlertRuleData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AlertRuleResponse {
+    id: i32,
+    stock_id: String,
+    condition: String,
+    threshold: Option<Decimal>,
+    enabled: bool,
+}
+
+impl From<AlertRuleData> for AlertRuleResponse {
+    fn from(value: AlertRuleData) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            stock_id: value.stock_id,
+            condition: value.condition,
+            threshold: value.threshold,
+            enabled: value.enabled,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 支払年ごとの受取済み配当金
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnnualDividendResponse {
+    year: i32,
+    amount: Decimal,
+}
+
+impl AnnualDividendResponse {
+    pub fn new(year: i32, amount: Decimal) -> Self {
+        Self { year, amount }
+    }
+}
@@ -0,0 +1,28 @@
+use applications::portfolio::DividendCalendarData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DividendCalendarResponse {
+    stock_id: String,
+    ex_date: String,
+    record_date: String,
+    payment_date: String,
+    amount_per_share: Decimal,
+    stock_count: i64,
+    amount: Decimal,
+}
+
+impl From<DividendCalendarData> for DividendCalendarResponse {
+    fn from(value: DividendCalendarData) -> Self {
+        Self {
+            amount: value.amount(),
+            stock_id: value.stock_id,
+            ex_date: value.ex_date.format("%Y-%m-%d").to_string(),
+            record_date: value.record_date.format("%Y-%m-%d").to_string(),
+            payment_date: value.payment_date.format("%Y-%m-%d").to_string(),
+            amount_per_share: value.amount_per_share,
+            stock_count: value.stock_count,
+        }
+    }
+}
@@ -1,21 +1,48 @@
-use applications::company::CompanyData;
+use applications::{company::CompanyData, favorite::FavoriteData, stock::QuoteData};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct FavoriteResponse {
+    pub watchlist_id: i32,
     pub stock_id: String,
     pub name: String,
     pub sector: String,
     pub industry: String,
+    pub sort_order: i32,
+    pub note: String,
+    pub tags: Vec<String>,
+    pub added_at: String,
+    /// 最新の株価の日付(株価情報が無い場合はNone)
+    pub date: Option<String>,
+    pub end_price: Option<Decimal>,
+    pub previous_close: Option<Decimal>,
+    pub change: Option<Decimal>,
+    pub change_rate: Option<f64>,
+    pub volume: Option<i64>,
 }
 
-impl From<CompanyData> for FavoriteResponse {
-    fn from(value: CompanyData) -> Self {
+impl FavoriteResponse {
+    /// コンストラクタ
+    pub fn new(favorite: FavoriteData, company: CompanyData, quote: Option<QuoteData>) -> Self {
         Self {
-            name: value.name,
-            stock_id: value.stock_id.to_string(),
-            sector: value.sector,
-            industry: value.industry,
+            watchlist_id: favorite.watchlist_id.unwrap_or_default(),
+            stock_id: favorite.stock_id,
+            name: company.name,
+            sector: company.sector,
+            industry: company.industry,
+            sort_order: favorite.sort_order,
+            note: favorite.note,
+            tags: favorite.tags,
+            added_at: favorite.added_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            date: quote
+                .as_ref()
+                .map(|q| q.date.format("%Y-%m-%d").to_string()),
+            end_price: quote.as_ref().map(|q| q.end_price),
+            previous_close: quote.as_ref().and_then(|q| q.previous_close),
+            change: quote.as_ref().and_then(|q| q.change()),
+            change_rate: quote.as_ref().and_then(|q| q.change_rate()),
+            volume: quote.as_ref().map(|q| q.volume),
         }
     }
 }
@@ -19,9 +19,9 @@ impl LoginUserId {
     }
 }
 
-impl Into<String> for LoginUserId {
-    fn into(self) -> String {
-        self.0
+impl From<LoginUserId> for String {
+    fn from(value: LoginUserId) -> Self {
+        value.0
     }
 }
 
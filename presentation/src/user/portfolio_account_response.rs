@@ -0,0 +1,19 @@
+use applications::portfolio::PortfolioAccountData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PortfolioAccountResponse {
+    id: i32,
+    name: String,
+    account_type: String,
+}
+
+impl From<PortfolioAccountData> for PortfolioAccountResponse {
+    fn from(value: PortfolioAccountData) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            name: value.name,
+            account_type: value.account_type,
+        }
+    }
+}
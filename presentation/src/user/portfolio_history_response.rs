@@ -0,0 +1,25 @@
+use applications::portfolio::PortfolioHistoryData;
+use serde::{Deserialize, Serialize};
+
+use crate::user::PortfolioValueResponse;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PortfolioHistoryResponse {
+    time_weighted_return: Option<f64>,
+    max_drawdown: Option<f64>,
+    history: Vec<PortfolioValueResponse>,
+}
+
+impl From<PortfolioHistoryData> for PortfolioHistoryResponse {
+    fn from(value: PortfolioHistoryData) -> Self {
+        Self {
+            time_weighted_return: value.time_weighted_return,
+            max_drawdown: value.max_drawdown,
+            history: value
+                .history
+                .into_iter()
+                .map(PortfolioValueResponse::from)
+                .collect(),
+        }
+    }
+}
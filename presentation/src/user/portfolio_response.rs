@@ -1,25 +1,70 @@
 use applications::portfolio::PortfolioData;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+use crate::user::AnnualDividendResponse;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PortfolioResponse {
+    portfolio_id: i32,
     user_id: String,
     stock_id: String,
-    stock_count: i32,
-    purchase: i32,
-    market_price: i32,
+    stock_count: i64,
+    purchase: Decimal,
+    cost_basis_method: String,
+    market_price: Decimal,
     latest_date: String,
+    previous_price: Option<Decimal>,
+    market_value: Decimal,
+    cost_basis: Decimal,
+    unrealized_gain: Decimal,
+    unrealized_gain_rate: Option<f64>,
+    realized_gain: Decimal,
+    day_change: Option<Decimal>,
+    day_change_rate: Option<f64>,
+    expected_annual_dividend: Decimal,
+    yield_on_cost: Option<f64>,
+    received_dividend: Decimal,
+    received_dividends: Vec<AnnualDividendResponse>,
+    /// ポートフォリオ全体の評価額に占める割合(%)
+    weight: Option<f64>,
+}
+
+impl PortfolioResponse {
+    pub fn with_weight(self, weight: Option<f64>) -> Self {
+        Self { weight, ..self }
+    }
 }
 
 impl From<PortfolioData> for PortfolioResponse {
     fn from(value: PortfolioData) -> Self {
         Self {
+            market_value: value.market_value(),
+            cost_basis: value.cost_basis(),
+            unrealized_gain: value.unrealized_gain(),
+            unrealized_gain_rate: value.unrealized_gain_rate(),
+            day_change: value.day_change(),
+            day_change_rate: value.day_change_rate(),
+            expected_annual_dividend: value.expected_annual_dividend(),
+            yield_on_cost: value.yield_on_cost(),
+            received_dividend: value.dividend.received_total(),
+            received_dividends: value
+                .dividend
+                .received
+                .iter()
+                .map(|(year, amount)| AnnualDividendResponse::new(*year, *amount))
+                .collect(),
+            portfolio_id: value.portfolio_id.unwrap_or_default(),
             user_id: value.user_id,
             stock_id: value.stock_id,
             stock_count: value.stock_count,
             purchase: value.purchase,
+            cost_basis_method: value.cost_basis_method.as_str().to_string(),
             market_price: value.market_price,
             latest_date: value.latest_date.format("%Y-%m-%d").to_string(),
+            previous_price: value.previous_price,
+            realized_gain: value.realized_gain,
+            weight: None,
         }
     }
 }
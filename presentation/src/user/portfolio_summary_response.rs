@@ -0,0 +1,50 @@
+use applications::portfolio::PortfolioSummaryData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::user::{AnnualDividendResponse, PortfolioResponse};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PortfolioSummaryResponse {
+    market_value: Decimal,
+    cost_basis: Decimal,
+    unrealized_gain: Decimal,
+    unrealized_gain_rate: Option<f64>,
+    realized_gain: Decimal,
+    day_change: Decimal,
+    day_change_rate: Option<f64>,
+    expected_annual_dividend: Decimal,
+    yield_on_cost: Option<f64>,
+    received_dividend: Decimal,
+    received_dividends: Vec<AnnualDividendResponse>,
+    portfolio: Vec<PortfolioResponse>,
+}
+
+impl From<PortfolioSummaryData> for PortfolioSummaryResponse {
+    fn from(value: PortfolioSummaryData) -> Self {
+        let portfolio = value
+            .portfolio
+            .iter()
+            .map(|p| PortfolioResponse::from(p.clone()).with_weight(value.weight(p)))
+            .collect();
+
+        Self {
+            market_value: value.market_value,
+            cost_basis: value.cost_basis,
+            unrealized_gain: value.unrealized_gain,
+            unrealized_gain_rate: value.unrealized_gain_rate(),
+            realized_gain: value.realized_gain,
+            day_change: value.day_change,
+            day_change_rate: value.day_change_rate(),
+            expected_annual_dividend: value.expected_annual_dividend,
+            yield_on_cost: value.yield_on_cost(),
+            received_dividend: value.received_dividend.values().sum(),
+            received_dividends: value
+                .received_dividend
+                .iter()
+                .map(|(year, amount)| AnnualDividendResponse::new(*year, *amount))
+                .collect(),
+            portfolio,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use applications::portfolio::PortfolioValueData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PortfolioValueResponse {
+    date: String,
+    market_value: Decimal,
+    cost_basis: Decimal,
+    cumulative_return: f64,
+}
+
+impl From<PortfolioValueData> for PortfolioValueResponse {
+    fn from(value: PortfolioValueData) -> Self {
+        Self {
+            date: value.date.format("%Y-%m-%d").to_string(),
+            market_value: value.market_value,
+            cost_basis: value.cost_basis,
+            cumulative_return: value.cumulative_return,
+        }
+    }
+}
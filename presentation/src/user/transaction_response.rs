@@ -0,0 +1,30 @@
+use applications::portfolio::TransactionData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TransactionResponse {
+    id: i32,
+    portfolio_id: i32,
+    stock_id: String,
+    transaction_type: String,
+    date: String,
+    quantity: i64,
+    price: Decimal,
+    fee: Decimal,
+}
+
+impl From<TransactionData> for TransactionResponse {
+    fn from(value: TransactionData) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            portfolio_id: value.portfolio_id.unwrap_or_default(),
+            stock_id: value.stock_id,
+            transaction_type: value.transaction_type,
+            date: value.date.format("%Y-%m-%d").to_string(),
+            quantity: value.quantity,
+            price: value.price,
+            fee: value.fee,
+        }
+    }
+}
@@ -1,23 +1,39 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use axum::{
     extract::{Path, Query, State},
     response::{IntoResponse, Response},
-    routing::{get, post},
+    routing::{get, patch, post},
     Json, Router,
 };
+use serde::Deserialize;
 
 use crate::{
+    benchmark::{benchmark_query_command, BenchmarkResponse},
     common::{ApiResult, AppState, AppStateImpl},
     user::LoginUserId,
 };
 use applications::{
-    favorite::FavoriteData,
-    portfolio::{PortfolioApplicationError, PortfolioData, PortfolioUpdateCommand},
+    alert::{AlertApplicationError, AlertRuleData, AlertRuleUpdateCommand},
+    company::CompanyData,
+    favorite::{
+        FavoriteApplicationError, FavoriteData, FavoriteUpdateCommand, WatchlistData,
+    },
+    portfolio::{
+        PortfolioAccountData, PortfolioAccountUpdateCommand, PortfolioApplicationError,
+        PortfolioData, PortfolioSummaryData, PortfolioUpdateCommand, TransactionData,
+        TransactionUpdateCommand,
+    },
+    stock::QuoteData,
     user::UserApplicationError,
+    webhook::{WebhookApplicationError, WebhookData, WebhookUpdateCommand},
 };
 
-use crate::user::{FavoriteResponse, PortfolioResponse, UserResponse};
+use crate::user::{
+    AlertEventResponse, AlertRuleResponse, DividendCalendarResponse, FavoriteResponse, PortfolioAccountResponse,
+    PortfolioHistoryResponse, PortfolioSummaryResponse, TransactionResponse, UserResponse,
+    WatchlistResponse, WebhookDeliveryResponse, WebhookResponse,
+};
 
 pub fn user_controller(state: AppStateImpl) -> Router {
     let user_route = Router::new()
@@ -25,20 +41,123 @@ pub fn user_controller(state: AppStateImpl) -> Router {
         .route("/favorites", get(get_favorites))
         .route(
             "/favorites/:stock_id",
-            post(insert_favorite).delete(delete_favorite),
+            post(insert_favorite)
+                .patch(update_favorite)
+                .delete(delete_favorite),
+        )
+        .route("/watchlists", get(get_watchlists).post(insert_watchlist))
+        .route(
+            "/watchlists/:watchlist_id",
+            get(get_favorites)
+                .patch(update_watchlist)
+                .delete(delete_watchlist),
+        )
+        .route(
+            "/watchlists/:watchlist_id/:stock_id",
+            post(insert_favorite)
+                .patch(update_favorite)
+                .delete(delete_favorite),
+        )
+        .route("/alerts", get(get_alerts).post(insert_alert))
+        .route("/alerts/inbox", get(get_alert_events))
+        .route("/alerts/inbox/:event_id", patch(update_alert_event))
+        .route("/alerts/:alert_id", patch(update_alert).delete(delete_alert))
+        .route("/webhooks", get(get_webhooks).post(insert_webhook))
+        .route(
+            "/webhooks/:webhook_id",
+            patch(update_webhook).delete(delete_webhook),
+        )
+        .route(
+            "/webhooks/:webhook_id/deliveries",
+            get(get_webhook_deliveries),
         )
         .route("/portfolio", get(get_portfolio))
+        .route("/portfolio/history", get(get_portfolio_history))
+        .route("/portfolio/benchmark", get(get_portfolio_benchmark))
+        .route("/portfolio/dividends", get(get_dividend_calendar))
         .route(
             "/portfolio/:stock_id",
             post(insert_portfolio)
                 .patch(update_portfolio)
                 .delete(delete_portfolio),
         )
+        .route(
+            "/portfolio/:stock_id/transactions",
+            get(get_transactions).post(insert_transaction),
+        )
+        .route(
+            "/portfolio/:stock_id/transactions/:transaction_id",
+            patch(update_transaction).delete(delete_transaction),
+        )
+        .route(
+            "/portfolios",
+            get(get_portfolio_accounts).post(insert_portfolio_account),
+        )
+        .route(
+            "/portfolios/:portfolio_id",
+            get(get_portfolio)
+                .patch(update_portfolio_account)
+                .delete(delete_portfolio_account),
+        )
+        .route("/portfolios/:portfolio_id/history", get(get_portfolio_history))
+        .route(
+            "/portfolios/:portfolio_id/benchmark",
+            get(get_portfolio_benchmark),
+        )
+        .route(
+            "/portfolios/:portfolio_id/dividends",
+            get(get_dividend_calendar),
+        )
+        .route(
+            "/portfolios/:portfolio_id/:stock_id",
+            post(insert_portfolio)
+                .patch(update_portfolio)
+                .delete(delete_portfolio),
+        )
+        .route(
+            "/portfolios/:portfolio_id/:stock_id/transactions",
+            get(get_transactions).post(insert_transaction),
+        )
+        .route(
+            "/portfolios/:portfolio_id/:stock_id/transactions/:transaction_id",
+            patch(update_transaction).delete(delete_transaction),
+        )
         .with_state(state);
 
     Router::new().nest("/me", user_route)
 }
 
+/// ポートフォリオのパスパラメータ(portfolio_idが無い場合は既定のポートフォリオ)
+#[derive(Debug, Deserialize)]
+struct PortfolioPath {
+    portfolio_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PositionPath {
+    portfolio_id: Option<i32>,
+    stock_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransactionPath {
+    portfolio_id: Option<i32>,
+    stock_id: String,
+    transaction_id: i32,
+}
+
+/// ウォッチリストのパスパラメータ(watchlist_idが無い場合は既定のウォッチリスト)
+#[derive(Debug, Deserialize)]
+struct WatchlistPath {
+    watchlist_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WatchlistEntryPath {
+    watchlist_id: Option<i32>,
+    stock_id: String,
+}
+
 #[tracing::instrument(skip(state), err)]
 async fn get_user(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
     let user = state
@@ -53,22 +172,42 @@ async fn get_user(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult
 }
 
 #[tracing::instrument(skip(state), err)]
-async fn get_favorites(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
-    let stock_id_list = state
+async fn get_favorites(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<WatchlistPath>,
+) -> ApiResult<Response> {
+    let favorites = state
         .favorite_service()
-        .get_all(&user_id)
-        .await?
+        .get_all(&user_id, path.watchlist_id)
+        .await?;
+    let stock_id_list: Vec<String> = favorites
         .iter()
         .map(|favo| favo.stock_id.to_string())
         .collect();
 
-    let result = state
+    let mut companies: HashMap<String, CompanyData> = state
         .company_query_service()
-        .find_list(stock_id_list)
-        .await?;
-    let result: Vec<FavoriteResponse> = result
+        .find_list(stock_id_list.clone())
+        .await?
+        .into_iter()
+        .map(|c| (c.stock_id.clone(), c))
+        .collect();
+    let mut quotes: HashMap<String, QuoteData> = state
+        .stock_query_service()
+        .find_latest_many(stock_id_list)
+        .await?
+        .into_iter()
+        .map(|q| (q.stock_id.clone(), q))
+        .collect();
+    // ウォッチリストの表示順で返す
+    let result: Vec<FavoriteResponse> = favorites
         .into_iter()
-        .map(|c| FavoriteResponse::from(c))
+        .filter_map(|favo| {
+            let company = companies.remove(&favo.stock_id)?;
+            let quote = quotes.remove(&favo.stock_id);
+            Some(FavoriteResponse::new(favo, company, quote))
+        })
         .collect();
 
     Ok(Json(result).into_response())
@@ -78,10 +217,24 @@ async fn get_favorites(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiR
 async fn insert_favorite(
     state: State<AppStateImpl>,
     user_id: LoginUserId,
-    Path(stock_id): Path<String>,
+    Path(path): Path<WatchlistEntryPath>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> ApiResult<Response> {
-    let favorite = FavoriteData::new(user_id.to_string(), stock_id);
+    let mut favorite = FavoriteData::new(user_id.to_string(), path.stock_id);
+    favorite.watchlist_id = path.watchlist_id;
+    if let Some(note) = params.get("note") {
+        favorite.note = note.clone();
+    }
+    if let Some(tags) = params.get("tags") {
+        favorite.tags = split_tags(tags);
+    }
+    let data = serde_json::json!({
+        "action": "added",
+        "watchlist_id": favorite.watchlist_id,
+        "stock_id": favorite.stock_id,
+    });
     state.favorite_service().add(favorite).await?;
+    publish_webhook(&state, &user_id, "favorite", data).await;
 
     Ok(Json(serde_json::json!({
         "message": "succeed in regist favorite"
@@ -89,14 +242,51 @@ async fn insert_favorite(
     .into_response())
 }
 
+#[tracing::instrument(skip(state), err)]
+async fn update_favorite(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<WatchlistEntryPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = FavoriteUpdateCommand::new(user_id.to_string(), path.stock_id);
+    command.watchlist_id = path.watchlist_id;
+    if let Some(sort_order) = params.get("sort_order") {
+        let Ok(sort_order) = sort_order.parse() else { return Err(FavoriteApplicationError::InvalidParameter { name: "sort_order", value: sort_order.clone() }.into()) };
+        command.sort_order = Some(sort_order);
+    }
+    command.note = params.get("note").cloned();
+    command.tags = params.get("tags").map(|tags| split_tags(tags));
+    let data = serde_json::json!({
+        "action": "updated",
+        "watchlist_id": command.watchlist_id,
+        "stock_id": command.stock_id,
+    });
+
+    state.favorite_service().update(command).await?;
+    publish_webhook(&state, &user_id, "favorite", data).await;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in update favorite"
+    }))
+    .into_response())
+}
+
 #[tracing::instrument(skip(state), err)]
 async fn delete_favorite(
     state: State<AppStateImpl>,
     user_id: LoginUserId,
-    Path(stock_id): Path<String>,
+    Path(path): Path<WatchlistEntryPath>,
 ) -> ApiResult<Response> {
-    let favorite = FavoriteData::new(user_id.to_string(), stock_id);
+    let mut favorite = FavoriteData::new(user_id.to_string(), path.stock_id);
+    favorite.watchlist_id = path.watchlist_id;
+    let data = serde_json::json!({
+        "action": "removed",
+        "watchlist_id": favorite.watchlist_id,
+        "stock_id": favorite.stock_id,
+    });
     state.favorite_service().remove(favorite).await?;
+    publish_webhook(&state, &user_id, "favorite", data).await;
 
     Ok(Json(serde_json::json!({
         "message": "succeed in delete favorite"
@@ -105,14 +295,76 @@ async fn delete_favorite(
 }
 
 #[tracing::instrument(skip(state), err)]
-async fn get_portfolio(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
-    let portfolio = state.portfolio_service().get_all(&user_id).await?;
+async fn get_portfolio(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PortfolioPath>,
+) -> ApiResult<Response> {
+    let portfolio = state
+        .portfolio_service()
+        .get_all(&user_id, path.portfolio_id)
+        .await?;
+
+    let result = PortfolioSummaryData::new(portfolio);
+    let result = PortfolioSummaryResponse::from(result);
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_portfolio_history(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PortfolioPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let start = parse_param(&params, "start")?;
+    let end = parse_param(&params, "end")?;
 
-    let result = portfolio
+    let result = state
+        .portfolio_service()
+        .get_history(&user_id, path.portfolio_id, start, end)
+        .await?;
+    let result = PortfolioHistoryResponse::from(result);
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_dividend_calendar(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PortfolioPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let start = parse_param(&params, "start")?;
+    let end = parse_param(&params, "end")?;
+
+    let result: Vec<DividendCalendarResponse> = state
+        .portfolio_service()
+        .get_dividend_calendar(&user_id, path.portfolio_id, start, end)
+        .await?
         .into_iter()
-        .map(|p| PortfolioResponse::from(p))
+        .map(DividendCalendarResponse::from)
         .collect();
-    let result: Vec<PortfolioResponse> = result;
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state, params), err)]
+async fn get_portfolio_benchmark(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PortfolioPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let params = benchmark_query_command(&params)?;
+
+    let result = state
+        .benchmark_service()
+        .compare_portfolio(&user_id, path.portfolio_id, params)
+        .await?;
+    let result = BenchmarkResponse::from(result);
 
     Ok(Json(result).into_response())
 }
@@ -121,10 +373,12 @@ async fn get_portfolio(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiR
 async fn insert_portfolio(
     state: State<AppStateImpl>,
     user_id: LoginUserId,
-    Path(stock_id): Path<String>,
+    Path(path): Path<PositionPath>,
 ) -> ApiResult<Response> {
-    let portfolio = PortfolioData::new(user_id.to_string(), stock_id);
+    let mut portfolio = PortfolioData::new(user_id.to_string(), path.stock_id.clone());
+    portfolio.portfolio_id = path.portfolio_id;
     state.portfolio_service().add(portfolio).await?;
+    publish_portfolio_webhook(&state, &user_id, "added", &path).await;
 
     Ok(Json(serde_json::json!({
         "message": "succeed in regist portfolio"
@@ -136,7 +390,7 @@ async fn insert_portfolio(
 async fn update_portfolio(
     state: State<AppStateImpl>,
     user_id: LoginUserId,
-    Path(stock_id): Path<String>,
+    Path(path): Path<PositionPath>,
     Query(params): Query<HashMap<String, String>>,
 ) -> ApiResult<Response> {
     let purchase = if let Some(purchase) = params.get("purchase") {
@@ -151,9 +405,12 @@ async fn update_portfolio(
     } else {
         None
     };
-    let command = PortfolioUpdateCommand::new(user_id.to_string(), stock_id, purchase, stock_count);
+    let mut command = PortfolioUpdateCommand::new(user_id.to_string(), path.stock_id.clone(), purchase, stock_count);
+    command.portfolio_id = path.portfolio_id;
+    command.cost_basis_method = params.get("cost_basis_method").cloned();
 
     state.portfolio_service().update(command).await?;
+    publish_portfolio_webhook(&state, &user_id, "updated", &path).await;
 
     Ok(Json(serde_json::json!({
         "message": "succeed in update portfolio"
@@ -165,15 +422,473 @@ async fn update_portfolio(
 async fn delete_portfolio(
     state: State<AppStateImpl>,
     user_id: LoginUserId,
-    Path(stock_id): Path<String>,
+    Path(path): Path<PositionPath>,
 ) -> ApiResult<Response> {
     state
         .portfolio_service()
-        .remove(&user_id, &stock_id)
+        .remove(&user_id, path.portfolio_id, &path.stock_id)
         .await?;
+    publish_portfolio_webhook(&state, &user_id, "removed", &path).await;
 
     Ok(Json(serde_json::json!({
         "message": "succeed in update portfolio"
     }))
     .into_response())
 }
+
+#[tracing::instrument(skip(state), err)]
+async fn get_transactions(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PositionPath>,
+) -> ApiResult<Response> {
+    let result: Vec<TransactionResponse> = state
+        .portfolio_service()
+        .get_transactions(&user_id, path.portfolio_id, &path.stock_id)
+        .await?
+        .into_iter()
+        .map(TransactionResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn insert_transaction(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<PositionPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut transaction = TransactionData::new(user_id.to_string(), path.stock_id.clone());
+    transaction.portfolio_id = path.portfolio_id;
+    // 手数料以外は必須
+    let Some(transaction_type) = params.get("transaction_type") else { return Err(PortfolioApplicationError::InvalidParameter { name: "transaction_type", value: String::new() }.into()) };
+    transaction.transaction_type = transaction_type.clone();
+    let Some(date) = parse_param(&params, "date")? else { return Err(PortfolioApplicationError::InvalidParameter { name: "date", value: String::new() }.into()) };
+    transaction.date = date;
+    let Some(quantity) = parse_param(&params, "quantity")? else { return Err(PortfolioApplicationError::InvalidParameter { name: "quantity", value: String::new() }.into()) };
+    transaction.quantity = quantity;
+    let Some(price) = parse_param(&params, "price")? else { return Err(PortfolioApplicationError::InvalidParameter { name: "price", value: String::new() }.into()) };
+    transaction.price = price;
+    transaction.fee = parse_param(&params, "fee")?.unwrap_or_default();
+
+    let result = state
+        .portfolio_service()
+        .add_transaction(transaction)
+        .await?;
+    publish_portfolio_webhook(&state, &user_id, "transaction_added", &path).await;
+
+    Ok(Json(TransactionResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_transaction(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<TransactionPath>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = TransactionUpdateCommand::new(user_id.to_string(), path.stock_id.clone(), path.transaction_id);
+    command.portfolio_id = path.portfolio_id;
+    command.transaction_type = params.get("transaction_type").cloned();
+    command.date = parse_param(&params, "date")?;
+    command.quantity = parse_param(&params, "quantity")?;
+    command.price = parse_param(&params, "price")?;
+    command.fee = parse_param(&params, "fee")?;
+
+    let result = state
+        .portfolio_service()
+        .update_transaction(command)
+        .await?;
+    let position = PositionPath { portfolio_id: path.portfolio_id, stock_id: path.stock_id };
+    publish_portfolio_webhook(&state, &user_id, "transaction_updated", &position).await;
+
+    Ok(Json(TransactionResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn delete_transaction(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(path): Path<TransactionPath>,
+) -> ApiResult<Response> {
+    state
+        .portfolio_service()
+        .remove_transaction(&user_id, path.portfolio_id, &path.stock_id, path.transaction_id)
+        .await?;
+    let position = PositionPath { portfolio_id: path.portfolio_id, stock_id: path.stock_id };
+    publish_portfolio_webhook(&state, &user_id, "transaction_removed", &position).await;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in delete transaction"
+    }))
+    .into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_portfolio_accounts(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+) -> ApiResult<Response> {
+    let result: Vec<PortfolioAccountResponse> = state
+        .portfolio_service()
+        .get_accounts(&user_id)
+        .await?
+        .into_iter()
+        .map(PortfolioAccountResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn insert_portfolio_account(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let Some(name) = params.get("name") else { return Err(PortfolioApplicationError::InvalidParameter { name: "name", value: String::new() }.into()) };
+    let mut account = PortfolioAccountData::new(user_id.to_string(), name.clone());
+    if let Some(account_type) = params.get("account_type") {
+        account.account_type = account_type.clone();
+    }
+
+    let result = state.portfolio_service().add_account(account).await?;
+
+    Ok(Json(PortfolioAccountResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_portfolio_account(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(portfolio_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = PortfolioAccountUpdateCommand::new(user_id.to_string(), portfolio_id);
+    command.name = params.get("name").cloned();
+    command.account_type = params.get("account_type").cloned();
+
+    let result = state.portfolio_service().update_account(command).await?;
+
+    Ok(Json(PortfolioAccountResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn delete_portfolio_account(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(portfolio_id): Path<i32>,
+) -> ApiResult<Response> {
+    state
+        .portfolio_service()
+        .remove_account(&user_id, portfolio_id)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in delete portfolio"
+    }))
+    .into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_watchlists(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
+    let result: Vec<WatchlistResponse> = state
+        .favorite_service()
+        .get_watchlists(&user_id)
+        .await?
+        .into_iter()
+        .map(WatchlistResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn insert_watchlist(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let Some(name) = params.get("name") else { return Err(FavoriteApplicationError::InvalidParameter { name: "name", value: String::new() }.into()) };
+    let watchlist = WatchlistData::new(user_id.to_string(), name.clone());
+
+    let result = state.favorite_service().add_watchlist(watchlist).await?;
+
+    Ok(Json(WatchlistResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_watchlist(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(watchlist_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let Some(name) = params.get("name") else { return Err(FavoriteApplicationError::InvalidParameter { name: "name", value: String::new() }.into()) };
+
+    let result = state
+        .favorite_service()
+        .rename_watchlist(&user_id, watchlist_id, name.clone())
+        .await?;
+
+    Ok(Json(WatchlistResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn delete_watchlist(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(watchlist_id): Path<i32>,
+) -> ApiResult<Response> {
+    state
+        .favorite_service()
+        .remove_watchlist(&user_id, watchlist_id)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in delete watchlist"
+    }))
+    .into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_alerts(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
+    let result: Vec<AlertRuleResponse> = state
+        .alert_service()
+        .get_rules(&user_id)
+        .await?
+        .into_iter()
+        .map(AlertRuleResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn insert_alert(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let Some(stock_id) = params.get("stock_id") else { return Err(AlertApplicationError::InvalidParameter { name: "stock_id", value: String::new() }.into()) };
+    let Some(condition) = params.get("condition") else { return Err(AlertApplicationError::InvalidParameter { name: "condition", value: String::new() }.into()) };
+    let rule = AlertRuleData::new(
+        user_id.to_string(),
+        stock_id.clone(),
+        condition.clone(),
+        parse_alert_param(&params, "threshold")?,
+    );
+
+    let result = state.alert_service().add_rule(rule).await?;
+
+    Ok(Json(AlertRuleResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_alert(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(alert_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = AlertRuleUpdateCommand::new(user_id.to_string(), alert_id);
+    command.condition = params.get("condition").cloned();
+    command.threshold = parse_alert_param(&params, "threshold")?;
+    command.enabled = parse_alert_param(&params, "enabled")?;
+
+    let result = state.alert_service().update_rule(command).await?;
+
+    Ok(Json(AlertRuleResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn delete_alert(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(alert_id): Path<i32>,
+) -> ApiResult<Response> {
+    state.alert_service().remove_rule(&user_id, alert_id).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in delete alert"
+    }))
+    .into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_alert_events(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let unread_only = parse_alert_param(&params, "unread")?.unwrap_or(false);
+
+    let result: Vec<AlertEventResponse> = state
+        .alert_service()
+        .get_events(&user_id, unread_only)
+        .await?
+        .into_iter()
+        .map(AlertEventResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_alert_event(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(event_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let read = parse_alert_param(&params, "read")?.unwrap_or(true);
+
+    let result = state
+        .alert_service()
+        .mark_read(&user_id, event_id, read)
+        .await?;
+
+    Ok(Json(AlertEventResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_webhooks(state: State<AppStateImpl>, user_id: LoginUserId) -> ApiResult<Response> {
+    let result: Vec<WebhookResponse> = state
+        .webhook_service()
+        .get_webhooks(&user_id)
+        .await?
+        .into_iter()
+        .map(WebhookResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn insert_webhook(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let Some(url) = params.get("url") else { return Err(WebhookApplicationError::InvalidParameter { name: "url", value: String::new() }.into()) };
+    let Some(events) = params.get("events") else { return Err(WebhookApplicationError::InvalidParameter { name: "events", value: String::new() }.into()) };
+    let webhook = WebhookData::new(user_id.to_string(), url.clone(), split_events(events));
+
+    let result = state.webhook_service().add_webhook(webhook).await?;
+
+    Ok(Json(WebhookResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn update_webhook(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(webhook_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<Response> {
+    let mut command = WebhookUpdateCommand::new(user_id.to_string(), webhook_id);
+    command.url = params.get("url").cloned();
+    command.events = params.get("events").map(|events| split_events(events));
+    if let Some(enabled) = params.get("enabled") {
+        let Ok(enabled) = enabled.parse() else { return Err(WebhookApplicationError::InvalidParameter { name: "enabled", value: enabled.clone() }.into()) };
+        command.enabled = Some(enabled);
+    }
+
+    let result = state.webhook_service().update_webhook(command).await?;
+
+    Ok(Json(WebhookResponse::from(result)).into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn delete_webhook(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(webhook_id): Path<i32>,
+) -> ApiResult<Response> {
+    state
+        .webhook_service()
+        .remove_webhook(&user_id, webhook_id)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "succeed in delete webhook"
+    }))
+    .into_response())
+}
+
+#[tracing::instrument(skip(state), err)]
+async fn get_webhook_deliveries(
+    state: State<AppStateImpl>,
+    user_id: LoginUserId,
+    Path(webhook_id): Path<i32>,
+) -> ApiResult<Response> {
+    let result: Vec<WebhookDeliveryResponse> = state
+        .webhook_service()
+        .get_deliveries(&user_id, webhook_id)
+        .await?
+        .into_iter()
+        .map(WebhookDeliveryResponse::from)
+        .collect();
+
+    Ok(Json(result).into_response())
+}
+
+/// Webhookの送信キューに登録する(登録に失敗しても元の処理は成功として扱う)
+async fn publish_webhook(state: &AppStateImpl, user_id: &str, event: &str, data: serde_json::Value) {
+    if let Err(e) = state.webhook_service().publish(user_id, event, data).await {
+        tracing::warn!("failed to publish {} webhook: {}", event, e);
+    }
+}
+
+/// ポートフォリオの変更をWebhookの送信キューに登録する
+async fn publish_portfolio_webhook(
+    state: &AppStateImpl,
+    user_id: &str,
+    action: &str,
+    path: &PositionPath,
+) {
+    let data = serde_json::json!({
+        "action": action,
+        "portfolio_id": path.portfolio_id,
+        "stock_id": path.stock_id,
+    });
+    publish_webhook(state, user_id, "portfolio", data).await;
+}
+
+/// カンマ区切りのイベントの種別を分割する
+fn split_events(events: &str) -> Vec<String> {
+    events
+        .split(',')
+        .map(|event| event.trim().to_string())
+        .filter(|event| !event.is_empty())
+        .collect()
+}
+
+/// 指定されていればアラートのクエリパラメータを変換する
+fn parse_alert_param<T: FromStr>(
+    params: &HashMap<String, String>,
+    name: &'static str,
+) -> ApiResult<Option<T>> {
+    let Some(value) = params.get(name) else { return Ok(None) };
+    let Ok(value) = value.parse() else { return Err(AlertApplicationError::InvalidParameter { name, value: value.clone() }.into()) };
+
+    Ok(Some(value))
+}
+
+/// カンマ区切りのタグを分割する
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',').map(|tag| tag.to_string()).collect()
+}
+
+/// 指定されていればクエリパラメータを変換する(日付はYYYY-MM-DD形式)
+fn parse_param<T: FromStr>(
+    params: &HashMap<String, String>,
+    name: &'static str,
+) -> ApiResult<Option<T>> {
+    let Some(value) = params.get(name) else { return Ok(None) };
+    let Ok(value) = value.parse() else { return Err(PortfolioApplicationError::InvalidParameter { name, value: value.clone() }.into()) };
+
+    Ok(Some(value))
+}
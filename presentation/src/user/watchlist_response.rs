@@ -0,0 +1,17 @@
+use applications::favorite::WatchlistData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WatchlistResponse {
+    id: i32,
+    name: String,
+}
+
+impl From<WatchlistData> for WatchlistResponse {
+    fn from(value: WatchlistData) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            name: value.name,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use applications::webhook::WebhookDeliveryData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    id: i32,
+    webhook_id: i32,
+    event: String,
+    payload: serde_json::Value,
+    status: String,
+    attempts: i32,
+    next_attempt_at: String,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    created_at: String,
+    delivered_at: Option<String>,
+}
+
+impl From<WebhookDeliveryData> for WebhookDeliveryResponse {
+    fn from(value: WebhookDeliveryData) -> Self {
+        Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            event: value.event,
+            payload: serde_json::from_str(&value.payload)
+                .unwrap_or(serde_json::Value::String(value.payload)),
+            status: value.status,
+            attempts: value.attempts,
+            next_attempt_at: value
+                .next_attempt_at
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: value.created_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            delivered_at: value
+                .delivered_at
+                .map(|d| d.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+        }
+    }
+}
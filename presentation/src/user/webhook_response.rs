@@ -0,0 +1,23 @@
+use applications::webhook::WebhookData;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    id: i32,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+    enabled: bool,
+}
+
+impl From<WebhookData> for WebhookResponse {
+    fn from(value: WebhookData) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            url: value.url,
+            secret: value.secret,
+            events: value.events,
+            enabled: value.enabled,
+        }
+    }
+}
@@ -0,0 +1,1103 @@
+//! CSVファイルから株価・企業情報・株式分割・配当金・株価指数を、EDINETの提出書類から財務諸表をデータベースに取り込む
+//! また、取り込んだ株価の欠損・異常値を検査する
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use chrono::NaiveDate;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use dotenvy::{self, dotenv};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use applications::{
+    alert::{AlertService, AlertServiceImpl},
+    company::{CompanyData, CompanyImportService, CompanyQueryError, CompanyQueryService},
+    corporate_action::{
+        CorporateActionApplicationError, CorporateActionData, CorporateActionService,
+        CorporateActionServiceImpl,
+    },
+    data_quality::{DataQualityCheckCommand, DataQualityService, DataQualityServiceImpl},
+    dividend::{DividendApplicationError, DividendData, DividendService, DividendServiceImpl},
+    financial_statement::{
+        FinancialStatementApplicationError, FinancialStatementService,
+        FinancialStatementServiceImpl,
+    },
+    market_index::{
+        IndexPriceData, MarketIndexData, MarketIndexImportService, MarketIndexQueryError,
+        MarketIndexQueryService,
+    },
+    stock::{StockData, StockImportService},
+    webhook::{WebhookService, WebhookServiceImpl},
+};
+use domain::{
+    corporate_action::CorporateAction, dividend::Dividend, trading_calendar::TradingCalendar,
+    user::UserDomainService,
+};
+use financial_report::load_trading_calendar;
+use infrastructures::{
+    alert::PostgresAlertRepositoryImpl,
+    company::{PostgresCompanyImportServiceImpl, PostgresCompanyQueryServiceImpl},
+    corporate_action::PostgresCorporateActionRepositoryImpl,
+    dividend::PostgresDividendRepositoryImpl,
+    favorite::PostgresFavoriteRepositoryImpl,
+    financial_statement::{read_edinet_filing, PostgresFinancialStatementRepositoryImpl},
+    market_index::{PostgresMarketIndexImportServiceImpl, PostgresMarketIndexQueryServiceImpl},
+    stock::{PostgresStockImportServiceImpl, PostgresStockQueryServiceImpl},
+    user::PostgresUserRepositoryImpl,
+    webhook::{HttpWebhookSenderImpl, PostgresWebhookRepositoryImpl},
+};
+use presentation::admin::DataQualityReportResponse;
+
+/// 1回の登録処理でまとめて送信する行数の上限
+///
+/// PostgreSQLのバインド変数の上限(65535個)を1行のバインド変数の最大数(株価の7項目)で割った行数
+const MAX_BATCH_SIZE: i64 = 65535 / 7;
+
+#[derive(Debug, Parser)]
+#[command(about = "株価・企業情報・配当金・株価指数・財務諸表をデータベースに取り込む")]
+struct Cli {
+    #[command(subcommand)]
+    target: ImportTarget,
+}
+
+#[derive(Debug, Subcommand)]
+enum ImportTarget {
+    /// 日次株価(OHLCV)の取り込み
+    Stocks(ImportArgs),
+    /// 企業マスタの取り込み
+    Companies(ImportArgs),
+    /// 登録済みの企業名から検索用に正規化した値を作り直す
+    SearchNames,
+    /// 株式分割・併合の取り込み
+    CorporateActions(ImportArgs),
+    /// 配当金の取り込み
+    Dividends(ImportArgs),
+    /// 株価指数マスタの取り込み
+    Indices(ImportArgs),
+    /// 株価指数の日次の値の取り込み
+    IndexPrices(ImportArgs),
+    /// EDINETの提出書類(XBRL/CSV)から財務諸表の取り込み
+    Financials(FilingArgs),
+    /// 株価の欠損・異常値を検査し、結果をJSONで出力する
+    DataQuality(DataQualityArgs),
+}
+
+#[derive(Debug, Args)]
+struct ImportArgs {
+    /// 取り込むCSVファイル
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// CSVファイルの文字コード
+    #[arg(long, value_enum, default_value_t = FileEncoding::Auto)]
+    encoding: FileEncoding,
+    /// 項目名とCSVの列名の対応付け (例: --column end_price=終値)
+    #[arg(long = "column", value_name = "FIELD=HEADER", value_parser = parse_column)]
+    columns: Vec<(String, String)>,
+    /// 1回の登録処理でまとめて送信する行数
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u16).range(1..=MAX_BATCH_SIZE))]
+    batch_size: u16,
+    /// 検証のみ行い、データベースへの登録は行わない
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Args)]
+struct FilingArgs {
+    /// 取り込むXBRLインスタンス(.xbrl)またはXBRLをCSVに変換したファイル(.csv)
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// 検証のみ行い、データベースへの登録は行わない
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Args)]
+struct DataQualityArgs {
+    /// 検査する銘柄の証券コード(指定しない場合は全銘柄)
+    #[arg(long)]
+    stock_id: Option<String>,
+    /// 検査期間の開始日 (例: 2023-01-04)
+    #[arg(long)]
+    start: Option<NaiveDate>,
+    /// 検査期間の終了日
+    #[arg(long)]
+    end: Option<NaiveDate>,
+    /// 前営業日からの終値の変動率の上限 (例: 0.3 = 30%)
+    #[arg(long)]
+    max_change_rate: Option<f64>,
+    /// 臨時休場などを記載した営業日カレンダーのファイル(指定しない場合は環境変数 TRADING_CALENDAR)
+    #[arg(long)]
+    calendar: Option<PathBuf>,
+    /// 問題が見つかった場合はエラー終了する
+    #[arg(long)]
+    fail_on_issues: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FileEncoding {
+    /// UTF-8として読めない場合はShift_JISとして読み込む
+    Auto,
+    #[value(name = "utf-8")]
+    Utf8,
+    #[value(name = "shift_jis")]
+    ShiftJis,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // .envファイルを読み込み
+    dotenv().ok();
+    // Default Logger初期化
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            dotenvy::var("RUST_LOG").unwrap_or_else(|_| "warn".to_string()),
+        ))
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let cli = Cli::parse();
+    let rejected_count = match cli.target {
+        ImportTarget::Stocks(args) => import_stocks(args).await?,
+        ImportTarget::Companies(args) => import_companies(args).await?,
+        ImportTarget::SearchNames => refresh_search_names().await?,
+        ImportTarget::CorporateActions(args) => import_corporate_actions(args).await?,
+        ImportTarget::Dividends(args) => import_dividends(args).await?,
+        ImportTarget::Indices(args) => import_indices(args).await?,
+        ImportTarget::IndexPrices(args) => import_index_prices(args).await?,
+        ImportTarget::Financials(args) => import_financial_statements(args).await?,
+        ImportTarget::DataQuality(args) => check_data_quality(args).await?,
+    };
+
+    if rejected_count > 0 {
+        return Err(anyhow!("{rejected_count} records were rejected"));
+    }
+
+    Ok(())
+}
+
+/// 株価の取り込み
+async fn import_stocks(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(StockData::FIELDS, &args.columns)?;
+    let services = if args.dry_run {
+        None
+    } else {
+        let connection = connect().await?;
+        let user_domain_service = UserDomainService::new(&Arc::new(
+            PostgresUserRepositoryImpl::new(connection.clone()),
+        ));
+        let alert_service = AlertServiceImpl::new(
+            &Arc::new(PostgresAlertRepositoryImpl::new(connection.clone())),
+            PostgresStockQueryServiceImpl::new(connection.clone(), load_trading_calendar()?),
+            user_domain_service.clone(),
+        );
+        let webhook_service = WebhookServiceImpl::new(
+            &Arc::new(PostgresWebhookRepositoryImpl::new(connection.clone())),
+            &Arc::new(PostgresFavoriteRepositoryImpl::new(connection.clone())),
+            HttpWebhookSenderImpl::new()?,
+            user_domain_service,
+        );
+        Some((
+            PostgresStockImportServiceImpl::new(connection.clone()),
+            PostgresCompanyQueryServiceImpl::new(connection),
+            alert_service,
+            webhook_service,
+        ))
+    };
+
+    let mut rejected_count = 0;
+    let mut imported_ids = HashSet::new();
+    for path in args.files.iter() {
+        let mut parsed = read_csv_file::<StockData>(path, args.encoding, &mapping)?;
+        if let Some((_, company_query_service, _, _)) = &services {
+            reject_unregistered_companies(&mut parsed, company_query_service).await?;
+        }
+        rejected_count += parsed.report(path);
+
+        let Some((service, _, _, _)) = &services else {
+            continue;
+        };
+        let mut affected = 0;
+        for batch in parsed.records.chunks(args.batch_size as usize) {
+            affected += service
+                .upsert(batch.iter().map(|(_, stock)| stock.clone()).collect())
+                .await
+                .with_context(|| format!("failed to upsert stocks: {}", path.display()))?;
+        }
+        println!("{}: {} rows upserted", path.display(), affected);
+        imported_ids.extend(parsed.records.iter().map(|(_, stock)| stock.stock_id.clone()));
+    }
+
+    // 取り込んだ銘柄の最新の株価でアラートを判定し、お気に入りに登録しているユーザーに通知する
+    if let Some((_, _, alert_service, webhook_service)) = &services {
+        let imported_ids: Vec<String> = imported_ids.into_iter().collect();
+        let triggered = alert_service
+            .evaluate(imported_ids.clone())
+            .await
+            .context("failed to evaluate alerts")?;
+        println!("{} alerts triggered", triggered.len());
+
+        let queued = webhook_service
+            .publish_price_update(imported_ids)
+            .await
+            .context("failed to publish price webhooks")?;
+        println!("{} webhooks queued", queued);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 企業情報の取り込み
+async fn import_companies(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(CompanyData::FIELDS, &args.columns)?;
+    let service = if args.dry_run {
+        None
+    } else {
+        Some(PostgresCompanyImportServiceImpl::new(connect().await?))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let parsed = read_csv_file::<CompanyData>(path, args.encoding, &mapping)?;
+        rejected_count += parsed.report(path);
+
+        let Some(service) = &service else { continue };
+        let mut affected = 0;
+        for batch in parsed.records.chunks(args.batch_size as usize) {
+            affected += service
+                .upsert(batch.iter().map(|(_, company)| company.clone()).collect())
+                .await
+                .with_context(|| format!("failed to upsert companies: {}", path.display()))?;
+        }
+        println!("{}: {} rows upserted", path.display(), affected);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 企業名の検索用の値の再作成
+async fn refresh_search_names() -> anyhow::Result<usize> {
+    let service = PostgresCompanyImportServiceImpl::new(connect().await?);
+    let affected = service
+        .refresh_search_names()
+        .await
+        .context("failed to refresh search names")?;
+    println!("{} companies updated", affected);
+
+    Ok(0)
+}
+
+/// 株式分割・併合の取り込み
+async fn import_corporate_actions(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(CorporateActionData::FIELDS, &args.columns)?;
+    let service = if args.dry_run {
+        None
+    } else {
+        let connection = connect().await?;
+        let repository = Arc::new(PostgresCorporateActionRepositoryImpl::new(
+            connection.clone(),
+        ));
+        Some(CorporateActionServiceImpl::new(
+            &repository,
+            PostgresCompanyQueryServiceImpl::new(connection),
+        ))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let mut parsed = read_csv_file::<CorporateActionData>(path, args.encoding, &mapping)?;
+
+        if let Some(service) = &service {
+            let mut saved = vec![];
+            for (line, corporate_action) in std::mem::take(&mut parsed.records) {
+                match service.save(corporate_action.clone()).await {
+                    Ok(()) => saved.push((line, corporate_action)),
+                    Err(CorporateActionApplicationError::Disconnect(e)) => {
+                        return Err(e.context(format!(
+                            "failed to save corporate actions: {}",
+                            path.display()
+                        )));
+                    }
+                    Err(e) => parsed.rejected.push((line, e.to_string())),
+                }
+            }
+            parsed.records = saved;
+            parsed.rejected.sort_by_key(|(line, _)| *line);
+        }
+        rejected_count += parsed.report(path);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 配当金の取り込み
+async fn import_dividends(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(DividendData::FIELDS, &args.columns)?;
+    let service = if args.dry_run {
+        None
+    } else {
+        let connection = connect().await?;
+        let repository = Arc::new(PostgresDividendRepositoryImpl::new(connection.clone()));
+        Some(DividendServiceImpl::new(
+            &repository,
+            PostgresCompanyQueryServiceImpl::new(connection),
+        ))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let mut parsed = read_csv_file::<DividendData>(path, args.encoding, &mapping)?;
+
+        if let Some(service) = &service {
+            let mut saved = vec![];
+            for (line, dividend) in std::mem::take(&mut parsed.records) {
+                match service.save(dividend.clone()).await {
+                    Ok(()) => saved.push((line, dividend)),
+                    Err(DividendApplicationError::Disconnect(e)) => {
+                        return Err(
+                            e.context(format!("failed to save dividends: {}", path.display()))
+                        );
+                    }
+                    Err(e) => parsed.rejected.push((line, e.to_string())),
+                }
+            }
+            parsed.records = saved;
+            parsed.rejected.sort_by_key(|(line, _)| *line);
+        }
+        rejected_count += parsed.report(path);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 株価指数マスタの取り込み
+async fn import_indices(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(MarketIndexData::FIELDS, &args.columns)?;
+    let service = if args.dry_run {
+        None
+    } else {
+        Some(PostgresMarketIndexImportServiceImpl::new(connect().await?))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let parsed = read_csv_file::<MarketIndexData>(path, args.encoding, &mapping)?;
+        rejected_count += parsed.report(path);
+
+        let Some(service) = &service else { continue };
+        let mut affected = 0;
+        for batch in parsed.records.chunks(args.batch_size as usize) {
+            affected += service
+                .upsert_indices(batch.iter().map(|(_, index)| index.clone()).collect())
+                .await
+                .with_context(|| format!("failed to upsert indices: {}", path.display()))?;
+        }
+        println!("{}: {} rows upserted", path.display(), affected);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 株価指数の日次の値の取り込み
+async fn import_index_prices(args: ImportArgs) -> anyhow::Result<usize> {
+    let mapping = ColumnMapping::new(IndexPriceData::FIELDS, &args.columns)?;
+    let services = if args.dry_run {
+        None
+    } else {
+        let connection = connect().await?;
+        Some((
+            PostgresMarketIndexImportServiceImpl::new(connection.clone()),
+            PostgresMarketIndexQueryServiceImpl::new(connection),
+        ))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let mut parsed = read_csv_file::<IndexPriceData>(path, args.encoding, &mapping)?;
+        if let Some((_, query_service)) = &services {
+            reject_unregistered_indices(&mut parsed, query_service).await?;
+        }
+        rejected_count += parsed.report(path);
+
+        let Some((service, _)) = &services else {
+            continue;
+        };
+        let mut affected = 0;
+        for batch in parsed.records.chunks(args.batch_size as usize) {
+            affected += service
+                .upsert_prices(batch.iter().map(|(_, price)| price.clone()).collect())
+                .await
+                .with_context(|| format!("failed to upsert index prices: {}", path.display()))?;
+        }
+        println!("{}: {} rows upserted", path.display(), affected);
+    }
+
+    Ok(rejected_count)
+}
+
+/// 財務諸表の取り込み (1ファイル1決算期)
+async fn import_financial_statements(args: FilingArgs) -> anyhow::Result<usize> {
+    let service = if args.dry_run {
+        None
+    } else {
+        let connection = connect().await?;
+        let repository = Arc::new(PostgresFinancialStatementRepositoryImpl::new(
+            connection.clone(),
+        ));
+        Some(FinancialStatementServiceImpl::new(
+            &repository,
+            PostgresCompanyQueryServiceImpl::new(connection),
+        ))
+    };
+
+    let mut rejected_count = 0;
+    for path in args.files.iter() {
+        let statement = match read_edinet_filing(path) {
+            Ok(statement) => statement,
+            Err(e) => {
+                eprintln!("{}: {:#}", path.display(), e);
+                rejected_count += 1;
+                continue;
+            }
+        };
+        let summary = format!(
+            "stock_id={} period={} {}..{}",
+            statement.stock_id, statement.period_type, statement.period_start, statement.period_end
+        );
+
+        let Some(service) = &service else {
+            println!("{}: {}", path.display(), summary);
+            continue;
+        };
+        match service.save(statement).await {
+            Ok(()) => println!("{}: {} saved", path.display(), summary),
+            Err(FinancialStatementApplicationError::Disconnect(e)) => {
+                return Err(e.context(format!(
+                    "failed to save financial statement: {}",
+                    path.display()
+                )));
+            }
+            Err(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                rejected_count += 1;
+            }
+        }
+    }
+
+    Ok(rejected_count)
+}
+
+/// 株価の欠損・異常値の検査
+async fn check_data_quality(args: DataQualityArgs) -> anyhow::Result<usize> {
+    let calendar = match &args.calendar {
+        Some(path) => TradingCalendar::load(path)
+            .with_context(|| format!("failed to load trading calendar: {}", path.display()))?,
+        None => load_trading_calendar()?,
+    };
+    let connection = connect().await?;
+    let service = DataQualityServiceImpl::new(
+        PostgresCompanyQueryServiceImpl::new(connection.clone()),
+        PostgresStockQueryServiceImpl::new(connection, calendar.clone()),
+        calendar,
+    );
+
+    let mut command = DataQualityCheckCommand::new();
+    command.stock_id = args.stock_id;
+    command.start = args.start;
+    command.end = args.end;
+    if let Some(max_change_rate) = args.max_change_rate {
+        command.max_change_rate = max_change_rate;
+    }
+    let report = service
+        .check(command)
+        .await
+        .context("failed to check data quality")?;
+
+    let issue_count = report.issues.len();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&DataQualityReportResponse::from(report))?
+    );
+
+    if args.fail_on_issues && issue_count > 0 {
+        return Err(anyhow!("{issue_count} data quality issues were found"));
+    }
+
+    Ok(0)
+}
+
+/// 企業情報が未登録の株価は登録できないため取り込み対象から除外する
+async fn reject_unregistered_companies(
+    parsed: &mut ParsedCsv<StockData>,
+    company_query_service: &impl CompanyQueryService,
+) -> anyhow::Result<()> {
+    let stock_id_list: HashSet<String> = parsed
+        .records
+        .iter()
+        .map(|(_, stock)| stock.stock_id.clone())
+        .collect();
+    let registered: HashSet<String> = company_query_service
+        .find_list(stock_id_list.into_iter().collect())
+        .await?
+        .into_iter()
+        .map(|company| company.stock_id)
+        .collect();
+
+    let (records, unregistered): (Vec<_>, Vec<_>) = std::mem::take(&mut parsed.records)
+        .into_iter()
+        .partition(|(_, stock)| registered.contains(&stock.stock_id));
+    parsed.records = records;
+    parsed
+        .rejected
+        .extend(unregistered.into_iter().map(|(line, stock)| {
+            (
+                line,
+                CompanyQueryError::CompanyNotFound(stock.stock_id).to_string(),
+            )
+        }));
+    parsed.rejected.sort_by_key(|(line, _)| *line);
+
+    Ok(())
+}
+
+/// 指数マスタに登録されていない指数の値を取り込み対象から除く
+async fn reject_unregistered_indices(
+    parsed: &mut ParsedCsv<IndexPriceData>,
+    query_service: &impl MarketIndexQueryService,
+) -> anyhow::Result<()> {
+    let registered: HashSet<String> = query_service
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|index| index.index_id)
+        .collect();
+
+    let (records, unregistered): (Vec<_>, Vec<_>) = std::mem::take(&mut parsed.records)
+        .into_iter()
+        .partition(|(_, price)| registered.contains(&price.index_id));
+    parsed.records = records;
+    parsed
+        .rejected
+        .extend(unregistered.into_iter().map(|(line, price)| {
+            (
+                line,
+                MarketIndexQueryError::MarketIndexNotFound(price.index_id).to_string(),
+            )
+        }));
+    parsed.rejected.sort_by_key(|(line, _)| *line);
+
+    Ok(())
+}
+
+async fn connect() -> anyhow::Result<sqlx::PgPool> {
+    let pg_connection = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL").context("DATABASE_URL does not exist")?)
+        .await?;
+
+    Ok(pg_connection)
+}
+
+fn parse_column(value: &str) -> Result<(String, String), String> {
+    let Some((field, header)) = value.split_once('=') else {
+        return Err(format!("expected FIELD=HEADER: {value}"));
+    };
+
+    Ok((field.trim().to_string(), header.trim().to_string()))
+}
+
+/// CSVの1行から変換可能なデータ
+trait CsvRecord: Sized {
+    /// 読み込む項目名
+    const FIELDS: &'static [&'static str];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String>;
+    /// 重複チェック用のキー
+    fn key(&self) -> String;
+}
+
+impl CsvRecord for StockData {
+    const FIELDS: &'static [&'static str] = &[
+        "stock_id",
+        "date",
+        "start_price",
+        "end_price",
+        "high_price",
+        "low_price",
+        "volume",
+    ];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let stock = StockData {
+            stock_id: row.get("stock_id").to_string(),
+            date: row.date("date")?,
+            volume: row.number("volume")?,
+            start_price: row.number("start_price")?,
+            end_price: row.number("end_price")?,
+            high_price: row.number("high_price")?,
+            low_price: row.number("low_price")?,
+        };
+        stock.validate().map_err(|e| e.to_string())?;
+
+        Ok(stock)
+    }
+
+    fn key(&self) -> String {
+        format!("{}/{}", self.stock_id, self.date)
+    }
+}
+
+impl CsvRecord for CompanyData {
+    const FIELDS: &'static [&'static str] = &["stock_id", "name", "sector", "industry"];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let company = CompanyData {
+            name: row.get("name").to_string(),
+            stock_id: row.get("stock_id").to_string(),
+            sector: row.get("sector").to_string(),
+            industry: row.get("industry").to_string(),
+        };
+        company.validate().map_err(|e| e.to_string())?;
+
+        Ok(company)
+    }
+
+    fn key(&self) -> String {
+        self.stock_id.clone()
+    }
+}
+
+impl CsvRecord for CorporateActionData {
+    const FIELDS: &'static [&'static str] = &["stock_id", "ex_date", "ratio_from", "ratio_to"];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let corporate_action = CorporateActionData {
+            stock_id: row.get("stock_id").to_string(),
+            ex_date: row.date("ex_date")?,
+            ratio_from: row.number("ratio_from")?,
+            ratio_to: row.number("ratio_to")?,
+        };
+        CorporateAction::try_from(corporate_action.clone()).map_err(|e| e.to_string())?;
+
+        Ok(corporate_action)
+    }
+
+    fn key(&self) -> String {
+        format!("{}/{}", self.stock_id, self.ex_date)
+    }
+}
+
+impl CsvRecord for DividendData {
+    const FIELDS: &'static [&'static str] = &[
+        "stock_id",
+        "ex_date",
+        "record_date",
+        "payment_date",
+        "amount_per_share",
+    ];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let dividend = DividendData {
+            stock_id: row.get("stock_id").to_string(),
+            ex_date: row.date("ex_date")?,
+            record_date: row.date("record_date")?,
+            payment_date: row.date("payment_date")?,
+            amount_per_share: row.number("amount_per_share")?,
+        };
+        Dividend::try_from(dividend.clone()).map_err(|e| e.to_string())?;
+
+        Ok(dividend)
+    }
+
+    fn key(&self) -> String {
+        format!("{}/{}", self.stock_id, self.ex_date)
+    }
+}
+
+impl CsvRecord for MarketIndexData {
+    const FIELDS: &'static [&'static str] = &["index_id", "name"];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let index = MarketIndexData {
+            index_id: row.get("index_id").to_string(),
+            name: row.get("name").to_string(),
+        };
+        index.validate().map_err(|e| e.to_string())?;
+
+        Ok(index)
+    }
+
+    fn key(&self) -> String {
+        self.index_id.clone()
+    }
+}
+
+impl CsvRecord for IndexPriceData {
+    const FIELDS: &'static [&'static str] = &[
+        "index_id",
+        "date",
+        "start_price",
+        "end_price",
+        "high_price",
+        "low_price",
+    ];
+
+    fn from_row(row: &CsvRow) -> Result<Self, String> {
+        let price = IndexPriceData {
+            index_id: row.get("index_id").to_string(),
+            date: row.date("date")?,
+            start_price: row.number("start_price")?,
+            end_price: row.number("end_price")?,
+            high_price: row.number("high_price")?,
+            low_price: row.number("low_price")?,
+        };
+        price.validate().map_err(|e| e.to_string())?;
+
+        Ok(price)
+    }
+
+    fn key(&self) -> String {
+        format!("{}/{}", self.index_id, self.date)
+    }
+}
+
+/// 項目名とCSVの列名の対応
+#[derive(Debug)]
+struct ColumnMapping {
+    headers: Vec<(&'static str, String)>,
+}
+
+impl ColumnMapping {
+    /// コンストラクタ
+    ///
+    /// 指定の無い項目は項目名をそのまま列名として扱う
+    fn new(fields: &[&'static str], columns: &[(String, String)]) -> anyhow::Result<Self> {
+        if let Some((field, _)) = columns.iter().find(|(f, _)| !fields.contains(&f.as_str())) {
+            return Err(anyhow!(
+                "unknown field: {field} (expected one of {})",
+                fields.join(", ")
+            ));
+        }
+
+        let headers = fields
+            .iter()
+            .map(|field| {
+                let header = columns
+                    .iter()
+                    .rev()
+                    .find(|(f, _)| f == field)
+                    .map(|(_, header)| header.clone())
+                    .unwrap_or_else(|| field.to_string());
+                (*field, header)
+            })
+            .collect();
+
+        Ok(Self { headers })
+    }
+
+    /// ヘッダー行から各項目の列番号を取得する
+    fn indices(
+        &self,
+        header_record: &csv::StringRecord,
+    ) -> anyhow::Result<HashMap<&'static str, usize>> {
+        let mut indices = HashMap::new();
+        for (field, header) in self.headers.iter() {
+            let index = header_record
+                .iter()
+                .position(|h| h.trim() == header)
+                .ok_or_else(|| anyhow!("column not found: {header} (field {field})"))?;
+            indices.insert(*field, index);
+        }
+
+        Ok(indices)
+    }
+}
+
+/// CSVの1行
+struct CsvRow<'a> {
+    record: &'a csv::StringRecord,
+    indices: &'a HashMap<&'static str, usize>,
+}
+
+impl CsvRow<'_> {
+    fn get(&self, field: &str) -> &str {
+        self.indices
+            .get(field)
+            .and_then(|index| self.record.get(*index))
+            .unwrap_or_default()
+            .trim()
+    }
+
+    /// 桁区切りのカンマを含む数値も受け付ける
+    fn number<T: FromStr>(&self, field: &str) -> Result<T, String> {
+        let value = self.get(field);
+        value
+            .replace(',', "")
+            .parse()
+            .map_err(|_| format!("invalid parameter: {field}={value}"))
+    }
+
+    fn date(&self, field: &str) -> Result<NaiveDate, String> {
+        let value = self.get(field);
+        ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"]
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+            .ok_or_else(|| format!("invalid parameter: {field}={value}"))
+    }
+}
+
+/// 読み込み結果
+#[derive(Debug)]
+struct ParsedCsv<T> {
+    /// 取り込む行(行番号, データ)
+    records: Vec<(u64, T)>,
+    /// 取り込めなかった行(行番号, 理由)
+    rejected: Vec<(u64, String)>,
+}
+
+impl<T> ParsedCsv<T> {
+    /// 読み込み結果を出力し、取り込めなかった行数を返す
+    fn report(&self, path: &Path) -> usize {
+        for (line, reason) in self.rejected.iter() {
+            eprintln!("{}:{}: {}", path.display(), line, reason);
+        }
+        println!(
+            "{}: {} rows accepted, {} rows rejected",
+            path.display(),
+            self.records.len(),
+            self.rejected.len()
+        );
+
+        self.rejected.len()
+    }
+}
+
+fn read_csv_file<T: CsvRecord>(
+    path: &Path,
+    encoding: FileEncoding,
+    mapping: &ColumnMapping,
+) -> anyhow::Result<ParsedCsv<T>> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let text =
+        decode(&bytes, encoding).with_context(|| format!("failed to decode {}", path.display()))?;
+
+    parse_csv(&text, mapping).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// 文字コードを変換する
+fn decode(bytes: &[u8], encoding: FileEncoding) -> anyhow::Result<String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let shift_jis = |bytes: &[u8]| {
+        encoding_rs::SHIFT_JIS
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .map(|text| text.into_owned())
+            .ok_or_else(|| anyhow!("invalid Shift_JIS sequence"))
+    };
+
+    match encoding {
+        FileEncoding::Utf8 => Ok(std::str::from_utf8(bytes)?.to_string()),
+        FileEncoding::ShiftJis => shift_jis(bytes),
+        FileEncoding::Auto => match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(text.to_string()),
+            Err(_) => shift_jis(bytes),
+        },
+    }
+}
+
+fn parse_csv<T: CsvRecord>(text: &str, mapping: &ColumnMapping) -> anyhow::Result<ParsedCsv<T>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(text.as_bytes());
+    let indices = mapping.indices(reader.headers()?)?;
+
+    let mut parsed = ParsedCsv {
+        records: vec![],
+        rejected: vec![],
+    };
+    // 同一キーの行が複数ある場合は最初の行を採用する
+    let mut defined_lines = HashMap::new();
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or_default();
+                parsed.rejected.push((line, e.to_string()));
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        let row = CsvRow {
+            record: &record,
+            indices: &indices,
+        };
+
+        match T::from_row(&row) {
+            Ok(data) => match defined_lines.entry(data.key()) {
+                Entry::Occupied(defined) => parsed.rejected.push((
+                    line,
+                    format!("duplicated row: first defined at line {}", defined.get()),
+                )),
+                Entry::Vacant(entry) => {
+                    entry.insert(line);
+                    parsed.records.push((line, data));
+                }
+            },
+            Err(reason) => parsed.rejected.push((line, reason)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use applications::{
+        company::CompanyData, dividend::DividendData, market_index::IndexPriceData,
+        stock::StockData,
+    };
+
+    use clap::Parser;
+
+    use super::{decode, parse_csv, Cli, ColumnMapping, CsvRecord, FileEncoding, MAX_BATCH_SIZE};
+
+    #[test]
+    fn batch_size_within_bind_limit() {
+        let parse = |batch_size: i64| {
+            Cli::try_parse_from([
+                "import",
+                "stocks",
+                "--batch-size",
+                &batch_size.to_string(),
+                "stocks.csv",
+            ])
+        };
+
+        assert!(parse(MAX_BATCH_SIZE).is_ok());
+        assert!(parse(MAX_BATCH_SIZE + 1).is_err());
+        assert!(parse(0).is_err());
+    }
+
+    #[test]
+    fn decode_shift_jis() -> anyhow::Result<()> {
+        let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode("証券コード,企業名");
+
+        assert!(decode(&bytes, FileEncoding::Auto)? == "証券コード,企業名");
+        assert!(decode(&bytes, FileEncoding::ShiftJis)? == "証券コード,企業名");
+        assert!(decode(&bytes, FileEncoding::Utf8).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_utf8_with_bom() -> anyhow::Result<()> {
+        let bytes = "\u{feff}証券コード".as_bytes();
+
+        assert!(decode(bytes, FileEncoding::Auto)? == "証券コード");
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_stocks_with_mapping() -> anyhow::Result<()> {
+        let columns = vec![
+            ("stock_id".to_string(), "コード".to_string()),
+            ("date".to_string(), "日付".to_string()),
+            ("start_price".to_string(), "始値".to_string()),
+            ("end_price".to_string(), "終値".to_string()),
+            ("high_price".to_string(), "高値".to_string()),
+            ("low_price".to_string(), "安値".to_string()),
+            ("volume".to_string(), "出来高".to_string()),
+        ];
+        let mapping = ColumnMapping::new(StockData::FIELDS, &columns)?;
+        let text = "コード,日付,始値,高値,安値,終値,出来高\n\
+            7203,2022/12/01,100,120,90,110,\"1,000\"\n\
+            7203,2022/12/02,100,80,90,110,1000\n\
+            7203,2022/12/01,100,120,90,110,1000\n\
+            7203,2022/12/05,abc,120,90,110,1000\n";
+
+        let parsed = parse_csv::<StockData>(text, &mapping)?;
+
+        assert!(parsed.records.len() == 1);
+        assert!(parsed.records[0].1.volume == 1000);
+        assert!(
+            parsed
+                .rejected
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>()
+                == vec![3, 4, 5]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_index_prices() -> anyhow::Result<()> {
+        let mapping = ColumnMapping::new(IndexPriceData::FIELDS, &[])?;
+        let text = "index_id,date,start_price,end_price,high_price,low_price\n\
+            TOPIX,2022/12/01,\"1,990.50\",2000.25,2005.75,1985.00\n\
+            TOPIX,2022/12/02,1990.5,2000.25,1980.0,1985.0\n";
+
+        let parsed = parse_csv::<IndexPriceData>(text, &mapping)?;
+
+        assert!(parsed.records.len() == 1);
+        assert!(parsed.records[0].1.start_price == Decimal::new(19905, 1));
+        assert!(parsed.records[0].1.end_price == Decimal::new(200025, 2));
+        assert!(
+            parsed
+                .rejected
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>()
+                == vec![3]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_dividends() -> anyhow::Result<()> {
+        let mapping = ColumnMapping::new(DividendData::FIELDS, &[])?;
+        let text = "stock_id,ex_date,record_date,payment_date,amount_per_share\n\
+            8058,2023/03/30,2023/03/31,2023/06/26,85.5\n\
+            8058,2023/09/28,2023/09/29,2023/09/01,70\n\
+            8058,2023/12/28,2023/12/29,2024/03/01,0\n";
+
+        let parsed = parse_csv::<DividendData>(text, &mapping)?;
+
+        assert!(parsed.records.len() == 1);
+        assert!(parsed.records[0].1.amount_per_share == Decimal::new(855, 1));
+        assert!(
+            parsed
+                .rejected
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>()
+                == vec![3, 4]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_companies_missing_column_return_err() -> anyhow::Result<()> {
+        let mapping = ColumnMapping::new(CompanyData::FIELDS, &[])?;
+        let text = "stock_id,name,sector\n7203,トヨタ自動車,輸送用機器\n";
+
+        assert!(parse_csv::<CompanyData>(text, &mapping).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_field_mapping_return_err() {
+        let columns = vec![("unknown".to_string(), "列".to_string())];
+
+        assert!(ColumnMapping::new(CompanyData::FIELDS, &columns).is_err());
+    }
+}
@@ -10,18 +10,28 @@ use dotenvy::{self, dotenv};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use applications::{
-    favorite::FavoriteServiceImpl, portfolio::PortfolioServiceImpl, user::UserServiceImpl,
+    alert::AlertServiceImpl, benchmark::BenchmarkServiceImpl,
+    data_quality::DataQualityServiceImpl, favorite::FavoriteServiceImpl,
+    financial_statement::FinancialStatementServiceImpl, portfolio::PortfolioServiceImpl,
+    stock::IndicatorServiceImpl, user::UserServiceImpl, webhook::WebhookServiceImpl,
 };
 use domain::user::UserDomainService;
-use financial_report::init_app;
+use financial_report::{init_app, load_trading_calendar, spawn_webhook_worker};
 use infrastructures::{
+    alert::PostgresAlertRepositoryImpl,
     auth::{OICDClient, OICDserviceImpl},
     company::PostgresCompanyQueryServiceImpl,
+    corporate_action::PostgresCorporateActionRepositoryImpl,
+    dividend::PostgresDividendRepositoryImpl,
     favorite::PostgresFavoriteRepositoryImpl,
+    financial_statement::PostgresFinancialStatementRepositoryImpl,
+    market_index::PostgresMarketIndexQueryServiceImpl,
     portfolio::PostgresPortfolioRepositoryImpl,
+    screener::PostgresScreenerQueryServiceImpl,
     session::{SessionRepositoryImpl, SessionServiceImpl},
     stock::PostgresStockQueryServiceImpl,
     user::PostgresUserRepositoryImpl,
+    webhook::{HttpWebhookSenderImpl, PostgresWebhookRepositoryImpl},
 };
 use presentation::common::AppStateImpl;
 
@@ -51,6 +61,7 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
     let state = setup_state().await?;
+    spawn_webhook_worker(state.clone());
 
     let app = init_app(state);
 
@@ -87,7 +98,10 @@ async fn setup_state() -> anyhow::Result<AppStateImpl> {
     .await?;
     let oicd_service = OICDserviceImpl::new(oicd_client);
 
-    let stock_query_service = PostgresStockQueryServiceImpl::new(pg_connection.clone());
+    let trading_calendar = load_trading_calendar()?;
+    let stock_query_service =
+        PostgresStockQueryServiceImpl::new(pg_connection.clone(), trading_calendar.clone());
+    let indicator_service = IndicatorServiceImpl::new(stock_query_service.clone());
 
     let company_query_service = PostgresCompanyQueryServiceImpl::new(pg_connection.clone());
 
@@ -96,21 +110,75 @@ async fn setup_state() -> anyhow::Result<AppStateImpl> {
     let favorite_service =
         FavoriteServiceImpl::new(&favorite_repository, user_domain_service.clone());
 
-    let portfolio_repository = Arc::new(PostgresPortfolioRepositoryImpl::new(pg_connection));
+    let portfolio_repository =
+        Arc::new(PostgresPortfolioRepositoryImpl::new(pg_connection.clone()));
+    let dividend_repository = Arc::new(PostgresDividendRepositoryImpl::new(pg_connection.clone()));
+    let corporate_action_repository = Arc::new(PostgresCorporateActionRepositoryImpl::new(
+        pg_connection.clone(),
+    ));
     let portfolio_service = PortfolioServiceImpl::new(
         &portfolio_repository,
+        &dividend_repository,
+        &corporate_action_repository,
         stock_query_service.clone(),
+        user_domain_service.clone(),
+    );
+
+    let alert_repository = Arc::new(PostgresAlertRepositoryImpl::new(pg_connection.clone()));
+    let alert_service = AlertServiceImpl::new(
+        &alert_repository,
+        stock_query_service.clone(),
+        user_domain_service.clone(),
+    );
+
+    let webhook_repository = Arc::new(PostgresWebhookRepositoryImpl::new(pg_connection.clone()));
+    let webhook_service = WebhookServiceImpl::new(
+        &webhook_repository,
+        &favorite_repository,
+        HttpWebhookSenderImpl::new()?,
         user_domain_service,
     );
 
+    let financial_statement_repository = Arc::new(PostgresFinancialStatementRepositoryImpl::new(
+        pg_connection.clone(),
+    ));
+    let financial_statement_service = FinancialStatementServiceImpl::new(
+        &financial_statement_repository,
+        company_query_service.clone(),
+    );
+
+    let screener_query_service = PostgresScreenerQueryServiceImpl::new(pg_connection.clone());
+
+    let data_quality_service = DataQualityServiceImpl::new(
+        company_query_service.clone(),
+        stock_query_service.clone(),
+        trading_calendar,
+    );
+
+    let market_index_query_service = PostgresMarketIndexQueryServiceImpl::new(pg_connection);
+    let benchmark_service = BenchmarkServiceImpl::new(
+        market_index_query_service.clone(),
+        stock_query_service.clone(),
+        portfolio_service.clone(),
+    );
+
     let state = AppStateImpl::new(
         Arc::new(user_service),
         Arc::new(session_service),
         Arc::new(oicd_service),
         Arc::new(stock_query_service),
+        Arc::new(indicator_service),
         Arc::new(company_query_service),
         Arc::new(favorite_service),
         Arc::new(portfolio_service),
+        Arc::new(financial_statement_service),
+        Arc::new(market_index_query_service),
+        Arc::new(benchmark_service),
+        Arc::new(alert_service),
+        Arc::new(webhook_service),
+        Arc::new(screener_query_service),
+        Arc::new(data_quality_service),
+        dotenvy::var("ADMIN_TOKEN").ok(),
     );
 
     Ok(state)
@@ -10,17 +10,29 @@ use dotenvy::{self, dotenv};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use applications::{
+    alert::{AlertServiceImpl, InmemoryAlertRepositoryImpl},
+    benchmark::BenchmarkServiceImpl,
     company::InmemoryCompanyQueryServiceImpl,
+    corporate_action::InmemoryCorporateActionRepositoryImpl,
+    data_quality::DataQualityServiceImpl,
+    dividend::InmemoryDividendRepositoryImpl,
     favorite::{FavoriteServiceImpl, InmemoryFavoriteRepositoryImpl},
+    financial_statement::{
+        FinancialStatementServiceImpl, InmemoryFinancialStatementRepositoryImpl,
+    },
+    market_index::InmemoryMarketIndexQueryServiceImpl,
     portfolio::{InmemoryPortfolioRepositoryImpl, PortfolioServiceImpl},
-    stock::InmemoryStockQueryServiceImpl,
+    screener::InmemoryScreenerQueryServiceImpl,
+    stock::{IndicatorServiceImpl, InmemoryStockQueryServiceImpl},
     user::{InmemoryUserRepositoryImpl, UserServiceImpl},
+    webhook::{InmemoryWebhookRepositoryImpl, WebhookServiceImpl},
 };
 use domain::user::UserDomainService;
-use financial_report::init_app;
+use financial_report::{init_app, load_trading_calendar, spawn_webhook_worker};
 use infrastructures::{
     auth::{OICDClient, OICDserviceImpl},
     session::{SessionRepositoryImpl, SessionServiceImpl},
+    webhook::HttpWebhookSenderImpl,
 };
 use presentation::common::AppStateImpl;
 
@@ -50,6 +62,7 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
     let state = setup_state().await?;
+    spawn_webhook_worker(state.clone());
 
     let app = init_app(state);
 
@@ -82,30 +95,83 @@ async fn setup_state() -> anyhow::Result<AppStateImpl> {
     .await?;
     let oicd_service = OICDserviceImpl::new(oicd_client);
 
-    let stock_query_service = InmemoryStockQueryServiceImpl::new();
+    let trading_calendar = load_trading_calendar()?;
+    let stock_query_service = InmemoryStockQueryServiceImpl {
+        trading_calendar: trading_calendar.clone(),
+        ..Default::default()
+    };
+    let indicator_service = IndicatorServiceImpl::new(stock_query_service.clone());
 
     let company_query_service = InmemoryCompanyQueryServiceImpl::new();
 
+    let screener_query_service = InmemoryScreenerQueryServiceImpl::new();
+
+    let data_quality_service = DataQualityServiceImpl::new(
+        company_query_service.clone(),
+        stock_query_service.clone(),
+        trading_calendar,
+    );
+
     let favorite_repository = Arc::new(InmemoryFavoriteRepositoryImpl::new());
     let user_domain_service = UserDomainService::new(&user_repository);
     let favorite_service =
         FavoriteServiceImpl::new(&favorite_repository, user_domain_service.clone());
 
     let portfolio_repository = Arc::new(InmemoryPortfolioRepositoryImpl::new());
+    let dividend_repository = Arc::new(InmemoryDividendRepositoryImpl::new());
     let portfolio_service = PortfolioServiceImpl::new(
         &portfolio_repository,
+        &dividend_repository,
+        &Arc::new(InmemoryCorporateActionRepositoryImpl::new()),
+        stock_query_service.clone(),
+        user_domain_service.clone(),
+    );
+
+    let alert_repository = Arc::new(InmemoryAlertRepositoryImpl::new());
+    let alert_service = AlertServiceImpl::new(
+        &alert_repository,
         stock_query_service.clone(),
+        user_domain_service.clone(),
+    );
+
+    let webhook_repository = Arc::new(InmemoryWebhookRepositoryImpl::new());
+    let webhook_service = WebhookServiceImpl::new(
+        &webhook_repository,
+        &favorite_repository,
+        HttpWebhookSenderImpl::new()?,
         user_domain_service,
     );
 
+    let financial_statement_repository = Arc::new(InmemoryFinancialStatementRepositoryImpl::new());
+    let financial_statement_service = FinancialStatementServiceImpl::new(
+        &financial_statement_repository,
+        company_query_service.clone(),
+    );
+
+    let market_index_query_service = InmemoryMarketIndexQueryServiceImpl::new();
+    let benchmark_service = BenchmarkServiceImpl::new(
+        market_index_query_service.clone(),
+        stock_query_service.clone(),
+        portfolio_service.clone(),
+    );
+
     let state = AppStateImpl::new(
         Arc::new(user_service),
         Arc::new(session_service),
         Arc::new(oicd_service),
         Arc::new(stock_query_service),
+        Arc::new(indicator_service),
         Arc::new(company_query_service),
         Arc::new(favorite_service),
         Arc::new(portfolio_service),
+        Arc::new(financial_statement_service),
+        Arc::new(market_index_query_service),
+        Arc::new(benchmark_service),
+        Arc::new(alert_service),
+        Arc::new(webhook_service),
+        Arc::new(screener_query_service),
+        Arc::new(data_quality_service),
+        dotenvy::var("ADMIN_TOKEN").ok(),
     );
 
     Ok(state)
@@ -1,15 +1,46 @@
+use std::time::Duration;
+
+use anyhow::Context;
 use axum::{middleware, Router};
+use domain::trading_calendar::TradingCalendar;
 use presentation::{
-    common::{api_controllers, AppStateImpl},
+    common::{api_controllers, AppState, AppStateImpl},
     session::session_manage_layer,
 };
+use tokio::task::JoinHandle;
+
+/// Webhookの送信待ちを確認する間隔(秒)
+const WEBHOOK_DELIVERY_INTERVAL_SECONDS: u64 = 10;
 
 /// アプリケーション初期化
 pub fn init_app(state: AppStateImpl) -> Router {
-    let app = api_controllers(state.clone()).layer(middleware::from_fn_with_state(
+    api_controllers(state.clone()).layer(middleware::from_fn_with_state(
         state.clone(),
         session_manage_layer,
-    ));
+    ))
+}
+
+/// 営業日カレンダーを読み込む
+///
+/// 環境変数 TRADING_CALENDAR にファイルが指定されている場合は、臨時休場などの上書きを反映する
+pub fn load_trading_calendar() -> anyhow::Result<TradingCalendar> {
+    let Ok(path) = dotenvy::var("TRADING_CALENDAR") else {
+        return Ok(TradingCalendar::new());
+    };
+
+    TradingCalendar::load(&path).with_context(|| format!("failed to load trading calendar: {path}"))
+}
 
-    app
+/// Webhookの送信待ちを定期的に送信するタスクを起動する
+pub fn spawn_webhook_worker(state: AppStateImpl) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(WEBHOOK_DELIVERY_INTERVAL_SECONDS));
+        loop {
+            interval.tick().await;
+            if let Err(e) = state.webhook_service().deliver_pending().await {
+                tracing::error!("failed to deliver webhooks: {}", e);
+            }
+        }
+    })
 }